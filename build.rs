@@ -1,9 +1,20 @@
-use std::{env, path::Path};
+#[cfg(feature = "linked")]
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::Command,
+};
 
 fn main() {
+    println!("cargo:rustc-check-cfg=cfg(vp_ash_video_queue)");
+
     #[cfg(feature = "linked")]
     link_vulkan_profiles();
 
+    // Without a library to inspect, only bind the video queue functions when requested.
+    #[cfg(all(not(feature = "linked"), feature = "VK_KHR_video_queue"))]
+    println!("cargo:rustc-cfg=vp_ash_video_queue");
+
     // Add 'fake' Vulkan Functions for testing.
     #[cfg(feature = "test")]
     link_mock_vulkan()
@@ -11,8 +22,7 @@ fn main() {
 
 #[cfg(feature = "test")]
 fn link_mock_vulkan() {
-    let vulkan_sdk = Path::new(env!("VULKAN_SDK"));
-    let vulkan_include_dir = vulkan_sdk.join("Include");
+    let vulkan_include_dir = vulkan_include_dir();
 
     cc::Build::new()
         .file("tests/common/mock_vulkan_api.cpp")
@@ -27,77 +37,392 @@ fn link_vulkan_profiles() {
     // Rerun on env change.
     println!("cargo:rerun-if-env-changed=VULKAN_SDK");
     println!("cargo:rerun-if-env-changed=VULKAN_PROFILES_PATH");
+    println!("cargo:rerun-if-env-changed=PKG_CONFIG_PATH");
 
-    // Load the paths from env.
-    let vulkan_sdk = Path::new(env!("VULKAN_SDK"));
-    let profiles_dir = Path::new(env!("VULKAN_PROFILES_PATH"));
-
-    // If test/example flags are enabled, overwrite env paths.
-    #[cfg(feature = "test")]
-    let profiles_dir = Path::new("tests/vulkan_profiles");
-    #[cfg(feature = "example")]
-    let profiles_dir = Path::new("examples/vulkan_profiles");
+    // Find the library and the Vulkan headers.
+    let profiles_dir = profiles_dir();
+    let vulkan_include_dir = vulkan_include_dir();
 
     // Extend paths to correct file/directory.
     let profiles_source = profiles_dir.join("vulkan_profiles.cpp");
-    let vulkan_include_dir = vulkan_sdk.join("Include");
+    let profiles_header = profiles_dir.join("vulkan").join("vulkan_profiles.h");
 
     // Rerun on source change.
     println!(
         "cargo:rerun-if-changed={}",
         profiles_source.to_string_lossy()
     );
+    println!(
+        "cargo:rerun-if-changed={}",
+        profiles_header.to_string_lossy()
+    );
 
     // Setup cc build for the Vulkan Profiles Library.
     let mut build = cc::Build::new();
 
     build
-        .file(profiles_source)
         .cpp(true)
         .std("c++17")
-        .include(profiles_dir)
+        .include(&profiles_dir)
         .include(&vulkan_include_dir)
         .define("VP_USE_OBJECT", "1");
 
-    #[cfg(feature = "VK_KHR_video_queue")]
-    build.define("VK_KHR_video_queue", "1");
-
-    // Platform defines
-    #[cfg(feature = "VK_USE_PLATFORM_ANDROID_KHR")]
-    build.define("VK_USE_PLATFORM_ANDROID_KHR", "1");
-    #[cfg(feature = "VK_USE_PLATFORM_FUCHSIA")]
-    build.define("VK_USE_PLATFORM_FUCHSIA", "1");
-    #[cfg(feature = "VK_USE_PLATFORM_IOS_MVK")]
-    build.define("VK_USE_PLATFORM_IOS_MVK", "1");
-    #[cfg(feature = "VK_USE_PLATFORM_MACOS_MVK")]
-    build.define("VK_USE_PLATFORM_MACOS_MVK", "1");
-    #[cfg(feature = "VK_USE_PLATFORM_METAL_EXT")]
-    build.define("VK_USE_PLATFORM_METAL_EXT", "1");
-    #[cfg(feature = "VK_USE_PLATFORM_VI_NN")]
-    build.define("VK_USE_PLATFORM_VI_NN", "1");
-    #[cfg(feature = "VK_USE_PLATFORM_WAYLAND_KHR")]
-    build.define("VK_USE_PLATFORM_WAYLAND_KHR", "1");
-    #[cfg(feature = "VK_USE_PLATFORM_WIN32_KHR")]
-    build.define("VK_USE_PLATFORM_WIN32_KHR", "1");
-    #[cfg(feature = "VK_USE_PLATFORM_XCB_KHR")]
-    build.define("VK_USE_PLATFORM_XCB_KHR", "1");
-    #[cfg(feature = "VK_USE_PLATFORM_XLIB_KHR")]
-    build.define("VK_USE_PLATFORM_XLIB_KHR", "1");
-    #[cfg(feature = "VK_USE_PLATFORM_DIRECTFB_EXT")]
-    build.define("VK_USE_PLATFORM_DIRECTFB_EXT", "1");
-    #[cfg(feature = "VK_USE_PLATFORM_XLIB_XRANDR_EXT")]
-    build.define("VK_USE_PLATFORM_XLIB_XRANDR_EXT", "1");
-    #[cfg(feature = "VK_USE_PLATFORM_GGP")]
-    build.define("VK_USE_PLATFORM_GGP", "1");
-    #[cfg(feature = "VK_USE_PLATFORM_SCREEN_QNX")]
-    build.define("VK_USE_PLATFORM_SCREEN_QNX", "1");
-    #[cfg(feature = "VK_USE_PLATFORM_SCI")]
-    build.define("VK_USE_PLATFORM_SCI", "1");
-
-    // Beta define
-    #[cfg(feature = "VK_ENABLE_BETA_EXTENSIONS")]
-    build.define("VK_ENABLE_BETA_EXTENSIONS", "1");
-
-    // Compile the library.
-    build.compile("vulkan_profiles_ash");
+    // Dynamic mode, compile without the Vulkan prototypes, `include/vp_ash_library.cpp` defines
+    // the loader functions the library references.
+    #[cfg(feature = "dynamic")]
+    build
+        .define("VK_NO_PROTOTYPES", None)
+        .define("VP_DYNAMIC_VULKAN_FUNCTIONS", "1")
+        .define("VP_ASH_DYNAMIC", None);
+
+    // Platform and beta defines, the extensions behind a guard of `vulkan.h` are only declared with
+    // it. Define the guards of the extensions the profiles require, and the ones enabled by
+    // feature.
+    let required_extensions = source_required_extensions(&profiles_header);
+    for (guard, extensions) in platform_guards(&vulkan_include_dir) {
+        let enabled = env::var_os(format!("CARGO_FEATURE_{guard}")).is_some();
+        if enabled || !extensions.is_disjoint(&required_extensions) {
+            build.define(&guard, "1");
+        }
+    }
+
+    // Video queue, bind the functions if the library declares them and the Vulkan headers
+    // define `VK_KHR_video_queue`, which guards them.
+    let video_queue = source_declares_video_queue(&profiles_header)
+        && defined_macros(&build, ["VK_KHR_video_queue".to_owned()]).contains("VK_KHR_video_queue");
+    if video_queue {
+        println!("cargo:rustc-cfg=vp_ash_video_queue");
+    } else if cfg!(feature = "VK_KHR_video_queue") {
+        panic!(
+            "The `VK_KHR_video_queue` feature is enabled but the video queue functions of `{}` \
+            are not compiled, either the library does not declare them or the Vulkan headers do \
+            not define `VK_KHR_video_queue`. Regenerate the library with the Vulkan SDK 1.4.304, \
+            use newer Vulkan headers or disable the feature.",
+            profiles_header.display()
+        );
+    }
+
+    // Generate the constants for the profiles compiled into the library.
+    generate_profiles(&profiles_header, &build);
+
+    // Compile the library, through the source including it with the functions `vp-ash` adds.
+    let library_source = Path::new("include/vp_ash_library.cpp");
+    println!(
+        "cargo:rerun-if-changed={}",
+        library_source.to_string_lossy()
+    );
+    build.file(library_source).compile("vulkan_profiles_ash");
+}
+
+/// A profile declared in the generated `vulkan_profiles.h`.
+#[cfg(feature = "linked")]
+#[derive(Default)]
+struct ProfileDefines {
+    name: Option<String>,
+    spec_version: Option<u32>,
+    min_api_version: Option<[u32; 4]>,
+}
+
+/// Parses the profile defines in `header` and writes the `vp_ash::profiles` module to `OUT_DIR`.
+///
+/// Profiles whose `#if defined(...)` guards are not met by `build`, because the Vulkan headers
+/// lack one of their extensions, are compiled out of the library and get no constants.
+#[cfg(feature = "linked")]
+fn generate_profiles(header: &Path, build: &cc::Build) {
+    use std::{collections::BTreeMap, fmt::Write};
+
+    let source = read_source(header);
+
+    let mut profiles: BTreeMap<String, ProfileDefines> = BTreeMap::new();
+    for line in source.lines() {
+        let Some(define) = line.trim().strip_prefix("#define ") else {
+            continue;
+        };
+        let Some((macro_name, value)) = define.split_once(char::is_whitespace) else {
+            continue;
+        };
+        let value = value.trim();
+
+        if let Some(prefix) = macro_name.strip_suffix("_NAME") {
+            // Only the profile names are string literals.
+            if let Some(name) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+                profiles.entry(prefix.to_owned()).or_default().name = Some(name.to_owned());
+            }
+        } else if let Some(prefix) = macro_name.strip_suffix("_SPEC_VERSION") {
+            let spec_version = value
+                .parse()
+                .unwrap_or_else(|_| panic!("invalid `{macro_name}` value `{value}`"));
+            profiles.entry(prefix.to_owned()).or_default().spec_version = Some(spec_version);
+        } else if let Some(prefix) = macro_name.strip_suffix("_MIN_API_VERSION") {
+            let version = parse_version(value)
+                .unwrap_or_else(|| panic!("invalid `{macro_name}` value `{value}`"));
+            profiles
+                .entry(prefix.to_owned())
+                .or_default()
+                .min_api_version = Some(version);
+        }
+    }
+
+    let compiled = defined_macros(
+        build,
+        profiles.keys().map(|prefix| format!("{prefix}_NAME")),
+    );
+
+    let mut module = String::new();
+    let mut all = Vec::new();
+    for (prefix, defines) in &profiles {
+        let (Some(name), Some(spec_version), Some(min_api_version)) =
+            (&defines.name, defines.spec_version, defines.min_api_version)
+        else {
+            panic!(
+                "`{}` is missing one of the `{prefix}_NAME`, `{prefix}_SPEC_VERSION` or \
+                `{prefix}_MIN_API_VERSION` defines",
+                header.display()
+            );
+        };
+        if !compiled.contains(&format!("{prefix}_NAME")) {
+            continue;
+        }
+        let [variant, major, minor, patch] = min_api_version;
+
+        writeln!(
+            module,
+            "/// The `{name}` profile.\n\
+            pub const {prefix}: vp::ProfileProperties = \
+            vp::ProfileProperties::new({prefix}_NAME, {prefix}_SPEC_VERSION);\n\
+            /// The name of the `{name}` profile.\n\
+            pub const {prefix}_NAME: &CStr = c\"{name}\";\n\
+            /// The specification version of the `{name}` profile.\n\
+            pub const {prefix}_SPEC_VERSION: u32 = {spec_version};\n\
+            /// The minimum Vulkan API version of the `{name}` profile.\n\
+            pub const {prefix}_MIN_API_VERSION: u32 = \
+            vk::make_api_version({variant}, {major}, {minor}, {patch});\n"
+        )
+        .unwrap();
+        all.push(prefix.as_str());
+    }
+
+    writeln!(
+        module,
+        "/// Every profile in the library.\n\
+        pub const ALL: &[vp::ProfileProperties] = &[{}];",
+        all.join(", ")
+    )
+    .unwrap();
+
+    let out_dir = env::var_os("OUT_DIR").expect("`OUT_DIR` is set by cargo");
+    std::fs::write(Path::new(&out_dir).join("profiles.rs"), module)
+        .expect("failed to write the generated profiles");
+}
+
+/// Returns the macros of `names` that are defined when preprocessing the library header with
+/// `build`.
+#[cfg(feature = "linked")]
+fn defined_macros(
+    build: &cc::Build,
+    names: impl IntoIterator<Item = String>,
+) -> std::collections::BTreeSet<String> {
+    use std::fmt::Write;
+
+    // String literals are not expanded, so each defined name is printed as is.
+    let mut probe = String::from("#include \"vulkan/vulkan_profiles.h\"\n");
+    for name in names {
+        writeln!(probe, "#ifdef {name}\nvp_ash_defined \"{name}\"\n#endif").unwrap();
+    }
+
+    let out_dir = env::var_os("OUT_DIR").expect("`OUT_DIR` is set by cargo");
+    let probe_path = Path::new(&out_dir).join("vp_ash_probe.cpp");
+    std::fs::write(&probe_path, probe).expect("failed to write the preprocessor probe");
+
+    let output = build.clone().file(probe_path).expand();
+    String::from_utf8_lossy(&output)
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("vp_ash_defined \""))
+        .filter_map(|name| name.strip_suffix('"'))
+        .map(str::to_owned)
+        .collect()
+}
+
+/// Parses a `VK_MAKE_VERSION` or `VK_MAKE_API_VERSION` invocation into its variant, major, minor
+/// and patch versions.
+#[cfg(feature = "linked")]
+fn parse_version(value: &str) -> Option<[u32; 4]> {
+    let (macro_name, args) = value.strip_suffix(')')?.split_once('(')?;
+    let args = args
+        .split(',')
+        .map(|arg| arg.trim().parse())
+        .collect::<Result<Vec<u32>, _>>()
+        .ok()?;
+
+    match (macro_name.trim(), args.as_slice()) {
+        ("VK_MAKE_VERSION", &[major, minor, patch]) => Some([0, major, minor, patch]),
+        ("VK_MAKE_API_VERSION", &[variant, major, minor, patch]) => {
+            Some([variant, major, minor, patch])
+        }
+        _ => None,
+    }
+}
+
+/// Returns whether `header` declares the video queue functions.
+#[cfg(feature = "linked")]
+fn source_declares_video_queue(header: &Path) -> bool {
+    read_source(header).contains("vpGetProfileVideoProfiles")
+}
+
+/// Returns the extensions the profiles in `header` require.
+#[cfg(feature = "linked")]
+fn source_required_extensions(header: &Path) -> std::collections::BTreeSet<String> {
+    // The profiles are guarded by `defined(<extension>)` for each extension they require.
+    read_source(header)
+        .split("defined(")
+        .skip(1)
+        .filter_map(|guard| guard.split_once(')'))
+        .map(|(name, _)| name.trim().to_owned())
+        .collect()
+}
+
+/// Returns the `#ifdef` guards of `vulkan/vulkan.h`, like `VK_USE_PLATFORM_WIN32_KHR` or
+/// `VK_ENABLE_BETA_EXTENSIONS`, with the extensions declared by the headers each one includes.
+#[cfg(feature = "linked")]
+fn platform_guards(vulkan_include_dir: &Path) -> Vec<(String, std::collections::BTreeSet<String>)> {
+    let vulkan_dir = vulkan_include_dir.join("vulkan");
+
+    let mut guards: Vec<(String, std::collections::BTreeSet<String>)> = Vec::new();
+    let mut guard = None;
+    for line in read_source(&vulkan_dir.join("vulkan.h")).lines() {
+        let line = line.trim();
+        if let Some(name) = line.strip_prefix("#ifdef ") {
+            guard = Some(name.trim());
+            continue;
+        }
+        if line.starts_with("#endif") {
+            guard = None;
+            continue;
+        }
+        let (Some(guard), Some(include)) = (guard, line.strip_prefix("#include ")) else {
+            continue;
+        };
+
+        // The guards also include system headers, like `<windows.h>`, which declare no extension.
+        let header = vulkan_dir.join(include.trim().trim_matches(['"', '<', '>']));
+        if !header.is_file() {
+            continue;
+        }
+        // The extensions are declared as `#define VK_KHR_win32_surface 1`.
+        let extensions: std::collections::BTreeSet<String> = read_source(&header)
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("#define "))
+            .filter_map(|define| define.trim().strip_suffix(" 1"))
+            .map(str::trim)
+            .filter(|name| name.starts_with("VK_") && name.contains(|c: char| c.is_lowercase()))
+            .map(str::to_owned)
+            .collect();
+
+        match guards.iter_mut().find(|(name, _)| name == guard) {
+            Some((_, declared)) => declared.extend(extensions),
+            None => guards.push((guard.to_owned(), extensions)),
+        }
+    }
+
+    guards
+}
+
+#[cfg(feature = "linked")]
+fn read_source(path: &Path) -> String {
+    std::fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("failed to read `{}`: {err}", path.display()))
+}
+
+/// Returns the directory of the generated Vulkan Profiles library.
+///
+/// `VULKAN_PROFILES_PATH` may list several directories, separated like `PATH`, the first one that
+/// contains a generated library is used.
+#[cfg(feature = "linked")]
+fn profiles_dir() -> PathBuf {
+    // If test/example flags are enabled, use the libraries in the repository.
+    if cfg!(feature = "example") {
+        return PathBuf::from("examples/vulkan_profiles");
+    }
+    if cfg!(feature = "test") {
+        return PathBuf::from("tests/vulkan_profiles");
+    }
+
+    let Some(paths) = env::var_os("VULKAN_PROFILES_PATH") else {
+        panic!(
+            "`VULKAN_PROFILES_PATH` is not set. Set it to the `--output-library-src` directory \
+            used when generating the Vulkan Profiles library, e.g. in the `[env]` section of \
+            `.cargo/config.toml`."
+        );
+    };
+
+    let paths: Vec<PathBuf> = env::split_paths(&paths).collect();
+    paths
+        .iter()
+        .find(|path| {
+            path.join("vulkan_profiles.cpp").is_file()
+                && path.join("vulkan").join("vulkan_profiles.h").is_file()
+        })
+        .cloned()
+        .unwrap_or_else(|| {
+            panic!(
+                "`VULKAN_PROFILES_PATH` does not contain a generated Vulkan Profiles library, \
+                searched {paths:?} for `vulkan_profiles.cpp` and `vulkan/vulkan_profiles.h`. \
+                Generate the library with `--output-library-src <dir>` and \
+                `--output-library-inc <dir>/vulkan`."
+            )
+        })
+}
+
+/// Returns the directory containing `vulkan/vulkan.h`.
+///
+/// Searches `VULKAN_SDK`, then `pkg-config vulkan`, then the system include directories.
+#[cfg(feature = "linked")]
+fn vulkan_include_dir() -> PathBuf {
+    let has_vulkan_header = |dir: &Path| dir.join("vulkan").join("vulkan.h").is_file();
+
+    let mut searched = Vec::new();
+
+    // The SDK uses `Include` on Windows and `include` elsewhere.
+    if let Some(vulkan_sdk) = env::var_os("VULKAN_SDK") {
+        let vulkan_sdk = PathBuf::from(vulkan_sdk);
+        for dir in [vulkan_sdk.join("Include"), vulkan_sdk.join("include")] {
+            if has_vulkan_header(&dir) {
+                return dir;
+            }
+            searched.push(dir);
+        }
+    }
+
+    if let Ok(output) = Command::new("pkg-config")
+        .args(["--cflags-only-I", "vulkan"])
+        .output()
+    {
+        if output.status.success() {
+            let cflags = String::from_utf8_lossy(&output.stdout);
+            for dir in cflags
+                .split_whitespace()
+                .filter_map(|flag| flag.strip_prefix("-I"))
+            {
+                let dir = PathBuf::from(dir);
+                if has_vulkan_header(&dir) {
+                    return dir;
+                }
+                searched.push(dir);
+            }
+        }
+    }
+
+    for dir in ["/usr/include", "/usr/local/include"] {
+        let dir = PathBuf::from(dir);
+        if has_vulkan_header(&dir) {
+            return dir;
+        }
+        searched.push(dir);
+    }
+
+    panic!(
+        "Cannot find the Vulkan headers, searched {searched:?} for `vulkan/vulkan.h`. Install the \
+        Vulkan SDK and set `VULKAN_SDK`, or install the Vulkan headers package for your \
+        distribution (e.g. `libvulkan-dev` or `vulkan-headers`)."
+    );
 }
@@ -9,7 +9,7 @@ use ash::{
 use rand::Rng;
 use rand_distr::Distribution;
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
-use vp_ash::vp;
+use vp_ash::{profiles, vp};
 
 const TRY_DEBUG: bool = true;
 const BUFFER_VALUES: u32 = 2u32.pow(26);
@@ -42,21 +42,15 @@ fn main() {
     let vp_entry = vp_ash::Entry::linked();
     let capabilities = {
         let create_info = vp::CapabilitiesCreateInfo::default()
-            .api_version(vk::make_api_version(0, 1, 2, 198))
+            .api_version(profiles::VP_VPA_EXAMPLES_COMPUTE_MIN_API_VERSION)
             .flags(vp::CapabilitiesCreateFlags::STATIC);
 
         unsafe { vp_entry.create_capabilities(&create_info, None) }.unwrap()
     };
 
     // Profiles for this application.
-    let core_profile = vp::ProfileProperties::default()
-        .profile_name(c"VP_VPA_examples_compute")
-        .unwrap()
-        .spec_version(1);
-    let debug_profile = vp::ProfileProperties::default()
-        .profile_name(c"VP_VPA_examples_debug")
-        .unwrap()
-        .spec_version(1);
+    let core_profile = profiles::VP_VPA_EXAMPLES_COMPUTE;
+    let debug_profile = profiles::VP_VPA_EXAMPLES_DEBUG;
 
     // Sanity check that profiles are present, if the instance in the build environment is missing
     // the required extensions, this will fail.
@@ -113,47 +107,25 @@ fn main() {
 
     // Select a physical device.
     let physical_device = {
-        unsafe { instance.enumerate_physical_devices() }
-            .unwrap()
+        let devices = unsafe {
+            vp_ash::DeviceSelector::new(&capabilities)
+                .profile(core_profile)
+                .select(&entry, &instance)
+        }
+        .unwrap();
+
+        devices
             .into_iter()
-            .filter(|&device| unsafe {
-                capabilities
-                    .get_physical_device_profile_support(&instance, device, &core_profile)
-                    .unwrap()}
-            )
-            .min_by_key(|&device| {
-                let properties = unsafe {instance.get_physical_device_properties(device)};
-
-                match properties.device_type {
-                    vk::PhysicalDeviceType::DISCRETE_GPU => 0,
-                    vk::PhysicalDeviceType::INTEGRATED_GPU => 1,
-                    vk::PhysicalDeviceType::VIRTUAL_GPU => 2,
-                    vk::PhysicalDeviceType::CPU => 3,
-                    vk::PhysicalDeviceType::OTHER => 4,
-                    _ => 5,
-                }
-            }).expect("No GPU in your system meets the requirements to run this application. Try updating your drivers.")
+            .find(|device| device.is_accepted())
+            .expect("No GPU in your system meets the requirements to run this application. Try updating your drivers.")
+            .physical_device
     };
 
     // Get the queue family index.
     let queue_family_index = {
         // Get the required properties
-        let required_properties = {
-            let mut required_properties = vk::QueueFamilyProperties2KHR::default();
-            let mut count = 1;
-            unsafe {
-                capabilities
-                    .get_profile_queue_family_properties(
-                        &core_profile,
-                        None,
-                        &mut count,
-                        Some(core::slice::from_mut(&mut required_properties)),
-                    )
-                    .unwrap()
-            };
-
-            required_properties.queue_family_properties
-        };
+        let requirements =
+            unsafe { capabilities.queue_family_requirements(&core_profile, None) }.unwrap();
 
         // Get the device properties
         let properties =
@@ -161,13 +133,8 @@ fn main() {
 
         // Find the index that meets the requirements.
         properties
-            .into_iter()
-            .position(|properties| {
-                properties.queue_count >= required_properties.queue_count
-                    && properties
-                        .queue_flags
-                        .contains(required_properties.queue_flags)
-            })
+            .iter()
+            .position(|properties| requirements[0].is_satisfied_by(properties))
             .unwrap() as u32
     };
 
@@ -0,0 +1,106 @@
+// Compiled instead of `vulkan_profiles.cpp` to add the functions `vp-ash` needs that the Vulkan
+// Profiles API does not have, implemented with the tables of the library.
+
+#if defined(VP_ASH_DYNAMIC)
+// With the `dynamic` feature the library is compiled with `VK_NO_PROTOTYPES` so that it does not
+// link against the Vulkan loader, `vp-ash` resolves the functions of
+// `VP_PROFILE_CREATE_DYNAMIC_BIT` before creating the capabilities. The loader functions the
+// library references are only called through `VP_PROFILE_CREATE_STATIC_BIT` or without a
+// capabilities object, these definitions, local to this file, import nothing and fail instead.
+#include <vulkan/vulkan_core.h>
+
+static VKAPI_ATTR PFN_vkVoidFunction VKAPI_CALL vkGetInstanceProcAddr(VkInstance, const char*) {
+    return nullptr;
+}
+
+static VKAPI_ATTR PFN_vkVoidFunction VKAPI_CALL vkGetDeviceProcAddr(VkDevice, const char*) {
+    return nullptr;
+}
+
+static VKAPI_ATTR VkResult VKAPI_CALL vkEnumerateInstanceVersion(uint32_t*) {
+    return VK_ERROR_INITIALIZATION_FAILED;
+}
+
+static VKAPI_ATTR VkResult VKAPI_CALL vkEnumerateInstanceExtensionProperties(
+    const char*, uint32_t*, VkExtensionProperties*) {
+    return VK_ERROR_INITIALIZATION_FAILED;
+}
+
+static VKAPI_ATTR VkResult VKAPI_CALL vkEnumerateDeviceExtensionProperties(
+    VkPhysicalDevice, const char*, uint32_t*, VkExtensionProperties*) {
+    return VK_ERROR_INITIALIZATION_FAILED;
+}
+
+// Only called after `vkEnumerateDeviceExtensionProperties` succeeded.
+static VKAPI_ATTR void VKAPI_CALL vkGetPhysicalDeviceFeatures2(
+    VkPhysicalDevice, VkPhysicalDeviceFeatures2*) {}
+
+static VKAPI_ATTR void VKAPI_CALL vkGetPhysicalDeviceProperties2(
+    VkPhysicalDevice, VkPhysicalDeviceProperties2*) {}
+
+static VKAPI_ATTR void VKAPI_CALL vkGetPhysicalDeviceFormatProperties2(
+    VkPhysicalDevice, VkFormat, VkFormatProperties2*) {}
+
+static VKAPI_ATTR void VKAPI_CALL vkGetPhysicalDeviceQueueFamilyProperties2(
+    VkPhysicalDevice, uint32_t* pQueueFamilyPropertyCount, VkQueueFamilyProperties2*) {
+    *pQueueFamilyPropertyCount = 0;
+}
+
+static VKAPI_ATTR VkResult VKAPI_CALL vkCreateInstance(
+    const VkInstanceCreateInfo*, const VkAllocationCallbacks*, VkInstance*) {
+    return VK_ERROR_INITIALIZATION_FAILED;
+}
+
+static VKAPI_ATTR VkResult VKAPI_CALL vkCreateDevice(
+    VkPhysicalDevice, const VkDeviceCreateInfo*, const VkAllocationCallbacks*, VkDevice*) {
+    return VK_ERROR_INITIALIZATION_FAILED;
+}
+#endif  // VP_ASH_DYNAMIC
+
+#include "vulkan_profiles.cpp"
+
+// Lists the blocks of `pProfile` and of its required profiles, in the order of the block scoped
+// queries. Each block has the profile that declares it and no API version.
+extern "C" VPAPI_ATTR VkResult vpAshGetProfileBlocks(
+    VpCapabilities                              capabilities,
+    const VpProfileProperties*                  pProfile,
+    uint32_t*                                   pPropertyCount,
+    VpBlockProperties*                          pProperties) {
+    (void)capabilities;
+
+    std::vector<VpBlockProperties> blocks;
+
+    const std::vector<VpProfileProperties>& gathered_profiles = detail::GatherProfiles(*pProfile);
+
+    for (std::size_t profile_index = 0, profile_count = gathered_profiles.size(); profile_index < profile_count; ++profile_index) {
+        const detail::VpProfileDesc* profile_desc = detail::vpGetProfileDesc(gathered_profiles[profile_index].profileName);
+        if (profile_desc == nullptr) return VK_ERROR_UNKNOWN;
+
+        for (uint32_t capability_index = 0; capability_index < profile_desc->requiredCapabilityCount; ++capability_index) {
+            const detail::VpCapabilitiesDesc& cap_desc = profile_desc->pRequiredCapabilities[capability_index];
+
+            for (uint32_t variant_index = 0; variant_index < cap_desc.variantCount; ++variant_index) {
+                VpBlockProperties block{gathered_profiles[profile_index], 0, {}};
+                strncpy(block.blockName, cap_desc.pVariants[variant_index].blockName, VP_MAX_PROFILE_NAME_SIZE - 1);
+                blocks.push_back(block);
+            }
+        }
+    }
+
+    VkResult result = VK_SUCCESS;
+
+    if (pProperties == nullptr) {
+        *pPropertyCount = static_cast<uint32_t>(blocks.size());
+    } else {
+        if (*pPropertyCount < static_cast<uint32_t>(blocks.size())) {
+            result = VK_INCOMPLETE;
+        } else {
+            *pPropertyCount = static_cast<uint32_t>(blocks.size());
+        }
+        for (uint32_t block_index = 0; block_index < *pPropertyCount; ++block_index) {
+            pProperties[block_index] = blocks[block_index];
+        }
+    }
+
+    return result;
+}
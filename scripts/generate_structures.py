@@ -0,0 +1,296 @@
+"""Generates `vp-ash-core/src/structure/registry.rs` from the ash sources and `vk.xml`.
+
+Usage: python scripts/generate_structures.py <path to ash>/src/vk [<path to vk.xml>] > vp-ash-core/src/structure/registry.rs
+
+Lists every structure of the feature, property, format, queue family and video chains in ash
+with the offsets and types of their members, and the named values of the flags and enums of the
+members, which the profile JSON spells by their Vulkan names.
+
+The limits compare by the `limittype` of their member in `vk.xml`, which should be the registry of
+the Vulkan version of ash. Without `vk.xml` the comparisons are guessed from the member names.
+"""
+
+import re
+import sys
+import xml.etree.ElementTree as ElementTree
+from pathlib import Path
+
+# The chains, with the structure at their head and the trait of the structures extending it.
+CHAINS = [
+    ("Features", "PhysicalDeviceFeatures2", "ExtendsPhysicalDeviceFeatures2"),
+    ("Properties", "PhysicalDeviceProperties2", "ExtendsPhysicalDeviceProperties2"),
+    ("Format", "FormatProperties2", "ExtendsFormatProperties2"),
+    ("QueueFamily", "QueueFamilyProperties2", "ExtendsQueueFamilyProperties2"),
+    ("VideoProfile", "VideoProfileInfoKHR", "ExtendsVideoProfileInfoKHR"),
+    ("VideoCapabilities", "VideoCapabilitiesKHR", "ExtendsVideoCapabilitiesKHR"),
+    ("VideoFormat", "VideoFormatPropertiesKHR", "ExtendsVideoFormatPropertiesKHR"),
+]
+
+PRIMITIVES = {
+    "Bool32": "Bool32",
+    "u8": "U8",
+    "u16": "U16",
+    "u32": "U32",
+    "i32": "I32",
+    "u64": "U64",
+    "i64": "I64",
+    "f32": "F32",
+    "usize": "Usize",
+}
+
+
+def parse(vk: Path):
+    definitions = (vk / "definitions.rs").read_text()
+    native = (vk / "native.rs").read_text()
+    bitflags = (vk / "bitflags.rs").read_text()
+    enums = (vk / "enums.rs").read_text()
+    const_debugs = (vk / "const_debugs.rs").read_text()
+
+    structs = {}
+    lines = definitions.splitlines()
+    for index, line in enumerate(lines):
+        match = re.match(r"pub struct (\w+)(?:<'a>)? \{$", line)
+        if not match:
+            continue
+
+        vulkan_name = None
+        for previous in lines[max(index - 6, 0) : index]:
+            doc = re.search(r"man/html/(\w+)\.html", previous)
+            if doc:
+                vulkan_name = doc.group(1)
+
+        fields = []
+        for field in lines[index + 1 :]:
+            if field == "}":
+                break
+            field = re.match(r"    pub (\w+): (.+),$", field)
+            if field and field.group(1) not in ("s_type", "p_next", "_marker"):
+                fields.append((field.group(1), field.group(2)))
+
+        structs[match.group(1)] = (vulkan_name or "Vk" + match.group(1), fields)
+
+    aliases = dict(re.findall(r"^pub type (\w+) = (?:::core::ffi::)?(\w+);", definitions, re.M))
+    aliases.update(re.findall(r"^pub type (\w+) = ::core::ffi::(\w+);", native, re.M))
+    aliases.update({"c_uint": "u32", "c_int": "i32"})
+
+    flags = dict(re.findall(r"vk_bitflags_wrapped!\((\w+), (Flags64|Flags)\);", bitflags))
+    enum_names = set(re.findall(r"^pub struct (\w+)\(pub\(crate\) i32\);", enums, re.M))
+
+    extends = {}
+    for trait, name in re.findall(r"^unsafe impl (\w+) for (\w+)<'_> \{\}", definitions, re.M):
+        extends.setdefault(trait, []).append(name)
+
+    # The named values of each flags and enum type, from their `Debug` implementations.
+    known = {}
+    for name, body in re.findall(r"^impl fmt::Debug for (\w+) \{$(.*?)^\}$", const_debugs, re.M | re.S):
+        if name in flags:
+            known[name] = re.findall(name + r"\s*::\s*(\w+)\s*\.\s*0\s*,\s*\"\w+\"", body)
+        elif name in enum_names:
+            known[name] = re.findall(r"Self::(\w+) => Some\(\"\w+\"\)", body)
+
+    return structs, aliases, flags, enum_names, extends, known
+
+
+def parse_limit_types(path: Path):
+    """Returns the `limittype` of the members of each structure in `vk.xml`, by Vulkan structure
+    name and then by the member name without case and underscores."""
+    limit_types = {}
+    for struct in ElementTree.parse(path).getroot().iterfind("types/type[@category='struct']"):
+        members = {}
+        for member in struct.iterfind("member"):
+            limit_type = member.get("limittype")
+            if limit_type:
+                members[member.findtext("name").lower()] = limit_type
+        limit_types[struct.get("name")] = members
+
+    return limit_types
+
+
+def limit_compare(limit_type: str):
+    """Returns the `Compare` of a `limittype`, `None` for limits that compare exactly."""
+    kinds = limit_type.split(",")
+    if "range" in kinds:
+        return "Range"
+    if "max" in kinds or "bits" in kinds:
+        return "Max"
+    if "min" in kinds:
+        return "Min"
+    return None
+
+
+def guess_compare(field: str, member_type: str):
+    """Returns the `Compare` of a member from the naming conventions of the Vulkan limits."""
+    if member_type == "MemberType::Bool32":
+        return None
+    if field.endswith("_range") and member_type.startswith("MemberType::Array") and member_type.endswith(", 2)"):
+        return "Range"
+    if field.startswith("max_"):
+        return "Max"
+    if field.startswith("min_") or field.endswith(("_alignment", "_granularity", "_atom_size")):
+        return "Min"
+    if field.endswith(("_bits", "_count")):
+        return "Max"
+    return None
+
+
+def vulkan_prefix(name: str) -> str:
+    """Returns the prefix of the Vulkan names of the values of a flags or enum type, like
+    `VK_FORMAT_FEATURE_2_` for `FormatFeatureFlags2`."""
+    name = re.sub(r"[A-Z]{2,}$", "", name)
+    name = name.replace("Flags2", "2").removesuffix("Flags")
+    words = re.sub(r"(?<=[a-z0-9])(?=[A-Z])|(?<=[a-z])(?=[0-9])|(?<=[A-Z])(?=[A-Z][a-z])", "_", name)
+    return f"VK_{words.upper()}_"
+
+
+def const_name(name: str) -> str:
+    """Converts an ash name like `PhysicalDeviceVulkan12Features` to `PHYSICAL_DEVICE_VULKAN_12_FEATURES`."""
+    words = re.sub(r"(?<=[a-z])(?=[A-Z0-9])|(?<=[A-Z])(?=[A-Z][a-z])", "_", name)
+    return words.upper()
+
+
+class Generator:
+    def __init__(self, structs, aliases, flags, enum_names, lifetimes, limit_types):
+        self.structs = structs
+        self.aliases = aliases
+        self.flags = flags
+        self.enum_names = enum_names
+        self.lifetimes = lifetimes
+        # The `limittype` of the members of each structure, `None` to guess them.
+        self.limit_types = limit_types
+        # The member lists to generate, by ash name.
+        self.members = {}
+        # The flags and enum types of the members.
+        self.value_types = set()
+
+    def member_type(self, ty: str) -> str:
+        while ty in self.aliases and ty not in PRIMITIVES:
+            ty = self.aliases[ty]
+
+        if ty in PRIMITIVES:
+            return f"MemberType::{PRIMITIVES[ty]}"
+        if ty.startswith("*"):
+            return "MemberType::Pointer"
+        if ty in self.flags:
+            self.value_types.add(ty)
+            variant = "Flags64" if self.flags[ty] == "Flags64" else "Flags"
+            return f'MemberType::{variant}("{ty}")'
+        if ty in self.enum_names:
+            self.value_types.add(ty)
+            return f'MemberType::Enum("{ty}")'
+
+        array = re.match(r"\[(\w+); (\w+)\]$", ty)
+        if array:
+            element, length = array.groups()
+            if not length.isdigit():
+                length = "vk::" + length
+            if element == "c_char":
+                return f"MemberType::Chars({length})"
+            element = self.member_type(element)
+            if element.startswith("MemberType::Struct"):
+                raise ValueError(f"arrays of structures are not supported: {ty}")
+            return f"MemberType::Array(&{element}, {length})"
+
+        if ty in self.structs:
+            return f'MemberType::Struct("{self.structs[ty][0]}", {self.member_list(ty)})'
+
+        raise ValueError(f"unknown member type: {ty}")
+
+    def member_list(self, name: str) -> str:
+        const = const_name(name)
+        if name not in self.members:
+            vulkan_name, struct_fields = self.structs[name]
+            fields = []
+            for field, ty in struct_fields:
+                member_type = self.member_type(ty)
+                fields.append((field, member_type, self.compare(vulkan_name, field, member_type)))
+            self.members[name] = (const, fields)
+
+        return const
+
+    def compare(self, vulkan_name: str, field: str, member_type: str):
+        if self.limit_types is None:
+            return guess_compare(field, member_type)
+
+        limit_type = self.limit_types.get(vulkan_name, {}).get(field.replace("_", ""))
+        return limit_compare(limit_type) if limit_type else None
+
+    def ty(self, name: str) -> str:
+        return f"vk::{name}<'static>" if name in self.lifetimes else f"vk::{name}"
+
+
+def main():
+    vk = Path(sys.argv[1])
+    structs, aliases, flags, enum_names, extends, known = parse(vk)
+    definitions = (vk / "definitions.rs").read_text()
+    lifetimes = set(re.findall(r"^pub struct (\w+)<'a> \{$", definitions, re.M))
+    limit_types = parse_limit_types(Path(sys.argv[2])) if len(sys.argv) > 2 else None
+
+    generator = Generator(structs, aliases, flags, enum_names, lifetimes, limit_types)
+
+    out = []
+    if limit_types is None:
+        print("warning: no vk.xml, guessing the comparisons of the limits from their names", file=sys.stderr)
+        out.append("// Generated by `scripts/generate_structures.py` from ash 0.38.0+1.3.281 without vk.xml, the")
+        out.append("// comparisons of the limits are guessed from their names, do not edit.")
+    else:
+        out.append("// Generated by `scripts/generate_structures.py` from ash 0.38.0+1.3.281 and vk.xml, do not")
+        out.append("// edit.")
+    out.append("")
+    out.append("use core::mem::offset_of;")
+    out.append("")
+    out.append("use ash::vk;")
+    out.append("")
+    out.append("use super::{Chain, Compare, Member, MemberType, StructureInfo, ValueNames};")
+    out.append("")
+    out.append("/// The structures of each chain, the head first.")
+    out.append("pub(super) static STRUCTURES: &[StructureInfo] = &[")
+    for chain, head, trait in CHAINS:
+        for name in [head] + sorted(extends.get(trait, []), key=str.lower):
+            members = generator.member_list(name)
+            out.append(f"    StructureInfo::new::<{generator.ty(name)}>(")
+            out.append(f'        "{structs[name][0]}",')
+            out.append(f"        Chain::{chain},")
+            out.append(f"        {members},")
+            out.append("    ),")
+    out.append("];")
+
+    for name, (const, fields) in generator.members.items():
+        out.append("")
+        if not fields:
+            out.append(f"const {const}: &[Member] = &[];")
+            continue
+        out.append(f"const {const}: &[Member] = members!({generator.ty(name)} {{")
+        for field, member_type, compare in fields:
+            compare = f" => {compare}" if compare else ""
+            out.append(f"    {field}: {member_type}{compare},")
+        out.append("});")
+
+    for static, kind, names in [
+        ("FLAGS", "u64", sorted(ty for ty in generator.value_types if ty in flags)),
+        ("ENUMS", "i32", sorted(ty for ty in generator.value_types if ty in enum_names)),
+    ]:
+        out.append("")
+        out.append(f"/// The named values of the {static.lower()} of the members.")
+        # The names of the enums also name the formats of the diagnostics.
+        if static == "FLAGS":
+            out.append('#[cfg(feature = "json")]')
+        out.append(f"pub(super) static {static}: &[ValueNames<{kind}>] = &[")
+        for name in names:
+            out.append("    ValueNames {")
+            out.append(f'        name: "{name}",')
+            out.append(f'        prefix: "{vulkan_prefix(name)}",')
+            out.append("        known: &[")
+            for value in known.get(name, []):
+                raw = f"vk::{name}::{value}.as_raw()"
+                if kind == "u64" and flags[name] == "Flags":
+                    raw = f"{raw} as u64"
+                out.append(f'            ({raw}, "{value}"),')
+            out.append("        ],")
+            out.append("    },")
+        out.append("];")
+
+    print("\n".join(out))
+
+
+if __name__ == "__main__":
+    main()
@@ -2,40 +2,118 @@ use core::ffi::CStr;
 
 use alloc::vec::Vec;
 
-use ash::{prelude::VkResult, vk, RawPtr};
+use ash::{vk, RawPtr};
 
 use crate::{
-    utils::{read_into_uninitialized_vector, read_into_uninitialized_vector_mut},
+    entry::MissingEntryPoint,
+    error::{Error, VpResult},
+    utils::{
+        block_name_as_ptr, bool_from_vk, load_fn, read_into_uninitialized_vector,
+        read_into_uninitialized_vector_mut,
+    },
     vp,
 };
 
+/// The implementation behind a [`vp::Capabilities`] handle.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BackendKind {
+    /// The generated library, statically linked or loaded.
+    Library,
+    /// The profiles of a [`json::Library`](crate::json::Library).
+    #[cfg(feature = "json")]
+    Json,
+}
+
 #[derive(Clone)]
 /// The Vulkan Profiles capabilities, roughly equivalent to an [`ash::Device`].
 pub struct Capabilities {
     handle: vp::Capabilities,
     fp: CapabilitiesFn,
+    vulkan_functions: vp::VulkanFunctions,
+    #[cfg_attr(not(vp_ash_video_queue), allow(dead_code))]
+    backend: BackendKind,
 }
 
 impl Capabilities {
     /// Create the [`Capabilities`] object using a handle and statically linked function pointers.
+    ///
+    /// # Safety
+    /// `handle` must be a live capabilities object created by the statically linked library, and
+    /// must outlive the returned object and its clones.
     #[cfg(feature = "linked")]
-    pub fn linked(handle: vp::Capabilities) -> Self {
+    pub unsafe fn linked(handle: vp::Capabilities) -> Self {
         Self {
             handle,
             fp: CapabilitiesFn::linked(),
+            vulkan_functions: vp::VulkanFunctions::default(),
+            backend: BackendKind::Library,
         }
     }
 
+    /// Create the [`Capabilities`] object from a handle and its function pointer table.
+    ///
+    /// # Safety
+    /// `handle` must be a live capabilities object, created by the library the function pointers
+    /// of `fp` belong to, and must outlive the returned object and its clones.
+    pub unsafe fn from_parts(handle: vp::Capabilities, fp: CapabilitiesFn) -> Self {
+        Self {
+            handle,
+            fp,
+            vulkan_functions: vp::VulkanFunctions::default(),
+            backend: BackendKind::Library,
+        }
+    }
+
+    /// Sets the Vulkan functions the capabilities were created with, which the diagnostics use
+    /// to query the instance and the physical devices.
+    pub fn with_vulkan_functions(mut self, vulkan_functions: vp::VulkanFunctions) -> Self {
+        self.vulkan_functions = vulkan_functions;
+        self
+    }
+
+    /// Sets the implementation behind the handle.
+    #[cfg(feature = "json")]
+    pub(crate) fn with_backend(mut self, backend: BackendKind) -> Self {
+        self.backend = backend;
+        self
+    }
+
     /// Returns the underlying [`vp::Capabilities`] handle.
     pub fn handle(&self) -> vp::Capabilities {
         self.handle
     }
 
+    /// Returns the implementation behind the handle.
+    #[cfg(vp_ash_video_queue)]
+    pub(crate) fn backend(&self) -> BackendKind {
+        self.backend
+    }
+
     /// Returns the raw function pointer table
     pub fn fp(&self) -> &CapabilitiesFn {
         &self.fp
     }
 
+    /// Returns the Vulkan functions the capabilities were created with, `None` for the functions
+    /// the library imported itself.
+    pub fn vulkan_functions(&self) -> &vp::VulkanFunctions {
+        &self.vulkan_functions
+    }
+
+    /// Maps a failed `result` of a query on `profile_properties` to an [`Error`].
+    unsafe fn profile_error(
+        &self,
+        result: vk::Result,
+        profile_properties: &vp::ProfileProperties,
+    ) -> Error {
+        profile_error(
+            self.fp.has_multiple_variants_profile,
+            self.handle,
+            result,
+            profile_properties,
+        )
+    }
+
     /// Destroys allocator object.
     pub unsafe fn destroy_capabilities(
         &self,
@@ -47,10 +125,11 @@ impl Capabilities {
     /// Query the list of available profiles in the library.
     ///
     /// <https://vulkan.lunarg.com/doc/view/1.4.304.0/windows/profiles_api_library.html#query-profiles>
-    pub unsafe fn get_profiles(&self) -> VkResult<Vec<vp::ProfileProperties>> {
+    pub unsafe fn get_profiles(&self) -> VpResult<Vec<vp::ProfileProperties>> {
         read_into_uninitialized_vector(|count, data| {
             (self.fp.get_profiles)(self.handle, count, data)
         })
+        .map_err(Error::Vulkan)
     }
 
     /// List the required profiles of a profile.
@@ -59,10 +138,11 @@ impl Capabilities {
     pub unsafe fn get_profile_required_profiles(
         &self,
         profile_properties: &vp::ProfileProperties,
-    ) -> VkResult<Vec<vp::ProfileProperties>> {
+    ) -> VpResult<Vec<vp::ProfileProperties>> {
         read_into_uninitialized_vector(|count, data| {
             (self.fp.get_profile_required_profiles)(self.handle, profile_properties, count, data)
         })
+        .map_err(|err| self.profile_error(err, profile_properties))
     }
 
     /// Query the profile required Vulkan API version.
@@ -81,10 +161,11 @@ impl Capabilities {
     pub unsafe fn get_profile_fallbacks(
         &self,
         profile_properties: &vp::ProfileProperties,
-    ) -> VkResult<Vec<vp::ProfileProperties>> {
+    ) -> VpResult<Vec<vp::ProfileProperties>> {
         read_into_uninitialized_vector(|count, data| {
             (self.fp.get_profile_fallbacks)(self.handle, profile_properties, count, data)
         })
+        .map_err(|err| self.profile_error(err, profile_properties))
     }
 
     /// Query whether the profile has multiple variants. Profiles with multiple variants can only
@@ -95,16 +176,38 @@ impl Capabilities {
     pub unsafe fn has_multiple_variants_profile(
         &self,
         profile_properties: &vp::ProfileProperties,
-    ) -> VkResult<bool> {
+    ) -> VpResult<bool> {
         let mut has_multiple_variants = vk::FALSE;
         (self.fp.has_multiple_variants_profile)(
             self.handle,
             profile_properties,
             &mut has_multiple_variants,
         )
-        .result()?;
+        .result()
+        .map_err(|err| self.profile_error(err, profile_properties))?;
 
-        Ok(has_multiple_variants == vk::TRUE)
+        bool_from_vk(has_multiple_variants)
+    }
+
+    /// Query the blocks of a profile and of its required profiles, with the profile declaring
+    /// each block. Every variant of the profile is one block, so the result can be passed to the
+    /// block scoped queries.
+    ///
+    /// Not part of the Vulkan Profiles API, returns [`Error::UnsupportedBackend`] for a library
+    /// that was not compiled by `vp-ash`.
+    pub unsafe fn get_profile_blocks(
+        &self,
+        profile_properties: &vp::ProfileProperties,
+    ) -> VpResult<Vec<vp::BlockProperties>> {
+        let get_profile_blocks = self
+            .fp
+            .get_profile_blocks
+            .ok_or(Error::UnsupportedBackend)?;
+
+        read_into_uninitialized_vector(|count, data| {
+            get_profile_blocks(self.handle, profile_properties, count, data)
+        })
+        .map_err(|err| self.profile_error(err, profile_properties))
     }
 
     /// Check whether a profile is supported at the instance level.
@@ -114,7 +217,7 @@ impl Capabilities {
         &self,
         layer_name: Option<&CStr>,
         profile_properties: &vp::ProfileProperties,
-    ) -> VkResult<bool> {
+    ) -> VpResult<bool> {
         let layer_name_ptr = match layer_name {
             Some(layer_name) => layer_name.as_ptr(),
             _ => core::ptr::null(),
@@ -127,9 +230,10 @@ impl Capabilities {
             profile_properties,
             &mut supported,
         )
-        .result()?;
+        .result()
+        .map_err(|err| self.profile_error(err, profile_properties))?;
 
-        Ok(supported == vk::TRUE)
+        bool_from_vk(supported)
     }
 
     /// Check whether a variant of a profile is supported at the instance level and report this list
@@ -140,7 +244,7 @@ impl Capabilities {
         &self,
         layer_name: Option<&CStr>,
         profile_properties: &vp::ProfileProperties,
-    ) -> VkResult<(bool, Vec<vp::BlockProperties>)> {
+    ) -> VpResult<(bool, Vec<vp::BlockProperties>)> {
         let layer_name_ptr = match layer_name {
             Some(layer_name) => layer_name.as_ptr(),
             _ => core::ptr::null(),
@@ -156,13 +260,10 @@ impl Capabilities {
                 count,
                 data,
             )
-        })?;
+        })
+        .map_err(|err| self.profile_error(err, profile_properties))?;
 
-        match supported {
-            vk::TRUE => Ok((true, blocks)),
-            vk::FALSE => Ok((false, blocks)),
-            _ => panic!("Unexpected result for vk::Bool32: {}", supported),
-        }
+        Ok((bool_from_vk(supported)?, blocks))
     }
 
     /// Create a VkInstance with the profile instance extensions enabled.
@@ -173,7 +274,7 @@ impl Capabilities {
         entry: &ash::Entry,
         instance_create_info: &vp::InstanceCreateInfo<'_>,
         allocation_callbacks: Option<&vk::AllocationCallbacks<'_>>,
-    ) -> VkResult<ash::Instance> {
+    ) -> VpResult<ash::Instance> {
         let mut instance = core::mem::zeroed();
         (self.fp.create_instance)(
             self.handle,
@@ -193,7 +294,7 @@ impl Capabilities {
         instance: &ash::Instance,
         physical_device: vk::PhysicalDevice,
         profile_properties: &vp::ProfileProperties,
-    ) -> VkResult<bool> {
+    ) -> VpResult<bool> {
         let mut supported = vk::FALSE;
         (self.fp.get_physical_device_profile_support)(
             self.handle,
@@ -202,8 +303,9 @@ impl Capabilities {
             profile_properties,
             &mut supported,
         )
-        .result()?;
-        Ok(supported == vk::TRUE)
+        .result()
+        .map_err(|err| self.profile_error(err, profile_properties))?;
+        bool_from_vk(supported)
     }
 
     /// Check whether a variant of a profile is supported by the physical device and report this
@@ -215,7 +317,7 @@ impl Capabilities {
         instance: &ash::Instance,
         physical_device: vk::PhysicalDevice,
         profile_properties: &vp::ProfileProperties,
-    ) -> VkResult<(bool, Vec<vp::BlockProperties>)> {
+    ) -> VpResult<(bool, Vec<vp::BlockProperties>)> {
         let mut supported = vk::FALSE;
         let blocks = read_into_uninitialized_vector_mut(|count, data| {
             (self.fp.get_physical_device_profile_variants_support)(
@@ -227,12 +329,10 @@ impl Capabilities {
                 count,
                 data,
             )
-        })?;
+        })
+        .map_err(|err| self.profile_error(err, profile_properties))?;
 
-        match supported {
-            vk::TRUE => Ok((true, blocks)),
-            _ => Ok((false, blocks)),
-        }
+        Ok((bool_from_vk(supported)?, blocks))
     }
 
     /// Create a VkDevice with the profile features and device extensions enabled.
@@ -244,7 +344,7 @@ impl Capabilities {
         physical_device: vk::PhysicalDevice,
         device_create_info: &vp::DeviceCreateInfo<'_>,
         allocation_callbacks: Option<&vk::AllocationCallbacks<'_>>,
-    ) -> VkResult<ash::Device> {
+    ) -> VpResult<ash::Device> {
         let mut device = vk::Device::null();
         (self.fp.create_device)(
             self.handle,
@@ -264,11 +364,8 @@ impl Capabilities {
         &self,
         profile_properties: &vp::ProfileProperties,
         block_name: Option<&CStr>,
-    ) -> VkResult<Vec<vk::ExtensionProperties>> {
-        let block_name_ptr = match block_name {
-            Some(name) => name.as_ptr(),
-            None => core::ptr::null(),
-        };
+    ) -> VpResult<Vec<vk::ExtensionProperties>> {
+        let block_name_ptr = block_name_as_ptr(block_name)?;
 
         read_into_uninitialized_vector(|count, data| {
             (self.fp.get_profile_instance_extension_properties)(
@@ -279,6 +376,7 @@ impl Capabilities {
                 data,
             )
         })
+        .map_err(|err| self.profile_error(err, profile_properties))
     }
 
     /// Query the list of device extensions of a profile.
@@ -288,11 +386,8 @@ impl Capabilities {
         &self,
         profile_properties: &vp::ProfileProperties,
         block_name: Option<&CStr>,
-    ) -> VkResult<Vec<vk::ExtensionProperties>> {
-        let block_name_ptr = match block_name {
-            Some(name) => name.as_ptr(),
-            None => core::ptr::null(),
-        };
+    ) -> VpResult<Vec<vk::ExtensionProperties>> {
+        let block_name_ptr = block_name_as_ptr(block_name)?;
 
         read_into_uninitialized_vector(|count, data| {
             (self.fp.get_profile_device_extension_properties)(
@@ -303,6 +398,7 @@ impl Capabilities {
                 data,
             )
         })
+        .map_err(|err| self.profile_error(err, profile_properties))
     }
 
     /// Fill the feature structures with the requirements of a profile.
@@ -313,11 +409,8 @@ impl Capabilities {
         profile_properties: &vp::ProfileProperties,
         block_name: Option<&CStr>,
         features: &mut vk::PhysicalDeviceFeatures2<'_>,
-    ) -> VkResult<()> {
-        let block_name_ptr = match block_name {
-            Some(name) => name.as_ptr(),
-            None => core::ptr::null(),
-        };
+    ) -> VpResult<()> {
+        let block_name_ptr = block_name_as_ptr(block_name)?;
 
         (self.fp.get_profile_features)(
             self.handle,
@@ -326,6 +419,7 @@ impl Capabilities {
             <*mut _>::cast(features),
         )
         .result()
+        .map_err(|err| self.profile_error(err, profile_properties))
     }
 
     /// Query the list of feature structure types specified by the profile.
@@ -335,11 +429,8 @@ impl Capabilities {
         &self,
         profile_properties: &vp::ProfileProperties,
         block_name: Option<&CStr>,
-    ) -> VkResult<Vec<vk::StructureType>> {
-        let block_name_ptr = match block_name {
-            Some(name) => name.as_ptr(),
-            None => core::ptr::null(),
-        };
+    ) -> VpResult<Vec<vk::StructureType>> {
+        let block_name_ptr = block_name_as_ptr(block_name)?;
 
         read_into_uninitialized_vector(|count, data| {
             (self.fp.get_profile_feature_structure_types)(
@@ -350,6 +441,7 @@ impl Capabilities {
                 data,
             )
         })
+        .map_err(|err| self.profile_error(err, profile_properties))
     }
 
     /// Fill the property structures with the requirements of a profile.
@@ -360,11 +452,8 @@ impl Capabilities {
         profile_properties: &vp::ProfileProperties,
         block_name: Option<&CStr>,
         properties: &mut vk::PhysicalDeviceProperties2<'_>,
-    ) -> VkResult<()> {
-        let block_name_ptr = match block_name {
-            Some(name) => name.as_ptr(),
-            None => core::ptr::null(),
-        };
+    ) -> VpResult<()> {
+        let block_name_ptr = block_name_as_ptr(block_name)?;
 
         (self.fp.get_profile_properties)(
             self.handle,
@@ -373,6 +462,7 @@ impl Capabilities {
             <*mut _>::cast(properties),
         )
         .result()
+        .map_err(|err| self.profile_error(err, profile_properties))
     }
 
     /// Query the list of property structure types specified by the profile.
@@ -382,11 +472,8 @@ impl Capabilities {
         &self,
         profile_properties: &vp::ProfileProperties,
         block_name: Option<&CStr>,
-    ) -> VkResult<Vec<vk::StructureType>> {
-        let block_name_ptr = match block_name {
-            Some(name) => name.as_ptr(),
-            None => core::ptr::null(),
-        };
+    ) -> VpResult<Vec<vk::StructureType>> {
+        let block_name_ptr = block_name_as_ptr(block_name)?;
 
         read_into_uninitialized_vector(|count, data| {
             (self.fp.get_profile_property_structure_types)(
@@ -397,6 +484,7 @@ impl Capabilities {
                 data,
             )
         })
+        .map_err(|err| self.profile_error(err, profile_properties))
     }
 
     /// Fill the queue family property structures with the requirements of a profile.
@@ -410,11 +498,8 @@ impl Capabilities {
         block_name: Option<&CStr>,
         property_count: &mut u32,
         queue_family_properties: Option<&mut [vk::QueueFamilyProperties2KHR<'_>]>,
-    ) -> VkResult<()> {
-        let block_name_ptr = match block_name {
-            Some(name) => name.as_ptr(),
-            None => core::ptr::null(),
-        };
+    ) -> VpResult<()> {
+        let block_name_ptr = block_name_as_ptr(block_name)?;
 
         let queue_family_properties_ptr = match queue_family_properties {
             Some(properties) => properties.as_mut_ptr(),
@@ -429,6 +514,7 @@ impl Capabilities {
             queue_family_properties_ptr,
         )
         .result()
+        .map_err(|err| self.profile_error(err, profile_properties))
     }
 
     /// Query the list of queue family property structure types specified by the profile.
@@ -438,11 +524,8 @@ impl Capabilities {
         &self,
         profile_properties: &vp::ProfileProperties,
         block_name: Option<&CStr>,
-    ) -> VkResult<Vec<vk::StructureType>> {
-        let block_name_ptr = match block_name {
-            Some(name) => name.as_ptr(),
-            None => core::ptr::null(),
-        };
+    ) -> VpResult<Vec<vk::StructureType>> {
+        let block_name_ptr = block_name_as_ptr(block_name)?;
 
         read_into_uninitialized_vector(|count, data| {
             (self.fp.get_profile_queue_family_structure_types)(
@@ -453,6 +536,7 @@ impl Capabilities {
                 data,
             )
         })
+        .map_err(|err| self.profile_error(err, profile_properties))
     }
 
     /// Query the list of formats with specified requirements by a profile.
@@ -462,11 +546,8 @@ impl Capabilities {
         &self,
         profile_properties: &vp::ProfileProperties,
         block_name: Option<&CStr>,
-    ) -> VkResult<Vec<vk::Format>> {
-        let block_name_ptr = match block_name {
-            Some(name) => name.as_ptr(),
-            None => core::ptr::null(),
-        };
+    ) -> VpResult<Vec<vk::Format>> {
+        let block_name_ptr = block_name_as_ptr(block_name)?;
 
         read_into_uninitialized_vector(|count, data| {
             (self.fp.get_profile_formats)(
@@ -477,6 +558,7 @@ impl Capabilities {
                 data,
             )
         })
+        .map_err(|err| self.profile_error(err, profile_properties))
     }
 
     /// Query the requirements of a format for a profile.
@@ -488,11 +570,8 @@ impl Capabilities {
         block_name: Option<&CStr>,
         format: vk::Format,
         properties: &mut vk::FormatProperties2<'_>,
-    ) -> VkResult<()> {
-        let block_name_ptr = match block_name {
-            Some(name) => name.as_ptr(),
-            None => core::ptr::null(),
-        };
+    ) -> VpResult<()> {
+        let block_name_ptr = block_name_as_ptr(block_name)?;
 
         (self.fp.get_profile_format_properties)(
             self.handle,
@@ -502,6 +581,7 @@ impl Capabilities {
             <*mut _>::cast(properties),
         )
         .result()
+        .map_err(|err| self.profile_error(err, profile_properties))
     }
 
     /// Query the list of format structure types specified by the profile.
@@ -511,11 +591,8 @@ impl Capabilities {
         &self,
         profile_properties: &vp::ProfileProperties,
         block_name: Option<&CStr>,
-    ) -> VkResult<Vec<vk::StructureType>> {
-        let block_name_ptr = match block_name {
-            Some(name) => name.as_ptr(),
-            None => core::ptr::null(),
-        };
+    ) -> VpResult<Vec<vk::StructureType>> {
+        let block_name_ptr = block_name_as_ptr(block_name)?;
 
         read_into_uninitialized_vector(|count, data| {
             (self.fp.get_profile_format_structure_types)(
@@ -526,6 +603,80 @@ impl Capabilities {
                 data,
             )
         })
+        .map_err(|err| self.profile_error(err, profile_properties))
+    }
+}
+
+/// Maps a failed `result` of a query on `profile_properties` to an [`Error`], the library
+/// returns [`vk::Result::ERROR_UNKNOWN`] for both unknown and multiple variant profiles.
+pub(crate) unsafe fn profile_error(
+    has_multiple_variants_profile: vp::PFN_vpHasMultipleVariantsProfile,
+    capabilities: vp::Capabilities,
+    result: vk::Result,
+    profile_properties: &vp::ProfileProperties,
+) -> Error {
+    if result != vk::Result::ERROR_UNKNOWN {
+        return Error::Vulkan(result);
+    }
+
+    let mut has_multiple_variants = vk::FALSE;
+    match has_multiple_variants_profile(
+        capabilities,
+        profile_properties,
+        &mut has_multiple_variants,
+    ) {
+        vk::Result::SUCCESS if has_multiple_variants == vk::TRUE => Error::MultipleVariantsProfile,
+        vk::Result::SUCCESS => Error::Vulkan(result),
+        _ => Error::UnknownProfile,
+    }
+}
+
+/// A [`Capabilities`] that destroys its handle with the allocation callbacks it was created with
+/// when dropped.
+pub struct OwnedCapabilities<'a> {
+    capabilities: Capabilities,
+    allocation_callbacks: Option<vk::AllocationCallbacks<'a>>,
+}
+
+impl<'a> OwnedCapabilities<'a> {
+    /// Take ownership of `capabilities`, `allocation_callbacks` must be compatible with the ones
+    /// used to create it.
+    ///
+    /// # Safety
+    /// No clone of `capabilities`, including the clones made through [`Deref`](core::ops::Deref),
+    /// may be used after the [`OwnedCapabilities`] is dropped.
+    pub unsafe fn new(
+        capabilities: Capabilities,
+        allocation_callbacks: Option<&vk::AllocationCallbacks<'a>>,
+    ) -> Self {
+        Self {
+            capabilities,
+            allocation_callbacks: allocation_callbacks.copied(),
+        }
+    }
+
+    /// Release ownership of the [`Capabilities`] without destroying it.
+    pub fn into_inner(self) -> Capabilities {
+        let this = core::mem::ManuallyDrop::new(self);
+        // SAFETY: `this` is never dropped, so the capabilities are only read once.
+        unsafe { core::ptr::read(&this.capabilities) }
+    }
+}
+
+impl core::ops::Deref for OwnedCapabilities<'_> {
+    type Target = Capabilities;
+
+    fn deref(&self) -> &Self::Target {
+        &self.capabilities
+    }
+}
+
+impl Drop for OwnedCapabilities<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            self.capabilities
+                .destroy_capabilities(self.allocation_callbacks.as_ref())
+        };
     }
 }
 
@@ -556,6 +707,8 @@ pub struct CapabilitiesFn {
     pub get_profile_format_structure_types: vp::PFN_vpGetProfileFormatStructureTypes,
     pub get_profile_queue_family_properties: vp::PFN_vpGetProfileQueueFamilyProperties,
     pub get_profile_queue_family_structure_types: vp::PFN_vpGetProfileQueueFamilyStructureTypes,
+    /// Only exported by the library `vp-ash` compiles, `None` for the other libraries.
+    pub get_profile_blocks: Option<vp::PFN_vpAshGetProfileBlocks>,
 }
 
 impl CapabilitiesFn {
@@ -590,6 +743,81 @@ impl CapabilitiesFn {
             get_profile_queue_family_properties: vp::linked::vpGetProfileQueueFamilyProperties,
             get_profile_queue_family_structure_types:
                 vp::linked::vpGetProfileQueueFamilyStructureTypes,
+            get_profile_blocks: Some(vp::linked::vpAshGetProfileBlocks),
+        }
+    }
+
+    /// Load the function pointers using `f` to resolve each function by name.
+    ///
+    /// Returns the first function that `f` resolves to a null pointer as an error, except for
+    /// [`get_profile_blocks`](Self::get_profile_blocks) which is left `None`.
+    pub fn load<F>(mut f: F) -> Result<Self, MissingEntryPoint>
+    where
+        F: FnMut(&CStr) -> *const core::ffi::c_void,
+    {
+        Self::load_erased(&mut f)
+    }
+
+    fn load_erased(
+        f: &mut dyn FnMut(&CStr) -> *const core::ffi::c_void,
+    ) -> Result<Self, MissingEntryPoint> {
+        unsafe {
+            Ok(Self {
+                destroy_capabilities: load_fn(f, c"vpDestroyCapabilities")?,
+                get_profiles: load_fn(f, c"vpGetProfiles")?,
+                get_profile_required_profiles: load_fn(f, c"vpGetProfileRequiredProfiles")?,
+                get_profile_api_version: load_fn(f, c"vpGetProfileAPIVersion")?,
+                get_profile_fallbacks: load_fn(f, c"vpGetProfileFallbacks")?,
+                has_multiple_variants_profile: load_fn(f, c"vpHasMultipleVariantsProfile")?,
+                get_instance_profile_support: load_fn(f, c"vpGetInstanceProfileSupport")?,
+                get_instance_profile_variants_support: load_fn(
+                    f,
+                    c"vpGetInstanceProfileVariantsSupport",
+                )?,
+                create_instance: load_fn(f, c"vpCreateInstance")?,
+                get_physical_device_profile_support: load_fn(
+                    f,
+                    c"vpGetPhysicalDeviceProfileSupport",
+                )?,
+                get_physical_device_profile_variants_support: load_fn(
+                    f,
+                    c"vpGetPhysicalDeviceProfileVariantsSupport",
+                )?,
+                create_device: load_fn(f, c"vpCreateDevice")?,
+                get_profile_instance_extension_properties: load_fn(
+                    f,
+                    c"vpGetProfileInstanceExtensionProperties",
+                )?,
+                get_profile_device_extension_properties: load_fn(
+                    f,
+                    c"vpGetProfileDeviceExtensionProperties",
+                )?,
+                get_profile_features: load_fn(f, c"vpGetProfileFeatures")?,
+                get_profile_feature_structure_types: load_fn(
+                    f,
+                    c"vpGetProfileFeatureStructureTypes",
+                )?,
+                get_profile_properties: load_fn(f, c"vpGetProfileProperties")?,
+                get_profile_property_structure_types: load_fn(
+                    f,
+                    c"vpGetProfilePropertyStructureTypes",
+                )?,
+                get_profile_formats: load_fn(f, c"vpGetProfileFormats")?,
+                get_profile_format_properties: load_fn(f, c"vpGetProfileFormatProperties")?,
+                get_profile_format_structure_types: load_fn(
+                    f,
+                    c"vpGetProfileFormatStructureTypes",
+                )?,
+                get_profile_queue_family_properties: load_fn(
+                    f,
+                    c"vpGetProfileQueueFamilyProperties",
+                )?,
+                get_profile_queue_family_structure_types: load_fn(
+                    f,
+                    c"vpGetProfileQueueFamilyStructureTypes",
+                )?,
+                get_profile_blocks: load_fn(f, c"vpAshGetProfileBlocks").ok(),
+            })
         }
     }
 }
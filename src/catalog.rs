@@ -0,0 +1,228 @@
+//! An owned snapshot of the requirements of every profile in the library.
+//!
+//! The [`ProfileCatalog`] does not borrow the [`Capabilities`] it was created from, so it can be
+//! cached, compared and sent to other threads.
+
+use core::ffi::CStr;
+
+use alloc::{collections::BTreeMap, ffi::CString, vec::Vec};
+
+use ash::vk;
+
+use crate::{
+    chain::{FeatureChain, PropertyChain},
+    error::{Error, VpResult},
+    format::FormatRequirement,
+    queue_family::QueueFamilyRequirement,
+    vp, Capabilities,
+};
+
+impl Capabilities {
+    /// Returns the requirements of every profile in the library.
+    pub fn catalog(&self) -> VpResult<ProfileCatalog> {
+        let profiles = self
+            .profiles()?
+            .iter()
+            .map(|profile| self.profile_requirements(profile.properties()))
+            .collect::<VpResult<_>>()?;
+
+        Ok(ProfileCatalog { profiles })
+    }
+
+    fn profile_requirements(
+        &self,
+        profile: &vp::ProfileProperties,
+    ) -> VpResult<ProfileRequirements> {
+        // SAFETY: The profile is in the library.
+        unsafe {
+            let device = match self.has_multiple_variants_profile(profile)? {
+                true => None,
+                false => Some(self.device_requirements(profile, None)?),
+            };
+
+            // A library loaded at runtime may not list the blocks.
+            let variants = match self.get_profile_blocks(profile) {
+                Err(Error::UnsupportedBackend) => None,
+                blocks => Some(
+                    blocks?
+                        .iter()
+                        .map(|block| {
+                            let block_name = block.block_name_as_c_str().unwrap_or(c"");
+                            Ok(VariantRequirements {
+                                profile: block.profiles,
+                                block_name: block_name.into(),
+                                device: self
+                                    .device_requirements(&block.profiles, Some(block_name))?,
+                            })
+                        })
+                        .collect::<VpResult<_>>()?,
+                ),
+            };
+
+            Ok(ProfileRequirements {
+                properties: *profile,
+                api_version: self.get_profile_api_version(profile),
+                fallbacks: self.get_profile_fallbacks(profile)?,
+                required_profiles: self.get_profile_required_profiles(profile)?,
+                instance_extensions: extensions(
+                    self.get_profile_instance_extension_properties(profile, None)?,
+                ),
+                device_extensions: extensions(
+                    self.get_profile_device_extension_properties(profile, None)?,
+                ),
+                device,
+                variants,
+            })
+        }
+    }
+
+    unsafe fn device_requirements(
+        &self,
+        profile: &vp::ProfileProperties,
+        block_name: Option<&CStr>,
+    ) -> VpResult<DeviceRequirements> {
+        let features = FeatureChain::new(self, profile, block_name)?;
+
+        let properties = PropertyChain::new(self, profile, block_name)?;
+
+        let format_structure_types =
+            self.get_profile_format_structure_types(profile, block_name)?;
+        let formats = self.format_requirements(profile, block_name)?;
+
+        let queue_family_structure_types =
+            self.get_profile_queue_family_structure_types(profile, block_name)?;
+        let queue_families = self.queue_family_requirements(profile, block_name)?;
+
+        Ok(DeviceRequirements {
+            features,
+            properties,
+            format_structure_types,
+            formats,
+            queue_family_structure_types,
+            queue_families,
+        })
+    }
+}
+
+fn extensions(properties: Vec<vk::ExtensionProperties>) -> Vec<Extension> {
+    properties
+        .iter()
+        .map(|properties| Extension {
+            name: properties
+                .extension_name_as_c_str()
+                .unwrap_or_default()
+                .into(),
+            spec_version: properties.spec_version,
+        })
+        .collect()
+}
+
+/// The requirements of every profile in the library, created by [`Capabilities::catalog`].
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct ProfileCatalog {
+    profiles: Vec<ProfileRequirements>,
+}
+
+impl ProfileCatalog {
+    /// Returns the requirements of each profile, in the order of [`Capabilities::profiles`].
+    pub fn profiles(&self) -> &[ProfileRequirements] {
+        &self.profiles
+    }
+
+    /// Returns the requirements of the profile named `name`.
+    pub fn profile(&self, name: &CStr) -> Option<&ProfileRequirements> {
+        self.profiles
+            .iter()
+            .find(|profile| profile.properties.profile_name_as_c_str() == Ok(name))
+    }
+}
+
+impl<'a> IntoIterator for &'a ProfileCatalog {
+    type Item = &'a ProfileRequirements;
+    type IntoIter = core::slice::Iter<'a, ProfileRequirements>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.profiles.iter()
+    }
+}
+
+/// The requirements of a profile, including the requirements of its required profiles.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct ProfileRequirements {
+    pub properties: vp::ProfileProperties,
+    pub api_version: u32,
+    pub fallbacks: Vec<vp::ProfileProperties>,
+    pub required_profiles: Vec<vp::ProfileProperties>,
+    /// The instance extensions of every variant.
+    pub instance_extensions: Vec<Extension>,
+    /// The device extensions of every variant.
+    pub device_extensions: Vec<Extension>,
+    /// The device requirements, `None` for profiles with multiple variants which the library only
+    /// reports per block, see [`variants`](Self::variants).
+    pub device: Option<DeviceRequirements>,
+    /// The device requirements of each block of the profile and of its required profiles. `None`
+    /// for a library loaded at runtime that does not export `vpAshGetProfileBlocks`, see
+    /// [`Capabilities::get_profile_blocks`].
+    pub variants: Option<Vec<VariantRequirements>>,
+}
+
+impl ProfileRequirements {
+    /// Returns the name of the profile.
+    pub fn name(&self) -> &CStr {
+        self.properties.profile_name_as_c_str().unwrap_or(c"")
+    }
+
+    /// Returns whether the profile has multiple variants.
+    pub fn has_multiple_variants(&self) -> bool {
+        self.device.is_none()
+    }
+}
+
+/// An extension required by a profile.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Extension {
+    pub name: CString,
+    pub spec_version: u32,
+}
+
+/// The device requirements of a block, one variant of a profile.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct VariantRequirements {
+    /// The profile declaring the block, the profile or one of its required profiles.
+    pub profile: vp::ProfileProperties,
+    pub block_name: CString,
+    pub device: DeviceRequirements,
+}
+
+/// The feature, property, format and queue family requirements of a profile.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct DeviceRequirements {
+    pub features: FeatureChain,
+    pub properties: PropertyChain,
+    pub format_structure_types: Vec<vk::StructureType>,
+    pub formats: BTreeMap<vk::Format, FormatRequirement>,
+    pub queue_family_structure_types: Vec<vk::StructureType>,
+    pub queue_families: Vec<QueueFamilyRequirement>,
+}
+
+impl DeviceRequirements {
+    /// Returns the feature structure `T`, if the profile reports it.
+    pub fn feature<T: vk::TaggedStructure>(&self) -> Option<&T> {
+        self.features.get()
+    }
+
+    /// Returns the property structure `T`, if the profile reports it.
+    pub fn property<T: vk::TaggedStructure>(&self) -> Option<&T> {
+        self.properties.get()
+    }
+
+    /// Returns the requirements of `format`.
+    pub fn format(&self, format: vk::Format) -> Option<&FormatRequirement> {
+        self.formats.get(&format)
+    }
+}
@@ -0,0 +1,142 @@
+//! Structure chains allocated from the structure types reported for a profile.
+//!
+//! The library reports which structures a profile has requirements in, the chains allocate
+//! each of them that `ash` knows, so the requirements can be queried without building the
+//! `p_next` chain by hand.
+//!
+//! ```no_run
+//! # use ash::vk;
+//! # fn example(capabilities: &vp_ash::Capabilities) -> vp_ash::VpResult<()> {
+//! let profile = capabilities.profile(c"VP_KHR_roadmap_2022")?;
+//! let features = profile.feature_chain(None)?;
+//!
+//! let vulkan_12 = features.get::<vk::PhysicalDeviceVulkan12Features>();
+//!
+//! let properties = profile.property_chain(None)?;
+//! let max_storage_buffer_range = properties.limits().max_storage_buffer_range;
+//! # Ok(()) }
+//! ```
+
+use core::ffi::CStr;
+
+use alloc::vec::Vec;
+
+use ash::vk;
+
+use crate::{
+    error::VpResult,
+    structure::{self, Chain, Structure},
+    vp, Capabilities,
+};
+
+/// The feature structures of a profile, starting with [`vk::PhysicalDeviceFeatures2`].
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct FeatureChain {
+    structure_types: Vec<vk::StructureType>,
+    structures: Vec<Structure>,
+}
+
+impl FeatureChain {
+    /// Queries the features of `profile_properties`, or of the block named `block_name`.
+    ///
+    /// See [`Profile::feature_chain`](crate::Profile::feature_chain) for the safe equivalent.
+    ///
+    /// <https://vulkan.lunarg.com/doc/view/1.4.304.0/windows/profiles_api_library.html#query-profile-features>
+    pub unsafe fn new(
+        capabilities: &Capabilities,
+        profile_properties: &vp::ProfileProperties,
+        block_name: Option<&CStr>,
+    ) -> VpResult<Self> {
+        let structure_types =
+            capabilities.get_profile_feature_structure_types(profile_properties, block_name)?;
+        let structures = structure::fill_chain(Chain::Features, &structure_types, |head| {
+            capabilities.get_profile_features(profile_properties, block_name, &mut *head.cast())
+        })?;
+
+        Ok(Self {
+            structure_types,
+            structures,
+        })
+    }
+
+    /// Returns the structure types reported by the library, including those unknown to `ash`.
+    pub fn structure_types(&self) -> &[vk::StructureType] {
+        &self.structure_types
+    }
+
+    /// Returns the allocated structures, the head first.
+    pub fn structures(&self) -> &[Structure] {
+        &self.structures
+    }
+
+    /// Returns the feature structure `T`, if the profile reports it.
+    pub fn get<T: vk::TaggedStructure>(&self) -> Option<&T> {
+        self.structures.iter().find_map(Structure::get)
+    }
+
+    /// Returns the [`vk::PhysicalDeviceFeatures2`] at the head of the chain, its `p_next` is null.
+    pub fn features2(&self) -> &vk::PhysicalDeviceFeatures2<'static> {
+        self.get()
+            .expect("the head of the chain is always allocated")
+    }
+}
+
+/// The property structures of a profile, starting with [`vk::PhysicalDeviceProperties2`].
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct PropertyChain {
+    structure_types: Vec<vk::StructureType>,
+    structures: Vec<Structure>,
+}
+
+impl PropertyChain {
+    /// Queries the properties of `profile_properties`, or of the block named `block_name`.
+    ///
+    /// See [`Profile::property_chain`](crate::Profile::property_chain) for the safe equivalent.
+    ///
+    /// <https://vulkan.lunarg.com/doc/view/1.4.304.0/windows/profiles_api_library.html#query-profile-device-properties>
+    pub unsafe fn new(
+        capabilities: &Capabilities,
+        profile_properties: &vp::ProfileProperties,
+        block_name: Option<&CStr>,
+    ) -> VpResult<Self> {
+        let structure_types =
+            capabilities.get_profile_property_structure_types(profile_properties, block_name)?;
+        let structures = structure::fill_chain(Chain::Properties, &structure_types, |head| {
+            capabilities.get_profile_properties(profile_properties, block_name, &mut *head.cast())
+        })?;
+
+        Ok(Self {
+            structure_types,
+            structures,
+        })
+    }
+
+    /// Returns the structure types reported by the library, including those unknown to `ash`.
+    pub fn structure_types(&self) -> &[vk::StructureType] {
+        &self.structure_types
+    }
+
+    /// Returns the allocated structures, the head first.
+    pub fn structures(&self) -> &[Structure] {
+        &self.structures
+    }
+
+    /// Returns the property structure `T`, if the profile reports it.
+    pub fn get<T: vk::TaggedStructure>(&self) -> Option<&T> {
+        self.structures.iter().find_map(Structure::get)
+    }
+
+    /// Returns the [`vk::PhysicalDeviceProperties2`] at the head of the chain, its `p_next` is
+    /// null.
+    pub fn properties2(&self) -> &vk::PhysicalDeviceProperties2<'static> {
+        self.get()
+            .expect("the head of the chain is always allocated")
+    }
+
+    /// Returns the limits of [`vk::PhysicalDeviceProperties`].
+    pub fn limits(&self) -> &vk::PhysicalDeviceLimits {
+        &self.properties2().properties.limits
+    }
+}
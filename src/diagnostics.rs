@@ -0,0 +1,1013 @@
+//! Explanations of why an instance or a physical device does not support a profile.
+//!
+//! [`Capabilities::get_instance_profile_support`] and
+//! [`Capabilities::get_physical_device_profile_support`] only report whether a profile is
+//! supported, [`Capabilities::explain_instance_support`] and
+//! [`Capabilities::explain_device_support`] list every requirement that is not met.
+//!
+//! ```no_run
+//! # unsafe fn example(
+//! #     capabilities: &vp_ash::Capabilities,
+//! #     entry: &ash::Entry,
+//! #     instance: &ash::Instance,
+//! #     physical_device: ash::vk::PhysicalDevice,
+//! #     profile: vp_ash::vp::ProfileProperties,
+//! # ) -> vp_ash::VpResult<()> {
+//! for unmet in capabilities.explain_instance_support(entry, None, &profile)? {
+//!     // Like "VK_KHR_display required, instance does not support it".
+//!     println!("{unmet}");
+//! }
+//! for unmet in capabilities.explain_device_support(entry, instance, physical_device, &profile)? {
+//!     // Like "VkPhysicalDeviceShaderFloat16Int8Features.shaderInt8 required true, device false".
+//!     println!("{unmet}");
+//! }
+//! # Ok(()) }
+//! ```
+
+use core::{ffi::CStr, fmt};
+
+use alloc::{ffi::CString, format, string::String, vec::Vec};
+
+use ash::vk;
+
+use crate::{
+    chain::{FeatureChain, PropertyChain},
+    error::VpResult,
+    format::FormatRequirement,
+    queue_family::{self, QueueFamilyRequirement},
+    structure::{self, Chain, Compare, Members, Structure, Value},
+    utils::{check_version, instance_fn_or_khr, read_into_uninitialized_vector},
+    vp, Capabilities,
+};
+
+impl Capabilities {
+    /// Returns every instance requirement of `profile_properties` that is not met, or an empty
+    /// list if the instance meets them all.
+    ///
+    /// The extensions are those of the implementation and the implicit layers, or of the layer
+    /// named `layer_name`, like [`get_instance_profile_support`](Self::get_instance_profile_support).
+    /// They are queried with the [`vp::VulkanFunctions`] the capabilities were created with, the
+    /// functions left as `None` are taken from `entry`.
+    ///
+    /// Profiles with multiple variants list an [`UnmetInstanceRequirement::Variant`] for each
+    /// block
+    /// [`get_instance_profile_variants_support`](Self::get_instance_profile_variants_support)
+    /// reports as unsupported.
+    pub unsafe fn explain_instance_support(
+        &self,
+        entry: &ash::Entry,
+        layer_name: Option<&CStr>,
+        profile_properties: &vp::ProfileProperties,
+    ) -> VpResult<Vec<UnmetInstanceRequirement>> {
+        let functions = self.vulkan_functions();
+        let instance_version = match functions.enumerate_instance_version {
+            Some(enumerate_instance_version) => {
+                let mut api_version = 0;
+                enumerate_instance_version(&mut api_version).result()?;
+                api_version
+            }
+            // Vulkan 1.0 loaders do not have `vkEnumerateInstanceVersion`.
+            None => entry
+                .try_enumerate_instance_version()?
+                .unwrap_or(vk::API_VERSION_1_0),
+        };
+
+        let enumerate_instance_extension_properties = functions
+            .enumerate_instance_extension_properties
+            .unwrap_or(entry.fp_v1_0().enumerate_instance_extension_properties);
+        let layer_name_ptr = layer_name.map_or(core::ptr::null(), CStr::as_ptr);
+        let instance_extensions = read_into_uninitialized_vector(|count, data| {
+            enumerate_instance_extension_properties(layer_name_ptr, count, data)
+        })?;
+
+        let mut unmet = Vec::new();
+        let required = self.get_profile_api_version(profile_properties);
+        if !check_version(instance_version, required) {
+            unmet.push(UnmetInstanceRequirement::ApiVersion {
+                required,
+                instance: instance_version,
+            });
+        }
+
+        let missing_extensions = |extensions: Vec<vk::ExtensionProperties>| {
+            extensions
+                .into_iter()
+                .filter_map(|extension| {
+                    let name = extension.extension_name_as_c_str().unwrap_or_default();
+                    (!has_extension(&instance_extensions, name)).then(|| {
+                        UnmetInstanceRequirement::Extension {
+                            name: name.into(),
+                            spec_version: extension.spec_version,
+                            layer_name: layer_name.map(CString::from),
+                        }
+                    })
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut extensions = Vec::new();
+        if self.has_multiple_variants_profile(profile_properties)? {
+            let (supported, blocks) =
+                self.get_instance_profile_variants_support(layer_name, profile_properties)?;
+            for block in blocks.iter().filter(|_| !supported) {
+                let block_name = block.block_name_as_c_str().unwrap_or_default();
+                let block_unmet =
+                    missing_extensions(self.get_profile_instance_extension_properties(
+                        &block.profiles,
+                        Some(block_name),
+                    )?);
+                if !block_unmet.is_empty() {
+                    unmet.push(UnmetInstanceRequirement::Variant {
+                        profile_name: block
+                            .profiles
+                            .profile_name_as_c_str()
+                            .unwrap_or_default()
+                            .into(),
+                        block_name: block_name.into(),
+                        unmet: block_unmet,
+                    });
+                }
+            }
+        } else {
+            extensions =
+                self.get_profile_instance_extension_properties(profile_properties, None)?;
+        }
+        // The library queries the physical devices with `vkGetPhysicalDeviceProperties2`, which
+        // Vulkan 1.0 instances only have with `VK_KHR_get_physical_device_properties2`.
+        if instance_version < vk::API_VERSION_1_1
+            && !has_extension(&extensions, vk::KHR_GET_PHYSICAL_DEVICE_PROPERTIES2_NAME)
+        {
+            extensions.push(
+                vk::ExtensionProperties::default()
+                    .extension_name(vk::KHR_GET_PHYSICAL_DEVICE_PROPERTIES2_NAME)
+                    .expect("the extension name fits")
+                    .spec_version(vk::KHR_GET_PHYSICAL_DEVICE_PROPERTIES2_SPEC_VERSION),
+            );
+        }
+        unmet.extend(missing_extensions(extensions));
+
+        Ok(unmet)
+    }
+
+    /// Returns every requirement of `profile_properties` that `physical_device` does not meet,
+    /// or an empty list if the device meets them all.
+    ///
+    /// The device is queried with the [`vp::VulkanFunctions`] the capabilities were created
+    /// with, the functions left as `None` are taken from `instance`. Like the library, the
+    /// `vkGetPhysicalDevice*2` functions fall back to their `KHR` aliases, a Vulkan 1.0 instance
+    /// without `VK_KHR_get_physical_device_properties2` returns
+    /// [`vk::Result::ERROR_EXTENSION_NOT_PRESENT`].
+    ///
+    /// Members are compared by the comparison the structure registry generates for them, from
+    /// their `limittype` in `vk.xml`:
+    /// - Features must be enabled and flags must contain the required flags.
+    /// - Maximum limits must be at least the required value, minimum limits at most.
+    /// - Ranges must contain the required range, other values must be equal.
+    ///
+    /// The registry of this release was generated without `vk.xml`, so the comparisons follow the
+    /// names of the limits: `max*`, `*Bits` and `*Count` are maximum limits, `min*`,
+    /// `*Alignment`, `*Granularity` and `*AtomSize` minimum limits, and two-element `*Range`
+    /// arrays ranges.
+    ///
+    /// Members the profile does not set have no requirement, but those it sets are compared even
+    /// when zero. A feature the profile sets to `false` is met by every device.
+    ///
+    /// The list is empty if and only if
+    /// [`get_physical_device_profile_variants_support`](Self::get_physical_device_profile_variants_support)
+    /// reports the profile as supported. Profiles with multiple variants list an
+    /// [`UnmetRequirement::Variant`] for each block the library reports as unsupported. Video
+    /// profiles are not compared, a device the library rejects without any other unmet
+    /// requirement lists [`UnmetRequirement::Uncompared`].
+    pub unsafe fn explain_device_support(
+        &self,
+        entry: &ash::Entry,
+        instance: &ash::Instance,
+        physical_device: vk::PhysicalDevice,
+        profile_properties: &vp::ProfileProperties,
+    ) -> VpResult<Vec<UnmetRequirement>> {
+        let device =
+            DeviceFunctions::new(self.vulkan_functions(), entry, instance, physical_device)?;
+        let (supported, unsupported_blocks) = self.get_physical_device_profile_variants_support(
+            instance,
+            physical_device,
+            profile_properties,
+        )?;
+        if supported {
+            return Ok(Vec::new());
+        }
+
+        let mut unmet = Vec::new();
+
+        let required = self.get_profile_api_version(profile_properties);
+        let device_api_version = device
+            .properties(&[])
+            .iter()
+            .find_map(Structure::get::<vk::PhysicalDeviceProperties2<'_>>)
+            .map_or(0, |properties2| properties2.properties.api_version);
+        if !check_version(device_api_version, required) {
+            unmet.push(UnmetRequirement::ApiVersion {
+                required,
+                device: device_api_version,
+            });
+        }
+
+        if self.has_multiple_variants_profile(profile_properties)? {
+            for block in unsupported_blocks {
+                let block_name = block.block_name_as_c_str().unwrap_or_default();
+                let mut block_unmet =
+                    self.explain_requirements(&device, &block.profiles, Some(block_name))?;
+                if block_unmet.is_empty() {
+                    block_unmet.push(UnmetRequirement::Uncompared);
+                }
+                unmet.push(UnmetRequirement::Variant {
+                    profile_name: block
+                        .profiles
+                        .profile_name_as_c_str()
+                        .unwrap_or_default()
+                        .into(),
+                    block_name: block_name.into(),
+                    unmet: block_unmet,
+                });
+            }
+        } else {
+            unmet.extend(self.explain_requirements(&device, profile_properties, None)?);
+        }
+
+        if unmet.is_empty() {
+            unmet.push(UnmetRequirement::Uncompared);
+        }
+
+        Ok(unmet)
+    }
+
+    /// Returns the extension, feature, property, format and queue family requirements of
+    /// `profile_properties`, or of its block named `block_name`, that `device` does not meet.
+    unsafe fn explain_requirements(
+        &self,
+        device: &DeviceFunctions,
+        profile_properties: &vp::ProfileProperties,
+        block_name: Option<&CStr>,
+    ) -> VpResult<Vec<UnmetRequirement>> {
+        let mut unmet = Vec::new();
+
+        let device_extensions = device.extensions()?;
+        for extension in
+            self.get_profile_device_extension_properties(profile_properties, block_name)?
+        {
+            let name = extension.extension_name_as_c_str().unwrap_or_default();
+            if !device_extensions
+                .iter()
+                .any(|device_extension| device_extension.extension_name_as_c_str() == Ok(name))
+            {
+                unmet.push(UnmetRequirement::Extension {
+                    name: name.into(),
+                    spec_version: extension.spec_version,
+                });
+            }
+        }
+
+        let features = FeatureChain::new(self, profile_properties, block_name)?;
+        let marked_features = marked_chain(Chain::Features, features.structure_types(), |head| {
+            self.get_profile_features(profile_properties, block_name, &mut *head.cast())
+        })?;
+        let device_features = device.features(features.structure_types());
+        for member in compare_chains(features.structures(), &marked_features, &device_features) {
+            unmet.push(UnmetRequirement::Feature(member));
+        }
+
+        let properties = PropertyChain::new(self, profile_properties, block_name)?;
+        let device_properties = device.properties(properties.structure_types());
+        let marked_properties =
+            marked_chain(Chain::Properties, properties.structure_types(), |head| {
+                self.get_profile_properties(profile_properties, block_name, &mut *head.cast())
+            })?;
+        for member in compare_chains(
+            properties.structures(),
+            &marked_properties,
+            &device_properties,
+        ) {
+            unmet.push(UnmetRequirement::Property(member));
+        }
+
+        let format_structure_types =
+            self.get_profile_format_structure_types(profile_properties, block_name)?;
+        for (format, requirement) in self.format_requirements(profile_properties, block_name)? {
+            let marked = marked_chain(Chain::Format, &format_structure_types, |head| {
+                self.get_profile_format_properties(
+                    profile_properties,
+                    block_name,
+                    format,
+                    &mut *head.cast(),
+                )
+            })?;
+            let device_format = device.format(format, &format_structure_types);
+            for member in compare_formats(&requirement, &marked, &device_format) {
+                unmet.push(UnmetRequirement::Format { format, member });
+            }
+        }
+
+        let queue_family_structure_types =
+            self.get_profile_queue_family_structure_types(profile_properties, block_name)?;
+        let marked_queue_families = queue_family::query_queue_families(
+            &queue_family_structure_types,
+            true,
+            |count, properties| {
+                self.get_profile_queue_family_properties(
+                    profile_properties,
+                    block_name,
+                    count,
+                    properties,
+                )
+            },
+        )?;
+        let device_queue_families = device.queue_families(&queue_family_structure_types);
+        for (requirement, marked) in self
+            .queue_family_requirements(profile_properties, block_name)?
+            .into_iter()
+            .zip(&marked_queue_families)
+        {
+            if !device_queue_families
+                .iter()
+                .any(|family| queue_family_satisfies(&requirement, marked, family))
+            {
+                unmet.push(UnmetRequirement::QueueFamily(requirement));
+            }
+        }
+
+        Ok(unmet)
+    }
+}
+
+/// A requirement of a profile that a physical device does not meet.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, PartialEq)]
+pub enum UnmetRequirement {
+    /// The API version of the device is lower than the profile's.
+    ApiVersion { required: u32, device: u32 },
+    /// The device does not support the extension.
+    Extension { name: CString, spec_version: u32 },
+    /// A feature structure member does not meet the requirement.
+    Feature(UnmetMember),
+    /// A property structure member does not meet the requirement.
+    Property(UnmetMember),
+    /// A format structure member does not meet the requirement.
+    ///
+    /// The feature flags combine [`vk::FormatProperties`] and [`vk::FormatProperties3`] like
+    /// [`FormatRequirement`], and are reported as members of [`vk::FormatProperties3`].
+    Format {
+        format: vk::Format,
+        member: UnmetMember,
+    },
+    /// No queue family of the device meets the requirement.
+    QueueFamily(QueueFamilyRequirement),
+    /// A block of a profile with multiple variants that the library reports as unsupported,
+    /// with its requirements that are not met.
+    Variant {
+        /// The name of the profile declaring the block, the profile or one of its required
+        /// profiles.
+        profile_name: CString,
+        block_name: CString,
+        unmet: Vec<Self>,
+    },
+    /// The library reports the profile as unsupported for a requirement that is not compared,
+    /// like its video profiles.
+    Uncompared,
+}
+
+impl fmt::Display for UnmetRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ApiVersion { required, device } => write!(
+                f,
+                "API version required {}, device {}",
+                Version(*required),
+                Version(*device)
+            ),
+            Self::Extension { name, .. } => {
+                write!(
+                    f,
+                    "{} required, device does not support it",
+                    name.to_string_lossy()
+                )
+            }
+            Self::Feature(member) | Self::Property(member) => fmt::Display::fmt(member, f),
+            Self::Format { format, member } => {
+                write_format(f, *format)?;
+                write!(f, ": {member}")
+            }
+            Self::QueueFamily(requirement) => {
+                write!(
+                    f,
+                    "no queue family has queue flags {:#x}, {} queues and {} timestamp valid bits",
+                    requirement.queue_flags.as_raw(),
+                    requirement.queue_count,
+                    requirement.timestamp_valid_bits
+                )?;
+                for (index, structure) in requirement.structures.iter().skip(1).enumerate() {
+                    let separator = if index == 0 {
+                        " with the required"
+                    } else {
+                        ","
+                    };
+                    write!(f, "{separator} {}", structure.name())?;
+                }
+                Ok(())
+            }
+            Self::Variant {
+                profile_name,
+                block_name,
+                unmet,
+            } => write_variant(f, profile_name, block_name, unmet),
+            Self::Uncompared => f.write_str(
+                "device does not support a requirement that is not compared, like a video profile",
+            ),
+        }
+    }
+}
+
+/// An instance requirement of a profile that is not met.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub enum UnmetInstanceRequirement {
+    /// The instance version is lower than the API version of the profile.
+    ApiVersion { required: u32, instance: u32 },
+    /// The extension is not supported by the implementation, or by the layer it was checked
+    /// against.
+    Extension {
+        name: CString,
+        spec_version: u32,
+        /// The layer whose extensions were checked, `None` for the implementation and the
+        /// implicit layers.
+        layer_name: Option<CString>,
+    },
+    /// A block of a profile with multiple variants that the library reports as unsupported,
+    /// with its requirements that are not met.
+    Variant {
+        /// The name of the profile declaring the block, the profile or one of its required
+        /// profiles.
+        profile_name: CString,
+        block_name: CString,
+        unmet: Vec<Self>,
+    },
+}
+
+impl fmt::Display for UnmetInstanceRequirement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ApiVersion { required, instance } => write!(
+                f,
+                "API version required {}, instance {}",
+                Version(*required),
+                Version(*instance)
+            ),
+            Self::Extension {
+                name, layer_name, ..
+            } => {
+                write!(f, "{} required, ", name.to_string_lossy())?;
+                match layer_name {
+                    Some(layer_name) => write!(
+                        f,
+                        "layer {} does not support it",
+                        layer_name.to_string_lossy()
+                    ),
+                    None => f.write_str("instance does not support it"),
+                }
+            }
+            Self::Variant {
+                profile_name,
+                block_name,
+                unmet,
+            } => write_variant(f, profile_name, block_name, unmet),
+        }
+    }
+}
+
+/// A structure member that does not meet the requirement of a profile.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, PartialEq)]
+pub struct UnmetMember {
+    /// The Vulkan name of the structure, like `VkPhysicalDeviceShaderFloat16Int8Features`.
+    pub structure: &'static str,
+    /// The Vulkan name of the member, with the path to nested members and array elements, like
+    /// `limits.maxComputeWorkGroupCount[0]`.
+    pub member: String,
+    pub required: Scalar,
+    pub device: Scalar,
+}
+
+impl fmt::Display for UnmetMember {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{} required {}, device {}",
+            self.structure, self.member, self.required, self.device
+        )
+    }
+}
+
+/// The value of a member that is compared.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, PartialOrd)]
+pub enum Scalar {
+    Bool(bool),
+    Unsigned(u64),
+    Signed(i64),
+    Float(f32),
+    /// The bits of a 32 or 64-bit flags type.
+    Flags(u64),
+    Enum(i32),
+}
+
+impl Scalar {
+    /// Returns the scalar of `value`, or `None` for strings, arrays, structures and pointers.
+    fn new(value: &Value<'_>) -> Option<Self> {
+        Some(match *value {
+            Value::Bool(value) => Self::Bool(value),
+            Value::U8(value) => Self::Unsigned(value.into()),
+            Value::U16(value) => Self::Unsigned(value.into()),
+            Value::U32(value) => Self::Unsigned(value.into()),
+            Value::U64(value) => Self::Unsigned(value),
+            Value::Usize(value) => Self::Unsigned(value as u64),
+            Value::I32(value) => Self::Signed(value.into()),
+            Value::I64(value) => Self::Signed(value),
+            Value::F32(value) => Self::Float(value),
+            Value::Flags(value) => Self::Flags(value),
+            Value::Enum(value) => Self::Enum(value),
+            Value::String(_) | Value::Array(_) | Value::Struct(_) | Value::Pointer(_) => {
+                return None
+            }
+        })
+    }
+
+    /// Returns whether the `device` value meets the `required` value.
+    fn is_met_by(self, device: Self, compare: Compare) -> bool {
+        match (self, device) {
+            (Self::Bool(required), Self::Bool(device)) => !required || device,
+            (Self::Flags(required), Self::Flags(device)) => device & required == required,
+            (Self::Enum(required), Self::Enum(device)) => device == required,
+            (required, device) => compare.test(device, required),
+        }
+    }
+}
+
+impl fmt::Display for Scalar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(value) => write!(f, "{value}"),
+            Self::Unsigned(value) => write!(f, "{value}"),
+            Self::Signed(value) => write!(f, "{value}"),
+            Self::Float(value) => write!(f, "{value}"),
+            Self::Flags(value) => write!(f, "{value:#x}"),
+            Self::Enum(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// Displays a format by its Vulkan name, like `VK_FORMAT_R8G8B8A8_UNORM`, or by its value when
+/// the registry does not name it.
+fn write_format(f: &mut fmt::Formatter<'_>, format: vk::Format) -> fmt::Result {
+    let name = structure::enum_names("Format").and_then(|names| {
+        let (_, name) = names
+            .known
+            .iter()
+            .find(|(value, _)| *value == format.as_raw())?;
+        Some((names.prefix, name))
+    });
+    match name {
+        Some((prefix, name)) => write!(f, "format {prefix}{name}"),
+        None => write!(f, "format {}", format.as_raw()),
+    }
+}
+
+/// Displays the unsupported block named `block_name` of the profile named `profile_name`,
+/// followed by its requirements that are not met.
+fn write_variant(
+    f: &mut fmt::Formatter<'_>,
+    profile_name: &CStr,
+    block_name: &CStr,
+    unmet: &[impl fmt::Display],
+) -> fmt::Result {
+    write!(
+        f,
+        "block {} of {} not supported",
+        block_name.to_string_lossy(),
+        profile_name.to_string_lossy()
+    )?;
+    for (index, unmet) in unmet.iter().enumerate() {
+        let separator = if index == 0 { ":" } else { ";" };
+        write!(f, "{separator} {unmet}")?;
+    }
+    Ok(())
+}
+
+/// Displays an API version as `major.minor.patch`.
+struct Version(u32);
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}.{}.{}",
+            vk::api_version_major(self.0),
+            vk::api_version_minor(self.0),
+            vk::api_version_patch(self.0)
+        )
+    }
+}
+
+/// The functions querying a physical device.
+struct DeviceFunctions {
+    physical_device: vk::PhysicalDevice,
+    enumerate_device_extension_properties: vk::PFN_vkEnumerateDeviceExtensionProperties,
+    get_physical_device_features2: vk::PFN_vkGetPhysicalDeviceFeatures2,
+    get_physical_device_properties2: vk::PFN_vkGetPhysicalDeviceProperties2,
+    get_physical_device_format_properties2: vk::PFN_vkGetPhysicalDeviceFormatProperties2,
+    get_physical_device_queue_family_properties2: vk::PFN_vkGetPhysicalDeviceQueueFamilyProperties2,
+}
+
+impl DeviceFunctions {
+    /// Takes the functions from `functions`, or from `instance` for those left as `None`, see
+    /// [`instance_fn_or_khr`].
+    unsafe fn new(
+        functions: &vp::VulkanFunctions,
+        entry: &ash::Entry,
+        instance: &ash::Instance,
+        physical_device: vk::PhysicalDevice,
+    ) -> VpResult<Self> {
+        Ok(Self {
+            physical_device,
+            enumerate_device_extension_properties: functions
+                .enumerate_device_extension_properties
+                .unwrap_or(instance.fp_v1_0().enumerate_device_extension_properties),
+            get_physical_device_features2: functions.get_physical_device_features2.map_or_else(
+                || {
+                    instance_fn_or_khr(
+                        entry,
+                        instance.handle(),
+                        c"vkGetPhysicalDeviceFeatures2",
+                        c"vkGetPhysicalDeviceFeatures2KHR",
+                    )
+                },
+                Ok,
+            )?,
+            get_physical_device_properties2: functions
+                .get_physical_device_properties2
+                .map_or_else(
+                    || {
+                        instance_fn_or_khr(
+                            entry,
+                            instance.handle(),
+                            c"vkGetPhysicalDeviceProperties2",
+                            c"vkGetPhysicalDeviceProperties2KHR",
+                        )
+                    },
+                    Ok,
+                )?,
+            get_physical_device_format_properties2: functions
+                .get_physical_device_format_properties2
+                .map_or_else(
+                    || {
+                        instance_fn_or_khr(
+                            entry,
+                            instance.handle(),
+                            c"vkGetPhysicalDeviceFormatProperties2",
+                            c"vkGetPhysicalDeviceFormatProperties2KHR",
+                        )
+                    },
+                    Ok,
+                )?,
+            get_physical_device_queue_family_properties2: functions
+                .get_physical_device_queue_family_properties2
+                .map_or_else(
+                    || {
+                        instance_fn_or_khr(
+                            entry,
+                            instance.handle(),
+                            c"vkGetPhysicalDeviceQueueFamilyProperties2",
+                            c"vkGetPhysicalDeviceQueueFamilyProperties2KHR",
+                        )
+                    },
+                    Ok,
+                )?,
+        })
+    }
+
+    unsafe fn extensions(&self) -> VpResult<Vec<vk::ExtensionProperties>> {
+        Ok(read_into_uninitialized_vector(|count, data| {
+            (self.enumerate_device_extension_properties)(
+                self.physical_device,
+                core::ptr::null(),
+                count,
+                data,
+            )
+        })?)
+    }
+
+    unsafe fn features(&self, structure_types: &[vk::StructureType]) -> Vec<Structure> {
+        query_chain(Chain::Features, structure_types, |head| {
+            (self.get_physical_device_features2)(self.physical_device, head.cast());
+        })
+    }
+
+    unsafe fn properties(&self, structure_types: &[vk::StructureType]) -> Vec<Structure> {
+        query_chain(Chain::Properties, structure_types, |head| {
+            (self.get_physical_device_properties2)(self.physical_device, head.cast());
+        })
+    }
+
+    /// Queries the format with the structures the profile lists, [`vk::FormatProperties3`] is
+    /// only chained if the profile lists it, as devices without `VK_KHR_format_feature_flags2`
+    /// do not support it.
+    unsafe fn format(
+        &self,
+        format: vk::Format,
+        structure_types: &[vk::StructureType],
+    ) -> FormatRequirement {
+        let structures = query_chain(Chain::Format, structure_types, |head| {
+            (self.get_physical_device_format_properties2)(
+                self.physical_device,
+                format,
+                head.cast(),
+            );
+        });
+        FormatRequirement::new(format, structures)
+    }
+
+    unsafe fn queue_families(
+        &self,
+        structure_types: &[vk::StructureType],
+    ) -> Vec<QueueFamilyRequirement> {
+        let Ok(families) =
+            queue_family::query_queue_families(structure_types, false, |count, properties| {
+                let properties = match properties {
+                    Some(properties) => properties.as_mut_ptr(),
+                    None => core::ptr::null_mut(),
+                };
+                (self.get_physical_device_queue_family_properties2)(
+                    self.physical_device,
+                    count,
+                    properties,
+                );
+                Ok::<_, core::convert::Infallible>(())
+            });
+        families
+    }
+}
+
+/// Allocates the structures of `s_types` in `chain` and fills them with `query`.
+unsafe fn query_chain(
+    chain: Chain,
+    s_types: &[vk::StructureType],
+    query: impl FnOnce(*mut vk::BaseOutStructure<'static>),
+) -> Vec<Structure> {
+    let Ok(structures) = structure::fill_chain(chain, s_types, |head| {
+        query(head);
+        Ok::<_, core::convert::Infallible>(())
+    });
+    structures
+}
+
+/// Queries the profile structures of `s_types` in `chain` with `query`, [marked](Structure::mark)
+/// first, so the members the profile sets are those equal in the zeroed structures.
+unsafe fn marked_chain(
+    chain: Chain,
+    s_types: &[vk::StructureType],
+    query: impl FnOnce(*mut vk::BaseOutStructure<'static>) -> VpResult<()>,
+) -> VpResult<Vec<Structure>> {
+    let mut structures = structure::allocate_chain(chain, s_types);
+    structures.iter_mut().for_each(Structure::mark);
+    structure::link(&mut structures);
+    let result = query(structures[0].as_mut_ptr());
+    structure::unlink(&mut structures);
+
+    result.map(|()| structures)
+}
+
+/// Compares each of the `required` structures with the `device` structure of the same type, only
+/// the members the `marked` structures tell the profile sets, see [`marked_chain`].
+fn compare_chains(
+    required: &[Structure],
+    marked: &[Structure],
+    device: &[Structure],
+) -> Vec<UnmetMember> {
+    let mut unmet = Vec::new();
+    for required in required {
+        if let (Some(marked), Some(device)) = (
+            find_structure(marked, required.s_type()),
+            find_structure(device, required.s_type()),
+        ) {
+            compare_structures(required, marked, device, &mut unmet);
+        }
+    }
+    unmet
+}
+
+/// Returns the structure of `s_type` in `structures`.
+fn find_structure(structures: &[Structure], s_type: vk::StructureType) -> Option<&Structure> {
+    structures
+        .iter()
+        .find(|structure| structure.s_type() == s_type)
+}
+
+/// Compares the combined feature flags of the format, then its other structures.
+fn compare_formats(
+    required: &FormatRequirement,
+    marked: &[Structure],
+    device: &FormatRequirement,
+) -> Vec<UnmetMember> {
+    let mut unmet = Vec::new();
+    for (member, required, device) in [
+        (
+            "linearTilingFeatures",
+            required.linear_tiling_features,
+            device.linear_tiling_features,
+        ),
+        (
+            "optimalTilingFeatures",
+            required.optimal_tiling_features,
+            device.optimal_tiling_features,
+        ),
+        (
+            "bufferFeatures",
+            required.buffer_features,
+            device.buffer_features,
+        ),
+    ] {
+        if !device.contains(required) {
+            unmet.push(UnmetMember {
+                structure: "VkFormatProperties3",
+                member: member.into(),
+                required: Scalar::Flags(required.as_raw()),
+                device: Scalar::Flags(device.as_raw()),
+            });
+        }
+    }
+
+    let other_structures = |structures: &[Structure]| -> Vec<Structure> {
+        structures
+            .iter()
+            .filter(|structure| {
+                !matches!(
+                    structure.s_type(),
+                    vk::StructureType::FORMAT_PROPERTIES_2 | vk::StructureType::FORMAT_PROPERTIES_3
+                )
+            })
+            .cloned()
+            .collect()
+    };
+    unmet.extend(compare_chains(
+        &other_structures(&required.structures),
+        marked,
+        &other_structures(&device.structures),
+    ));
+
+    unmet
+}
+
+/// Returns whether the device queue `family` meets the `requirement`, including the structures
+/// extending it.
+fn queue_family_satisfies(
+    requirement: &QueueFamilyRequirement,
+    marked: &QueueFamilyRequirement,
+    family: &QueueFamilyRequirement,
+) -> bool {
+    let properties = vk::QueueFamilyProperties {
+        queue_flags: family.queue_flags,
+        queue_count: family.queue_count,
+        timestamp_valid_bits: family.timestamp_valid_bits,
+        min_image_transfer_granularity: family.min_image_transfer_granularity,
+    };
+
+    requirement.is_satisfied_by(&properties)
+        && compare_chains(
+            &requirement.structures[1..],
+            &marked.structures[1..],
+            &family.structures[1..],
+        )
+        .is_empty()
+}
+
+fn compare_structures(
+    required: &Structure,
+    marked: &Structure,
+    device: &Structure,
+    unmet: &mut Vec<UnmetMember>,
+) {
+    let (mut required_members, mut marked_members, mut device_members) =
+        (required.members(), marked.members(), device.members());
+
+    // The heads of the chains wrap a structure that profiles name directly, like
+    // `VkPhysicalDeviceFeatures` in `VkPhysicalDeviceFeatures2`.
+    if required.s_type() == required.info().chain().head() {
+        if let (
+            Some((_, Value::Struct(required))),
+            Some((_, Value::Struct(marked))),
+            Some((_, Value::Struct(device))),
+        ) = (
+            required_members.next(),
+            marked_members.next(),
+            device_members.next(),
+        ) {
+            required_members = required;
+            marked_members = marked;
+            device_members = device;
+        }
+    }
+
+    let structure = required_members.name();
+    compare_members(
+        structure,
+        "",
+        Compare::Exact,
+        [required_members, marked_members, device_members],
+        unmet,
+    );
+}
+
+/// Compares the members of a structure, or of the nested structure at `path`.
+fn compare_members(
+    structure: &'static str,
+    path: &str,
+    parent_compare: Compare,
+    [required, marked, device]: [Members<'_>; 3],
+    unmet: &mut Vec<UnmetMember>,
+) {
+    for (((member, required), (_, marked)), (_, device)) in required.zip(marked).zip(device) {
+        // Nested limits, like `minImageTransferGranularity`, compare like their parent.
+        let compare = match member.compare() {
+            Compare::Exact => parent_compare,
+            compare => compare,
+        };
+        let path = match path.is_empty() {
+            true => camel_case(member.name()),
+            false => format!("{path}.{}", camel_case(member.name())),
+        };
+        compare_values(structure, &path, compare, [required, marked, device], unmet);
+    }
+}
+
+/// Compares the `required` and `device` values at `path`, if the `marked` value tells the profile
+/// sets it.
+fn compare_values(
+    structure: &'static str,
+    path: &str,
+    compare: Compare,
+    [required, marked, device]: [Value<'_>; 3],
+    unmet: &mut Vec<UnmetMember>,
+) {
+    match (required, marked, device) {
+        (Value::Struct(required), Value::Struct(marked), Value::Struct(device)) => {
+            compare_members(structure, path, compare, [required, marked, device], unmet);
+        }
+        (Value::Array(required), Value::Array(marked), Value::Array(device)) => {
+            for (index, ((required, marked), device)) in
+                required.zip(marked).zip(device).enumerate()
+            {
+                let path = format!("{path}[{index}]");
+                let values = [required, marked, device];
+                compare_values(structure, &path, compare.element(index), values, unmet);
+            }
+        }
+        (required, marked, device) => {
+            let (Some(required), Some(device)) = (Scalar::new(&required), Scalar::new(&device))
+            else {
+                return;
+            };
+            // Profiles OR flags into the mark, so a flags member only requires the bits it sets.
+            let is_set =
+                matches!(required, Scalar::Flags(_)) || Scalar::new(&marked) == Some(required);
+            if is_set && !required.is_met_by(device, compare) {
+                unmet.push(UnmetMember {
+                    structure,
+                    member: path.into(),
+                    required,
+                    device,
+                });
+            }
+        }
+    }
+}
+
+/// Converts an `ash` member name to its Vulkan name, like `max_image_dimension2_d` to
+/// `maxImageDimension2D`.
+fn camel_case(name: &str) -> String {
+    let mut camel_case = String::with_capacity(name.len());
+    let mut upper = false;
+    for c in name.chars() {
+        match c {
+            '_' => upper = true,
+            c if upper => {
+                camel_case.push(c.to_ascii_uppercase());
+                upper = false;
+            }
+            c => camel_case.push(c),
+        }
+    }
+    camel_case
+}
+
+fn has_extension(extensions: &[vk::ExtensionProperties], name: &CStr) -> bool {
+    extensions
+        .iter()
+        .any(|extension| extension.extension_name_as_c_str() == Ok(name))
+}
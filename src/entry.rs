@@ -1,10 +1,27 @@
-use ash::{prelude::VkResult, vk, RawPtr};
+use core::{ffi::CStr, fmt};
 
-use crate::vp;
+#[cfg(feature = "loaded")]
+use alloc::sync::Arc;
+#[cfg(feature = "loaded")]
+use std::ffi::OsStr;
+
+use ash::{
+    prelude::VkResult,
+    vk::{self, Handle},
+    RawPtr,
+};
+
+use crate::{capabilities::CapabilitiesFn, vp};
 
 /// The Vulkan Profiles entry, roughly equivalent to an [ash::Entry].
+#[derive(Clone)]
 pub struct Entry {
     entry_fn: EntryFn,
+    capabilities_fn: CapabilitiesFn,
+    #[cfg(vp_ash_video_queue)]
+    video_queue_fn: vp::video_queue::CapabilitiesFn,
+    #[cfg(feature = "loaded")]
+    _lib_guard: Option<Arc<libloading::Library>>,
 }
 
 impl Entry {
@@ -13,27 +30,158 @@ impl Entry {
     pub fn linked() -> Self {
         Self {
             entry_fn: EntryFn::linked(),
+            capabilities_fn: CapabilitiesFn::linked(),
+            #[cfg(vp_ash_video_queue)]
+            video_queue_fn: vp::video_queue::CapabilitiesFn::linked(),
+            #[cfg(feature = "loaded")]
+            _lib_guard: None,
         }
     }
 
+    /// Load the Vulkan Profiles library from the default path for the platform.
+    ///
+    /// See [`Entry::load_from`] for details.
+    #[cfg(feature = "loaded")]
+    pub unsafe fn load() -> Result<Self, LoadingError> {
+        #[cfg(windows)]
+        const LIB_PATH: &str = "vulkan_profiles.dll";
+
+        #[cfg(all(
+            unix,
+            not(any(
+                target_os = "macos",
+                target_os = "ios",
+                target_os = "android",
+                target_os = "fuchsia"
+            ))
+        ))]
+        const LIB_PATH: &str = "libvulkan_profiles.so";
+
+        #[cfg(any(target_os = "android", target_os = "fuchsia"))]
+        const LIB_PATH: &str = "libvulkan_profiles.so";
+
+        #[cfg(any(target_os = "macos", target_os = "ios"))]
+        const LIB_PATH: &str = "libvulkan_profiles.dylib";
+
+        Self::load_from(LIB_PATH)
+    }
+
+    /// Load the Vulkan Profiles library from `path`, resolving every function by symbol.
+    ///
+    /// The library must be built with `VP_USE_OBJECT` defined and export its functions unmangled.
+    ///
+    /// # Safety
+    /// `dlopen`ing arbitrary paths may execute arbitrary code. Any [`Capabilities`] created from
+    /// this [`Entry`] must be dropped before the [`Entry`] and all clones of it.
+    ///
+    /// [`Capabilities`]: crate::Capabilities
+    #[cfg(feature = "loaded")]
+    pub unsafe fn load_from(path: impl AsRef<OsStr>) -> Result<Self, LoadingError> {
+        let lib = libloading::Library::new(path)
+            .map_err(LoadingError::LibraryLoadFailure)
+            .map(Arc::new)?;
+
+        let mut load = |name: &CStr| {
+            lib.get::<*const core::ffi::c_void>(name.to_bytes_with_nul())
+                .map(|symbol| *symbol)
+                .unwrap_or(core::ptr::null())
+        };
+
+        Ok(Self {
+            entry_fn: EntryFn::load(&mut load)?,
+            capabilities_fn: CapabilitiesFn::load(&mut load)?,
+            #[cfg(vp_ash_video_queue)]
+            video_queue_fn: vp::video_queue::CapabilitiesFn::load(&mut load)?,
+            _lib_guard: Some(lib),
+        })
+    }
+
+    /// Returns the raw function pointer table.
+    pub fn fp(&self) -> &EntryFn {
+        &self.entry_fn
+    }
+
+    /// Returns the raw function pointer table for the video queue functions.
+    #[cfg(vp_ash_video_queue)]
+    pub(crate) fn video_queue_fn(&self) -> &vp::video_queue::CapabilitiesFn {
+        &self.video_queue_fn
+    }
+
     /// Creates allocator object.
     ///
     /// <https://vulkan.lunarg.com/doc/view/1.4.304.0/windows/profiles_api_library.html#basic-usage>
-    #[cfg(feature = "linked")]
     pub unsafe fn create_capabilities(
         &self,
         capabilities_create_info: &vp::CapabilitiesCreateInfo<'_>,
         allocation_callbacks: Option<&vk::AllocationCallbacks<'_>>,
     ) -> VkResult<crate::Capabilities> {
-        let mut handle = core::mem::MaybeUninit::uninit();
-        let handle = (self.entry_fn.create_capabilities)(
+        #[cfg(feature = "dynamic")]
+        let dynamic_functions;
+        #[cfg(feature = "dynamic")]
+        let capabilities_create_info = &if capabilities_create_info
+            .flags
+            .contains(vp::CapabilitiesCreateFlags::DYNAMIC)
+        {
+            // The library does not implement the dynamic import, so resolve the functions here.
+            dynamic_functions = capabilities_create_info
+                .vulkan_functions
+                .as_ref()
+                .ok_or(vk::Result::ERROR_INITIALIZATION_FAILED)?
+                .resolve_dynamic()?;
+
+            vp::CapabilitiesCreateInfo::default()
+                .flags(capabilities_create_info.flags)
+                .api_version(capabilities_create_info.api_version)
+                .vulkan_functions(&dynamic_functions)
+        } else {
+            *capabilities_create_info
+        };
+
+        let mut handle = vp::Capabilities::null();
+        let result = (self.entry_fn.create_capabilities)(
             capabilities_create_info,
             allocation_callbacks.as_raw_ptr(),
-            handle.as_mut_ptr(),
+            &mut handle,
+        );
+
+        // The library returns the handle even when it fails to initialize it.
+        if result != vk::Result::SUCCESS {
+            if !handle.is_null() {
+                (self.capabilities_fn.destroy_capabilities)(
+                    handle,
+                    allocation_callbacks.as_raw_ptr(),
+                );
+            }
+            return Err(result);
+        }
+
+        Ok(
+            crate::Capabilities::from_parts(handle, self.capabilities_fn.clone())
+                .with_vulkan_functions(
+                    capabilities_create_info
+                        .vulkan_functions
+                        .as_ref()
+                        .copied()
+                        .unwrap_or_default(),
+                ),
         )
-        .assume_init_on_success(handle)?;
+    }
 
-        Ok(crate::Capabilities::linked(handle))
+    /// Creates allocator object that is destroyed when dropped.
+    ///
+    /// See [`Entry::create_capabilities`].
+    pub unsafe fn create_owned_capabilities<'a>(
+        &self,
+        capabilities_create_info: &vp::CapabilitiesCreateInfo<'_>,
+        allocation_callbacks: Option<&vk::AllocationCallbacks<'a>>,
+    ) -> VkResult<crate::OwnedCapabilities<'a>> {
+        let capabilities =
+            self.create_capabilities(capabilities_create_info, allocation_callbacks)?;
+
+        Ok(crate::OwnedCapabilities::new(
+            capabilities,
+            allocation_callbacks,
+        ))
     }
 }
 
@@ -51,4 +199,80 @@ impl EntryFn {
             create_capabilities: vp::linked::vpCreateCapabilities,
         }
     }
+
+    /// Load the function pointers using `f` to resolve each function by name.
+    ///
+    /// Returns the first function that `f` resolves to a null pointer as an error.
+    pub fn load<F>(mut f: F) -> Result<Self, MissingEntryPoint>
+    where
+        F: FnMut(&CStr) -> *const core::ffi::c_void,
+    {
+        Self::load_erased(&mut f)
+    }
+
+    fn load_erased(
+        f: &mut dyn FnMut(&CStr) -> *const core::ffi::c_void,
+    ) -> Result<Self, MissingEntryPoint> {
+        unsafe {
+            Ok(Self {
+                create_capabilities: crate::utils::load_fn(f, c"vpCreateCapabilities")?,
+            })
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+/// A function could not be resolved while loading a function pointer table.
+pub struct MissingEntryPoint {
+    /// The name of the function that could not be resolved.
+    pub name: &'static CStr,
+}
+
+impl fmt::Display for MissingEntryPoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Cannot load `{}` symbol from library",
+            self.name.to_string_lossy()
+        )
+    }
+}
+
+impl core::error::Error for MissingEntryPoint {}
+
+#[derive(Debug)]
+/// The Vulkan Profiles library could not be loaded.
+#[cfg(feature = "loaded")]
+pub enum LoadingError {
+    /// The library could not be opened.
+    LibraryLoadFailure(libloading::Error),
+    /// The library does not export a required function.
+    MissingEntryPoint(MissingEntryPoint),
+}
+
+#[cfg(feature = "loaded")]
+impl fmt::Display for LoadingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LibraryLoadFailure(err) => fmt::Display::fmt(err, f),
+            Self::MissingEntryPoint(err) => fmt::Display::fmt(err, f),
+        }
+    }
+}
+
+#[cfg(feature = "loaded")]
+impl core::error::Error for LoadingError {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(match self {
+            Self::LibraryLoadFailure(err) => err,
+            Self::MissingEntryPoint(err) => err,
+        })
+    }
+}
+
+#[cfg(feature = "loaded")]
+impl From<MissingEntryPoint> for LoadingError {
+    fn from(err: MissingEntryPoint) -> Self {
+        Self::MissingEntryPoint(err)
+    }
 }
@@ -34,7 +34,11 @@ impl core::fmt::Debug for DeviceCreateFlags {
 
 impl core::fmt::Debug for CapabilitiesCreateFlags {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        const KNOWN: &[(vk::Flags, &str)] = &[(CapabilitiesCreateFlags::STATIC.0, "STATIC")];
+        const KNOWN: &[(vk::Flags, &str)] = &[
+            (CapabilitiesCreateFlags::STATIC.0, "STATIC"),
+            #[cfg(feature = "dynamic")]
+            (CapabilitiesCreateFlags::DYNAMIC.0, "DYNAMIC"),
+        ];
         debug_flags(f, KNOWN, self.0)
     }
 }
@@ -0,0 +1,68 @@
+use core::fmt;
+
+use ash::vk::{self, CStrTooLargeForStaticArray};
+
+/// Result type returned by the [`Capabilities`](crate::Capabilities) functions.
+pub type VpResult<T> = Result<T, Error>;
+
+/// Errors returned by the Vulkan Profiles API.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The library returned a Vulkan error.
+    Vulkan(vk::Result),
+    /// The profile has multiple variants and the function only supports a single variant.
+    MultipleVariantsProfile,
+    /// The profile, or one of its required profiles, is not in the library.
+    UnknownProfile,
+    /// A name does not fit in the fixed size array used by the library.
+    NameTooLong {
+        static_array_size: usize,
+        c_str_size: usize,
+    },
+    /// The library returned a value that is not [`vk::TRUE`] or [`vk::FALSE`].
+    InvalidBool(vk::Bool32),
+    /// The library the capabilities were created with does not implement the function, like a
+    /// library loaded at runtime for the functions `vp-ash` adds.
+    UnsupportedBackend,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Vulkan(result) => fmt::Display::fmt(result, f),
+            Self::MultipleVariantsProfile => {
+                f.write_str("the profile has multiple variants, a block must be specified")
+            }
+            Self::UnknownProfile => f.write_str("the profile is not in the library"),
+            Self::NameTooLong {
+                static_array_size,
+                c_str_size,
+            } => write!(
+                f,
+                "name of length `{c_str_size}` does not fit in an array of length `{static_array_size}`"
+            ),
+            Self::InvalidBool(value) => write!(f, "`{value}` is not a valid `vk::Bool32`"),
+            Self::UnsupportedBackend => {
+                f.write_str("the library of the capabilities does not implement the function")
+            }
+        }
+    }
+}
+
+impl core::error::Error for Error {}
+
+impl From<vk::Result> for Error {
+    fn from(result: vk::Result) -> Self {
+        Self::Vulkan(result)
+    }
+}
+
+impl From<CStrTooLargeForStaticArray> for Error {
+    fn from(error: CStrTooLargeForStaticArray) -> Self {
+        Self::NameTooLong {
+            static_array_size: error.static_array_size,
+            c_str_size: error.c_str_size,
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! Resolution of the fallback profiles.
+//!
+//! A profile lists the profiles to fall back to when it is not supported, and those profiles can
+//! have fallbacks of their own. The resolver walks them breadth-first and picks the first
+//! profile supported by both the instance and the physical device.
+//!
+//! ```no_run
+//! # unsafe fn example(
+//! #     capabilities: &vp_ash::Capabilities,
+//! #     instance: &ash::Instance,
+//! #     profile: vp_ash::vp::ProfileProperties,
+//! # ) -> vp_ash::VpResult<()> {
+//! for resolution in capabilities.resolve_fallbacks(instance, &profile)? {
+//!     match resolution.profile {
+//!         Some(profile) => { /* Use the profile on `resolution.physical_device`. */ }
+//!         None => { /* Every profile in `resolution.rejected` is unsupported. */ }
+//!     }
+//! }
+//! # Ok(()) }
+//! ```
+
+use alloc::{vec, vec::Vec};
+
+use ash::vk;
+
+use crate::{error::VpResult, vp, Capabilities};
+
+impl Capabilities {
+    /// Returns `profile_properties` followed by its fallbacks, breadth-first.
+    ///
+    /// The fallbacks of the fallbacks are included, each profile is listed once.
+    pub unsafe fn fallback_order(
+        &self,
+        profile_properties: &vp::ProfileProperties,
+    ) -> VpResult<Vec<vp::ProfileProperties>> {
+        fallback_order(profile_properties, |profile| {
+            self.get_profile_fallbacks(profile)
+        })
+    }
+
+    /// Resolves the best supported profile among `profile_properties` and its fallbacks for
+    /// every physical device of `instance`.
+    pub unsafe fn resolve_fallbacks(
+        &self,
+        instance: &ash::Instance,
+        profile_properties: &vp::ProfileProperties,
+    ) -> VpResult<Vec<FallbackResolution>> {
+        let candidates = self.fallback_candidates(profile_properties)?;
+
+        instance
+            .enumerate_physical_devices()?
+            .into_iter()
+            .map(|physical_device| self.resolve(instance, physical_device, &candidates))
+            .collect()
+    }
+
+    /// Resolves the best supported profile among `profile_properties` and its fallbacks for
+    /// `physical_device`.
+    pub unsafe fn resolve_device_fallbacks(
+        &self,
+        instance: &ash::Instance,
+        physical_device: vk::PhysicalDevice,
+        profile_properties: &vp::ProfileProperties,
+    ) -> VpResult<FallbackResolution> {
+        let candidates = self.fallback_candidates(profile_properties)?;
+        self.resolve(instance, physical_device, &candidates)
+    }
+
+    /// Returns the fallback order with the instance support of each profile, which is the same
+    /// for every physical device.
+    unsafe fn fallback_candidates(
+        &self,
+        profile_properties: &vp::ProfileProperties,
+    ) -> VpResult<Vec<(vp::ProfileProperties, bool)>> {
+        self.fallback_order(profile_properties)?
+            .into_iter()
+            .map(|profile| Ok((profile, self.get_instance_profile_support(None, &profile)?)))
+            .collect()
+    }
+
+    unsafe fn resolve(
+        &self,
+        instance: &ash::Instance,
+        physical_device: vk::PhysicalDevice,
+        candidates: &[(vp::ProfileProperties, bool)],
+    ) -> VpResult<FallbackResolution> {
+        let mut rejected = Vec::new();
+        for &(profile, instance_supported) in candidates {
+            let reason = if !instance_supported {
+                Rejection::UnsupportedInstance
+            } else if !self.get_physical_device_profile_support(
+                instance,
+                physical_device,
+                &profile,
+            )? {
+                Rejection::UnsupportedDevice
+            } else {
+                return Ok(FallbackResolution {
+                    physical_device,
+                    profile: Some(profile),
+                    rejected,
+                });
+            };
+
+            rejected.push(RejectedProfile { profile, reason });
+        }
+
+        Ok(FallbackResolution {
+            physical_device,
+            profile: None,
+            rejected,
+        })
+    }
+}
+
+/// Returns `profile_properties` followed by the profiles `fallbacks` returns for it and for each
+/// profile it adds, breadth-first.
+///
+/// Profiles are compared by name and spec version, each profile is listed once.
+pub(crate) fn fallback_order<E>(
+    profile_properties: &vp::ProfileProperties,
+    mut fallbacks: impl FnMut(&vp::ProfileProperties) -> Result<Vec<vp::ProfileProperties>, E>,
+) -> Result<Vec<vp::ProfileProperties>, E> {
+    let mut order = vec![*profile_properties];
+    let mut index = 0;
+    while let Some(profile) = order.get(index) {
+        for fallback in fallbacks(profile)? {
+            if !order.contains(&fallback) {
+                order.push(fallback);
+            }
+        }
+        index += 1;
+    }
+
+    Ok(order)
+}
+
+/// The profile resolved for a physical device.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct FallbackResolution {
+    pub physical_device: vk::PhysicalDevice,
+    /// The first supported profile in the fallback order, or `None` if none is supported.
+    pub profile: Option<vp::ProfileProperties>,
+    /// The profiles rejected before the supported one, in the fallback order.
+    pub rejected: Vec<RejectedProfile>,
+}
+
+impl FallbackResolution {
+    /// Returns whether a supported profile was found.
+    pub fn is_supported(&self) -> bool {
+        self.profile.is_some()
+    }
+}
+
+/// A profile rejected while resolving the fallbacks.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct RejectedProfile {
+    pub profile: vp::ProfileProperties,
+    pub reason: Rejection,
+}
+
+/// Why a profile was rejected.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Rejection {
+    /// The instance does not support the profile.
+    UnsupportedInstance,
+    /// The physical device does not support the profile.
+    UnsupportedDevice,
+}
@@ -0,0 +1,125 @@
+//! The format requirements of a profile.
+
+use core::ffi::CStr;
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use ash::vk;
+
+use crate::{
+    error::VpResult,
+    structure::{self, Chain, Structure},
+    vp, Capabilities,
+};
+
+impl Capabilities {
+    /// Returns the requirements of each format in the profile, or in the block named
+    /// `block_name`.
+    ///
+    /// See [`Profile::format_requirements`](crate::Profile::format_requirements) for the safe
+    /// equivalent.
+    ///
+    /// <https://vulkan.lunarg.com/doc/view/1.4.304.0/windows/profiles_api_library.html#query-profile-format-properties>
+    pub unsafe fn format_requirements(
+        &self,
+        profile_properties: &vp::ProfileProperties,
+        block_name: Option<&CStr>,
+    ) -> VpResult<BTreeMap<vk::Format, FormatRequirement>> {
+        let structure_types =
+            self.get_profile_format_structure_types(profile_properties, block_name)?;
+
+        self.get_profile_formats(profile_properties, block_name)?
+            .into_iter()
+            .map(|format| {
+                let structures = structure::fill_chain(Chain::Format, &structure_types, |head| {
+                    self.get_profile_format_properties(
+                        profile_properties,
+                        block_name,
+                        format,
+                        &mut *head.cast(),
+                    )
+                })?;
+
+                Ok((format, FormatRequirement::new(format, structures)))
+            })
+            .collect()
+    }
+}
+
+/// The requirements of a format.
+///
+/// The feature flags combine [`vk::FormatProperties`] and [`vk::FormatProperties3`], so they
+/// contain every feature the profile requires.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct FormatRequirement {
+    pub format: vk::Format,
+    pub linear_tiling_features: vk::FormatFeatureFlags2,
+    pub optimal_tiling_features: vk::FormatFeatureFlags2,
+    pub buffer_features: vk::FormatFeatureFlags2,
+    /// The format structures, starting with [`vk::FormatProperties2`].
+    pub structures: Vec<Structure>,
+}
+
+impl FormatRequirement {
+    pub(crate) fn new(format: vk::Format, structures: Vec<Structure>) -> Self {
+        let mut requirement = Self {
+            format,
+            linear_tiling_features: vk::FormatFeatureFlags2::empty(),
+            optimal_tiling_features: vk::FormatFeatureFlags2::empty(),
+            buffer_features: vk::FormatFeatureFlags2::empty(),
+            structures,
+        };
+
+        if let Some(properties) = requirement.get::<vk::FormatProperties2<'_>>() {
+            let properties = properties.format_properties;
+            requirement.linear_tiling_features |= flags2(properties.linear_tiling_features);
+            requirement.optimal_tiling_features |= flags2(properties.optimal_tiling_features);
+            requirement.buffer_features |= flags2(properties.buffer_features);
+        }
+        if let Some(properties) = requirement.get::<vk::FormatProperties3<'_>>() {
+            let properties = *properties;
+            requirement.linear_tiling_features |= properties.linear_tiling_features;
+            requirement.optimal_tiling_features |= properties.optimal_tiling_features;
+            requirement.buffer_features |= properties.buffer_features;
+        }
+
+        requirement
+    }
+
+    /// Returns the format structure `T`, if the profile reports it.
+    pub fn get<T: vk::TaggedStructure>(&self) -> Option<&T> {
+        self.structures.iter().find_map(Structure::get)
+    }
+}
+
+/// The flags of [`vk::FormatFeatureFlags`] have the same bits in [`vk::FormatFeatureFlags2`].
+fn flags2(flags: vk::FormatFeatureFlags) -> vk::FormatFeatureFlags2 {
+    vk::FormatFeatureFlags2::from_raw(flags.as_raw().into())
+}
+
+/// Returns the first of `candidates` whose optimal tiling features in `requirements` contain
+/// `features`.
+///
+/// ```no_run
+/// # use ash::vk;
+/// # fn example(profile: vp_ash::Profile<'_>) -> vp_ash::VpResult<()> {
+/// let requirements = profile.format_requirements(None)?;
+/// let depth_format = vp_ash::format::first_format_with(
+///     &requirements,
+///     &[vk::Format::D32_SFLOAT, vk::Format::D24_UNORM_S8_UINT],
+///     vk::FormatFeatureFlags2::DEPTH_STENCIL_ATTACHMENT,
+/// );
+/// # Ok(()) }
+/// ```
+pub fn first_format_with(
+    requirements: &BTreeMap<vk::Format, FormatRequirement>,
+    candidates: &[vk::Format],
+    features: vk::FormatFeatureFlags2,
+) -> Option<vk::Format> {
+    candidates.iter().copied().find(|format| {
+        requirements
+            .get(format)
+            .is_some_and(|requirement| requirement.optimal_tiling_features.contains(features))
+    })
+}
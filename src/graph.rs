@@ -0,0 +1,305 @@
+//! The graph of the required and fallback profiles in the library.
+//!
+//! ```no_run
+//! # fn example(capabilities: &vp_ash::Capabilities) -> vp_ash::VpResult<()> {
+//! let graph = capabilities.profile_graph()?;
+//!
+//! for edge in graph.missing_profiles() {
+//!     // `edge.from` references `edge.to`, which is not in the library.
+//! }
+//!
+//! match graph.topological_order() {
+//!     Some(order) => { /* Every profile comes after the profiles it references. */ }
+//!     None => { /* `graph.find_cycle()` returns the profiles of a cycle. */ }
+//! }
+//!
+//! let dot = graph.to_dot();
+//! # Ok(()) }
+//! ```
+
+use core::{ffi::CStr, fmt::Write};
+
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::{error::VpResult, fallback, vp, Capabilities};
+
+impl Capabilities {
+    /// Returns the graph of the required and fallback profiles in the library.
+    pub fn profile_graph(&self) -> VpResult<ProfileGraph> {
+        // SAFETY: Querying the profiles only reads the profile tables of the library.
+        unsafe {
+            let profiles = self.get_profiles()?;
+
+            let mut edges = Vec::new();
+            for profile in &profiles {
+                for required in self.get_profile_required_profiles(profile)? {
+                    edges.push(Edge {
+                        from: *profile,
+                        to: required,
+                        kind: EdgeKind::Requires,
+                    });
+                }
+                for fallback in self.get_profile_fallbacks(profile)? {
+                    edges.push(Edge {
+                        from: *profile,
+                        to: fallback,
+                        kind: EdgeKind::Fallback,
+                    });
+                }
+            }
+
+            Ok(ProfileGraph { profiles, edges })
+        }
+    }
+}
+
+/// The profiles in the library with their `requires` and `fallback` edges.
+///
+/// Profiles are identified by name, the spec version of a referenced profile is ignored.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct ProfileGraph {
+    profiles: Vec<vp::ProfileProperties>,
+    edges: Vec<Edge>,
+}
+
+impl ProfileGraph {
+    /// Returns the profiles in the library.
+    pub fn profiles(&self) -> &[vp::ProfileProperties] {
+        &self.profiles
+    }
+
+    /// Returns every edge, including those to missing profiles.
+    pub fn edges(&self) -> &[Edge] {
+        &self.edges
+    }
+
+    /// Returns whether the profile named `name` is in the library.
+    pub fn contains(&self, name: &CStr) -> bool {
+        self.index(name).is_some()
+    }
+
+    /// Returns the edges referencing a profile that is not in the library.
+    pub fn missing_profiles(&self) -> Vec<&Edge> {
+        self.edges
+            .iter()
+            .filter(|edge| self.index(name(&edge.to)).is_none())
+            .collect()
+    }
+
+    /// Returns the profiles required by the profile named `name`, directly or through other
+    /// required profiles, breadth-first.
+    pub fn required_profiles(&self, name: &CStr) -> Vec<vp::ProfileProperties> {
+        self.reachable(name, EdgeKind::Requires)
+    }
+
+    /// Returns the fallbacks of the profile named `name`, and their fallbacks, breadth-first.
+    ///
+    /// The order is the one of [`Capabilities::fallback_order`] without the profile itself,
+    /// missing profiles are skipped.
+    pub fn fallbacks(&self, name: &CStr) -> Vec<vp::ProfileProperties> {
+        let Some(start) = self.index(name) else {
+            return Vec::new();
+        };
+
+        let Ok(mut order) = fallback::fallback_order(&self.profiles[start], |profile| {
+            let index = self
+                .index(self::name(profile))
+                .expect("the profile is in the library");
+            Ok::<_, core::convert::Infallible>(
+                self.neighbors(index, Some(EdgeKind::Fallback))
+                    .map(|neighbor| self.profiles[neighbor])
+                    .collect(),
+            )
+        });
+        order.remove(0);
+        order
+    }
+
+    /// Returns the profiles ordered so that each profile comes after the profiles it requires
+    /// and falls back to, or `None` if the graph has a cycle.
+    ///
+    /// Missing profiles are not in the order.
+    pub fn topological_order(&self) -> Option<Vec<vp::ProfileProperties>> {
+        let order = self.sort().ok()?;
+        Some(
+            order
+                .into_iter()
+                .map(|index| self.profiles[index])
+                .collect(),
+        )
+    }
+
+    /// Returns the profiles of a cycle, each referencing the next and the last referencing the
+    /// first, or `None` if the graph has no cycle.
+    pub fn find_cycle(&self) -> Option<Vec<vp::ProfileProperties>> {
+        let cycle = self.sort().err()?;
+        Some(
+            cycle
+                .into_iter()
+                .map(|index| self.profiles[index])
+                .collect(),
+        )
+    }
+
+    /// Returns the graph in the Graphviz DOT language.
+    ///
+    /// Fallback edges are dashed and missing profiles are red.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph profiles {\n");
+
+        // Writing to a `String` cannot fail.
+        for profile in &self.profiles {
+            let _ = writeln!(dot, "    {};", quoted(name(profile)));
+        }
+        let mut missing = Vec::new();
+        for edge in self.missing_profiles() {
+            if !missing.contains(&name(&edge.to)) {
+                missing.push(name(&edge.to));
+                let _ = writeln!(dot, "    {} [color=red];", quoted(name(&edge.to)));
+            }
+        }
+        for edge in &self.edges {
+            let style = match edge.kind {
+                EdgeKind::Requires => "label=\"requires\"",
+                EdgeKind::Fallback => "label=\"fallback\", style=dashed",
+            };
+            let _ = writeln!(
+                dot,
+                "    {} -> {} [{style}];",
+                quoted(name(&edge.from)),
+                quoted(name(&edge.to))
+            );
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    fn index(&self, name: &CStr) -> Option<usize> {
+        self.profiles
+            .iter()
+            .position(|profile| self::name(profile) == name)
+    }
+
+    /// Returns the indices of the profiles referenced by the profile at `index`.
+    fn neighbors(&self, index: usize, kind: Option<EdgeKind>) -> impl Iterator<Item = usize> + '_ {
+        let from = name(&self.profiles[index]);
+        self.edges
+            .iter()
+            .filter(move |edge| {
+                name(&edge.from) == from && (kind.is_none() || kind == Some(edge.kind))
+            })
+            .filter_map(|edge| self.index(name(&edge.to)))
+    }
+
+    fn reachable(&self, name: &CStr, kind: EdgeKind) -> Vec<vp::ProfileProperties> {
+        let Some(start) = self.index(name) else {
+            return Vec::new();
+        };
+
+        let mut visited = vec![start];
+        let mut next = 0;
+        while let Some(&index) = visited.get(next) {
+            for neighbor in self.neighbors(index, Some(kind)) {
+                if !visited.contains(&neighbor) {
+                    visited.push(neighbor);
+                }
+            }
+            next += 1;
+        }
+
+        visited
+            .into_iter()
+            .skip(1)
+            .map(|index| self.profiles[index])
+            .collect()
+    }
+
+    /// Sorts the profiles depth-first, returns the indices of a cycle if there is one.
+    fn sort(&self) -> Result<Vec<usize>, Vec<usize>> {
+        let mut state = vec![Visit::New; self.profiles.len()];
+        let mut order = Vec::with_capacity(self.profiles.len());
+        let mut path = Vec::new();
+
+        for index in 0..self.profiles.len() {
+            self.visit(index, &mut state, &mut order, &mut path)?;
+        }
+
+        Ok(order)
+    }
+
+    fn visit(
+        &self,
+        index: usize,
+        state: &mut [Visit],
+        order: &mut Vec<usize>,
+        path: &mut Vec<usize>,
+    ) -> Result<(), Vec<usize>> {
+        match state[index] {
+            Visit::Done => return Ok(()),
+            Visit::InProgress => {
+                let start = path
+                    .iter()
+                    .position(|&visiting| visiting == index)
+                    .expect("the profiles in progress are on the path");
+                return Err(path[start..].to_vec());
+            }
+            Visit::New => {}
+        }
+
+        state[index] = Visit::InProgress;
+        path.push(index);
+        for neighbor in self.neighbors(index, None) {
+            self.visit(neighbor, state, order, path)?;
+        }
+        path.pop();
+        state[index] = Visit::Done;
+        order.push(index);
+
+        Ok(())
+    }
+}
+
+/// A reference from one profile to another.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct Edge {
+    pub from: vp::ProfileProperties,
+    pub to: vp::ProfileProperties,
+    pub kind: EdgeKind,
+}
+
+/// How a profile references another profile.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum EdgeKind {
+    /// The profile requires the other profile.
+    Requires,
+    /// The profile falls back to the other profile.
+    Fallback,
+}
+
+#[derive(Clone, Copy)]
+enum Visit {
+    New,
+    InProgress,
+    Done,
+}
+
+fn name(profile: &vp::ProfileProperties) -> &CStr {
+    profile.profile_name_as_c_str().unwrap_or_default()
+}
+
+/// Returns `name` as a quoted DOT identifier.
+fn quoted(name: &CStr) -> String {
+    let mut quoted = String::from("\"");
+    for c in name.to_string_lossy().chars() {
+        if matches!(c, '"' | '\\') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
@@ -0,0 +1,1401 @@
+//! The functions of the [`vp::Capabilities`] handles created by a [`Library`].
+//!
+//! Each function mirrors the function of the same name in the generated `vulkan_profiles.cpp`,
+//! reading the profile descriptions parsed from the JSON instead of the generated tables.
+//!
+//! [`Library`]: super::Library
+
+use core::ffi::{c_char, c_void, CStr};
+
+use alloc::{boxed::Box, ffi::CString, vec, vec::Vec};
+
+use ash::{
+    prelude::VkResult,
+    vk::{self, Handle},
+};
+
+use super::parse::{ProfileDesc, VariantDesc};
+use crate::{
+    capabilities::{BackendKind, CapabilitiesFn},
+    structure::{self, Chain, Member, MemberType, Structure},
+    utils::{check_version, read_into_uninitialized_vector},
+    vp,
+};
+
+/// The Vulkan functions the backend calls, validated when the capabilities are created.
+#[derive(Clone, Copy)]
+struct Functions {
+    enumerate_instance_version: Option<vk::PFN_vkEnumerateInstanceVersion>,
+    enumerate_instance_extension_properties: vk::PFN_vkEnumerateInstanceExtensionProperties,
+    enumerate_device_extension_properties: vk::PFN_vkEnumerateDeviceExtensionProperties,
+    get_physical_device_features2: vk::PFN_vkGetPhysicalDeviceFeatures2,
+    get_physical_device_properties2: vk::PFN_vkGetPhysicalDeviceProperties2,
+    get_physical_device_format_properties2: vk::PFN_vkGetPhysicalDeviceFormatProperties2,
+    get_physical_device_queue_family_properties2: vk::PFN_vkGetPhysicalDeviceQueueFamilyProperties2,
+    create_instance: vk::PFN_vkCreateInstance,
+    create_device: vk::PFN_vkCreateDevice,
+}
+
+impl Functions {
+    fn new(functions: &vp::VulkanFunctions) -> Option<Self> {
+        Some(Self {
+            enumerate_instance_version: functions.enumerate_instance_version,
+            enumerate_instance_extension_properties: functions
+                .enumerate_instance_extension_properties?,
+            enumerate_device_extension_properties: functions
+                .enumerate_device_extension_properties?,
+            get_physical_device_features2: functions.get_physical_device_features2?,
+            get_physical_device_properties2: functions.get_physical_device_properties2?,
+            get_physical_device_format_properties2: functions
+                .get_physical_device_format_properties2?,
+            get_physical_device_queue_family_properties2: functions
+                .get_physical_device_queue_family_properties2?,
+            create_instance: functions.create_instance?,
+            create_device: functions.create_device?,
+        })
+    }
+}
+
+/// The object behind a [`vp::Capabilities`] handle of the backend.
+struct Backend {
+    profiles: Vec<ProfileDesc>,
+    functions: Functions,
+}
+
+/// Creates the capabilities of `profiles`, importing the Vulkan functions like
+/// `vpCreateCapabilities`.
+pub(crate) unsafe fn create_capabilities(
+    profiles: Vec<ProfileDesc>,
+    create_info: &vp::CapabilitiesCreateInfo<'_>,
+) -> VkResult<crate::Capabilities> {
+    #[allow(unused_mut)]
+    let mut functions = create_info
+        .vulkan_functions
+        .as_ref()
+        .copied()
+        .unwrap_or_default();
+
+    #[cfg(feature = "link_vulkan")]
+    if create_info
+        .flags
+        .contains(vp::CapabilitiesCreateFlags::STATIC)
+    {
+        functions = with_fallback(functions, vp::VulkanFunctions::linked());
+    }
+
+    #[cfg(feature = "dynamic")]
+    if create_info
+        .flags
+        .contains(vp::CapabilitiesCreateFlags::DYNAMIC)
+    {
+        functions = functions.resolve_dynamic()?;
+    }
+
+    let vulkan_functions = functions;
+    let functions = Functions::new(&functions).ok_or(vk::Result::ERROR_INITIALIZATION_FAILED)?;
+    let backend = Box::new(Backend {
+        profiles,
+        functions,
+    });
+    let handle = vp::Capabilities::from_raw(Box::into_raw(backend) as usize as u64);
+
+    Ok(crate::Capabilities::from_parts(handle, FUNCTIONS.clone())
+        .with_vulkan_functions(vulkan_functions)
+        .with_backend(BackendKind::Json))
+}
+
+/// Returns `functions` with the missing functions taken from `fallback`.
+#[cfg(feature = "link_vulkan")]
+fn with_fallback(
+    functions: vp::VulkanFunctions,
+    fallback: vp::VulkanFunctions,
+) -> vp::VulkanFunctions {
+    vp::VulkanFunctions {
+        get_instance_proc_addr: functions
+            .get_instance_proc_addr
+            .or(fallback.get_instance_proc_addr),
+        get_device_proc_addr: functions
+            .get_device_proc_addr
+            .or(fallback.get_device_proc_addr),
+        enumerate_instance_version: functions
+            .enumerate_instance_version
+            .or(fallback.enumerate_instance_version),
+        enumerate_instance_extension_properties: functions
+            .enumerate_instance_extension_properties
+            .or(fallback.enumerate_instance_extension_properties),
+        enumerate_device_extension_properties: functions
+            .enumerate_device_extension_properties
+            .or(fallback.enumerate_device_extension_properties),
+        get_physical_device_features2: functions
+            .get_physical_device_features2
+            .or(fallback.get_physical_device_features2),
+        get_physical_device_properties2: functions
+            .get_physical_device_properties2
+            .or(fallback.get_physical_device_properties2),
+        get_physical_device_format_properties2: functions
+            .get_physical_device_format_properties2
+            .or(fallback.get_physical_device_format_properties2),
+        get_physical_device_queue_family_properties2: functions
+            .get_physical_device_queue_family_properties2
+            .or(fallback.get_physical_device_queue_family_properties2),
+        create_instance: functions.create_instance.or(fallback.create_instance),
+        create_device: functions.create_device.or(fallback.create_device),
+    }
+}
+
+static FUNCTIONS: CapabilitiesFn = CapabilitiesFn {
+    destroy_capabilities,
+    get_profiles,
+    get_profile_required_profiles,
+    get_profile_api_version,
+    get_profile_fallbacks,
+    has_multiple_variants_profile,
+    get_instance_profile_support,
+    get_instance_profile_variants_support,
+    create_instance,
+    get_physical_device_profile_support,
+    get_physical_device_profile_variants_support,
+    create_device,
+    get_profile_instance_extension_properties,
+    get_profile_device_extension_properties,
+    get_profile_features,
+    get_profile_feature_structure_types,
+    get_profile_properties,
+    get_profile_property_structure_types,
+    get_profile_formats,
+    get_profile_format_properties,
+    get_profile_format_structure_types,
+    get_profile_queue_family_properties,
+    get_profile_queue_family_structure_types,
+    get_profile_blocks: Some(get_profile_blocks),
+};
+
+impl Backend {
+    unsafe fn from_handle<'a>(capabilities: vp::Capabilities) -> &'a Self {
+        &*(capabilities.as_raw() as usize as *const Self)
+    }
+
+    fn profile(&self, name: &CStr) -> Option<&ProfileDesc> {
+        self.profiles.iter().find(|profile| *profile.name == *name)
+    }
+
+    fn profile_by_name(&self, name: &str) -> Option<&ProfileDesc> {
+        self.profiles
+            .iter()
+            .find(|profile| profile.name.as_bytes() == name.as_bytes())
+    }
+
+    fn profile_desc(&self, properties: &vp::ProfileProperties) -> VkResult<&ProfileDesc> {
+        properties
+            .profile_name_as_c_str()
+            .ok()
+            .and_then(|name| self.profile(name))
+            .ok_or(vk::Result::ERROR_UNKNOWN)
+    }
+
+    /// Resolves the profile `names` of `profile`, like its required or fallback profiles.
+    fn resolve(&self, names: &[alloc::string::String]) -> VkResult<Vec<vp::ProfileProperties>> {
+        names
+            .iter()
+            .map(|name| {
+                self.profile_by_name(name)
+                    .map(properties)
+                    .ok_or(vk::Result::ERROR_UNKNOWN)
+            })
+            .collect()
+    }
+
+    /// Returns the properties of the referenced profiles, like [`Backend::resolve`], but a
+    /// profile that is not in the library is returned by name, as the tables of the library list
+    /// the referenced names.
+    fn references(&self, names: &[alloc::string::String]) -> VkResult<Vec<vp::ProfileProperties>> {
+        names
+            .iter()
+            .map(|name| match self.profile_by_name(name) {
+                Some(profile) => Ok(properties(profile)),
+                None => {
+                    let name =
+                        CString::new(name.as_str()).map_err(|_| vk::Result::ERROR_UNKNOWN)?;
+                    vp::ProfileProperties::default()
+                        .profile_name(&name)
+                        .map_err(|_| vk::Result::ERROR_UNKNOWN)
+                }
+            })
+            .collect()
+    }
+
+    /// Returns the profiles required by `profile` followed by `profile`, or only `profile` when
+    /// a block is selected, like `GatherProfiles`.
+    fn gather(
+        &self,
+        profile: &vp::ProfileProperties,
+        with_required: bool,
+    ) -> VkResult<Vec<(vp::ProfileProperties, &ProfileDesc)>> {
+        let desc = self.profile_desc(profile)?;
+
+        let mut profiles = Vec::new();
+        if with_required {
+            for required in self.resolve(&desc.required)? {
+                profiles.push((required, self.profile_desc(&required)?));
+            }
+        }
+        profiles.push((*profile, desc));
+
+        Ok(profiles)
+    }
+
+    /// Returns the variants of the gathered profiles in the block `block_name`, or all variants
+    /// if `block_name` is `None`.
+    ///
+    /// The result is [`vk::Result::INCOMPLETE`] if no variant is in the block.
+    fn variants(
+        &self,
+        profile: &vp::ProfileProperties,
+        block_name: Option<&CStr>,
+        with_required: bool,
+    ) -> VkResult<(Vec<&VariantDesc>, vk::Result)> {
+        let mut variants = Vec::new();
+        for (_, desc) in self.gather(profile, with_required)? {
+            variants.extend(desc.capabilities.iter().flatten().filter(|variant| {
+                block_name.is_none() || block_name == Some(&*variant.block_name)
+            }));
+        }
+
+        let result = match (block_name, variants.is_empty()) {
+            (Some(_), true) => vk::Result::INCOMPLETE,
+            _ => vk::Result::SUCCESS,
+        };
+
+        Ok((variants, result))
+    }
+
+    fn has_multiple_variants(&self, profile: &vp::ProfileProperties) -> VkResult<bool> {
+        Ok(self
+            .gather(profile, true)?
+            .iter()
+            .any(|(_, desc)| desc.capabilities.iter().any(|variants| variants.len() > 1)))
+    }
+
+    /// Returns the sorted structure types of `chain` in the variants, like
+    /// `vpGetProfileFeatureStructureTypes`.
+    fn structure_types(
+        &self,
+        profile: &vp::ProfileProperties,
+        block_name: Option<&CStr>,
+        chain: Chain,
+    ) -> VkResult<(Vec<vk::StructureType>, vk::Result)> {
+        let (variants, result) = self.variants(profile, block_name, true)?;
+
+        let mut s_types = Vec::new();
+        for s_type in variants
+            .iter()
+            .flat_map(|variant| variant.structure_types(chain))
+        {
+            if !s_types.contains(&s_type) {
+                s_types.push(s_type);
+            }
+        }
+        s_types.sort();
+
+        Ok((s_types, result))
+    }
+
+    unsafe fn instance_support(
+        &self,
+        layer_name: *const c_char,
+        profile: &vp::ProfileProperties,
+    ) -> VkResult<(bool, Vec<vp::BlockProperties>)> {
+        let mut api_version = vk::API_VERSION_1_0;
+        if let Some(enumerate_instance_version) = self.functions.enumerate_instance_version {
+            enumerate_instance_version(&mut api_version).result()?;
+        }
+
+        let extensions = read_into_uninitialized_vector(|count, data| {
+            (self.functions.enumerate_instance_extension_properties)(layer_name, count, data)
+        })?;
+
+        if api_version < vk::API_VERSION_1_1
+            && !has_extension(&extensions, vk::KHR_GET_PHYSICAL_DEVICE_PROPERTIES2_NAME)
+        {
+            return Ok((false, Vec::new()));
+        }
+
+        let desc = self.profile_desc(profile)?;
+        let mut support = Support::default();
+        self.instance_support_single(api_version, &extensions, profile, &mut support)?;
+        for required in self.resolve(&desc.required)? {
+            self.instance_support_single(0, &extensions, &required, &mut support)?;
+        }
+
+        Ok(support.into_blocks())
+    }
+
+    /// Checks the instance support of `profile` without its required profiles, like
+    /// `vpGetInstanceProfileSupportSingleProfile`.
+    fn instance_support_single(
+        &self,
+        api_version: u32,
+        extensions: &[vk::ExtensionProperties],
+        profile: &vp::ProfileProperties,
+        support: &mut Support,
+    ) -> VkResult<()> {
+        let desc = self.profile_desc(profile)?;
+        let block = vp::BlockProperties::default()
+            .profiles(*profile)
+            .api_version(api_version);
+
+        if desc.spec_version < profile.spec_version {
+            support.supported = false;
+        }
+        if api_version != 0 && !check_version(api_version, desc.min_api_version) {
+            support.supported = false;
+        }
+
+        for variants in &desc.capabilities {
+            let mut supported_capabilities = false;
+
+            for variant in variants {
+                let block = variant_block(variant, block);
+                if variant
+                    .instance_extensions
+                    .iter()
+                    .all(|extension| has_extension(extensions, extension_name(extension)))
+                {
+                    supported_capabilities = true;
+                    support.supported_blocks.push(block);
+                } else {
+                    support.unsupported_blocks.push(block);
+                }
+            }
+
+            if !supported_capabilities {
+                support.supported = false;
+                return Ok(());
+            }
+        }
+
+        Ok(())
+    }
+
+    unsafe fn device_support(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        profile: &vp::ProfileProperties,
+    ) -> VkResult<(bool, Vec<vp::BlockProperties>)> {
+        let extensions = read_into_uninitialized_vector(|count, data| {
+            (self.functions.enumerate_device_extension_properties)(
+                physical_device,
+                core::ptr::null(),
+                count,
+                data,
+            )
+        })?;
+
+        let desc = self.profile_desc(profile)?;
+        let mut support = Support::default();
+        if desc.spec_version < profile.spec_version {
+            support.supported = false;
+        }
+
+        let mut properties = vk::PhysicalDeviceProperties2::default();
+        (self.functions.get_physical_device_properties2)(physical_device, &mut properties);
+
+        for (properties_profile, desc) in self.gather(profile, true)? {
+            if !check_version(properties.properties.api_version, desc.min_api_version) {
+                support.supported = false;
+            }
+
+            let block = vp::BlockProperties::default()
+                .profiles(properties_profile)
+                .api_version(desc.min_api_version);
+
+            for variants in &desc.capabilities {
+                let mut supported_capabilities = false;
+
+                for variant in variants {
+                    let block = variant_block(variant, block);
+                    if self.variant_support(physical_device, &extensions, variant) {
+                        supported_capabilities = true;
+                        support.supported_blocks.push(block);
+                        break;
+                    }
+                    support.unsupported_blocks.push(block);
+                }
+
+                if !supported_capabilities {
+                    support.supported = false;
+                }
+            }
+        }
+
+        Ok(support.into_blocks())
+    }
+
+    /// Returns whether `physical_device` supports every requirement of `variant`.
+    unsafe fn variant_support(
+        &self,
+        physical_device: vk::PhysicalDevice,
+        extensions: &[vk::ExtensionProperties],
+        variant: &VariantDesc,
+    ) -> bool {
+        if !variant
+            .device_extensions
+            .iter()
+            .all(|extension| has_extension(extensions, extension_name(extension)))
+        {
+            return false;
+        }
+
+        let mut features = linked_structures(
+            core::iter::once(Chain::Features.head())
+                .chain(variant.features.iter().map(|r| r.info.s_type())),
+        );
+        let features = head(&mut features);
+        (self.functions.get_physical_device_features2)(physical_device, features.cast());
+        if !variant.features.iter().all(|r| r.matches(features)) {
+            return false;
+        }
+
+        let mut properties = linked_structures(
+            core::iter::once(Chain::Properties.head())
+                .chain(variant.properties.iter().map(|r| r.info.s_type())),
+        );
+        let properties = head(&mut properties);
+        (self.functions.get_physical_device_properties2)(physical_device, properties.cast());
+        if !variant.properties.iter().all(|r| r.matches(properties)) {
+            return false;
+        }
+
+        if !variant.queue_families.is_empty() {
+            let mut count = 0;
+            (self.functions.get_physical_device_queue_family_properties2)(
+                physical_device,
+                &mut count,
+                core::ptr::null_mut(),
+            );
+
+            // Each family gets its own chain of the extension structures the profile sets.
+            let mut chains = (0..count)
+                .map(|_| {
+                    linked_structures(
+                        variant
+                            .queue_families
+                            .iter()
+                            .flatten()
+                            .map(|r| r.info.s_type())
+                            .filter(|&s_type| s_type != Chain::QueueFamily.head()),
+                    )
+                })
+                .collect::<Vec<_>>();
+            let mut families = chains
+                .iter_mut()
+                .map(|chain| vk::QueueFamilyProperties2 {
+                    p_next: head(chain).cast(),
+                    ..Default::default()
+                })
+                .collect::<Vec<_>>();
+            (self.functions.get_physical_device_queue_family_properties2)(
+                physical_device,
+                &mut count,
+                families.as_mut_ptr(),
+            );
+
+            let supported = variant.queue_families.iter().all(|requirements| {
+                families.iter().take(count as usize).any(|family| {
+                    let family = <*const _>::cast(family);
+                    requirements.iter().all(|r| r.matches(family))
+                })
+            });
+            if !supported {
+                return false;
+            }
+        }
+
+        variant.formats.iter().all(|format| {
+            let mut chain = linked_structures(
+                core::iter::once(Chain::Format.head())
+                    .chain(format.requirements.iter().map(|r| r.info.s_type())),
+            );
+            let chain = head(&mut chain);
+            (self.functions.get_physical_device_format_properties2)(
+                physical_device,
+                format.format,
+                chain.cast(),
+            );
+            format.requirements.iter().all(|r| r.matches(chain))
+        })
+    }
+
+    /// Returns the blocks enabled by a create info, like `GatherBlocks`.
+    unsafe fn gather_blocks(
+        &self,
+        full_profiles: &[vp::ProfileProperties],
+        profile_blocks: &[vp::BlockProperties],
+    ) -> VkResult<Vec<vp::BlockProperties>> {
+        let mut blocks = Vec::new();
+        for profile in full_profiles {
+            for (properties, _) in self.gather(profile, true)? {
+                blocks.push(vp::BlockProperties::default().profiles(properties));
+            }
+        }
+        blocks.extend_from_slice(profile_blocks);
+
+        Ok(blocks)
+    }
+
+    /// Returns the variants selected by `blocks`, an empty block name selects every variant.
+    fn block_variants<'a>(
+        &'a self,
+        blocks: &'a [vp::BlockProperties],
+    ) -> impl Iterator<Item = VkResult<&'a VariantDesc>> + 'a {
+        blocks.iter().flat_map(move |block| {
+            let block_name = block.block_name_as_c_str().unwrap_or(c"");
+            let variants = match self.profile_desc(&block.profiles) {
+                Ok(desc) => desc
+                    .capabilities
+                    .iter()
+                    .flatten()
+                    .filter(|variant| block_name.is_empty() || *variant.block_name == *block_name)
+                    .map(Ok)
+                    .collect(),
+                Err(err) => vec![Err(err)],
+            };
+            variants.into_iter()
+        })
+    }
+}
+
+/// Returns the properties of `profile`, its name was checked to fit when it was parsed.
+fn properties(profile: &ProfileDesc) -> vp::ProfileProperties {
+    vp::ProfileProperties::default()
+        .profile_name(&profile.name)
+        .unwrap_or_default()
+        .spec_version(profile.spec_version)
+}
+
+/// Returns `block` with the name of `variant`.
+fn variant_block(variant: &VariantDesc, block: vp::BlockProperties) -> vp::BlockProperties {
+    block.block_name(&variant.block_name).unwrap_or(block)
+}
+
+/// The support of a profile and the blocks that decided it.
+struct Support {
+    supported: bool,
+    supported_blocks: Vec<vp::BlockProperties>,
+    unsupported_blocks: Vec<vp::BlockProperties>,
+}
+
+impl Default for Support {
+    fn default() -> Self {
+        Self {
+            supported: true,
+            supported_blocks: Vec::new(),
+            unsupported_blocks: Vec::new(),
+        }
+    }
+}
+
+impl Support {
+    /// Returns the support and the supported blocks, or the unsupported blocks if the profile
+    /// is not supported.
+    fn into_blocks(self) -> (bool, Vec<vp::BlockProperties>) {
+        match self.supported {
+            true => (true, self.supported_blocks),
+            false => (false, self.unsupported_blocks),
+        }
+    }
+}
+
+fn extension_name(extension: &vk::ExtensionProperties) -> &CStr {
+    extension.extension_name_as_c_str().unwrap_or(c"")
+}
+
+fn has_extension(extensions: &[vk::ExtensionProperties], name: &CStr) -> bool {
+    extensions
+        .iter()
+        .any(|extension| extension_name(extension) == name)
+}
+
+unsafe fn block_name<'a>(p_block_name: *const c_char) -> Option<&'a CStr> {
+    (!p_block_name.is_null()).then(|| CStr::from_ptr(p_block_name))
+}
+
+unsafe fn slice<'a, T>(p: *const T, count: u32) -> &'a [T] {
+    match p.is_null() {
+        true => &[],
+        false => core::slice::from_raw_parts(p, count as usize),
+    }
+}
+
+/// Allocates the structures of `s_types` that are in the registry, skipping repeated structure
+/// types, linked into a `pNext` chain in order.
+///
+/// # Safety
+/// The structures are [linked](structure::link), they must only be used through the chain.
+unsafe fn linked_structures(
+    s_types: impl IntoIterator<Item = vk::StructureType>,
+) -> Vec<Structure> {
+    let mut structures = Vec::<Structure>::new();
+    for s_type in s_types {
+        if structures
+            .iter()
+            .all(|structure| structure.s_type() != s_type)
+        {
+            structures.extend(Structure::new(s_type));
+        }
+    }
+    structure::link(&mut structures);
+
+    structures
+}
+
+/// Returns the head of a chain of [`linked_structures`], or null if the chain is empty.
+///
+/// # Safety
+/// See [`Structure::as_mut_ptr`].
+unsafe fn head(structures: &mut [Structure]) -> *mut vk::BaseOutStructure<'static> {
+    structures
+        .first_mut()
+        .map_or(core::ptr::null_mut(), |structure| structure.as_mut_ptr())
+}
+
+/// Returns the structure of type `s_type` in the chain at `p`.
+unsafe fn find_in_chain(
+    mut p: *mut vk::BaseOutStructure<'_>,
+    s_type: vk::StructureType,
+) -> Option<*mut u8> {
+    while !p.is_null() {
+        if (*p).s_type == s_type {
+            return Some(p.cast());
+        }
+        p = (*p).p_next;
+    }
+    None
+}
+
+/// Writes `items` to a Vulkan count and array pair, returning [`vk::Result::INCOMPLETE`] if the
+/// array is too small and `result` otherwise.
+unsafe fn write_array<T: Copy>(
+    items: &[T],
+    p_count: *mut u32,
+    p_items: *mut T,
+    result: vk::Result,
+) -> vk::Result {
+    let len = items.len() as u32;
+    if p_items.is_null() {
+        *p_count = len;
+        return result;
+    }
+
+    let result = match *p_count < len {
+        true => vk::Result::INCOMPLETE,
+        false => {
+            *p_count = len;
+            result
+        }
+    };
+    core::ptr::copy_nonoverlapping(items.as_ptr(), p_items, *p_count as usize);
+
+    result
+}
+
+macro_rules! try_vk {
+    ($result:expr) => {
+        match $result {
+            Ok(value) => value,
+            Err(err) => return err,
+        }
+    };
+}
+
+unsafe extern "system" fn destroy_capabilities(
+    capabilities: vp::Capabilities,
+    _p_allocator: *const vk::AllocationCallbacks<'_>,
+) {
+    if !capabilities.is_null() {
+        drop(Box::from_raw(capabilities.as_raw() as usize as *mut Backend));
+    }
+}
+
+unsafe extern "system" fn get_profiles(
+    capabilities: vp::Capabilities,
+    p_property_count: *mut u32,
+    p_properties: *mut vp::ProfileProperties,
+) -> vk::Result {
+    let backend = Backend::from_handle(capabilities);
+    let profiles = backend.profiles.iter().map(properties).collect::<Vec<_>>();
+
+    write_array(
+        &profiles,
+        p_property_count,
+        p_properties,
+        vk::Result::SUCCESS,
+    )
+}
+
+unsafe extern "system" fn get_profile_required_profiles(
+    capabilities: vp::Capabilities,
+    p_profile: *const vp::ProfileProperties,
+    p_property_count: *mut u32,
+    p_properties: *mut vp::ProfileProperties,
+) -> vk::Result {
+    let backend = Backend::from_handle(capabilities);
+    let desc = try_vk!(backend.profile_desc(&*p_profile));
+    let required = try_vk!(backend.references(&desc.required));
+
+    write_array(
+        &required,
+        p_property_count,
+        p_properties,
+        vk::Result::SUCCESS,
+    )
+}
+
+unsafe extern "system" fn get_profile_api_version(
+    capabilities: vp::Capabilities,
+    p_profile: *const vp::ProfileProperties,
+) -> u32 {
+    let backend = Backend::from_handle(capabilities);
+    let Ok(profiles) = backend.gather(&*p_profile, true) else {
+        return 0;
+    };
+
+    let (major, minor, patch) = profiles.iter().fold((0, 0, 0), |version, (_, desc)| {
+        let api_version = desc.min_api_version;
+        (
+            version.0.max(vk::api_version_major(api_version)),
+            version.1.max(vk::api_version_minor(api_version)),
+            version.2.max(vk::api_version_patch(api_version)),
+        )
+    });
+
+    vk::make_api_version(0, major, minor, patch)
+}
+
+unsafe extern "system" fn get_profile_fallbacks(
+    capabilities: vp::Capabilities,
+    p_profile: *const vp::ProfileProperties,
+    p_property_count: *mut u32,
+    p_properties: *mut vp::ProfileProperties,
+) -> vk::Result {
+    let backend = Backend::from_handle(capabilities);
+    let desc = try_vk!(backend.profile_desc(&*p_profile));
+    let fallbacks = try_vk!(backend.references(&desc.fallbacks));
+
+    write_array(
+        &fallbacks,
+        p_property_count,
+        p_properties,
+        vk::Result::SUCCESS,
+    )
+}
+
+unsafe extern "system" fn has_multiple_variants_profile(
+    capabilities: vp::Capabilities,
+    p_profile: *const vp::ProfileProperties,
+    p_has_multiple_variants: *mut vk::Bool32,
+) -> vk::Result {
+    let backend = Backend::from_handle(capabilities);
+    let has_multiple_variants = try_vk!(backend.has_multiple_variants(&*p_profile));
+    *p_has_multiple_variants = has_multiple_variants.into();
+
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn get_instance_profile_support(
+    capabilities: vp::Capabilities,
+    p_layer_name: *const c_char,
+    p_profile: *const vp::ProfileProperties,
+    p_supported: *mut vk::Bool32,
+) -> vk::Result {
+    let backend = Backend::from_handle(capabilities);
+    let (supported, _) = try_vk!(backend.instance_support(p_layer_name, &*p_profile));
+    *p_supported = supported.into();
+
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn get_instance_profile_variants_support(
+    capabilities: vp::Capabilities,
+    p_layer_name: *const c_char,
+    p_profile: *const vp::ProfileProperties,
+    p_supported: *mut vk::Bool32,
+    p_property_count: *mut u32,
+    p_properties: *mut vp::BlockProperties,
+) -> vk::Result {
+    let backend = Backend::from_handle(capabilities);
+    let (supported, blocks) = try_vk!(backend.instance_support(p_layer_name, &*p_profile));
+    *p_supported = supported.into();
+
+    write_array(&blocks, p_property_count, p_properties, vk::Result::SUCCESS)
+}
+
+unsafe extern "system" fn create_instance(
+    capabilities: vp::Capabilities,
+    p_create_info: *const vp::InstanceCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_instance: *mut vk::Instance,
+) -> vk::Result {
+    let backend = Backend::from_handle(capabilities);
+    let create_info = &*p_create_info;
+    let Some(instance_create_info) = create_info.p_create_info.as_ref() else {
+        return vk::Result::ERROR_INITIALIZATION_FAILED;
+    };
+
+    let blocks = try_vk!(backend.gather_blocks(
+        slice(
+            create_info.p_enabled_full_profiles,
+            create_info.enabled_full_profile_count,
+        ),
+        slice(
+            create_info.p_enabled_profile_blocks,
+            create_info.enabled_profile_block_count,
+        ),
+    ));
+
+    let mut extensions = slice(
+        instance_create_info.pp_enabled_extension_names,
+        instance_create_info.enabled_extension_count,
+    )
+    .to_vec();
+    for variant in backend.block_variants(&blocks) {
+        for extension in &try_vk!(variant).instance_extensions {
+            push_extension(&mut extensions, extension_name(extension));
+        }
+    }
+
+    let mut application_info = match instance_create_info.p_application_info.as_ref() {
+        Some(application_info) => *application_info,
+        None => vk::ApplicationInfo::default(),
+    };
+    if instance_create_info.p_application_info.is_null() {
+        if let Some(block) = blocks.first() {
+            application_info.api_version = get_profile_api_version(capabilities, &block.profiles);
+        }
+    }
+
+    // Vulkan 1.0 needs the extension to query the device support.
+    if application_info.api_version < vk::API_VERSION_1_1 {
+        push_extension(
+            &mut extensions,
+            vk::KHR_GET_PHYSICAL_DEVICE_PROPERTIES2_NAME,
+        );
+    }
+
+    let mut instance_create_info = *instance_create_info;
+    instance_create_info.p_application_info = &application_info;
+
+    #[cfg(any(target_os = "macos", target_os = "ios"))]
+    {
+        push_extension(&mut extensions, vk::KHR_PORTABILITY_ENUMERATION_NAME);
+        instance_create_info.flags |= vk::InstanceCreateFlags::ENUMERATE_PORTABILITY_KHR;
+    }
+
+    if !extensions.is_empty() {
+        instance_create_info.enabled_extension_count = extensions.len() as u32;
+        instance_create_info.pp_enabled_extension_names = extensions.as_ptr();
+    }
+
+    (backend.functions.create_instance)(&instance_create_info, p_allocator, p_instance)
+}
+
+/// Adds the extension `name` to `extensions` if it is not in it already.
+unsafe fn push_extension(extensions: &mut Vec<*const c_char>, name: &CStr) {
+    if !extensions
+        .iter()
+        .any(|&extension| CStr::from_ptr(extension) == name)
+    {
+        extensions.push(name.as_ptr());
+    }
+}
+
+unsafe extern "system" fn get_physical_device_profile_support(
+    capabilities: vp::Capabilities,
+    _instance: vk::Instance,
+    physical_device: vk::PhysicalDevice,
+    p_profile: *const vp::ProfileProperties,
+    p_supported: *mut vk::Bool32,
+) -> vk::Result {
+    let backend = Backend::from_handle(capabilities);
+    let (supported, _) = try_vk!(backend.device_support(physical_device, &*p_profile));
+    *p_supported = supported.into();
+
+    vk::Result::SUCCESS
+}
+
+unsafe extern "system" fn get_physical_device_profile_variants_support(
+    capabilities: vp::Capabilities,
+    _instance: vk::Instance,
+    physical_device: vk::PhysicalDevice,
+    p_profile: *const vp::ProfileProperties,
+    p_supported: *mut vk::Bool32,
+    p_property_count: *mut u32,
+    p_properties: *mut vp::BlockProperties,
+) -> vk::Result {
+    let backend = Backend::from_handle(capabilities);
+    let (supported, blocks) = try_vk!(backend.device_support(physical_device, &*p_profile));
+    *p_supported = supported.into();
+
+    write_array(&blocks, p_property_count, p_properties, vk::Result::SUCCESS)
+}
+
+unsafe extern "system" fn create_device(
+    capabilities: vp::Capabilities,
+    physical_device: vk::PhysicalDevice,
+    p_create_info: *const vp::DeviceCreateInfo<'_>,
+    p_allocator: *const vk::AllocationCallbacks<'_>,
+    p_device: *mut vk::Device,
+) -> vk::Result {
+    let backend = Backend::from_handle(capabilities);
+    let create_info = &*p_create_info;
+    let Some(device_create_info) = create_info.p_create_info.as_ref() else {
+        return vk::Result::ERROR_INITIALIZATION_FAILED;
+    };
+
+    let blocks = try_vk!(backend.gather_blocks(
+        slice(
+            create_info.p_enabled_full_profiles,
+            create_info.enabled_full_profile_count,
+        ),
+        slice(
+            create_info.p_enabled_profile_blocks,
+            create_info.enabled_profile_block_count,
+        ),
+    ));
+    let variants = try_vk!(backend
+        .block_variants(&blocks)
+        .collect::<VkResult<Vec<_>>>());
+
+    let mut extensions = slice(
+        device_create_info.pp_enabled_extension_names,
+        device_create_info.enabled_extension_count,
+    )
+    .to_vec();
+    for variant in &variants {
+        for extension in &variant.device_extensions {
+            push_extension(&mut extensions, extension_name(extension));
+        }
+    }
+
+    // Chain the feature structures of the profiles and of the application.
+    let mut s_types = vec![Chain::Features.head()];
+    s_types.extend(
+        variants
+            .iter()
+            .flat_map(|variant| variant.features.iter().map(|r| r.info.s_type())),
+    );
+    let mut p_next = device_create_info.p_next.cast::<vk::BaseOutStructure<'_>>();
+    while !p_next.is_null() {
+        s_types.extend(
+            structure::info((*p_next).s_type)
+                .filter(|info| info.chain() == Chain::Features)
+                .map(|info| info.s_type()),
+        );
+        p_next = (*p_next).p_next;
+    }
+    let mut chain = linked_structures(s_types);
+    let head = head(&mut chain);
+
+    let features2 = head.cast::<vk::PhysicalDeviceFeatures2<'_>>();
+    if let Some(enabled_features) = device_create_info.p_enabled_features.as_ref() {
+        (*features2).features = *enabled_features;
+    }
+
+    for variant in &variants {
+        for requirement in &variant.features {
+            requirement.fill(head);
+        }
+    }
+
+    // Enable the features the application enables in its own structures.
+    let mut p_next = device_create_info.p_next.cast::<vk::BaseOutStructure<'_>>();
+    while !p_next.is_null() {
+        let info = structure::info((*p_next).s_type).filter(|info| info.chain() == Chain::Features);
+        if let (Some(info), Some(output)) = (
+            info,
+            info.and_then(|info| find_in_chain(head, info.s_type())),
+        ) {
+            enable_features(info.members(), p_next.cast(), output);
+        }
+        p_next = (*p_next).p_next;
+    }
+
+    apply_robustness(head, create_info.flags);
+
+    let device_create_info = vk::DeviceCreateInfo {
+        p_next: head.cast::<c_void>(),
+        queue_create_info_count: device_create_info.queue_create_info_count,
+        p_queue_create_infos: device_create_info.p_queue_create_infos,
+        enabled_extension_count: extensions.len() as u32,
+        pp_enabled_extension_names: extensions.as_ptr(),
+        ..Default::default()
+    };
+
+    (backend.functions.create_device)(physical_device, &device_create_info, p_allocator, p_device)
+}
+
+/// Enables the `VkBool32` members of the structure at `output` that are enabled in the structure
+/// at `input`, recursing into nested structures.
+unsafe fn enable_features(members: &[Member], input: *const u8, output: *mut u8) {
+    for member in members {
+        let (input, output) = (input.add(member.offset()), output.add(member.offset()));
+        match *member.ty() {
+            MemberType::Bool32 if input.cast::<vk::Bool32>().read_unaligned() == vk::TRUE => {
+                output.cast::<vk::Bool32>().write_unaligned(vk::TRUE);
+            }
+            MemberType::Struct(_, members) => enable_features(members, input, output),
+            _ => {}
+        }
+    }
+}
+
+/// Disables the robust access features for the [`vp::DeviceCreateFlags`], like
+/// `ApplyRobustness`.
+unsafe fn apply_robustness(head: *mut vk::BaseOutStructure<'_>, flags: vp::DeviceCreateFlags) {
+    let disable_buffer = flags.contains(vp::DeviceCreateFlags::DISABLE_ROBUST_BUFFER_ACCESS);
+    let disable_image = flags.contains(vp::DeviceCreateFlags::DISABLE_ROBUST_IMAGE_ACCESS);
+    let find = |s_type| find_in_chain(head, s_type);
+
+    if let Some(features2) = find(vk::StructureType::PHYSICAL_DEVICE_FEATURES_2) {
+        let features2 = &mut *features2.cast::<vk::PhysicalDeviceFeatures2<'_>>();
+        if disable_buffer {
+            features2.features.robust_buffer_access = vk::FALSE;
+        }
+    }
+    if let Some(robustness2) = find(vk::StructureType::PHYSICAL_DEVICE_ROBUSTNESS_2_FEATURES_EXT) {
+        let robustness2 = &mut *robustness2.cast::<vk::PhysicalDeviceRobustness2FeaturesEXT<'_>>();
+        if disable_buffer {
+            robustness2.robust_buffer_access2 = vk::FALSE;
+        }
+        if disable_image {
+            robustness2.robust_image_access2 = vk::FALSE;
+        }
+    }
+    if let Some(image_robustness) =
+        find(vk::StructureType::PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES)
+    {
+        if disable_image {
+            (*image_robustness.cast::<vk::PhysicalDeviceImageRobustnessFeatures<'_>>())
+                .robust_image_access = vk::FALSE;
+        }
+    }
+    if let Some(vulkan13) = find(vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_3_FEATURES) {
+        if disable_image {
+            (*vulkan13.cast::<vk::PhysicalDeviceVulkan13Features<'_>>()).robust_image_access =
+                vk::FALSE;
+        }
+    }
+}
+
+unsafe fn extension_properties(
+    capabilities: vp::Capabilities,
+    p_profile: *const vp::ProfileProperties,
+    p_block_name: *const c_char,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::ExtensionProperties,
+    extensions: fn(&VariantDesc) -> &[vk::ExtensionProperties],
+) -> vk::Result {
+    let backend = Backend::from_handle(capabilities);
+    let block_name = block_name(p_block_name);
+    let (variants, result) =
+        try_vk!(backend.variants(&*p_profile, block_name, block_name.is_none()));
+
+    let mut properties = Vec::<vk::ExtensionProperties>::new();
+    for extension in variants.into_iter().flat_map(extensions) {
+        if !has_extension(&properties, extension_name(extension)) {
+            properties.push(*extension);
+        }
+    }
+
+    write_array(&properties, p_property_count, p_properties, result)
+}
+
+unsafe extern "system" fn get_profile_instance_extension_properties(
+    capabilities: vp::Capabilities,
+    p_profile: *const vp::ProfileProperties,
+    p_block_name: *const c_char,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::ExtensionProperties,
+) -> vk::Result {
+    extension_properties(
+        capabilities,
+        p_profile,
+        p_block_name,
+        p_property_count,
+        p_properties,
+        |variant| &variant.instance_extensions,
+    )
+}
+
+unsafe extern "system" fn get_profile_device_extension_properties(
+    capabilities: vp::Capabilities,
+    p_profile: *const vp::ProfileProperties,
+    p_block_name: *const c_char,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::ExtensionProperties,
+) -> vk::Result {
+    extension_properties(
+        capabilities,
+        p_profile,
+        p_block_name,
+        p_property_count,
+        p_properties,
+        |variant| &variant.device_extensions,
+    )
+}
+
+unsafe extern "system" fn get_profile_features(
+    capabilities: vp::Capabilities,
+    p_profile: *const vp::ProfileProperties,
+    p_block_name: *const c_char,
+    p_next: *mut c_void,
+) -> vk::Result {
+    let backend = Backend::from_handle(capabilities);
+    let (variants, result) = try_vk!(backend.variants(&*p_profile, block_name(p_block_name), true));
+
+    for requirement in variants.iter().flat_map(|variant| &variant.features) {
+        requirement.fill(p_next.cast());
+    }
+
+    result
+}
+
+unsafe fn structure_types(
+    capabilities: vp::Capabilities,
+    p_profile: *const vp::ProfileProperties,
+    p_block_name: *const c_char,
+    p_structure_type_count: *mut u32,
+    p_structure_types: *mut vk::StructureType,
+    chain: Chain,
+) -> vk::Result {
+    let backend = Backend::from_handle(capabilities);
+    let (s_types, result) =
+        try_vk!(backend.structure_types(&*p_profile, block_name(p_block_name), chain));
+
+    write_array(&s_types, p_structure_type_count, p_structure_types, result)
+}
+
+unsafe extern "system" fn get_profile_feature_structure_types(
+    capabilities: vp::Capabilities,
+    p_profile: *const vp::ProfileProperties,
+    p_block_name: *const c_char,
+    p_structure_type_count: *mut u32,
+    p_structure_types: *mut vk::StructureType,
+) -> vk::Result {
+    structure_types(
+        capabilities,
+        p_profile,
+        p_block_name,
+        p_structure_type_count,
+        p_structure_types,
+        Chain::Features,
+    )
+}
+
+unsafe extern "system" fn get_profile_properties(
+    capabilities: vp::Capabilities,
+    p_profile: *const vp::ProfileProperties,
+    p_block_name: *const c_char,
+    p_next: *mut c_void,
+) -> vk::Result {
+    let backend = Backend::from_handle(capabilities);
+    let block_name = block_name(p_block_name);
+
+    // The properties of different variants may conflict, so a block must be selected.
+    if block_name.is_none() && try_vk!(backend.has_multiple_variants(&*p_profile)) {
+        return vk::Result::ERROR_UNKNOWN;
+    }
+
+    let (variants, result) = try_vk!(backend.variants(&*p_profile, block_name, true));
+    for requirement in variants.iter().flat_map(|variant| &variant.properties) {
+        requirement.fill(p_next.cast());
+    }
+
+    result
+}
+
+unsafe extern "system" fn get_profile_property_structure_types(
+    capabilities: vp::Capabilities,
+    p_profile: *const vp::ProfileProperties,
+    p_block_name: *const c_char,
+    p_structure_type_count: *mut u32,
+    p_structure_types: *mut vk::StructureType,
+) -> vk::Result {
+    structure_types(
+        capabilities,
+        p_profile,
+        p_block_name,
+        p_structure_type_count,
+        p_structure_types,
+        Chain::Properties,
+    )
+}
+
+unsafe extern "system" fn get_profile_formats(
+    capabilities: vp::Capabilities,
+    p_profile: *const vp::ProfileProperties,
+    p_block_name: *const c_char,
+    p_format_count: *mut u32,
+    p_formats: *mut vk::Format,
+) -> vk::Result {
+    let backend = Backend::from_handle(capabilities);
+    let (variants, result) = try_vk!(backend.variants(&*p_profile, block_name(p_block_name), true));
+
+    let mut formats = Vec::new();
+    for format in variants.iter().flat_map(|variant| &variant.formats) {
+        if !formats.contains(&format.format) {
+            formats.push(format.format);
+        }
+    }
+
+    write_array(&formats, p_format_count, p_formats, result)
+}
+
+unsafe extern "system" fn get_profile_format_properties(
+    capabilities: vp::Capabilities,
+    p_profile: *const vp::ProfileProperties,
+    p_block_name: *const c_char,
+    format: vk::Format,
+    p_next: *mut c_void,
+) -> vk::Result {
+    let backend = Backend::from_handle(capabilities);
+    let (variants, result) = try_vk!(backend.variants(&*p_profile, block_name(p_block_name), true));
+    let p_next = p_next.cast::<vk::BaseOutStructure<'_>>();
+
+    for format in variants
+        .iter()
+        .flat_map(|variant| &variant.formats)
+        .filter(|desc| desc.format == format)
+    {
+        let fill = |p: *mut vk::BaseOutStructure<'_>| {
+            for requirement in &format.requirements {
+                requirement.fill(p);
+            }
+        };
+        fill(p_next);
+
+        // Both format structures report the flags of either structure in the profile.
+        if let Some(properties3) = find_in_chain(p_next, vk::StructureType::FORMAT_PROPERTIES_3) {
+            let properties3 = &mut *properties3.cast::<vk::FormatProperties3<'_>>();
+            let mut properties2 = vk::FormatProperties2::default();
+            fill(<*mut _>::cast(&mut properties2));
+
+            let properties = properties2.format_properties;
+            properties3.linear_tiling_features |= vk::FormatFeatureFlags2::from_raw(
+                properties.linear_tiling_features.as_raw().into(),
+            );
+            properties3.optimal_tiling_features |= vk::FormatFeatureFlags2::from_raw(
+                properties.optimal_tiling_features.as_raw().into(),
+            );
+            properties3.buffer_features |=
+                vk::FormatFeatureFlags2::from_raw(properties.buffer_features.as_raw().into());
+        }
+        if let Some(properties2) = find_in_chain(p_next, vk::StructureType::FORMAT_PROPERTIES_2) {
+            let properties2 =
+                &mut (*properties2.cast::<vk::FormatProperties2<'_>>()).format_properties;
+            let mut properties3 = vk::FormatProperties3::default();
+            fill(<*mut _>::cast(&mut properties3));
+
+            // The 32-bit flags are the low bits of the 64-bit flags.
+            properties2.linear_tiling_features |= vk::FormatFeatureFlags::from_raw(
+                properties3.linear_tiling_features.as_raw() as u32,
+            );
+            properties2.optimal_tiling_features |= vk::FormatFeatureFlags::from_raw(
+                properties3.optimal_tiling_features.as_raw() as u32,
+            );
+            properties2.buffer_features |=
+                vk::FormatFeatureFlags::from_raw(properties3.buffer_features.as_raw() as u32);
+        }
+    }
+
+    result
+}
+
+unsafe extern "system" fn get_profile_format_structure_types(
+    capabilities: vp::Capabilities,
+    p_profile: *const vp::ProfileProperties,
+    p_block_name: *const c_char,
+    p_structure_type_count: *mut u32,
+    p_structure_types: *mut vk::StructureType,
+) -> vk::Result {
+    structure_types(
+        capabilities,
+        p_profile,
+        p_block_name,
+        p_structure_type_count,
+        p_structure_types,
+        Chain::Format,
+    )
+}
+
+unsafe extern "system" fn get_profile_queue_family_properties(
+    capabilities: vp::Capabilities,
+    p_profile: *const vp::ProfileProperties,
+    p_block_name: *const c_char,
+    p_property_count: *mut u32,
+    p_properties: *mut vk::QueueFamilyProperties2KHR<'_>,
+) -> vk::Result {
+    let backend = Backend::from_handle(capabilities);
+    let (variants, mut result) =
+        try_vk!(backend.variants(&*p_profile, block_name(p_block_name), true));
+    let families = variants
+        .iter()
+        .flat_map(|variant| &variant.queue_families)
+        .collect::<Vec<_>>();
+
+    if p_properties.is_null() {
+        *p_property_count = families.len() as u32;
+        return result;
+    }
+
+    let count = (*p_property_count as usize).min(families.len());
+    if count < families.len() {
+        result = vk::Result::INCOMPLETE;
+    }
+    for (index, requirements) in families.iter().take(count).enumerate() {
+        for requirement in requirements.iter() {
+            requirement.fill(p_properties.add(index).cast());
+        }
+    }
+    *p_property_count = count as u32;
+
+    result
+}
+
+unsafe extern "system" fn get_profile_queue_family_structure_types(
+    capabilities: vp::Capabilities,
+    p_profile: *const vp::ProfileProperties,
+    p_block_name: *const c_char,
+    p_structure_type_count: *mut u32,
+    p_structure_types: *mut vk::StructureType,
+) -> vk::Result {
+    structure_types(
+        capabilities,
+        p_profile,
+        p_block_name,
+        p_structure_type_count,
+        p_structure_types,
+        Chain::QueueFamily,
+    )
+}
+
+unsafe extern "system" fn get_profile_blocks(
+    capabilities: vp::Capabilities,
+    p_profile: *const vp::ProfileProperties,
+    p_property_count: *mut u32,
+    p_properties: *mut vp::BlockProperties,
+) -> vk::Result {
+    let backend = Backend::from_handle(capabilities);
+    let mut blocks = Vec::new();
+    for (properties, desc) in try_vk!(backend.gather(&*p_profile, true)) {
+        let block = vp::BlockProperties::default().profiles(properties);
+        blocks.extend(
+            desc.capabilities
+                .iter()
+                .flatten()
+                .map(|variant| variant_block(variant, block)),
+        );
+    }
+
+    write_array(&blocks, p_property_count, p_properties, vk::Result::SUCCESS)
+}
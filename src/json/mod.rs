@@ -0,0 +1,124 @@
+//! A Vulkan Profiles backend that reads the profiles from profile JSON at runtime.
+//!
+//! The [`Library`] implements the Vulkan Profiles API in Rust, so the profiles do not need to be
+//! compiled into the C++ library. The [`Capabilities`] it creates behave like the capabilities of
+//! the generated library, with the same queries and the same support checks.
+//!
+//! ```no_run
+//! # use vp_ash::{json::Library, vp};
+//! # unsafe fn example(json: &str, vulkan_functions: &vp::VulkanFunctions) -> Result<(), Box<dyn std::error::Error>> {
+//! let library = Library::from_json(json)?;
+//!
+//! let create_info = vp::CapabilitiesCreateInfo::default().vulkan_functions(vulkan_functions);
+//! let capabilities = library.create_owned_capabilities(&create_info)?;
+//! # Ok(()) }
+//! ```
+//!
+//! The JSON is parsed into the data model of [`schema`] first, a [`ProfilesFile`] can also be
+//! inspected without creating a library. Video profiles are not supported by the backend, JSON
+//! that requires them is rejected when it is added to a library.
+
+use alloc::vec::Vec;
+
+use ash::prelude::VkResult;
+
+use vp_ash_core::json::parse;
+
+use crate::{vp, Capabilities, OwnedCapabilities};
+
+pub use vp_ash_core::json::{generate, schema, Generator, ParseError, ProfilesFile};
+
+mod backend;
+
+/// Profiles parsed from profile JSON, roughly equivalent to the generated library.
+#[derive(Clone, Default)]
+pub struct Library {
+    /// The profiles sorted by name, like the profiles of the generated library.
+    profiles: Vec<parse::ProfileDesc>,
+}
+
+impl Library {
+    /// Creates an empty library.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a library with the profiles of `json`.
+    pub fn from_json(json: &str) -> Result<Self, ParseError> {
+        let mut library = Self::new();
+        library.add_json(json)?;
+
+        Ok(library)
+    }
+
+    /// Adds the profiles of `json` to the library.
+    ///
+    /// The capabilities blocks of `json` are only visible to the profiles of `json`, the
+    /// required and fallback profiles may be in any JSON added to the library. A required or
+    /// fallback profile missing from the library is still listed by
+    /// [`Capabilities::get_profile_required_profiles`] and [`Capabilities::get_profile_fallbacks`],
+    /// by name with a spec version of 0, like the generated library. Returns an error
+    /// if `json` is invalid or defines a profile that is already in the library, the library is
+    /// unchanged on error.
+    pub fn add_json(&mut self, json: &str) -> Result<(), ParseError> {
+        self.add_file(&ProfilesFile::from_json(json)?)
+    }
+
+    /// Adds the profiles of a parsed profile JSON document to the library.
+    ///
+    /// See [`Library::add_json`].
+    pub fn add_file(&mut self, file: &ProfilesFile) -> Result<(), ParseError> {
+        let profiles = parse::parse_profiles(file)?;
+
+        for profile in &profiles {
+            if self
+                .profiles
+                .iter()
+                .any(|existing| existing.name == profile.name)
+            {
+                return Err(ParseError::new(
+                    &alloc::format!("profiles.{}", profile.name.to_string_lossy()),
+                    "the profile is already in the library",
+                ));
+            }
+        }
+
+        self.profiles.extend(profiles);
+        self.profiles.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Ok(())
+    }
+
+    /// Creates capabilities for the profiles in the library.
+    ///
+    /// The Vulkan functions are imported like the generated library: with
+    /// [`vp::CapabilitiesCreateFlags::STATIC`] the missing functions are taken from
+    /// `vp::VulkanFunctions::linked` when the `link_vulkan` feature is enabled, with
+    /// `vp::CapabilitiesCreateFlags::DYNAMIC` the global functions are resolved from
+    /// `get_instance_proc_addr`, the instance functions must be loaded with
+    /// [`vp::VulkanFunctions::load_instance`].
+    /// Returns [`vk::Result::ERROR_INITIALIZATION_FAILED`] if a required function is missing.
+    ///
+    /// The capabilities do not borrow the library, later changes to the library are not visible
+    /// to them.
+    ///
+    /// [`vk::Result::ERROR_INITIALIZATION_FAILED`]: ash::vk::Result::ERROR_INITIALIZATION_FAILED
+    pub unsafe fn create_capabilities(
+        &self,
+        capabilities_create_info: &vp::CapabilitiesCreateInfo<'_>,
+    ) -> VkResult<Capabilities> {
+        backend::create_capabilities(self.profiles.clone(), capabilities_create_info)
+    }
+
+    /// Creates capabilities that are destroyed when dropped.
+    ///
+    /// See [`Library::create_capabilities`].
+    pub unsafe fn create_owned_capabilities(
+        &self,
+        capabilities_create_info: &vp::CapabilitiesCreateInfo<'_>,
+    ) -> VkResult<OwnedCapabilities<'static>> {
+        let capabilities = self.create_capabilities(capabilities_create_info)?;
+
+        Ok(OwnedCapabilities::new(capabilities, None))
+    }
+}
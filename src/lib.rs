@@ -26,11 +26,37 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
-pub use capabilities::Capabilities;
+pub use capabilities::{Capabilities, OwnedCapabilities};
+pub use catalog::{ProfileCatalog, ProfileRequirements};
+pub use chain::{FeatureChain, PropertyChain};
+pub use diagnostics::{UnmetInstanceRequirement, UnmetRequirement};
 pub use entry::Entry;
+pub use error::{Error, VpResult};
+pub use format::FormatRequirement;
+pub use graph::ProfileGraph;
+pub use profile::Profile;
+pub use queue_family::QueueFamilyRequirement;
+pub use selector::DeviceSelector;
+pub use vp_ash_core::structure;
+#[cfg(feature = "json")]
+pub use vp_ash_macros::include_profiles;
 
 pub mod capabilities;
+pub mod catalog;
+pub mod chain;
+pub mod diagnostics;
 pub mod entry;
+pub mod error;
+pub mod fallback;
+pub mod format;
+pub mod graph;
+#[cfg(feature = "json")]
+pub mod json;
+pub mod profile;
+#[cfg(feature = "linked")]
+pub mod profiles;
+pub mod queue_family;
+pub mod selector;
 pub mod vp;
 
 #[cfg(feature = "debug")]
@@ -0,0 +1,247 @@
+//! Safe queries of the profile metadata in the library.
+//!
+//! The profile queries only read the profile tables compiled into the Vulkan Profiles library,
+//! they do not touch any Vulkan objects. The profiles are validated against the library when
+//! the [`Profile`] is created, so the queries cannot be given an unknown profile.
+
+use core::ffi::CStr;
+#[cfg(feature = "debug")]
+use core::fmt;
+
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use ash::vk;
+
+use crate::{
+    chain::{FeatureChain, PropertyChain},
+    error::{Error, VpResult},
+    format::FormatRequirement,
+    queue_family::QueueFamilyRequirement,
+    vp, Capabilities,
+};
+
+impl Capabilities {
+    /// Returns the profiles in the library.
+    pub fn profiles(&self) -> VpResult<Vec<Profile<'_>>> {
+        // SAFETY: Querying the profiles only reads the profile tables of the library.
+        let profiles = unsafe { self.get_profiles()? };
+
+        Ok(profiles
+            .into_iter()
+            .map(|properties| Profile {
+                capabilities: self,
+                properties,
+            })
+            .collect())
+    }
+
+    /// Returns the profile named `name`.
+    ///
+    /// Returns [`Error::NameTooLong`] if `name` cannot be a profile name and
+    /// [`Error::UnknownProfile`] if the library does not contain the profile.
+    pub fn profile(&self, name: &CStr) -> VpResult<Profile<'_>> {
+        // Validate the length before searching the library.
+        vp::ProfileProperties::default().profile_name(name)?;
+
+        self.profiles()?
+            .into_iter()
+            .find(|profile| profile.name() == name)
+            .ok_or(Error::UnknownProfile)
+    }
+
+    /// Returns the profile in the library that matches `properties`.
+    fn profile_from_properties(&self, properties: vp::ProfileProperties) -> VpResult<Profile<'_>> {
+        let name = properties
+            .profile_name_as_c_str()
+            .map_err(|_| Error::UnknownProfile)?;
+
+        self.profile(name)
+    }
+}
+
+/// A profile in the library, created by [`Capabilities::profiles`] or
+/// [`Capabilities::profile`].
+#[derive(Clone, Copy)]
+pub struct Profile<'a> {
+    capabilities: &'a Capabilities,
+    properties: vp::ProfileProperties,
+}
+
+impl<'a> Profile<'a> {
+    /// Returns the properties of the profile as reported by the library.
+    pub fn properties(&self) -> &vp::ProfileProperties {
+        &self.properties
+    }
+
+    /// Returns the name of the profile.
+    pub fn name(&self) -> &CStr {
+        self.properties.profile_name_as_c_str().unwrap_or(c"")
+    }
+
+    /// Returns the specification version of the profile.
+    pub fn spec_version(&self) -> u32 {
+        self.properties.spec_version
+    }
+
+    /// Returns the Vulkan API version required by the profile and its required profiles.
+    ///
+    /// <https://vulkan.lunarg.com/doc/view/1.4.304.0/windows/profiles_api_library.html#query-profile-vulkan-api-version>
+    pub fn api_version(&self) -> u32 {
+        // SAFETY: The profile is in the library.
+        unsafe { self.capabilities.get_profile_api_version(&self.properties) }
+    }
+
+    /// Returns the recommended fallback profiles of the profile.
+    ///
+    /// <https://vulkan.lunarg.com/doc/view/1.4.304.0/windows/profiles_api_library.html#query-profile-fallbacks>
+    pub fn fallbacks(&self) -> VpResult<Vec<Self>> {
+        // SAFETY: The profile is in the library.
+        let fallbacks = unsafe { self.capabilities.get_profile_fallbacks(&self.properties)? };
+
+        fallbacks
+            .into_iter()
+            .map(|properties| self.capabilities.profile_from_properties(properties))
+            .collect()
+    }
+
+    /// Returns the profile followed by its fallbacks, and their fallbacks, breadth-first.
+    pub fn fallback_order(&self) -> VpResult<Vec<Self>> {
+        // SAFETY: The profile is in the library.
+        let order = unsafe { self.capabilities.fallback_order(&self.properties)? };
+
+        order
+            .into_iter()
+            .map(|properties| self.capabilities.profile_from_properties(properties))
+            .collect()
+    }
+
+    /// Returns the profiles required by the profile.
+    ///
+    /// <https://vulkan.lunarg.com/doc/view/1.4.304.0/windows/profiles_api_library.html#query-profile-required-profiles>
+    pub fn required_profiles(&self) -> VpResult<Vec<Self>> {
+        // SAFETY: The profile is in the library.
+        let required = unsafe {
+            self.capabilities
+                .get_profile_required_profiles(&self.properties)?
+        };
+
+        required
+            .into_iter()
+            .map(|properties| self.capabilities.profile_from_properties(properties))
+            .collect()
+    }
+
+    /// Returns whether the profile has multiple variants.
+    ///
+    /// <https://vulkan.lunarg.com/doc/view/1.4.304.0/windows/profiles_api_library.html#query-profile-with-multiple-variants>
+    pub fn has_multiple_variants(&self) -> VpResult<bool> {
+        // SAFETY: The profile is in the library.
+        unsafe {
+            self.capabilities
+                .has_multiple_variants_profile(&self.properties)
+        }
+    }
+
+    /// Returns the instance extensions of the profile, or of the block named `block_name`.
+    ///
+    /// <https://vulkan.lunarg.com/doc/view/1.4.304.0/windows/profiles_api_library.html#query-profile-instance-extensions>
+    pub fn instance_extensions(
+        &self,
+        block_name: Option<&CStr>,
+    ) -> VpResult<Vec<vk::ExtensionProperties>> {
+        // SAFETY: The profile is in the library and the block name length is validated.
+        unsafe {
+            self.capabilities
+                .get_profile_instance_extension_properties(&self.properties, block_name)
+        }
+    }
+
+    /// Returns the device extensions of the profile, or of the block named `block_name`.
+    ///
+    /// <https://vulkan.lunarg.com/doc/view/1.4.304.0/windows/profiles_api_library.html#query-profile-device-extensions>
+    pub fn device_extensions(
+        &self,
+        block_name: Option<&CStr>,
+    ) -> VpResult<Vec<vk::ExtensionProperties>> {
+        // SAFETY: The profile is in the library and the block name length is validated.
+        unsafe {
+            self.capabilities
+                .get_profile_device_extension_properties(&self.properties, block_name)
+        }
+    }
+
+    /// Returns the feature structures of the profile, or of the block named `block_name`.
+    ///
+    /// <https://vulkan.lunarg.com/doc/view/1.4.304.0/windows/profiles_api_library.html#query-profile-features>
+    pub fn feature_chain(&self, block_name: Option<&CStr>) -> VpResult<FeatureChain> {
+        // SAFETY: The profile is in the library and the block name length is validated.
+        unsafe { FeatureChain::new(self.capabilities, &self.properties, block_name) }
+    }
+
+    /// Returns the requirements of each format in the profile, or in the block named
+    /// `block_name`.
+    ///
+    /// <https://vulkan.lunarg.com/doc/view/1.4.304.0/windows/profiles_api_library.html#query-profile-format-properties>
+    pub fn format_requirements(
+        &self,
+        block_name: Option<&CStr>,
+    ) -> VpResult<BTreeMap<vk::Format, FormatRequirement>> {
+        // SAFETY: The profile is in the library and the block name length is validated.
+        unsafe {
+            self.capabilities
+                .format_requirements(&self.properties, block_name)
+        }
+    }
+
+    /// Returns the requirements of each queue family in the profile, or in the block named
+    /// `block_name`.
+    ///
+    /// <https://vulkan.lunarg.com/doc/view/1.4.304.0/windows/profiles_api_library.html#query-profile-queue-family-properties>
+    pub fn queue_family_requirements(
+        &self,
+        block_name: Option<&CStr>,
+    ) -> VpResult<Vec<QueueFamilyRequirement>> {
+        // SAFETY: The profile is in the library and the block name length is validated.
+        unsafe {
+            self.capabilities
+                .queue_family_requirements(&self.properties, block_name)
+        }
+    }
+
+    /// Returns the property structures of the profile, or of the block named `block_name`.
+    ///
+    /// <https://vulkan.lunarg.com/doc/view/1.4.304.0/windows/profiles_api_library.html#query-profile-device-properties>
+    pub fn property_chain(&self, block_name: Option<&CStr>) -> VpResult<PropertyChain> {
+        // SAFETY: The profile is in the library and the block name length is validated.
+        unsafe { PropertyChain::new(self.capabilities, &self.properties, block_name) }
+    }
+
+    /// Returns the formats with requirements in the profile, or in the block named `block_name`.
+    ///
+    /// <https://vulkan.lunarg.com/doc/view/1.4.304.0/windows/profiles_api_library.html#query-profile-format-properties>
+    pub fn formats(&self, block_name: Option<&CStr>) -> VpResult<Vec<vk::Format>> {
+        // SAFETY: The profile is in the library and the block name length is validated.
+        unsafe {
+            self.capabilities
+                .get_profile_formats(&self.properties, block_name)
+        }
+    }
+}
+
+impl PartialEq for Profile<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        self.properties == other.properties
+    }
+}
+
+impl Eq for Profile<'_> {}
+
+#[cfg(feature = "debug")]
+impl fmt::Debug for Profile<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Profile")
+            .field("name", &self.name())
+            .field("spec_version", &self.spec_version())
+            .finish()
+    }
+}
@@ -0,0 +1,14 @@
+//! Constants for the profiles in the statically linked library.
+//!
+//! Generated by the build script from the `*_NAME`, `*_SPEC_VERSION` and `*_MIN_API_VERSION`
+//! defines in the library's `vulkan/vulkan_profiles.h`, so a misspelled profile is a compile error.
+//! Profiles whose guards compile them out of the library, e.g. when the Vulkan headers lack one of
+//! their extensions, have no constants.
+
+use core::ffi::CStr;
+
+use ash::vk;
+
+use crate::vp;
+
+include!(concat!(env!("OUT_DIR"), "/profiles.rs"));
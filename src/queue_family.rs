@@ -0,0 +1,150 @@
+//! The queue family requirements of a profile.
+
+use core::{ffi::CStr, ptr};
+
+use alloc::{vec, vec::Vec};
+
+use ash::vk;
+
+use crate::{
+    error::VpResult,
+    structure::{self, Chain, Structure},
+    vp, Capabilities,
+};
+
+impl Capabilities {
+    /// Returns the requirements of each queue family in the profile, or in the block named
+    /// `block_name`.
+    ///
+    /// See [`Profile::queue_family_requirements`](crate::Profile::queue_family_requirements) for
+    /// the safe equivalent.
+    ///
+    /// <https://vulkan.lunarg.com/doc/view/1.4.304.0/windows/profiles_api_library.html#query-profile-queue-family-properties>
+    pub unsafe fn queue_family_requirements(
+        &self,
+        profile_properties: &vp::ProfileProperties,
+        block_name: Option<&CStr>,
+    ) -> VpResult<Vec<QueueFamilyRequirement>> {
+        let structure_types =
+            self.get_profile_queue_family_structure_types(profile_properties, block_name)?;
+
+        query_queue_families(&structure_types, false, |count, properties| {
+            self.get_profile_queue_family_properties(
+                profile_properties,
+                block_name,
+                count,
+                properties,
+            )
+        })
+    }
+}
+
+/// Queries the queue families with `query`, which behaves like
+/// `vkGetPhysicalDeviceQueueFamilyProperties2`, extending each of them with the structures of
+/// `structure_types`, which are [marked](Structure::mark) first if `mark` is set.
+pub(crate) unsafe fn query_queue_families<E>(
+    structure_types: &[vk::StructureType],
+    mark: bool,
+    mut query: impl FnMut(&mut u32, Option<&mut [vk::QueueFamilyProperties2<'_>]>) -> Result<(), E>,
+) -> Result<Vec<QueueFamilyRequirement>, E> {
+    let mut count = 0;
+    query(&mut count, None)?;
+
+    // The queue family properties are an array, so their heads are not allocated as
+    // `Structure`s, only the structures extending them.
+    let mut extensions: Vec<Vec<Structure>> = (0..count)
+        .map(|_| {
+            let mut structures = structure::allocate_chain(Chain::QueueFamily, structure_types);
+            structures.remove(0);
+            if mark {
+                structures.iter_mut().for_each(Structure::mark);
+            }
+            structures
+        })
+        .collect();
+    let mut heads = vec![vk::QueueFamilyProperties2::default(); count as usize];
+    for (head, structures) in heads.iter_mut().zip(&mut extensions) {
+        structure::link(structures);
+        if let Some(first) = structures.first_mut() {
+            head.p_next = first.as_mut_ptr().cast();
+        }
+    }
+
+    let result = query(&mut count, Some(&mut heads));
+    extensions
+        .iter_mut()
+        .for_each(|structures| structure::unlink(structures));
+    result?;
+
+    Ok(heads
+        .iter_mut()
+        .zip(extensions)
+        .take(count as usize)
+        .map(|(head, extensions)| {
+            head.p_next = ptr::null_mut();
+            let mut structures = vec![Structure::from_struct(head)];
+            structures.extend(extensions);
+
+            let properties = head.queue_family_properties;
+            QueueFamilyRequirement {
+                queue_flags: properties.queue_flags,
+                queue_count: properties.queue_count,
+                timestamp_valid_bits: properties.timestamp_valid_bits,
+                min_image_transfer_granularity: properties.min_image_transfer_granularity,
+                structures,
+            }
+        })
+        .collect())
+}
+
+/// The requirements of a queue family.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub struct QueueFamilyRequirement {
+    pub queue_flags: vk::QueueFlags,
+    pub queue_count: u32,
+    pub timestamp_valid_bits: u32,
+    pub min_image_transfer_granularity: vk::Extent3D,
+    /// The queue family structures, starting with [`vk::QueueFamilyProperties2`].
+    pub structures: Vec<Structure>,
+}
+
+impl QueueFamilyRequirement {
+    /// Returns the queue family structure `T`, if the profile reports it.
+    pub fn get<T: vk::TaggedStructure>(&self) -> Option<&T> {
+        self.structures.iter().find_map(Structure::get)
+    }
+
+    /// Returns the required global priorities, if the profile reports them.
+    pub fn global_priority(&self) -> Option<&vk::QueueFamilyGlobalPriorityPropertiesKHR<'static>> {
+        self.get()
+    }
+
+    /// Returns the required video codec operations, if the profile reports them.
+    pub fn video(&self) -> Option<&vk::QueueFamilyVideoPropertiesKHR<'static>> {
+        self.get()
+    }
+
+    /// Returns whether the queue family `properties` of a device have the required queue flags,
+    /// at least as many queues and at least as many timestamp bits.
+    ///
+    /// ```no_run
+    /// # fn example(
+    /// #     instance: &ash::Instance,
+    /// #     physical_device: ash::vk::PhysicalDevice,
+    /// #     profile: vp_ash::Profile<'_>,
+    /// # ) -> vp_ash::VpResult<()> {
+    /// let requirement = &profile.queue_family_requirements(None)?[0];
+    /// let queue_family_index = unsafe {
+    ///     instance.get_physical_device_queue_family_properties(physical_device)
+    /// }
+    /// .iter()
+    /// .position(|properties| requirement.is_satisfied_by(properties));
+    /// # Ok(()) }
+    /// ```
+    pub fn is_satisfied_by(&self, properties: &vk::QueueFamilyProperties) -> bool {
+        properties.queue_flags.contains(self.queue_flags)
+            && properties.queue_count >= self.queue_count
+            && properties.timestamp_valid_bits >= self.timestamp_valid_bits
+    }
+}
@@ -0,0 +1,278 @@
+//! Selection of the physical device to run on.
+//!
+//! The [`DeviceSelector`] checks every physical device against the profiles an application
+//! needs and ranks the devices supporting them.
+//!
+//! ```no_run
+//! # use ash::vk;
+//! # unsafe fn example(
+//! #     capabilities: &vp_ash::Capabilities,
+//! #     entry: &ash::Entry,
+//! #     instance: &ash::Instance,
+//! #     profile: vp_ash::vp::ProfileProperties,
+//! # ) -> vp_ash::VpResult<()> {
+//! let devices = vp_ash::DeviceSelector::new(capabilities)
+//!     .profile(profile)
+//!     .preferred_vendors(&[0x10DE, 0x1002])
+//!     .select(entry, instance)?;
+//!
+//! let physical_device = devices
+//!     .iter()
+//!     .find(|device| device.is_accepted())
+//!     .map(|device| device.physical_device);
+//! # Ok(()) }
+//! ```
+
+use core::cmp::Reverse;
+
+use alloc::vec::Vec;
+
+use ash::vk;
+
+use crate::{error::VpResult, utils::instance_fn_or_khr, vp, Capabilities};
+
+/// The device types from the most to the least preferred, used by default.
+const DEVICE_TYPES: [vk::PhysicalDeviceType; 5] = [
+    vk::PhysicalDeviceType::DISCRETE_GPU,
+    vk::PhysicalDeviceType::INTEGRATED_GPU,
+    vk::PhysicalDeviceType::VIRTUAL_GPU,
+    vk::PhysicalDeviceType::CPU,
+    vk::PhysicalDeviceType::OTHER,
+];
+
+/// Ranks the physical devices supporting a set of profiles.
+///
+/// The devices supporting every profile are ranked by, in order:
+/// 1. Whether the device has the UUID given to [`device_uuid`](Self::device_uuid).
+/// 2. The position of the device type in [`device_types`](Self::device_types).
+/// 3. The position of the vendor in [`preferred_vendors`](Self::preferred_vendors).
+/// 4. The size of the device local memory, if [`rank_by_memory`](Self::rank_by_memory).
+/// 5. The API version, if [`rank_by_api_version`](Self::rank_by_api_version).
+///
+/// Device types and vendors that are not listed rank after the listed ones.
+#[derive(Clone)]
+pub struct DeviceSelector<'a> {
+    capabilities: &'a Capabilities,
+    profiles: Vec<vp::ProfileProperties>,
+    device_types: Vec<vk::PhysicalDeviceType>,
+    vendor_ids: Vec<u32>,
+    device_uuid: Option<[u8; vk::UUID_SIZE]>,
+    rank_by_memory: bool,
+    rank_by_api_version: bool,
+}
+
+impl<'a> DeviceSelector<'a> {
+    /// Creates a selector preferring discrete GPUs, then more device local memory, then a
+    /// higher API version.
+    pub fn new(capabilities: &'a Capabilities) -> Self {
+        Self {
+            capabilities,
+            profiles: Vec::new(),
+            device_types: DEVICE_TYPES.to_vec(),
+            vendor_ids: Vec::new(),
+            device_uuid: None,
+            rank_by_memory: true,
+            rank_by_api_version: true,
+        }
+    }
+
+    /// Adds a profile the devices must support.
+    pub fn profile(mut self, profile: vp::ProfileProperties) -> Self {
+        self.profiles.push(profile);
+        self
+    }
+
+    /// Adds profiles the devices must support.
+    pub fn profiles(mut self, profiles: &[vp::ProfileProperties]) -> Self {
+        self.profiles.extend_from_slice(profiles);
+        self
+    }
+
+    /// Sets the device types from the most to the least preferred.
+    pub fn device_types(mut self, device_types: &[vk::PhysicalDeviceType]) -> Self {
+        self.device_types = device_types.to_vec();
+        self
+    }
+
+    /// Sets the vendor IDs from the most to the least preferred.
+    pub fn preferred_vendors(mut self, vendor_ids: &[u32]) -> Self {
+        self.vendor_ids = vendor_ids.to_vec();
+        self
+    }
+
+    /// Ranks the device with `device_uuid` first, if it supports the profiles.
+    pub fn device_uuid(mut self, device_uuid: [u8; vk::UUID_SIZE]) -> Self {
+        self.device_uuid = Some(device_uuid);
+        self
+    }
+
+    /// Sets whether devices with more device local memory rank higher.
+    pub fn rank_by_memory(mut self, rank_by_memory: bool) -> Self {
+        self.rank_by_memory = rank_by_memory;
+        self
+    }
+
+    /// Sets whether devices with a higher API version rank higher.
+    pub fn rank_by_api_version(mut self, rank_by_api_version: bool) -> Self {
+        self.rank_by_api_version = rank_by_api_version;
+        self
+    }
+
+    /// Checks every physical device of `instance` against the profiles and ranks them.
+    ///
+    /// The accepted devices come first, from the best to the worst, followed by the rejected
+    /// devices in the order they were enumerated.
+    ///
+    /// The device UUID is queried with [`vk::PhysicalDeviceIDProperties`], through the
+    /// `get_physical_device_properties2` of the capabilities' Vulkan functions or, like the
+    /// library, `vkGetPhysicalDeviceProperties2` and then `vkGetPhysicalDeviceProperties2KHR` of
+    /// `instance`. A Vulkan 1.0 instance must enable `VK_KHR_get_physical_device_properties2`,
+    /// returns [`vk::Result::ERROR_EXTENSION_NOT_PRESENT`] if neither function is found.
+    pub unsafe fn select(
+        &self,
+        entry: &ash::Entry,
+        instance: &ash::Instance,
+    ) -> VpResult<Vec<RankedDevice>> {
+        let get_physical_device_properties2 = self
+            .capabilities
+            .vulkan_functions()
+            .get_physical_device_properties2
+            .map_or_else(
+                || {
+                    instance_fn_or_khr(
+                        entry,
+                        instance.handle(),
+                        c"vkGetPhysicalDeviceProperties2",
+                        c"vkGetPhysicalDeviceProperties2KHR",
+                    )
+                },
+                Ok,
+            )?;
+
+        let devices = instance
+            .enumerate_physical_devices()?
+            .into_iter()
+            .map(|physical_device| {
+                self.check(instance, get_physical_device_properties2, physical_device)
+            })
+            .collect::<VpResult<_>>()?;
+
+        Ok(self.rank(devices))
+    }
+
+    unsafe fn check(
+        &self,
+        instance: &ash::Instance,
+        get_physical_device_properties2: vk::PFN_vkGetPhysicalDeviceProperties2,
+        physical_device: vk::PhysicalDevice,
+    ) -> VpResult<RankedDevice> {
+        let mut id_properties = vk::PhysicalDeviceIDProperties::default();
+        let mut properties2 =
+            vk::PhysicalDeviceProperties2::default().push_next(&mut id_properties);
+        get_physical_device_properties2(physical_device, &mut properties2);
+        let properties = properties2.properties;
+        let device_uuid = id_properties.device_uuid;
+
+        let memory_properties = instance.get_physical_device_memory_properties(physical_device);
+        let device_local_memory = memory_properties
+            .memory_heaps_as_slice()
+            .iter()
+            .filter(|heap| heap.flags.contains(vk::MemoryHeapFlags::DEVICE_LOCAL))
+            .map(|heap| heap.size)
+            .sum();
+
+        let mut unsupported_profiles = Vec::new();
+        for profile in &self.profiles {
+            if !self.capabilities.get_physical_device_profile_support(
+                instance,
+                physical_device,
+                profile,
+            )? {
+                unsupported_profiles.push(*profile);
+            }
+        }
+
+        let reason = if !unsupported_profiles.is_empty() {
+            Reason::UnsupportedProfiles(unsupported_profiles)
+        } else if self.device_uuid == Some(device_uuid) {
+            Reason::RequestedDevice
+        } else {
+            Reason::SupportsProfiles
+        };
+
+        Ok(RankedDevice {
+            physical_device,
+            properties,
+            device_uuid,
+            device_local_memory,
+            reason,
+        })
+    }
+
+    /// Sorts `devices` like [`select`](Self::select), the accepted devices first from the best
+    /// to the worst.
+    pub fn rank(&self, mut devices: Vec<RankedDevice>) -> Vec<RankedDevice> {
+        devices.sort_by_key(|device| {
+            (
+                !device.is_accepted(),
+                device.is_accepted().then(|| self.rank_key(device)),
+            )
+        });
+        devices
+    }
+
+    /// The key of an accepted device, lower is better.
+    fn rank_key(&self, device: &RankedDevice) -> (bool, usize, usize, Reverse<u64>, Reverse<u32>) {
+        (
+            device.reason != Reason::RequestedDevice,
+            position(&self.device_types, device.properties.device_type),
+            position(&self.vendor_ids, device.properties.vendor_id),
+            Reverse(match self.rank_by_memory {
+                true => device.device_local_memory,
+                false => 0,
+            }),
+            Reverse(match self.rank_by_api_version {
+                true => device.properties.api_version,
+                false => 0,
+            }),
+        )
+    }
+}
+
+/// Returns the position of `value` in `list`, or the length of `list` if it is not listed.
+fn position<T: PartialEq>(list: &[T], value: T) -> usize {
+    list.iter()
+        .position(|item| *item == value)
+        .unwrap_or(list.len())
+}
+
+/// A physical device checked by a [`DeviceSelector`].
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone)]
+pub struct RankedDevice {
+    pub physical_device: vk::PhysicalDevice,
+    pub properties: vk::PhysicalDeviceProperties,
+    pub device_uuid: [u8; vk::UUID_SIZE],
+    /// The total size of the device local memory heaps.
+    pub device_local_memory: vk::DeviceSize,
+    pub reason: Reason,
+}
+
+impl RankedDevice {
+    /// Returns whether the device supports every profile.
+    pub fn is_accepted(&self) -> bool {
+        !matches!(self.reason, Reason::UnsupportedProfiles(_))
+    }
+}
+
+/// Why a device was accepted or rejected.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, PartialEq, Eq)]
+pub enum Reason {
+    /// The device has the requested UUID and supports every profile.
+    RequestedDevice,
+    /// The device supports every profile.
+    SupportsProfiles,
+    /// The device does not support these profiles.
+    UnsupportedProfiles(Vec<vp::ProfileProperties>),
+}
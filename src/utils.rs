@@ -8,6 +8,8 @@ use ash::{
     vk::{self, CStrTooLargeForStaticArray},
 };
 
+use crate::error::{Error, VpResult};
+
 #[inline]
 pub(crate) fn write_c_str_slice_with_nul(
     target: &mut [core::ffi::c_char],
@@ -96,6 +98,71 @@ where
     }
 }
 
+/// Returns a pointer to `block_name`, or null if there is no block name, checking that it fits
+/// in a [`vp::BlockProperties`](crate::vp::BlockProperties).
+pub(crate) fn block_name_as_ptr(
+    block_name: Option<&core::ffi::CStr>,
+) -> VpResult<*const core::ffi::c_char> {
+    let Some(block_name) = block_name else {
+        return Ok(core::ptr::null());
+    };
+
+    let c_str_size = block_name.to_bytes_with_nul().len();
+    if c_str_size > crate::vp::VP_MAX_PROFILE_NAME_SIZE {
+        return Err(Error::NameTooLong {
+            static_array_size: crate::vp::VP_MAX_PROFILE_NAME_SIZE,
+            c_str_size,
+        });
+    }
+
+    Ok(block_name.as_ptr())
+}
+
+/// Converts a [`vk::Bool32`] returned by the library.
+pub(crate) fn bool_from_vk(value: vk::Bool32) -> VpResult<bool> {
+    match value {
+        vk::TRUE => Ok(true),
+        vk::FALSE => Ok(false),
+        _ => Err(Error::InvalidBool(value)),
+    }
+}
+
+/// Resolves `name` using `f` and casts the result to the function pointer type `T`.
+pub(crate) unsafe fn load_fn<T>(
+    f: &mut dyn FnMut(&core::ffi::CStr) -> *const core::ffi::c_void,
+    name: &'static core::ffi::CStr,
+) -> Result<T, crate::entry::MissingEntryPoint> {
+    debug_assert_eq!(size_of::<T>(), size_of::<*const core::ffi::c_void>());
+
+    let function = f(name);
+    if function.is_null() {
+        return Err(crate::entry::MissingEntryPoint { name });
+    }
+
+    Ok(core::mem::transmute_copy(&function))
+}
+
+/// Returns the function of `instance` named `name`, or else the one named `khr_name` of the
+/// extension it was promoted from, cast to the function pointer type `T`.
+///
+/// Returns [`vk::Result::ERROR_EXTENSION_NOT_PRESENT`] if neither function is found, like on a
+/// Vulkan 1.0 instance without the extension.
+pub(crate) unsafe fn instance_fn_or_khr<T>(
+    entry: &ash::Entry,
+    instance: vk::Instance,
+    name: &core::ffi::CStr,
+    khr_name: &core::ffi::CStr,
+) -> VpResult<T> {
+    debug_assert_eq!(size_of::<T>(), size_of::<*const core::ffi::c_void>());
+
+    let function = [name, khr_name]
+        .into_iter()
+        .find_map(|name| entry.get_instance_proc_addr(instance, name.as_ptr()))
+        .ok_or(vk::Result::ERROR_EXTENSION_NOT_PRESENT)?;
+
+    Ok(core::mem::transmute_copy(&function))
+}
+
 #[cfg(feature = "debug")]
 pub(crate) fn debug_flags<Value: Into<u64> + Copy>(
     f: &mut core::fmt::Formatter<'_>,
@@ -123,3 +190,14 @@ pub(crate) fn debug_flags<Value: Into<u64> + Copy>(
     }
     Ok(())
 }
+
+/// Returns whether `actual` is at least the `expected` major and minor version, like
+/// `vpCheckVersion`.
+pub(crate) fn check_version(actual: u32, expected: u32) -> bool {
+    let actual = (vk::api_version_major(actual), vk::api_version_minor(actual));
+    let expected = (
+        vk::api_version_major(expected),
+        vk::api_version_minor(expected),
+    );
+    actual >= expected
+}
@@ -1,4 +1,4 @@
-#![allow(non_camel_case_types)]
+#![allow(non_camel_case_types, non_snake_case)]
 
 use core::ffi;
 
@@ -15,7 +15,7 @@ pub type PFN_vpCreateCapabilities = unsafe extern "system" fn(
 pub type PFN_vpDestroyCapabilities = unsafe extern "system" fn(
     capabilities: vp::Capabilities,
     pAllocator: *const vk::AllocationCallbacks<'_>,
-) -> ffi::c_void;
+);
 
 pub type PFN_vpGetProfiles = unsafe extern "system" fn(
     capabilities: vp::Capabilities,
@@ -48,6 +48,15 @@ pub type PFN_vpHasMultipleVariantsProfile = unsafe extern "system" fn(
     pHasMultipleVariants: *mut vk::Bool32,
 ) -> vk::Result;
 
+/// Lists the blocks of a profile and of its required profiles, added by `vp-ash` to the library it
+/// compiles.
+pub type PFN_vpAshGetProfileBlocks = unsafe extern "system" fn(
+    capabilities: vp::Capabilities,
+    pProfile: *const vp::ProfileProperties,
+    pPropertyCount: *mut u32,
+    pProperties: *mut vp::BlockProperties,
+) -> vk::Result;
+
 pub type PFN_vpGetInstanceProfileSupport = unsafe extern "system" fn(
     capabilities: vp::Capabilities,
     pLayerName: *const ffi::c_char,
@@ -14,7 +14,7 @@ extern "system" {
     pub(crate) fn vpDestroyCapabilities(
         capabilities: vp::Capabilities,
         pAllocator: *const vk::AllocationCallbacks<'_>,
-    ) -> ffi::c_void;
+    );
 
     pub(crate) fn vpGetProfiles(
         capabilities: vp::Capabilities,
@@ -47,6 +47,13 @@ extern "system" {
         pHasMultipleVariants: *mut vk::Bool32,
     ) -> vk::Result;
 
+    pub(crate) fn vpAshGetProfileBlocks(
+        capabilities: vp::Capabilities,
+        pProfile: *const vp::ProfileProperties,
+        pPropertyCount: *mut u32,
+        pProperties: *mut vp::BlockProperties,
+    ) -> vk::Result;
+
     pub(crate) fn vpGetInstanceProfileSupport(
         capabilities: vp::Capabilities,
         pLayerName: *const ffi::c_char,
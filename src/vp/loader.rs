@@ -0,0 +1,65 @@
+use core::ffi;
+
+use ash::vk;
+
+extern "system" {
+    pub(crate) fn vkGetInstanceProcAddr(
+        instance: vk::Instance,
+        pName: *const ffi::c_char,
+    ) -> vk::PFN_vkVoidFunction;
+
+    pub(crate) fn vkGetDeviceProcAddr(
+        device: vk::Device,
+        pName: *const ffi::c_char,
+    ) -> vk::PFN_vkVoidFunction;
+
+    pub(crate) fn vkEnumerateInstanceVersion(pApiVersion: *mut u32) -> vk::Result;
+
+    pub(crate) fn vkEnumerateInstanceExtensionProperties(
+        pLayerName: *const ffi::c_char,
+        pPropertyCount: *mut u32,
+        pProperties: *mut vk::ExtensionProperties,
+    ) -> vk::Result;
+
+    pub(crate) fn vkEnumerateDeviceExtensionProperties(
+        physicalDevice: vk::PhysicalDevice,
+        pLayerName: *const ffi::c_char,
+        pPropertyCount: *mut u32,
+        pProperties: *mut vk::ExtensionProperties,
+    ) -> vk::Result;
+
+    pub(crate) fn vkGetPhysicalDeviceFeatures2(
+        physicalDevice: vk::PhysicalDevice,
+        pFeatures: *mut vk::PhysicalDeviceFeatures2<'_>,
+    );
+
+    pub(crate) fn vkGetPhysicalDeviceProperties2(
+        physicalDevice: vk::PhysicalDevice,
+        pProperties: *mut vk::PhysicalDeviceProperties2<'_>,
+    );
+
+    pub(crate) fn vkGetPhysicalDeviceFormatProperties2(
+        physicalDevice: vk::PhysicalDevice,
+        format: vk::Format,
+        pFormatProperties: *mut vk::FormatProperties2<'_>,
+    );
+
+    pub(crate) fn vkGetPhysicalDeviceQueueFamilyProperties2(
+        physicalDevice: vk::PhysicalDevice,
+        pQueueFamilyPropertyCount: *mut u32,
+        pQueueFamilyProperties: *mut vk::QueueFamilyProperties2<'_>,
+    );
+
+    pub(crate) fn vkCreateInstance(
+        pCreateInfo: *const vk::InstanceCreateInfo<'_>,
+        pAllocator: *const vk::AllocationCallbacks<'_>,
+        pInstance: *mut vk::Instance,
+    ) -> vk::Result;
+
+    pub(crate) fn vkCreateDevice(
+        physicalDevice: vk::PhysicalDevice,
+        pCreateInfo: *const vk::DeviceCreateInfo<'_>,
+        pAllocator: *const vk::AllocationCallbacks<'_>,
+        pDevice: *mut vk::Device,
+    ) -> vk::Result;
+}
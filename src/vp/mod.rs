@@ -4,7 +4,9 @@ pub use structs::*;
 mod functions;
 #[cfg(feature = "linked")]
 pub(crate) mod linked;
+#[cfg(feature = "link_vulkan")]
+mod loader;
 mod structs;
 
-#[cfg(feature = "VK_KHR_video_queue")]
+#[cfg(vp_ash_video_queue)]
 pub mod video_queue;
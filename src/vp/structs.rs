@@ -31,6 +31,31 @@ impl Default for ProfileProperties {
 }
 
 impl ProfileProperties {
+    /// Creates the properties of the profile named `name`, usable in `const` contexts.
+    ///
+    /// # Panics
+    /// Panics if `name` does not fit in [`VP_MAX_PROFILE_NAME_SIZE`] bytes, in a `const` context
+    /// this is a compile error.
+    pub const fn new(name: &CStr, spec_version: u32) -> Self {
+        let bytes = name.to_bytes_with_nul();
+        assert!(
+            bytes.len() <= VP_MAX_PROFILE_NAME_SIZE,
+            "profile name does not fit in `VP_MAX_PROFILE_NAME_SIZE`"
+        );
+
+        let mut profile_name = [0; VP_MAX_PROFILE_NAME_SIZE];
+        let mut i = 0;
+        while i < bytes.len() {
+            profile_name[i] = bytes[i] as ffi::c_char;
+            i += 1;
+        }
+
+        Self {
+            profile_name,
+            spec_version,
+        }
+    }
+
     pub fn profile_name(mut self, name: &CStr) -> Result<Self, CStrTooLargeForStaticArray> {
         write_c_str_slice_with_nul(self.profile_name.as_mut_slice(), name)?;
         Ok(self)
@@ -268,23 +293,188 @@ ash::vk_bitflags_wrapped!(CapabilitiesCreateFlags, vk::Flags);
 
 impl CapabilitiesCreateFlags {
     pub const STATIC: Self = Self(0x0000001);
-    // pub const DYNAMIC: Self = Self(0x0000002);
+    /// Resolve the global functions missing from [`VulkanFunctions`] using its
+    /// `get_instance_proc_addr`. Both `get_instance_proc_addr` and `get_device_proc_addr` must be
+    /// provided, and the instance-level functions loaded with [`VulkanFunctions::load_instance`].
+    #[cfg(feature = "dynamic")]
+    pub const DYNAMIC: Self = Self(0x0000002);
 }
 
 #[repr(C)]
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy, Default)]
+/// The Vulkan functions used by the library, any functions left as `None` are imported by the
+/// library according to the [`CapabilitiesCreateFlags`].
+///
+/// <https://vulkan.lunarg.com/doc/view/1.4.304.0/windows/profiles_api_library.html#basic-usage>
 pub struct VulkanFunctions {
-    pub get_instance_proc_addr: vk::PFN_vkGetInstanceProcAddr,
-    pub get_device_proc_addr: vk::PFN_vkGetDeviceProcAddr,
-    pub enumerate_instance_version: vk::PFN_vkEnumerateInstanceVersion,
-    pub enumerate_instance_extension_properties: vk::PFN_vkEnumerateInstanceExtensionProperties,
-    pub enumerate_device_extension_properties: vk::PFN_vkEnumerateDeviceExtensionProperties,
-    pub get_physical_device_features2: vk::PFN_vkGetPhysicalDeviceFeatures2,
-    pub get_physical_device_properties2: vk::PFN_vkGetPhysicalDeviceProperties2,
-    pub get_physical_device_format_properties2: vk::PFN_vkGetPhysicalDeviceFormatProperties2,
+    pub get_instance_proc_addr: Option<vk::PFN_vkGetInstanceProcAddr>,
+    pub get_device_proc_addr: Option<vk::PFN_vkGetDeviceProcAddr>,
+    pub enumerate_instance_version: Option<vk::PFN_vkEnumerateInstanceVersion>,
+    pub enumerate_instance_extension_properties:
+        Option<vk::PFN_vkEnumerateInstanceExtensionProperties>,
+    pub enumerate_device_extension_properties: Option<vk::PFN_vkEnumerateDeviceExtensionProperties>,
+    pub get_physical_device_features2: Option<vk::PFN_vkGetPhysicalDeviceFeatures2>,
+    pub get_physical_device_properties2: Option<vk::PFN_vkGetPhysicalDeviceProperties2>,
+    pub get_physical_device_format_properties2:
+        Option<vk::PFN_vkGetPhysicalDeviceFormatProperties2>,
     pub get_physical_device_queue_family_properties2:
-        vk::PFN_vkGetPhysicalDeviceQueueFamilyProperties2,
-    pub create_instance: vk::PFN_vkCreateInstance,
-    pub create_device: vk::PFN_vkCreateDevice,
+        Option<vk::PFN_vkGetPhysicalDeviceQueueFamilyProperties2>,
+    pub create_instance: Option<vk::PFN_vkCreateInstance>,
+    pub create_device: Option<vk::PFN_vkCreateDevice>,
+}
+
+impl VulkanFunctions {
+    /// Create the [`VulkanFunctions`] from the function pointers of an [`ash::Entry`].
+    ///
+    /// Only the global functions are taken from the entry, the loader does not expose the
+    /// instance-level functions without an instance, see [`VulkanFunctions::from_instance`].
+    pub fn from_entry(entry: &ash::Entry) -> Self {
+        let get_instance_proc_addr = entry.static_fn().get_instance_proc_addr;
+
+        // `ash` fills the missing Vulkan 1.1 functions with a panicking stub.
+        let has_enumerate_instance_version = unsafe {
+            get_instance_proc_addr(vk::Instance::null(), c"vkEnumerateInstanceVersion".as_ptr())
+        }
+        .is_some();
+
+        Self {
+            get_instance_proc_addr: Some(get_instance_proc_addr),
+            enumerate_instance_version: has_enumerate_instance_version
+                .then_some(entry.fp_v1_1().enumerate_instance_version),
+            enumerate_instance_extension_properties: Some(
+                entry.fp_v1_0().enumerate_instance_extension_properties,
+            ),
+            create_instance: Some(entry.fp_v1_0().create_instance),
+            ..Default::default()
+        }
+    }
+
+    /// Create the [`VulkanFunctions`] from the functions exported by the statically linked Vulkan
+    /// loader, the functions the library imports with [`CapabilitiesCreateFlags::STATIC`].
+    #[cfg(feature = "link_vulkan")]
+    pub fn linked() -> Self {
+        use super::loader;
+
+        Self {
+            get_instance_proc_addr: Some(loader::vkGetInstanceProcAddr),
+            get_device_proc_addr: Some(loader::vkGetDeviceProcAddr),
+            enumerate_instance_version: Some(loader::vkEnumerateInstanceVersion),
+            enumerate_instance_extension_properties: Some(
+                loader::vkEnumerateInstanceExtensionProperties,
+            ),
+            enumerate_device_extension_properties: Some(
+                loader::vkEnumerateDeviceExtensionProperties,
+            ),
+            get_physical_device_features2: Some(loader::vkGetPhysicalDeviceFeatures2),
+            get_physical_device_properties2: Some(loader::vkGetPhysicalDeviceProperties2),
+            get_physical_device_format_properties2: Some(
+                loader::vkGetPhysicalDeviceFormatProperties2,
+            ),
+            get_physical_device_queue_family_properties2: Some(
+                loader::vkGetPhysicalDeviceQueueFamilyProperties2,
+            ),
+            create_instance: Some(loader::vkCreateInstance),
+            create_device: Some(loader::vkCreateDevice),
+        }
+    }
+
+    /// Create the [`VulkanFunctions`] from the function pointers of an [`ash::Entry`] and the
+    /// [`ash::Instance`] created from it.
+    ///
+    /// The capabilities created with these functions must only be used with `instance` and its
+    /// physical devices.
+    pub unsafe fn from_instance(entry: &ash::Entry, instance: &ash::Instance) -> Self {
+        Self {
+            get_device_proc_addr: Some(instance.fp_v1_0().get_device_proc_addr),
+            enumerate_device_extension_properties: Some(
+                instance.fp_v1_0().enumerate_device_extension_properties,
+            ),
+            create_device: Some(instance.fp_v1_0().create_device),
+            ..Self::from_entry(entry)
+        }
+        .load_instance(instance.handle())
+    }
+
+    /// Load the instance-level functions that are `None` for `instance` using
+    /// `get_instance_proc_addr`.
+    ///
+    /// The physical device queries fall back to their `KHR` aliases when the instance does not
+    /// support Vulkan 1.1, like the library does.
+    pub unsafe fn load_instance(mut self, instance: vk::Instance) -> Self {
+        let Some(get_instance_proc_addr) = self.get_instance_proc_addr else {
+            return self;
+        };
+        let load = |name: &CStr| get_instance_proc_addr(instance, name.as_ptr());
+
+        self.get_device_proc_addr = self
+            .get_device_proc_addr
+            .or_else(|| core::mem::transmute(load(c"vkGetDeviceProcAddr")));
+        self.enumerate_device_extension_properties = self
+            .enumerate_device_extension_properties
+            .or_else(|| core::mem::transmute(load(c"vkEnumerateDeviceExtensionProperties")));
+        self.create_device = self
+            .create_device
+            .or_else(|| core::mem::transmute(load(c"vkCreateDevice")));
+
+        self.get_physical_device_features2 = self.get_physical_device_features2.or_else(|| {
+            core::mem::transmute(
+                load(c"vkGetPhysicalDeviceFeatures2")
+                    .or_else(|| load(c"vkGetPhysicalDeviceFeatures2KHR")),
+            )
+        });
+        self.get_physical_device_properties2 = self.get_physical_device_properties2.or_else(|| {
+            core::mem::transmute(
+                load(c"vkGetPhysicalDeviceProperties2")
+                    .or_else(|| load(c"vkGetPhysicalDeviceProperties2KHR")),
+            )
+        });
+        self.get_physical_device_format_properties2 =
+            self.get_physical_device_format_properties2.or_else(|| {
+                core::mem::transmute(
+                    load(c"vkGetPhysicalDeviceFormatProperties2")
+                        .or_else(|| load(c"vkGetPhysicalDeviceFormatProperties2KHR")),
+                )
+            });
+        self.get_physical_device_queue_family_properties2 = self
+            .get_physical_device_queue_family_properties2
+            .or_else(|| {
+                core::mem::transmute(
+                    load(c"vkGetPhysicalDeviceQueueFamilyProperties2")
+                        .or_else(|| load(c"vkGetPhysicalDeviceQueueFamilyProperties2KHR")),
+                )
+            });
+
+        self
+    }
+
+    /// Resolve the missing global functions for [`CapabilitiesCreateFlags::DYNAMIC`], requires
+    /// `get_instance_proc_addr` and `get_device_proc_addr`.
+    ///
+    /// The loader only resolves the global functions without an instance, the instance-level
+    /// functions must already be loaded with [`VulkanFunctions::load_instance`].
+    #[cfg(feature = "dynamic")]
+    pub(crate) unsafe fn resolve_dynamic(&self) -> ash::prelude::VkResult<Self> {
+        let (Some(get_instance_proc_addr), Some(_)) =
+            (self.get_instance_proc_addr, self.get_device_proc_addr)
+        else {
+            return Err(vk::Result::ERROR_INITIALIZATION_FAILED);
+        };
+        let load = |name: &CStr| get_instance_proc_addr(vk::Instance::null(), name.as_ptr());
+
+        let mut functions = *self;
+        functions.enumerate_instance_version = functions
+            .enumerate_instance_version
+            .or_else(|| core::mem::transmute(load(c"vkEnumerateInstanceVersion")));
+        functions.enumerate_instance_extension_properties = functions
+            .enumerate_instance_extension_properties
+            .or_else(|| core::mem::transmute(load(c"vkEnumerateInstanceExtensionProperties")));
+        functions.create_instance = functions
+            .create_instance
+            .or_else(|| core::mem::transmute(load(c"vkCreateInstance")));
+
+        Ok(functions)
+    }
 }
 
 #[repr(C)]
@@ -323,4 +513,25 @@ impl<'a> CapabilitiesCreateInfo<'a> {
         self.vulkan_functions = functions;
         self
     }
+
+    /// Fill `vulkan_functions` from an [`ash::Entry`] and use them for the create info.
+    ///
+    /// See [`VulkanFunctions::from_entry`].
+    pub fn from_entry(vulkan_functions: &'a mut VulkanFunctions, entry: &ash::Entry) -> Self {
+        *vulkan_functions = VulkanFunctions::from_entry(entry);
+        Self::default().vulkan_functions(vulkan_functions)
+    }
+
+    /// Fill `vulkan_functions` from an [`ash::Entry`] and an [`ash::Instance`] and use them for
+    /// the create info.
+    ///
+    /// See [`VulkanFunctions::from_instance`].
+    pub unsafe fn from_instance(
+        vulkan_functions: &'a mut VulkanFunctions,
+        entry: &ash::Entry,
+        instance: &ash::Instance,
+    ) -> Self {
+        *vulkan_functions = VulkanFunctions::from_instance(entry, instance);
+        Self::default().vulkan_functions(vulkan_functions)
+    }
 }
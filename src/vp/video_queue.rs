@@ -1,9 +1,15 @@
 use alloc::vec::Vec;
 use core::ffi::{self, CStr};
 
-use ash::{prelude::VkResult, vk};
+use ash::vk;
 
-use crate::{utils::read_into_uninitialized_vector, vp};
+use crate::{
+    capabilities::{profile_error, BackendKind},
+    entry::MissingEntryPoint,
+    error::{Error, VpResult},
+    utils::{block_name_as_ptr, load_fn, read_into_uninitialized_vector},
+    vp,
+};
 
 pub struct Capabilities {
     handle: vp::Capabilities,
@@ -11,6 +17,31 @@ pub struct Capabilities {
 }
 
 impl Capabilities {
+    /// Create the [`Capabilities`] object using the capabilities handle and the function pointers
+    /// of the entry it was created from.
+    ///
+    /// Returns [`Error::UnsupportedBackend`] if `capabilities` were not created by the generated
+    /// library, e.g. by a [`json::Library`](crate::json::Library).
+    pub fn new(entry: &crate::Entry, capabilities: &crate::Capabilities) -> VpResult<Self> {
+        if capabilities.backend() != BackendKind::Library {
+            return Err(Error::UnsupportedBackend);
+        }
+
+        Ok(Self {
+            handle: capabilities.handle(),
+            fp: entry.video_queue_fn().clone(),
+        })
+    }
+
+    /// Create the [`Capabilities`] object from a handle and its function pointer table.
+    ///
+    /// The function pointers must come from the same library that created `handle`.
+    pub fn from_parts(handle: vp::Capabilities, fp: CapabilitiesFn) -> Self {
+        Self { handle, fp }
+    }
+
+    /// Create the [`Capabilities`] object using a handle and statically linked function pointers.
+    #[cfg(feature = "linked")]
     pub fn linked(handle: vp::Capabilities) -> Self {
         Self {
             handle,
@@ -27,6 +58,21 @@ impl Capabilities {
         &self.fp
     }
 
+    /// Maps a failed `result` of a query on `profile_properties` to an [`Error`], like
+    /// [`crate::Capabilities`] does.
+    unsafe fn profile_error(
+        &self,
+        result: vk::Result,
+        profile_properties: &vp::ProfileProperties,
+    ) -> Error {
+        profile_error(
+            self.fp.has_multiple_variants_profile,
+            self.handle,
+            result,
+            profile_properties,
+        )
+    }
+
     /// Query the list of video profiles specified by the profile.
     ///
     /// <https://vulkan.lunarg.com/doc/view/1.4.304.0/windows/profiles_api_library.html#query-profile-video-profiles>
@@ -34,11 +80,8 @@ impl Capabilities {
         &self,
         profile_properties: &vp::ProfileProperties,
         block_name: Option<&CStr>,
-    ) -> VkResult<Vec<vp::VideoProfileProperties>> {
-        let block_name_ptr = match block_name {
-            Some(name) => name.as_ptr(),
-            None => core::ptr::null(),
-        };
+    ) -> VpResult<Vec<vp::VideoProfileProperties>> {
+        let block_name_ptr = block_name_as_ptr(block_name)?;
 
         read_into_uninitialized_vector(|count, data| {
             (self.fp.get_profile_video_profiles)(
@@ -49,6 +92,7 @@ impl Capabilities {
                 data,
             )
         })
+        .map_err(|err| self.profile_error(err, profile_properties))
     }
 
     /// Query the video profile info structures for a video profile defined by a profile.
@@ -60,11 +104,8 @@ impl Capabilities {
         block_name: Option<&CStr>,
         video_profile_index: u32,
         video_profile_info: &mut vk::VideoProfileInfoKHR<'_>,
-    ) -> VkResult<()> {
-        let block_name_ptr = match block_name {
-            Some(name) => name.as_ptr(),
-            None => core::ptr::null(),
-        };
+    ) -> VpResult<()> {
+        let block_name_ptr = block_name_as_ptr(block_name)?;
 
         (self.fp.get_profile_video_profile_info)(
             self.handle,
@@ -74,6 +115,7 @@ impl Capabilities {
             video_profile_info,
         )
         .result()
+        .map_err(|err| self.profile_error(err, profile_properties))
     }
 
     /// Query the list of video profiles specified by the profile.
@@ -84,11 +126,8 @@ impl Capabilities {
         profile_properties: &vp::ProfileProperties,
         block_name: Option<&CStr>,
         video_profile_index: u32,
-    ) -> VkResult<Vec<vk::StructureType>> {
-        let block_name_ptr = match block_name {
-            Some(name) => name.as_ptr(),
-            None => core::ptr::null(),
-        };
+    ) -> VpResult<Vec<vk::StructureType>> {
+        let block_name_ptr = block_name_as_ptr(block_name)?;
 
         read_into_uninitialized_vector(|count, data| {
             (self.fp.get_profile_video_profile_info_structure_types)(
@@ -100,6 +139,7 @@ impl Capabilities {
                 data,
             )
         })
+        .map_err(|err| self.profile_error(err, profile_properties))
     }
 
     /// Query the video capabilities requirements for a video profile defined by a profile.
@@ -111,11 +151,8 @@ impl Capabilities {
         block_name: Option<&CStr>,
         video_profile_index: u32,
         capabilities: &mut vk::VideoCapabilitiesKHR<'_>,
-    ) -> VkResult<()> {
-        let block_name_ptr = match block_name {
-            Some(name) => name.as_ptr(),
-            None => core::ptr::null(),
-        };
+    ) -> VpResult<()> {
+        let block_name_ptr = block_name_as_ptr(block_name)?;
 
         (self.fp.get_profile_video_capabilities)(
             self.handle,
@@ -125,6 +162,7 @@ impl Capabilities {
             <*mut _>::cast(capabilities),
         )
         .result()
+        .map_err(|err| self.profile_error(err, profile_properties))
     }
 
     /// Query the list of video capability structure types specified by the profile for a video
@@ -136,11 +174,8 @@ impl Capabilities {
         profile_properties: &vp::ProfileProperties,
         block_name: Option<&CStr>,
         video_profile_index: u32,
-    ) -> VkResult<Vec<vk::StructureType>> {
-        let block_name_ptr = match block_name {
-            Some(name) => name.as_ptr(),
-            None => core::ptr::null(),
-        };
+    ) -> VpResult<Vec<vk::StructureType>> {
+        let block_name_ptr = block_name_as_ptr(block_name)?;
 
         read_into_uninitialized_vector(|count, data| {
             (self.fp.get_profile_video_capability_structure_types)(
@@ -152,6 +187,7 @@ impl Capabilities {
                 data,
             )
         })
+        .map_err(|err| self.profile_error(err, profile_properties))
     }
 
     /// Query the video format property requirements for a video profile defined by a profile.
@@ -164,11 +200,8 @@ impl Capabilities {
         video_profile_index: u32,
         property_count: &mut u32,
         format_properties: Option<&mut [vk::VideoFormatPropertiesKHR<'_>]>,
-    ) -> VkResult<()> {
-        let block_name_ptr = match block_name {
-            Some(name) => name.as_ptr(),
-            None => core::ptr::null(),
-        };
+    ) -> VpResult<()> {
+        let block_name_ptr = block_name_as_ptr(block_name)?;
 
         let format_properties_ptr = match format_properties {
             Some(properties) => properties.as_mut_ptr(),
@@ -184,6 +217,7 @@ impl Capabilities {
             format_properties_ptr,
         )
         .result()
+        .map_err(|err| self.profile_error(err, profile_properties))
     }
 
     /// Query the list of video format property structure types specified by the profile for a video
@@ -195,11 +229,8 @@ impl Capabilities {
         profile_properties: &vp::ProfileProperties,
         block_name: Option<&CStr>,
         video_profile_index: u32,
-    ) -> VkResult<Vec<vk::StructureType>> {
-        let block_name_ptr = match block_name {
-            Some(name) => name.as_ptr(),
-            None => core::ptr::null(),
-        };
+    ) -> VpResult<Vec<vk::StructureType>> {
+        let block_name_ptr = block_name_as_ptr(block_name)?;
 
         read_into_uninitialized_vector(|count, data| {
             (self.fp.get_profile_video_format_structure_types)(
@@ -211,9 +242,12 @@ impl Capabilities {
                 data,
             )
         })
+        .map_err(|err| self.profile_error(err, profile_properties))
     }
 }
 
+#[derive(Clone)]
+/// Function pointer table for [Capabilities].
 pub struct CapabilitiesFn {
     pub get_profile_video_profiles: PFN_vpGetProfileVideoProfiles,
     pub get_profile_video_profile_info: PFN_vpGetProfileVideoProfileInfo,
@@ -223,9 +257,13 @@ pub struct CapabilitiesFn {
     pub get_profile_video_capability_structure_types: PFN_vpGetProfileVideoCapabilityStructureTypes,
     pub get_profile_video_format_properties: PFN_vpGetProfileVideoFormatProperties,
     pub get_profile_video_format_structure_types: PFN_vpGetProfileVideoFormatStructureTypes,
+    /// Used to tell unknown profiles from profiles with multiple variants.
+    pub has_multiple_variants_profile: vp::PFN_vpHasMultipleVariantsProfile,
 }
 
 impl CapabilitiesFn {
+    /// Load the function pointers from the statically linked library.
+    #[cfg(feature = "linked")]
     pub fn linked() -> Self {
         Self {
             get_profile_video_profiles: linked::vpGetProfileVideoProfiles,
@@ -237,11 +275,51 @@ impl CapabilitiesFn {
                 linked::vpGetProfileVideoCapabilityStructureTypes,
             get_profile_video_format_properties: linked::vpGetProfileVideoFormatProperties,
             get_profile_video_format_structure_types: linked::vpGetProfileVideoFormatStructureTypes,
+            has_multiple_variants_profile: vp::linked::vpHasMultipleVariantsProfile,
+        }
+    }
+
+    /// Load the function pointers using `f` to resolve each function by name.
+    ///
+    /// Returns the first function that `f` resolves to a null pointer as an error.
+    pub fn load<F>(mut f: F) -> Result<Self, MissingEntryPoint>
+    where
+        F: FnMut(&CStr) -> *const ffi::c_void,
+    {
+        Self::load_erased(&mut f)
+    }
+
+    fn load_erased(
+        f: &mut dyn FnMut(&CStr) -> *const ffi::c_void,
+    ) -> Result<Self, MissingEntryPoint> {
+        unsafe {
+            Ok(Self {
+                get_profile_video_profiles: load_fn(f, c"vpGetProfileVideoProfiles")?,
+                get_profile_video_profile_info: load_fn(f, c"vpGetProfileVideoProfileInfo")?,
+                get_profile_video_profile_info_structure_types: load_fn(
+                    f,
+                    c"vpGetProfileVideoProfileInfoStructureTypes",
+                )?,
+                get_profile_video_capabilities: load_fn(f, c"vpGetProfileVideoCapabilities")?,
+                get_profile_video_capability_structure_types: load_fn(
+                    f,
+                    c"vpGetProfileVideoCapabilityStructureTypes",
+                )?,
+                get_profile_video_format_properties: load_fn(
+                    f,
+                    c"vpGetProfileVideoFormatProperties",
+                )?,
+                get_profile_video_format_structure_types: load_fn(
+                    f,
+                    c"vpGetProfileVideoFormatStructureTypes",
+                )?,
+                has_multiple_variants_profile: load_fn(f, c"vpHasMultipleVariantsProfile")?,
+            })
         }
     }
 }
 
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, non_snake_case)]
 pub type PFN_vpGetProfileVideoProfiles = unsafe extern "system" fn(
     capabilities: vp::Capabilities,
     pProfile: *const vp::ProfileProperties,
@@ -250,7 +328,7 @@ pub type PFN_vpGetProfileVideoProfiles = unsafe extern "system" fn(
     pVideoProfiles: *mut vp::VideoProfileProperties,
 ) -> vk::Result;
 
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, non_snake_case)]
 pub type PFN_vpGetProfileVideoProfileInfo = unsafe extern "system" fn(
     capabilities: vp::Capabilities,
     pProfile: *const vp::ProfileProperties,
@@ -259,7 +337,7 @@ pub type PFN_vpGetProfileVideoProfileInfo = unsafe extern "system" fn(
     pVideoProfileInfo: *mut vk::VideoProfileInfoKHR<'_>,
 ) -> vk::Result;
 
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, non_snake_case)]
 pub type PFN_vpGetProfileVideoProfileInfoStructureTypes = unsafe extern "system" fn(
     capabilities: vp::Capabilities,
     pProfile: *const vp::ProfileProperties,
@@ -269,7 +347,7 @@ pub type PFN_vpGetProfileVideoProfileInfoStructureTypes = unsafe extern "system"
     pStructureTypes: *mut vk::StructureType,
 ) -> vk::Result;
 
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, non_snake_case)]
 pub type PFN_vpGetProfileVideoCapabilities = unsafe extern "system" fn(
     capabilities: vp::Capabilities,
     pProfile: *const vp::ProfileProperties,
@@ -278,7 +356,7 @@ pub type PFN_vpGetProfileVideoCapabilities = unsafe extern "system" fn(
     pNext: *mut ffi::c_void,
 ) -> vk::Result;
 
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, non_snake_case)]
 pub type PFN_vpGetProfileVideoCapabilityStructureTypes = unsafe extern "system" fn(
     capabilities: vp::Capabilities,
     pProfile: *const vp::ProfileProperties,
@@ -288,7 +366,7 @@ pub type PFN_vpGetProfileVideoCapabilityStructureTypes = unsafe extern "system"
     pStructureTypes: *mut vk::StructureType,
 ) -> vk::Result;
 
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, non_snake_case)]
 pub type PFN_vpGetProfileVideoFormatProperties = unsafe extern "system" fn(
     capabilities: vp::Capabilities,
     pProfile: *const vp::ProfileProperties,
@@ -298,7 +376,7 @@ pub type PFN_vpGetProfileVideoFormatProperties = unsafe extern "system" fn(
     pProperties: *mut vk::VideoFormatPropertiesKHR<'_>,
 ) -> vk::Result;
 
-#[allow(non_camel_case_types)]
+#[allow(non_camel_case_types, non_snake_case)]
 pub type PFN_vpGetProfileVideoFormatStructureTypes = unsafe extern "system" fn(
     capabilities: vp::Capabilities,
     pProfile: *const vp::ProfileProperties,
@@ -308,6 +386,7 @@ pub type PFN_vpGetProfileVideoFormatStructureTypes = unsafe extern "system" fn(
     pStructureTypes: *mut vk::StructureType,
 ) -> vk::Result;
 
+#[cfg(feature = "linked")]
 mod linked {
     use core::ffi;
 
@@ -0,0 +1,128 @@
+mod common;
+
+use ash::vk;
+use common::{
+    setup, supported_profile, FALLBACK, FALLBACK_FALLBACK, REQUIRES, SUPPORTED, VARIANTS_SUPPORTED,
+};
+
+#[test]
+fn catalog() {
+    let (_, _, capabilities) = setup();
+
+    let catalog = capabilities.catalog().unwrap();
+    assert_eq!(catalog.profiles().len(), 10);
+    assert_eq!(catalog, capabilities.catalog().unwrap());
+
+    let supported = catalog.profile(SUPPORTED).unwrap();
+    assert_eq!(supported.name(), SUPPORTED);
+    assert_eq!(supported.api_version, vk::make_api_version(0, 1, 2, 0));
+    assert_eq!(supported.instance_extensions.len(), 1);
+    assert_eq!(
+        supported.instance_extensions[0].name.as_c_str(),
+        ash::ext::debug_utils::NAME
+    );
+
+    let device = supported.device.as_ref().unwrap();
+    let features = device.feature::<vk::PhysicalDeviceFeatures2>().unwrap();
+    assert_eq!(features.features.shader_float64, vk::TRUE);
+    assert!(features.p_next.is_null());
+    let properties = device.property::<vk::PhysicalDeviceProperties2>().unwrap();
+    assert_eq!(properties.properties.limits.max_image_dimension2_d, 16384);
+    assert!(device.format(vk::Format::R8G8B8A8_UNORM).is_some());
+    assert_eq!(device.queue_families.len(), 2);
+    let variants = supported.variants.as_ref().unwrap();
+    assert_eq!(variants.len(), 1);
+    assert_eq!(variants[0].block_name.as_c_str(), c"baseline");
+    assert_eq!(&variants[0].device, device);
+
+    let requires = catalog.profile(REQUIRES).unwrap();
+    assert_eq!(
+        requires.required_profiles[0]
+            .profile_name_as_c_str()
+            .unwrap(),
+        SUPPORTED
+    );
+    let profiles = requires
+        .variants
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|variant| variant.profile.profile_name_as_c_str().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(profiles, [SUPPORTED, REQUIRES]);
+
+    let fallback = catalog.profile(FALLBACK).unwrap();
+    assert_eq!(
+        fallback.fallbacks[0].profile_name_as_c_str().unwrap(),
+        FALLBACK_FALLBACK
+    );
+
+    let variants = catalog.profile(VARIANTS_SUPPORTED).unwrap();
+    assert!(variants.has_multiple_variants());
+    let block_names = variants
+        .variants
+        .as_ref()
+        .unwrap()
+        .iter()
+        .map(|variant| variant.block_name.as_c_str())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        block_names,
+        [
+            c"supported_a",
+            c"supported_b",
+            c"device_unsupported_a",
+            c"instance_unsupported_a"
+        ]
+    );
+    assert!(variants
+        .variants
+        .as_ref()
+        .unwrap()
+        .iter()
+        .all(|variant| variant.profile.profile_name_as_c_str().unwrap() == VARIANTS_SUPPORTED));
+}
+
+#[test]
+fn catalog_without_blocks() {
+    let (_, _, capabilities) = setup();
+
+    // A library loaded at runtime that is not compiled from `include/vp_ash_library.cpp`.
+    let fp = vp_ash::capabilities::CapabilitiesFn {
+        get_profile_blocks: None,
+        ..capabilities.fp().clone()
+    };
+    let loaded = unsafe { vp_ash::Capabilities::from_parts(capabilities.handle(), fp) };
+    assert!(matches!(
+        unsafe { loaded.get_profile_blocks(&supported_profile()) },
+        Err(vp_ash::Error::UnsupportedBackend)
+    ));
+
+    let catalog = loaded.catalog().unwrap();
+    let supported = catalog.profile(SUPPORTED).unwrap();
+    assert!(supported.device.is_some());
+    assert_eq!(supported.variants, None);
+
+    let variants = catalog.profile(VARIANTS_SUPPORTED).unwrap();
+    assert!(variants.has_multiple_variants());
+    assert_eq!(variants.variants, None);
+}
+
+#[test]
+fn catalog_is_owned() {
+    let catalog = {
+        let (_, _, capabilities) = setup();
+        capabilities.catalog().unwrap()
+    };
+
+    let names = std::thread::spawn(move || {
+        catalog
+            .profiles()
+            .iter()
+            .map(|profile| profile.name().to_owned())
+            .collect::<Vec<_>>()
+    })
+    .join()
+    .unwrap();
+    assert!(names.iter().any(|name| name.as_c_str() == SUPPORTED));
+}
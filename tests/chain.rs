@@ -0,0 +1,56 @@
+mod common;
+
+use ash::vk;
+use common::{setup, SUPPORTED, UNSUPPORTED_INSTANCE};
+
+#[test]
+fn feature_chain() {
+    let (_, _, capabilities) = setup();
+
+    let profile = capabilities.profile(SUPPORTED).unwrap();
+    let features = profile.feature_chain(None).unwrap();
+
+    assert!(features
+        .structure_types()
+        .contains(&vk::StructureType::PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES));
+    assert_eq!(features.features2().features.shader_float64, vk::TRUE);
+    assert!(features.features2().p_next.is_null());
+
+    let float16_int8 = features
+        .get::<vk::PhysicalDeviceShaderFloat16Int8Features>()
+        .unwrap();
+    assert_eq!(float16_int8.shader_float16, vk::TRUE);
+    assert!(features
+        .get::<vk::PhysicalDeviceVulkan13Features>()
+        .is_none());
+}
+
+#[test]
+fn feature_chain_without_features() {
+    let (_, _, capabilities) = setup();
+
+    let profile = capabilities.profile(UNSUPPORTED_INSTANCE).unwrap();
+    let features = profile.feature_chain(None).unwrap();
+
+    assert_eq!(features.structures().len(), 1);
+    assert_eq!(features.features2().features.shader_float64, vk::FALSE);
+}
+
+#[test]
+fn property_chain() {
+    let (_, _, capabilities) = setup();
+
+    let profile = capabilities.profile(SUPPORTED).unwrap();
+    let properties = profile.property_chain(None).unwrap();
+
+    assert_eq!(properties.limits().max_image_dimension2_d, 16384);
+    assert!(properties.properties2().p_next.is_null());
+
+    let subgroup = properties
+        .get::<vk::PhysicalDeviceSubgroupProperties>()
+        .unwrap();
+    assert_eq!(subgroup.supported_stages, vk::ShaderStageFlags::COMPUTE);
+    assert!(properties
+        .get::<vk::PhysicalDeviceMaintenance3Properties>()
+        .is_none());
+}
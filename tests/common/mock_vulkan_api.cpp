@@ -1,3 +1,5 @@
+#include <cstring>
+
 #include <vulkan/vulkan_core.h>
 
 extern "C"{
@@ -298,4 +300,33 @@ extern "C"{
     // {
     //     return VK_ERROR_UNKNOWN;
     // }
+
+    // PFN_vkGetDeviceProcAddr
+    VKAPI_ATTR PFN_vkVoidFunction VKAPI_CALL vkGetDeviceProcAddr_MOCK(
+        VkDevice                                    device,
+        const char*                                 pName)
+    {
+        return vkGetDeviceProcAddr(device, pName);
+    }
+
+    // PFN_vkGetInstanceProcAddr
+    VKAPI_ATTR PFN_vkVoidFunction VKAPI_CALL vkGetInstanceProcAddr_MOCK(
+        VkInstance                                  instance,
+        const char*                                 pName)
+    {
+    #define VP_MOCK_RETURN_IF_NAME(funcName)         if (strcmp(pName, #funcName) == 0) return (PFN_vkVoidFunction)funcName##_MOCK;
+
+        VP_MOCK_RETURN_IF_NAME(vkGetInstanceProcAddr);
+        VP_MOCK_RETURN_IF_NAME(vkGetDeviceProcAddr);
+        VP_MOCK_RETURN_IF_NAME(vkEnumerateInstanceVersion);
+        VP_MOCK_RETURN_IF_NAME(vkEnumerateInstanceExtensionProperties);
+        VP_MOCK_RETURN_IF_NAME(vkEnumerateDeviceExtensionProperties);
+        VP_MOCK_RETURN_IF_NAME(vkGetPhysicalDeviceFeatures2);
+        VP_MOCK_RETURN_IF_NAME(vkGetPhysicalDeviceProperties2);
+        VP_MOCK_RETURN_IF_NAME(vkGetPhysicalDeviceFormatProperties2);
+        VP_MOCK_RETURN_IF_NAME(vkGetPhysicalDeviceQueueFamilyProperties2);
+    #undef VP_MOCK_RETURN_IF_NAME
+
+        return vkGetInstanceProcAddr(instance, pName);
+    }
 }
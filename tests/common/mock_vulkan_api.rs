@@ -41,6 +41,15 @@ extern "system" {
         pQueueFamilyProperties: *mut vk::QueueFamilyProperties2,
     );
 
+    pub fn vkGetInstanceProcAddr_MOCK(
+        instance: vk::Instance,
+        name: *const ffi::c_char,
+    ) -> vk::PFN_vkVoidFunction;
+
+    pub fn vkGetDeviceProcAddr_MOCK(
+        device: vk::Device,
+        name: *const ffi::c_char,
+    ) -> vk::PFN_vkVoidFunction;
 }
 
 extern "system" {
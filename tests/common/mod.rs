@@ -5,31 +5,27 @@ pub mod mock_vulkan_api;
 use std::ffi::CStr;
 
 use ash::vk;
-use vp_ash::vp;
-
-pub const SUPPORTED: &CStr = c"VP_VPA_test_supported";
-pub const UNSUPPORTED_DEVICE: &CStr = c"VP_VPA_test_unsupported_device";
-pub const UNSUPPORTED_INSTANCE: &CStr = c"VP_VPA_test_unsupported_instance";
-pub const REQUIRES: &CStr = c"VP_VPA_test_requires";
-pub const FALLBACK: &CStr = c"VP_VPA_test_fallback";
-pub const FALLBACK_FALLBACK: &CStr = c"VP_VPA_test_fallback_fallback";
-pub const VARIANTS_SUPPORTED: &CStr = c"VP_VPA_test_variants_supported";
-pub const VARIANTS_DEVICE_UNSUPPORTED: &CStr = c"VP_VPA_test_variants_device_unsupported";
-pub const VARIANTS_INSTANCE_UNSUPPORTED: &CStr = c"VP_VPA_test_variants_instance_unsupported";
-pub const VIDEO: &CStr = c"VP_VPA_test_video";
+use vp_ash::{profiles, vp};
+
+pub const SUPPORTED: &CStr = profiles::VP_VPA_TEST_SUPPORTED_NAME;
+pub const UNSUPPORTED_DEVICE: &CStr = profiles::VP_VPA_TEST_UNSUPPORTED_DEVICE_NAME;
+pub const UNSUPPORTED_INSTANCE: &CStr = profiles::VP_VPA_TEST_UNSUPPORTED_INSTANCE_NAME;
+pub const REQUIRES: &CStr = profiles::VP_VPA_TEST_REQUIRES_NAME;
+pub const FALLBACK: &CStr = profiles::VP_VPA_TEST_FALLBACK_NAME;
+pub const FALLBACK_FALLBACK: &CStr = profiles::VP_VPA_TEST_FALLBACK_FALLBACK_NAME;
+pub const VARIANTS_SUPPORTED: &CStr = profiles::VP_VPA_TEST_VARIANTS_SUPPORTED_NAME;
+pub const VARIANTS_DEVICE_UNSUPPORTED: &CStr =
+    profiles::VP_VPA_TEST_VARIANTS_DEVICE_UNSUPPORTED_NAME;
+pub const VARIANTS_INSTANCE_UNSUPPORTED: &CStr =
+    profiles::VP_VPA_TEST_VARIANTS_INSTANCE_UNSUPPORTED_NAME;
+pub const VIDEO: &CStr = profiles::VP_VPA_TEST_VIDEO_NAME;
 
 pub fn video_profile() -> vp::ProfileProperties {
-    vp::ProfileProperties::default()
-        .spec_version(1)
-        .profile_name(VIDEO)
-        .unwrap()
+    profiles::VP_VPA_TEST_VIDEO
 }
 
 pub fn supported_profile() -> vp::ProfileProperties {
-    vp::ProfileProperties::default()
-        .spec_version(2)
-        .profile_name(SUPPORTED)
-        .unwrap()
+    profiles::VP_VPA_TEST_SUPPORTED
 }
 
 pub fn all_expected_profiles_exist(
@@ -51,24 +47,32 @@ pub fn blocks_match(block: vp::BlockProperties, expected: vp::BlockProperties) -
         && block.profiles.spec_version == expected.profiles.spec_version
 }
 
-pub fn setup() -> (vp::VulkanFunctions, vp_ash::Entry, vp_ash::Capabilities) {
-    let vulkan_functions = vp::VulkanFunctions {
-        enumerate_instance_version: mock_vulkan_api::vkEnumerateInstanceVersion_MOCK,
-        get_instance_proc_addr: mock_vulkan_api::vkGetInstanceProcAddr,
-        get_device_proc_addr: mock_vulkan_api::vkGetDeviceProcAddr,
-        enumerate_instance_extension_properties:
+pub fn vulkan_functions() -> vp::VulkanFunctions {
+    vp::VulkanFunctions {
+        enumerate_instance_version: Some(mock_vulkan_api::vkEnumerateInstanceVersion_MOCK),
+        get_instance_proc_addr: Some(mock_vulkan_api::vkGetInstanceProcAddr),
+        get_device_proc_addr: Some(mock_vulkan_api::vkGetDeviceProcAddr),
+        enumerate_instance_extension_properties: Some(
             mock_vulkan_api::vkEnumerateInstanceExtensionProperties_MOCK,
-        enumerate_device_extension_properties:
+        ),
+        enumerate_device_extension_properties: Some(
             mock_vulkan_api::vkEnumerateDeviceExtensionProperties_MOCK,
-        get_physical_device_features2: mock_vulkan_api::vkGetPhysicalDeviceFeatures2_MOCK,
-        get_physical_device_properties2: mock_vulkan_api::vkGetPhysicalDeviceProperties2_MOCK,
-        get_physical_device_format_properties2:
+        ),
+        get_physical_device_features2: Some(mock_vulkan_api::vkGetPhysicalDeviceFeatures2_MOCK),
+        get_physical_device_properties2: Some(mock_vulkan_api::vkGetPhysicalDeviceProperties2_MOCK),
+        get_physical_device_format_properties2: Some(
             mock_vulkan_api::vkGetPhysicalDeviceFormatProperties2_MOCK,
-        get_physical_device_queue_family_properties2:
+        ),
+        get_physical_device_queue_family_properties2: Some(
             mock_vulkan_api::vkGetPhysicalDeviceQueueFamilyProperties2_MOCK,
-        create_instance: mock_vulkan_api::vkCreateInstance,
-        create_device: mock_vulkan_api::vkCreateDevice,
-    };
+        ),
+        create_instance: Some(mock_vulkan_api::vkCreateInstance),
+        create_device: Some(mock_vulkan_api::vkCreateDevice),
+    }
+}
+
+pub fn setup() -> (vp::VulkanFunctions, vp_ash::Entry, vp_ash::Capabilities) {
+    let vulkan_functions = vulkan_functions();
 
     let capabilities_create_info = vp::CapabilitiesCreateInfo::default()
         .flags(vp::CapabilitiesCreateFlags::STATIC)
@@ -109,3 +113,33 @@ pub fn setup_instance(
 
     (entry, instance)
 }
+
+/// The mock `vkGetInstanceProcAddr` that, like the Vulkan loader, only resolves the global
+/// functions without an instance.
+pub unsafe extern "system" fn strict_get_instance_proc_addr(
+    instance: vk::Instance,
+    name: *const std::ffi::c_char,
+) -> vk::PFN_vkVoidFunction {
+    const GLOBAL: [&CStr; 5] = [
+        c"vkGetInstanceProcAddr",
+        c"vkEnumerateInstanceVersion",
+        c"vkEnumerateInstanceExtensionProperties",
+        c"vkEnumerateInstanceLayerProperties",
+        c"vkCreateInstance",
+    ];
+
+    if instance == vk::Instance::null() && !GLOBAL.contains(&CStr::from_ptr(name)) {
+        return None;
+    }
+
+    mock_vulkan_api::vkGetInstanceProcAddr_MOCK(instance, name)
+}
+
+/// An [`ash::Entry`] using [`strict_get_instance_proc_addr`].
+pub fn strict_entry() -> ash::Entry {
+    unsafe {
+        ash::Entry::from_static_fn(ash::StaticFn {
+            get_instance_proc_addr: strict_get_instance_proc_addr,
+        })
+    }
+}
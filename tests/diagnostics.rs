@@ -0,0 +1,252 @@
+mod common;
+
+use ash::vk;
+use common::{
+    setup, setup_instance, supported_profile, UNSUPPORTED_DEVICE, UNSUPPORTED_INSTANCE,
+    VARIANTS_DEVICE_UNSUPPORTED, VARIANTS_INSTANCE_UNSUPPORTED, VARIANTS_SUPPORTED,
+};
+use vp_ash::{
+    diagnostics::{Scalar, UnmetMember},
+    vp, UnmetInstanceRequirement, UnmetRequirement,
+};
+
+#[test]
+fn explain_supported_instance() {
+    let (_, _, capabilities) = setup();
+    let entry = ash::Entry::linked();
+
+    let unmet = unsafe {
+        capabilities
+            .explain_instance_support(&entry, None, &supported_profile())
+            .unwrap()
+    };
+
+    assert!(unmet.is_empty());
+}
+
+#[test]
+fn explain_unsupported_instance() {
+    let profile = vp::ProfileProperties::default()
+        .profile_name(UNSUPPORTED_INSTANCE)
+        .unwrap();
+
+    let (_, _, capabilities) = setup();
+    let entry = ash::Entry::linked();
+
+    let unmet = unsafe {
+        capabilities
+            .explain_instance_support(&entry, None, &profile)
+            .unwrap()
+    };
+    assert_eq!(
+        unmet,
+        [UnmetInstanceRequirement::Extension {
+            name: vk::KHR_DISPLAY_NAME.into(),
+            spec_version: 1,
+            layer_name: None,
+        }]
+    );
+    assert_eq!(
+        unmet[0].to_string(),
+        "VK_KHR_display required, instance does not support it"
+    );
+
+    let unmet = unsafe {
+        capabilities
+            .explain_instance_support(&entry, Some(c"VK_LAYER_KHRONOS_validation"), &profile)
+            .unwrap()
+    };
+    assert_eq!(
+        unmet[0].to_string(),
+        "VK_KHR_display required, layer VK_LAYER_KHRONOS_validation does not support it"
+    );
+}
+
+#[test]
+fn explain_supported_device() {
+    let (_, _, capabilities) = setup();
+    let (entry, instance) = setup_instance(&capabilities, supported_profile());
+
+    let unmet = unsafe {
+        capabilities
+            .explain_device_support(
+                &entry,
+                &instance,
+                vk::PhysicalDevice::null(),
+                &supported_profile(),
+            )
+            .unwrap()
+    };
+
+    assert!(unmet.is_empty());
+}
+
+#[test]
+fn explain_unsupported_device() {
+    let profile = vp::ProfileProperties::default()
+        .profile_name(UNSUPPORTED_DEVICE)
+        .unwrap();
+
+    let (_, _, capabilities) = setup();
+    let (entry, instance) = setup_instance(&capabilities, supported_profile());
+
+    let unmet = unsafe {
+        capabilities
+            .explain_device_support(&entry, &instance, vk::PhysicalDevice::null(), &profile)
+            .unwrap()
+    };
+
+    assert_eq!(
+        unmet,
+        [UnmetRequirement::Feature(UnmetMember {
+            structure: "VkPhysicalDeviceShaderFloat16Int8Features",
+            member: "shaderInt8".into(),
+            required: Scalar::Bool(true),
+            device: Scalar::Bool(false),
+        })]
+    );
+    assert_eq!(
+        unmet[0].to_string(),
+        "VkPhysicalDeviceShaderFloat16Int8Features.shaderInt8 required true, device false"
+    );
+}
+
+#[test]
+fn explain_matches_device_support() {
+    let (_, _, capabilities) = setup();
+    let (entry, instance) = setup_instance(&capabilities, supported_profile());
+
+    for profile in unsafe { capabilities.get_profiles().unwrap() } {
+        let name = profile.profile_name_as_c_str().unwrap();
+
+        let unmet = unsafe {
+            capabilities
+                .explain_device_support(&entry, &instance, vk::PhysicalDevice::null(), &profile)
+                .unwrap()
+        };
+        let (supported, _) = unsafe {
+            capabilities
+                .get_physical_device_profile_variants_support(
+                    &instance,
+                    vk::PhysicalDevice::null(),
+                    &profile,
+                )
+                .unwrap()
+        };
+
+        assert_eq!(unmet.is_empty(), supported, "{name:?}");
+    }
+}
+
+#[test]
+fn explain_multiple_variants() {
+    let profile = vp::ProfileProperties::default()
+        .profile_name(VARIANTS_SUPPORTED)
+        .unwrap();
+
+    let (_, _, capabilities) = setup();
+    let (entry, instance) = setup_instance(&capabilities, supported_profile());
+
+    let unmet = unsafe {
+        capabilities
+            .explain_device_support(&entry, &instance, vk::PhysicalDevice::null(), &profile)
+            .unwrap()
+    };
+    assert!(unmet.is_empty());
+
+    let profile = vp::ProfileProperties::default()
+        .profile_name(VARIANTS_DEVICE_UNSUPPORTED)
+        .unwrap();
+
+    let unmet = unsafe {
+        capabilities
+            .explain_device_support(&entry, &instance, vk::PhysicalDevice::null(), &profile)
+            .unwrap()
+    };
+    assert_eq!(
+        unmet,
+        [
+            UnmetRequirement::Variant {
+                profile_name: VARIANTS_DEVICE_UNSUPPORTED.into(),
+                block_name: c"device_unsupported_a".into(),
+                unmet: vec![UnmetRequirement::Feature(UnmetMember {
+                    structure: "VkPhysicalDeviceShaderFloat16Int8Features",
+                    member: "shaderInt8".into(),
+                    required: Scalar::Bool(true),
+                    device: Scalar::Bool(false),
+                })],
+            },
+            UnmetRequirement::Variant {
+                profile_name: VARIANTS_DEVICE_UNSUPPORTED.into(),
+                block_name: c"device_unsupported_b".into(),
+                unmet: vec![UnmetRequirement::Extension {
+                    name: vk::EXT_HDR_METADATA_NAME.into(),
+                    spec_version: 1,
+                }],
+            },
+        ]
+    );
+    assert_eq!(
+        unmet[0].to_string(),
+        "block device_unsupported_a of VP_VPA_test_variants_device_unsupported not supported: \
+         VkPhysicalDeviceShaderFloat16Int8Features.shaderInt8 required true, device false"
+    );
+}
+
+#[test]
+fn explain_instance_multiple_variants() {
+    let profile = vp::ProfileProperties::default()
+        .profile_name(VARIANTS_INSTANCE_UNSUPPORTED)
+        .unwrap();
+
+    let (_, _, capabilities) = setup();
+    let entry = ash::Entry::linked();
+
+    let unmet = unsafe {
+        capabilities
+            .explain_instance_support(&entry, None, &profile)
+            .unwrap()
+    };
+    assert_eq!(
+        unmet,
+        [
+            UnmetInstanceRequirement::Variant {
+                profile_name: VARIANTS_INSTANCE_UNSUPPORTED.into(),
+                block_name: c"instance_unsupported_a".into(),
+                unmet: vec![UnmetInstanceRequirement::Extension {
+                    name: vk::KHR_DISPLAY_NAME.into(),
+                    spec_version: 1,
+                    layer_name: None,
+                }],
+            },
+            UnmetInstanceRequirement::Variant {
+                profile_name: VARIANTS_INSTANCE_UNSUPPORTED.into(),
+                block_name: c"instance_unsupported_b".into(),
+                unmet: vec![UnmetInstanceRequirement::Extension {
+                    name: vk::KHR_DEVICE_GROUP_CREATION_NAME.into(),
+                    spec_version: 1,
+                    layer_name: None,
+                }],
+            },
+        ]
+    );
+}
+
+#[test]
+fn display_format() {
+    let unmet = UnmetRequirement::Format {
+        format: vk::Format::R8G8B8A8_UNORM,
+        member: UnmetMember {
+            structure: "VkFormatProperties3",
+            member: "optimalTilingFeatures".into(),
+            required: Scalar::Flags(0x1),
+            device: Scalar::Flags(0x0),
+        },
+    };
+
+    assert_eq!(
+        unmet.to_string(),
+        "format VK_FORMAT_R8G8B8A8_UNORM: VkFormatProperties3.optimalTilingFeatures required 0x1, \
+         device 0x0"
+    );
+}
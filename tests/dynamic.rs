@@ -0,0 +1,97 @@
+#![cfg(feature = "dynamic")]
+
+mod common;
+
+use ash::vk;
+use common::{
+    all_expected_profiles_exist, mock_vulkan_api, setup, setup_instance,
+    strict_get_instance_proc_addr, supported_profile,
+};
+use vp_ash::vp;
+
+fn setup_dynamic() -> (ash::Instance, vp_ash::Capabilities) {
+    let (_, _, capabilities) = setup();
+    let (_, instance) = setup_instance(&capabilities, supported_profile());
+
+    let vulkan_functions = unsafe {
+        vp::VulkanFunctions {
+            get_instance_proc_addr: Some(strict_get_instance_proc_addr),
+            get_device_proc_addr: Some(mock_vulkan_api::vkGetDeviceProcAddr_MOCK),
+            ..Default::default()
+        }
+        .load_instance(instance.handle())
+    };
+
+    let capabilities_create_info = vp::CapabilitiesCreateInfo::default()
+        .flags(vp::CapabilitiesCreateFlags::DYNAMIC)
+        .vulkan_functions(&vulkan_functions)
+        .api_version(vk::make_api_version(0, 1, 2, 0));
+    let entry = vp_ash::Entry::linked();
+
+    let capabilities = unsafe {
+        entry
+            .create_capabilities(&capabilities_create_info, None)
+            .unwrap()
+    };
+
+    (instance, capabilities)
+}
+
+#[test]
+fn dynamic_profiles() {
+    let expected_profiles = [supported_profile()];
+
+    let (_, capabilities) = setup_dynamic();
+
+    let profiles = unsafe { capabilities.get_profiles().unwrap() };
+    assert!(all_expected_profiles_exist(&expected_profiles, &profiles));
+}
+
+#[test]
+fn dynamic_supported_device() {
+    let profile = supported_profile();
+
+    let (instance, capabilities) = setup_dynamic();
+
+    let supported = unsafe {
+        capabilities
+            .get_physical_device_profile_support(&instance, vk::PhysicalDevice::null(), &profile)
+            .unwrap()
+    };
+    assert!(supported);
+}
+
+#[test]
+fn dynamic_without_instance() {
+    // The instance-level functions cannot be resolved without an instance.
+    let vulkan_functions = vp::VulkanFunctions {
+        get_instance_proc_addr: Some(strict_get_instance_proc_addr),
+        get_device_proc_addr: Some(mock_vulkan_api::vkGetDeviceProcAddr_MOCK),
+        ..Default::default()
+    };
+
+    let capabilities_create_info = vp::CapabilitiesCreateInfo::default()
+        .flags(vp::CapabilitiesCreateFlags::DYNAMIC)
+        .vulkan_functions(&vulkan_functions)
+        .api_version(vk::make_api_version(0, 1, 2, 0));
+    let entry = vp_ash::Entry::linked();
+
+    let result = unsafe { entry.create_capabilities(&capabilities_create_info, None) };
+    assert_eq!(result.err(), Some(vk::Result::ERROR_INITIALIZATION_FAILED));
+}
+
+#[test]
+fn dynamic_missing_proc_addr() {
+    let vulkan_functions = vp::VulkanFunctions {
+        get_instance_proc_addr: Some(mock_vulkan_api::vkGetInstanceProcAddr_MOCK),
+        ..Default::default()
+    };
+
+    let capabilities_create_info = vp::CapabilitiesCreateInfo::default()
+        .flags(vp::CapabilitiesCreateFlags::DYNAMIC)
+        .vulkan_functions(&vulkan_functions);
+    let entry = vp_ash::Entry::linked();
+
+    let result = unsafe { entry.create_capabilities(&capabilities_create_info, None) };
+    assert_eq!(result.err(), Some(vk::Result::ERROR_INITIALIZATION_FAILED));
+}
@@ -0,0 +1,70 @@
+mod common;
+
+use std::ffi::CString;
+
+use ash::vk;
+use common::{setup, supported_profile, VARIANTS_SUPPORTED};
+use vp_ash::vp;
+
+#[test]
+fn unknown_profile() {
+    let profile = vp::ProfileProperties::default()
+        .profile_name(c"VP_VPA_test_unknown")
+        .unwrap();
+
+    let (_, _, capabilities) = setup();
+
+    let result = unsafe { capabilities.get_profile_fallbacks(&profile) };
+    assert_eq!(result, Err(vp_ash::Error::UnknownProfile));
+}
+
+#[test]
+fn block_name_too_long() {
+    let profile = supported_profile();
+    let block_name = CString::new("a".repeat(vp::VP_MAX_PROFILE_NAME_SIZE)).unwrap();
+
+    let (_, _, capabilities) = setup();
+
+    let result = unsafe { capabilities.get_profile_formats(&profile, Some(&block_name)) };
+    assert_eq!(
+        result,
+        Err(vp_ash::Error::NameTooLong {
+            static_array_size: vp::VP_MAX_PROFILE_NAME_SIZE,
+            c_str_size: vp::VP_MAX_PROFILE_NAME_SIZE + 1,
+        })
+    );
+}
+
+#[test]
+fn multiple_variants_profile() {
+    let profile = vp::ProfileProperties::default()
+        .profile_name(VARIANTS_SUPPORTED)
+        .unwrap();
+
+    let (_, _, capabilities) = setup();
+
+    let mut properties = vk::PhysicalDeviceProperties2::default();
+    let result = unsafe { capabilities.get_profile_properties(&profile, None, &mut properties) };
+    assert_eq!(result, Err(vp_ash::Error::MultipleVariantsProfile));
+}
+
+unsafe extern "system" fn has_multiple_variants_profile_invalid(
+    _capabilities: vp::Capabilities,
+    _profile: *const vp::ProfileProperties,
+    has_multiple_variants: *mut vk::Bool32,
+) -> vk::Result {
+    *has_multiple_variants = 2;
+    vk::Result::SUCCESS
+}
+
+#[test]
+fn invalid_bool() {
+    let (_, _, capabilities) = setup();
+
+    let mut fp = capabilities.fp().clone();
+    fp.has_multiple_variants_profile = has_multiple_variants_profile_invalid;
+    let capabilities = unsafe { vp_ash::Capabilities::from_parts(capabilities.handle(), fp) };
+
+    let result = unsafe { capabilities.has_multiple_variants_profile(&supported_profile()) };
+    assert_eq!(result, Err(vp_ash::Error::InvalidBool(2)));
+}
@@ -0,0 +1,84 @@
+mod common;
+
+use ash::vk;
+use common::{setup, setup_instance, supported_profile, FALLBACK, FALLBACK_FALLBACK, SUPPORTED};
+use vp_ash::{fallback::Rejection, vp};
+
+#[test]
+fn fallback_order() {
+    let (_, _, capabilities) = setup();
+
+    let profile = capabilities.profile(FALLBACK).unwrap();
+    let order = profile.fallback_order().unwrap();
+    let names: Vec<_> = order.iter().map(|profile| profile.name()).collect();
+    assert_eq!(names, [FALLBACK, FALLBACK_FALLBACK]);
+
+    let profile = capabilities.profile(SUPPORTED).unwrap();
+    assert_eq!(profile.fallback_order().unwrap().len(), 1);
+}
+
+#[test]
+fn resolve_device_fallbacks() {
+    let profile = vp::ProfileProperties::default()
+        .profile_name(FALLBACK)
+        .unwrap();
+
+    let (_, _, capabilities) = setup();
+    let (_, instance) = setup_instance(&capabilities, supported_profile());
+
+    let resolution = unsafe {
+        capabilities
+            .resolve_device_fallbacks(&instance, vk::PhysicalDevice::null(), &profile)
+            .unwrap()
+    };
+
+    assert!(resolution.is_supported());
+    assert_eq!(
+        resolution.profile.unwrap().profile_name_as_c_str().unwrap(),
+        FALLBACK_FALLBACK
+    );
+    assert_eq!(resolution.rejected.len(), 1);
+    assert_eq!(resolution.rejected[0].profile, profile);
+    assert_eq!(resolution.rejected[0].reason, Rejection::UnsupportedDevice);
+}
+
+#[test]
+fn resolve_fallbacks() {
+    let profile = vp::ProfileProperties::default()
+        .profile_name(FALLBACK)
+        .unwrap();
+
+    let (_, _, capabilities) = setup();
+    let (_, instance) = setup_instance(&capabilities, supported_profile());
+
+    let resolutions = unsafe { capabilities.resolve_fallbacks(&instance, &profile).unwrap() };
+
+    let physical_devices = unsafe { instance.enumerate_physical_devices().unwrap() };
+    assert_eq!(resolutions.len(), physical_devices.len());
+    for (resolution, physical_device) in resolutions.iter().zip(physical_devices) {
+        assert_eq!(resolution.physical_device, physical_device);
+        assert_eq!(
+            resolution.profile.unwrap().profile_name_as_c_str().unwrap(),
+            FALLBACK_FALLBACK
+        );
+        assert_eq!(resolution.rejected.len(), 1);
+        assert_eq!(resolution.rejected[0].reason, Rejection::UnsupportedDevice);
+    }
+}
+
+#[test]
+fn resolve_supported_profile() {
+    let profile = supported_profile();
+
+    let (_, _, capabilities) = setup();
+    let (_, instance) = setup_instance(&capabilities, profile);
+
+    let resolution = unsafe {
+        capabilities
+            .resolve_device_fallbacks(&instance, vk::PhysicalDevice::null(), &profile)
+            .unwrap()
+    };
+
+    assert_eq!(resolution.profile, Some(profile));
+    assert!(resolution.rejected.is_empty());
+}
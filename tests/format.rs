@@ -0,0 +1,70 @@
+mod common;
+
+use ash::vk;
+use common::{setup, SUPPORTED, UNSUPPORTED_INSTANCE};
+use vp_ash::format::first_format_with;
+
+#[test]
+fn format_requirements() {
+    let (_, _, capabilities) = setup();
+
+    let profile = capabilities.profile(SUPPORTED).unwrap();
+    let requirements = profile.format_requirements(None).unwrap();
+
+    assert_eq!(
+        requirements.keys().copied().collect::<Vec<_>>(),
+        [vk::Format::R8G8B8A8_UNORM]
+    );
+
+    let requirement = &requirements[&vk::Format::R8G8B8A8_UNORM];
+    assert_eq!(requirement.format, vk::Format::R8G8B8A8_UNORM);
+    assert_eq!(
+        requirement.linear_tiling_features,
+        vk::FormatFeatureFlags2::TRANSFER_DST
+    );
+    assert_eq!(
+        requirement.optimal_tiling_features,
+        vk::FormatFeatureFlags2::TRANSFER_SRC
+    );
+    assert_eq!(
+        requirement.buffer_features,
+        vk::FormatFeatureFlags2::STORAGE_TEXEL_BUFFER
+    );
+    assert!(requirement.get::<vk::FormatProperties2>().is_some());
+}
+
+#[test]
+fn format_requirements_without_formats() {
+    let (_, _, capabilities) = setup();
+
+    let profile = capabilities.profile(UNSUPPORTED_INSTANCE).unwrap();
+    let requirements = profile.format_requirements(None).unwrap();
+
+    assert!(requirements.is_empty());
+}
+
+#[test]
+fn first_format() {
+    let (_, _, capabilities) = setup();
+
+    let profile = capabilities.profile(SUPPORTED).unwrap();
+    let requirements = profile.format_requirements(None).unwrap();
+
+    let candidates = [vk::Format::B8G8R8A8_UNORM, vk::Format::R8G8B8A8_UNORM];
+    assert_eq!(
+        first_format_with(
+            &requirements,
+            &candidates,
+            vk::FormatFeatureFlags2::TRANSFER_SRC
+        ),
+        Some(vk::Format::R8G8B8A8_UNORM)
+    );
+    assert_eq!(
+        first_format_with(
+            &requirements,
+            &candidates,
+            vk::FormatFeatureFlags2::TRANSFER_DST
+        ),
+        None
+    );
+}
@@ -0,0 +1,74 @@
+mod common;
+
+use common::{setup, FALLBACK, FALLBACK_FALLBACK, REQUIRES, SUPPORTED};
+use vp_ash::graph::EdgeKind;
+
+#[test]
+fn profile_graph() {
+    let (_, _, capabilities) = setup();
+
+    let graph = capabilities.profile_graph().unwrap();
+    assert_eq!(graph.profiles().len(), 10);
+    assert_eq!(graph.edges().len(), 2);
+    assert!(graph.missing_profiles().is_empty());
+    assert!(graph.find_cycle().is_none());
+
+    let required = graph.required_profiles(REQUIRES);
+    assert_eq!(required.len(), 1);
+    assert_eq!(required[0].profile_name_as_c_str().unwrap(), SUPPORTED);
+
+    let fallbacks = graph.fallbacks(FALLBACK);
+    assert_eq!(fallbacks.len(), 1);
+    assert_eq!(
+        fallbacks[0].profile_name_as_c_str().unwrap(),
+        FALLBACK_FALLBACK
+    );
+    assert!(graph.fallbacks(SUPPORTED).is_empty());
+    let fallback = graph
+        .profiles()
+        .iter()
+        .find(|profile| profile.profile_name_as_c_str().unwrap() == FALLBACK)
+        .unwrap();
+    let order = unsafe { capabilities.fallback_order(fallback).unwrap() };
+    assert_eq!(graph.fallbacks(FALLBACK), order[1..]);
+
+    let edge = graph
+        .edges()
+        .iter()
+        .find(|edge| edge.kind == EdgeKind::Requires)
+        .unwrap();
+    assert_eq!(edge.from.profile_name_as_c_str().unwrap(), REQUIRES);
+}
+
+#[test]
+fn profile_graph_topological_order() {
+    let (_, _, capabilities) = setup();
+
+    let graph = capabilities.profile_graph().unwrap();
+    let order: Vec<_> = graph
+        .topological_order()
+        .unwrap()
+        .iter()
+        .map(|profile| profile.profile_name_as_c_str().unwrap().to_owned())
+        .collect();
+    assert_eq!(order.len(), 10);
+
+    let position = |name| order.iter().position(|profile| profile.as_c_str() == name);
+    assert!(position(SUPPORTED) < position(REQUIRES));
+    assert!(position(FALLBACK_FALLBACK) < position(FALLBACK));
+}
+
+#[test]
+fn profile_graph_dot() {
+    let (_, _, capabilities) = setup();
+
+    let dot = capabilities.profile_graph().unwrap().to_dot();
+    assert!(dot.starts_with("digraph profiles {\n"));
+    assert!(dot.contains(
+        "    \"VP_VPA_test_requires\" -> \"VP_VPA_test_supported\" [label=\"requires\"];\n"
+    ));
+    assert!(dot.contains(
+        "    \"VP_VPA_test_fallback\" -> \"VP_VPA_test_fallback_fallback\" [label=\"fallback\", style=dashed];\n"
+    ));
+    assert!(dot.ends_with("}\n"));
+}
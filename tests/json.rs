@@ -0,0 +1,476 @@
+#![cfg(feature = "json")]
+
+mod common;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use ash::{ext, khr, vk};
+use common::{
+    blocks_match, supported_profile, vulkan_functions, FALLBACK, FALLBACK_FALLBACK, REQUIRES,
+    SUPPORTED, UNSUPPORTED_DEVICE, UNSUPPORTED_INSTANCE, VARIANTS_INSTANCE_UNSUPPORTED,
+    VARIANTS_SUPPORTED,
+};
+use vp_ash::{json::Library, vp};
+
+const PROFILES: &[&str] = &[
+    include_str!("vulkan_profiles/profiles/VP_VPA_test_fallback.json"),
+    include_str!("vulkan_profiles/profiles/VP_VPA_test_requires.json"),
+    include_str!("vulkan_profiles/profiles/VP_VPA_test_supported.json"),
+    include_str!("vulkan_profiles/profiles/VP_VPA_test_unsupported_device.json"),
+    include_str!("vulkan_profiles/profiles/VP_VPA_test_unsupported_instance.json"),
+    include_str!("vulkan_profiles/profiles/VP_VPA_test_variants.json"),
+];
+
+fn setup_json() -> (vp::VulkanFunctions, vp_ash::Capabilities) {
+    let mut library = Library::new();
+    for json in PROFILES {
+        library.add_json(json).unwrap();
+    }
+
+    let vulkan_functions = vulkan_functions();
+    let capabilities_create_info = vp::CapabilitiesCreateInfo::default()
+        .vulkan_functions(&vulkan_functions)
+        .api_version(vk::make_api_version(0, 1, 2, 0));
+    let capabilities = unsafe {
+        library
+            .create_capabilities(&capabilities_create_info)
+            .unwrap()
+    };
+
+    (vulkan_functions, capabilities)
+}
+
+fn profile(name: &std::ffi::CStr) -> vp::ProfileProperties {
+    vp::ProfileProperties::default().profile_name(name).unwrap()
+}
+
+#[test]
+fn profiles() {
+    let (_, capabilities) = setup_json();
+
+    let profiles = capabilities.profiles().unwrap();
+    assert_eq!(profiles.len(), 9);
+
+    let profile = capabilities.profile(SUPPORTED).unwrap();
+    assert_eq!(profile.spec_version(), 2);
+    assert_eq!(profile.api_version(), vk::make_api_version(0, 1, 2, 0));
+    assert!(!profile.has_multiple_variants().unwrap());
+
+    let fallbacks = capabilities.profile(FALLBACK).unwrap().fallbacks().unwrap();
+    assert_eq!(fallbacks.len(), 1);
+    assert_eq!(fallbacks[0].name(), FALLBACK_FALLBACK);
+
+    let required = capabilities
+        .profile(REQUIRES)
+        .unwrap()
+        .required_profiles()
+        .unwrap();
+    assert_eq!(required.len(), 1);
+    assert_eq!(required[0].name(), SUPPORTED);
+
+    let variants = capabilities.profile(VARIANTS_SUPPORTED).unwrap();
+    assert!(variants.has_multiple_variants().unwrap());
+}
+
+#[test]
+fn extensions() {
+    let (_, capabilities) = setup_json();
+    let profile = capabilities.profile(SUPPORTED).unwrap();
+
+    let instance_extensions = profile.instance_extensions(None).unwrap();
+    assert_eq!(instance_extensions.len(), 1);
+    assert_eq!(
+        instance_extensions[0].extension_name_as_c_str(),
+        Ok(ext::debug_utils::NAME)
+    );
+
+    let device_extensions = profile.device_extensions(None).unwrap();
+    assert_eq!(device_extensions.len(), 4);
+    assert!(device_extensions
+        .iter()
+        .any(|extension| extension.extension_name_as_c_str() == Ok(khr::synchronization2::NAME)));
+
+    // The required profiles are only included without a block.
+    let requires = capabilities.profile(REQUIRES).unwrap();
+    assert_eq!(requires.device_extensions(None).unwrap().len(), 4);
+    assert_eq!(
+        requires.device_extensions(Some(c"baseline")).unwrap().len(),
+        0
+    );
+}
+
+#[test]
+fn features() {
+    let (_, capabilities) = setup_json();
+    let profile = supported_profile();
+
+    let mut float16_int8 = vk::PhysicalDeviceShaderFloat16Int8Features::default();
+    let mut features = vk::PhysicalDeviceFeatures2::default().push_next(&mut float16_int8);
+    unsafe {
+        capabilities
+            .get_profile_features(&profile, None, &mut features)
+            .unwrap()
+    };
+
+    assert_eq!(features.features.shader_float64, vk::TRUE);
+    assert_eq!(features.features.shader_int16, vk::FALSE);
+    assert_eq!(float16_int8.shader_float16, vk::TRUE);
+    assert_eq!(float16_int8.shader_int8, vk::FALSE);
+
+    let structure_types = unsafe {
+        capabilities
+            .get_profile_feature_structure_types(&profile, None)
+            .unwrap()
+    };
+    assert_eq!(
+        structure_types,
+        [
+            vk::StructureType::PHYSICAL_DEVICE_FEATURES_2,
+            vk::StructureType::PHYSICAL_DEVICE_SHADER_FLOAT16_INT8_FEATURES,
+        ]
+    );
+}
+
+#[test]
+fn properties() {
+    let (_, capabilities) = setup_json();
+
+    let mut subgroup = vk::PhysicalDeviceSubgroupProperties::default();
+    let mut properties = vk::PhysicalDeviceProperties2::default().push_next(&mut subgroup);
+    unsafe {
+        capabilities
+            .get_profile_properties(&supported_profile(), None, &mut properties)
+            .unwrap()
+    };
+
+    assert_eq!(properties.properties.limits.max_image_dimension2_d, 16384);
+    assert_eq!(subgroup.supported_stages, vk::ShaderStageFlags::COMPUTE);
+
+    let variants = profile(VARIANTS_SUPPORTED);
+    let mut properties = vk::PhysicalDeviceProperties2::default();
+    assert_eq!(
+        unsafe { capabilities.get_profile_properties(&variants, None, &mut properties) },
+        Err(vp_ash::Error::MultipleVariantsProfile)
+    );
+}
+
+#[test]
+fn formats_and_queue_families() {
+    let (_, capabilities) = setup_json();
+    let profile = supported_profile();
+
+    let formats = unsafe { capabilities.get_profile_formats(&profile, None).unwrap() };
+    assert_eq!(formats, [vk::Format::R8G8B8A8_UNORM]);
+
+    let mut properties3 = vk::FormatProperties3::default();
+    let mut properties = vk::FormatProperties2::default().push_next(&mut properties3);
+    unsafe {
+        capabilities
+            .get_profile_format_properties(
+                &profile,
+                None,
+                vk::Format::R8G8B8A8_UNORM,
+                &mut properties,
+            )
+            .unwrap()
+    };
+    assert_eq!(
+        properties.format_properties.optimal_tiling_features,
+        vk::FormatFeatureFlags::TRANSFER_SRC
+    );
+    assert_eq!(
+        properties3.buffer_features,
+        vk::FormatFeatureFlags2::STORAGE_TEXEL_BUFFER
+    );
+
+    let mut count = 0;
+    unsafe {
+        capabilities
+            .get_profile_queue_family_properties(&profile, None, &mut count, None)
+            .unwrap()
+    };
+    assert_eq!(count, 2);
+
+    let mut video = vk::QueueFamilyVideoPropertiesKHR::default();
+    let mut families = [
+        vk::QueueFamilyProperties2::default(),
+        vk::QueueFamilyProperties2::default().push_next(&mut video),
+    ];
+    unsafe {
+        capabilities
+            .get_profile_queue_family_properties(&profile, None, &mut count, Some(&mut families))
+            .unwrap()
+    };
+    assert_eq!(families[0].queue_family_properties.queue_count, 2);
+    assert_eq!(
+        families[1].queue_family_properties.queue_flags,
+        vk::QueueFlags::VIDEO_DECODE_KHR
+    );
+    assert_eq!(
+        video.video_codec_operations,
+        vk::VideoCodecOperationFlagsKHR::DECODE_AV1
+    );
+}
+
+#[test]
+fn instance_support() {
+    let (_, capabilities) = setup_json();
+
+    let supported = unsafe {
+        capabilities
+            .get_instance_profile_support(None, &supported_profile())
+            .unwrap()
+    };
+    assert!(supported);
+
+    let supported = unsafe {
+        capabilities
+            .get_instance_profile_support(None, &profile(UNSUPPORTED_INSTANCE))
+            .unwrap()
+    };
+    assert!(!supported);
+
+    let variants = profile(VARIANTS_INSTANCE_UNSUPPORTED);
+    let (supported, blocks) = unsafe {
+        capabilities
+            .get_instance_profile_variants_support(None, &variants)
+            .unwrap()
+    };
+    assert!(!supported);
+    assert_eq!(blocks.len(), 2, "{blocks:#?}");
+    assert!(blocks_match(
+        blocks[0],
+        vp::BlockProperties::default()
+            .profiles(variants)
+            .block_name(c"instance_unsupported_a")
+            .unwrap()
+    ));
+}
+
+#[test]
+fn device_support() {
+    let (_, capabilities) = setup_json();
+    let (_, instance) = common::setup_instance(&capabilities, supported_profile());
+
+    let supported = unsafe {
+        capabilities
+            .get_physical_device_profile_support(
+                &instance,
+                vk::PhysicalDevice::null(),
+                &supported_profile(),
+            )
+            .unwrap()
+    };
+    assert!(supported);
+
+    let supported = unsafe {
+        capabilities
+            .get_physical_device_profile_support(
+                &instance,
+                vk::PhysicalDevice::null(),
+                &profile(UNSUPPORTED_DEVICE),
+            )
+            .unwrap()
+    };
+    assert!(!supported);
+
+    let variants = profile(VARIANTS_SUPPORTED);
+    let (supported, blocks) = unsafe {
+        capabilities
+            .get_physical_device_profile_variants_support(
+                &instance,
+                vk::PhysicalDevice::null(),
+                &variants,
+            )
+            .unwrap()
+    };
+    assert!(supported);
+    assert_eq!(blocks.len(), 2, "{blocks:#?}");
+    assert!(blocks_match(
+        blocks[1],
+        vp::BlockProperties::default()
+            .profiles(variants)
+            .block_name(c"supported_b")
+            .unwrap()
+    ));
+}
+
+static FORMAT_CHAINED: AtomicBool = AtomicBool::new(false);
+
+unsafe extern "system" fn format_properties2(
+    _: vk::PhysicalDevice,
+    _: vk::Format,
+    p_format_properties: *mut vk::FormatProperties2<'_>,
+) {
+    let properties = &mut *p_format_properties;
+    FORMAT_CHAINED.store(!properties.p_next.is_null(), Ordering::Relaxed);
+    properties.format_properties = vk::FormatProperties {
+        linear_tiling_features: vk::FormatFeatureFlags::TRANSFER_DST,
+        optimal_tiling_features: vk::FormatFeatureFlags::TRANSFER_SRC,
+        buffer_features: vk::FormatFeatureFlags::STORAGE_TEXEL_BUFFER,
+    };
+}
+
+#[test]
+fn device_support_chains_profile_format_structures() {
+    let library = Library::from_json(PROFILES[2]).unwrap();
+    let vulkan_functions = vp::VulkanFunctions {
+        get_physical_device_format_properties2: Some(format_properties2),
+        ..vulkan_functions()
+    };
+    let capabilities_create_info = vp::CapabilitiesCreateInfo::default()
+        .vulkan_functions(&vulkan_functions)
+        .api_version(vk::make_api_version(0, 1, 2, 0));
+    let capabilities = unsafe {
+        library
+            .create_capabilities(&capabilities_create_info)
+            .unwrap()
+    };
+    let (_, instance) = common::setup_instance(&capabilities, supported_profile());
+
+    // The profile only sets `VkFormatProperties`, so no `VkFormatProperties3` is chained.
+    let supported = unsafe {
+        capabilities
+            .get_physical_device_profile_support(
+                &instance,
+                vk::PhysicalDevice::null(),
+                &supported_profile(),
+            )
+            .unwrap()
+    };
+    assert!(supported);
+    assert!(!FORMAT_CHAINED.load(Ordering::Relaxed));
+}
+
+#[test]
+fn missing_functions() {
+    let library = Library::from_json(PROFILES[2]).unwrap();
+    let vulkan_functions = vp::VulkanFunctions {
+        create_device: None,
+        ..vulkan_functions()
+    };
+
+    let capabilities_create_info =
+        vp::CapabilitiesCreateInfo::default().vulkan_functions(&vulkan_functions);
+    let result = unsafe { library.create_capabilities(&capabilities_create_info) };
+    assert_eq!(result.err(), Some(vk::Result::ERROR_INITIALIZATION_FAILED));
+}
+
+#[cfg(feature = "link_vulkan")]
+#[test]
+fn static_functions() {
+    let library = Library::from_json(PROFILES[2]).unwrap();
+
+    let capabilities_create_info = vp::CapabilitiesCreateInfo::default()
+        .flags(vp::CapabilitiesCreateFlags::STATIC)
+        .api_version(vk::make_api_version(0, 1, 2, 0));
+    let capabilities = unsafe {
+        library
+            .create_capabilities(&capabilities_create_info)
+            .unwrap()
+    };
+
+    let profiles = capabilities.profiles().unwrap();
+    assert_eq!(profiles.len(), 1);
+    assert!(
+        unsafe { capabilities.get_instance_profile_support(None, &supported_profile()) }.is_ok()
+    );
+}
+
+#[test]
+fn json_errors() {
+    let video = include_str!("vulkan_profiles/profiles/VP_VPA_test_video.json");
+    let error = Library::from_json(video).err().unwrap();
+    assert!(error.path().starts_with("capabilities."));
+    assert!(error.path().ends_with(".videoProfiles"));
+
+    let mut library = Library::from_json(PROFILES[2]).unwrap();
+    let error = library.add_json(PROFILES[2]).unwrap_err();
+    assert_eq!(error.path(), "profiles.VP_VPA_test_supported");
+
+    let json = PROFILES[2].replace("shaderFloat64", "shaderFloat65");
+    let error = Library::from_json(&json).err().unwrap();
+    assert_eq!(
+        error.path(),
+        "capabilities.baseline.features.VkPhysicalDeviceFeatures.shaderFloat65"
+    );
+
+    let error = Library::from_json("{").err().unwrap();
+    assert_eq!(error.path(), "");
+}
+
+#[test]
+fn missing_referenced_profiles() {
+    let requires = PROFILES[1].replace(
+        "\"description\": \"Profile that requires another profile.\",",
+        "\"description\": \"Profile that requires another profile.\",\n\"fallback\": [\"VP_VPA_test_fallback\"],",
+    );
+    let library = Library::from_json(&requires).unwrap();
+
+    let vulkan_functions = vulkan_functions();
+    let capabilities_create_info =
+        vp::CapabilitiesCreateInfo::default().vulkan_functions(&vulkan_functions);
+    let capabilities = unsafe {
+        library
+            .create_capabilities(&capabilities_create_info)
+            .unwrap()
+    };
+
+    // The missing profiles are listed by name.
+    let required = unsafe {
+        capabilities
+            .get_profile_required_profiles(&profile(REQUIRES))
+            .unwrap()
+    };
+    assert_eq!(required, [profile(SUPPORTED)]);
+
+    let fallbacks = unsafe {
+        capabilities
+            .get_profile_fallbacks(&profile(REQUIRES))
+            .unwrap()
+    };
+    assert_eq!(fallbacks, [profile(FALLBACK)]);
+}
+
+#[test]
+fn profile_graph_cycles_and_missing_profiles() {
+    let fallback = PROFILES[0].replace(
+        "\"description\": \"Profile that the fallback profiles falls back on.\",",
+        "\"description\": \"Profile that the fallback profiles falls back on.\",\n\"fallback\": [\"VP_VPA_test_fallback\"],",
+    );
+    let mut library = Library::from_json(&fallback).unwrap();
+    library.add_json(PROFILES[1]).unwrap();
+
+    let vulkan_functions = vulkan_functions();
+    let capabilities_create_info =
+        vp::CapabilitiesCreateInfo::default().vulkan_functions(&vulkan_functions);
+    let capabilities = unsafe {
+        library
+            .create_capabilities(&capabilities_create_info)
+            .unwrap()
+    };
+
+    let graph = capabilities.profile_graph().unwrap();
+
+    let missing = graph.missing_profiles();
+    assert_eq!(missing.len(), 1);
+    assert_eq!(missing[0].from.profile_name_as_c_str().unwrap(), REQUIRES);
+    assert_eq!(missing[0].to.profile_name_as_c_str().unwrap(), SUPPORTED);
+    assert!(!graph.contains(SUPPORTED));
+
+    let cycle = graph.find_cycle().unwrap();
+    assert_eq!(cycle.len(), 2);
+    assert!(cycle
+        .iter()
+        .any(|profile| profile.profile_name_as_c_str().unwrap() == FALLBACK_FALLBACK));
+    assert!(graph.topological_order().is_none());
+
+    let fallback = graph
+        .profiles()
+        .iter()
+        .find(|profile| profile.profile_name_as_c_str().unwrap() == FALLBACK)
+        .unwrap();
+    let order = unsafe { capabilities.fallback_order(fallback).unwrap() };
+    assert_eq!(order.len(), 2);
+    assert_eq!(graph.fallbacks(FALLBACK), order[1..]);
+}
@@ -0,0 +1,52 @@
+#![cfg(feature = "json")]
+
+use vp_ash::json::Generator;
+
+const SUPPORTED: &str = include_str!("vulkan_profiles/profiles/VP_VPA_test_supported.json");
+const VARIANTS: &str = include_str!("vulkan_profiles/profiles/VP_VPA_test_variants.json");
+
+#[test]
+fn generate() {
+    let mut generator = Generator::new();
+    generator
+        .add_json(VARIANTS)
+        .unwrap()
+        .add_json(SUPPORTED)
+        .unwrap();
+    let module = generator.generate();
+
+    assert!(module.contains(
+        "pub const VP_VPA_TEST_SUPPORTED_NAME: &::core::ffi::CStr = c\"VP_VPA_test_supported\";"
+    ));
+    assert!(module.contains("pub const VP_VPA_TEST_SUPPORTED_SPEC_VERSION: u32 = 2;"));
+    assert!(module.contains("pub const VP_VPA_TEST_SUPPORTED_MIN_API_VERSION: u32 = 0x402000;"));
+    assert!(module.contains(
+        "pub const ALL: &[::vp_ash::vp::ProfileProperties] = &[VP_VPA_TEST_SUPPORTED, \
+        VP_VPA_TEST_VARIANTS_DEVICE_UNSUPPORTED, VP_VPA_TEST_VARIANTS_INSTANCE_UNSUPPORTED, \
+        VP_VPA_TEST_VARIANTS_SUPPORTED];"
+    ));
+    assert!(module.contains(&format!("r#\"{SUPPORTED}\"#")));
+}
+
+#[test]
+fn generate_errors() {
+    let mut generator = Generator::new();
+
+    let video = include_str!("vulkan_profiles/profiles/VP_VPA_test_video.json");
+    let error = generator.add_json(video).err().unwrap();
+    assert_eq!(error.path(), "capabilities.baseline.videoProfiles");
+
+    let json = SUPPORTED.replace("\"VP_VPA_test_supported\"", "\"VP-VPA-test\"");
+    let error = generator.add_json(&json).err().unwrap();
+    assert_eq!(error.path(), "profiles.VP-VPA-test");
+
+    let json = SUPPORTED.replace("\"VP_VPA_test_supported\"", "\"VP_VPA_TEST_SUPPORTED\"");
+    generator.add_json(&json).unwrap();
+    let error = generator.add_json(SUPPORTED).err().unwrap();
+    assert_eq!(error.path(), "profiles.VP_VPA_test_supported");
+    let error = generator.add_json(&json).err().unwrap();
+    assert_eq!(error.message(), "the profile is already in the module");
+
+    // Failed JSON is not added.
+    assert!(!generator.generate().contains("c\"VP_VPA_test_supported\""));
+}
@@ -0,0 +1,41 @@
+#![cfg(feature = "json")]
+
+use ash::vk;
+
+mod profiles {
+    vp_ash::include_profiles!("tests/vulkan_profiles/profiles/VP_VPA_test_*u*e*.json");
+}
+
+#[test]
+fn include_profiles() {
+    let names: Vec<_> = profiles::ALL
+        .iter()
+        .map(|profile| profile.profile_name_as_c_str().unwrap())
+        .collect();
+    assert_eq!(
+        names,
+        [
+            profiles::VP_VPA_TEST_REQUIRES_NAME,
+            profiles::VP_VPA_TEST_SUPPORTED_NAME,
+            profiles::VP_VPA_TEST_UNSUPPORTED_DEVICE_NAME,
+            profiles::VP_VPA_TEST_UNSUPPORTED_INSTANCE_NAME,
+        ]
+    );
+
+    assert_eq!(
+        profiles::VP_VPA_TEST_SUPPORTED_NAME,
+        c"VP_VPA_test_supported"
+    );
+    assert_eq!(profiles::VP_VPA_TEST_SUPPORTED_SPEC_VERSION, 2);
+    assert_eq!(
+        profiles::VP_VPA_TEST_SUPPORTED_MIN_API_VERSION,
+        vk::make_api_version(0, 1, 2, 0)
+    );
+    assert_eq!(
+        profiles::JSON[1],
+        include_str!("vulkan_profiles/profiles/VP_VPA_test_supported.json")
+    );
+
+    let mut library = profiles::library();
+    assert!(library.add_json(profiles::JSON[0]).is_err());
+}
@@ -0,0 +1,110 @@
+#![cfg(feature = "json")]
+
+use ash::vk;
+use vp_ash::json::{
+    schema::{Contributor, Revision, Value},
+    ProfilesFile,
+};
+
+const VARIANTS: &str = include_str!("vulkan_profiles/profiles/VP_VPA_test_variants.json");
+const VIDEO: &str = include_str!("vulkan_profiles/profiles/VP_VPA_test_video.json");
+
+fn error_path(json: &str) -> String {
+    ProfilesFile::from_json(json)
+        .unwrap_err()
+        .path()
+        .to_string()
+}
+
+#[test]
+fn profiles() {
+    let file = ProfilesFile::from_json(VARIANTS).unwrap();
+    assert_eq!(
+        file.schema.as_deref(),
+        Some("https://schema.khronos.org/vulkan/profiles-0.8.2-304.json#")
+    );
+
+    let profile = &file.profiles["VP_VPA_test_variants_supported"];
+    assert_eq!(profile.api_version, vk::make_api_version(0, 1, 2, 0));
+    assert_eq!(
+        profile.capabilities,
+        [
+            vec!["supported_a"],
+            vec![
+                "supported_b",
+                "device_unsupported_a",
+                "instance_unsupported_a"
+            ],
+        ]
+    );
+}
+
+#[test]
+fn metadata() {
+    let file = ProfilesFile::from_json(VIDEO).unwrap();
+    let profile = &file.profiles["VP_VPA_test_video"];
+
+    assert_eq!(profile.version, 1);
+    assert_eq!(profile.label.as_deref(), Some("Video Profile"));
+    assert_eq!(
+        profile.contributors["Trent Shailer"],
+        Contributor {
+            company: Some("N/A".into()),
+            ..Default::default()
+        }
+    );
+    assert_eq!(
+        profile.history,
+        [Revision {
+            revision: 1,
+            date: "2025-02-10".into(),
+            author: "Trent Shailer".into(),
+            comment: "Initial revision".into(),
+        }]
+    );
+}
+
+#[test]
+fn capabilities() {
+    let file = ProfilesFile::from_json(VIDEO).unwrap();
+    let block = &file.capabilities["baseline"];
+
+    assert_eq!(block.extensions["VK_KHR_video_queue"], 1);
+    assert_eq!(block.queue_families_properties.len(), 1);
+    assert_eq!(
+        block.queue_families_properties[0]["VkQueueFamilyProperties"]["queueCount"],
+        Value::Unsigned(1)
+    );
+
+    let video_profile = &block.video_profiles[0];
+    let extent = &video_profile.capabilities["VkVideoCapabilitiesKHR"]["maxCodedExtent"];
+    assert_eq!(extent.as_struct().unwrap()["width"].as_u64(), Some(1920));
+    assert_eq!(
+        video_profile.formats[0]["VkVideoFormatPropertiesKHR"]["format"].as_str(),
+        Some("VK_FORMAT_G8_B8R8_2PLANE_420_UNORM")
+    );
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        error_path(&VARIANTS.replace("\"label\"", "\"lable\"")),
+        "profiles.VP_VPA_test_variants_device_unsupported.lable"
+    );
+    assert_eq!(
+        error_path(&VARIANTS.replace("\"device_unsupported_b\"\n", "\"missing\"\n")),
+        "profiles.VP_VPA_test_variants_device_unsupported.capabilities[1][1]"
+    );
+    assert_eq!(
+        error_path(&VIDEO.replace("\"revision\": 1", "\"revision\": \"1\"")),
+        "profiles.VP_VPA_test_video.history[0].revision"
+    );
+    assert_eq!(
+        error_path(&VIDEO.replace("\"maxDpbSlots\": 17", "\"maxDpbSlots\": null")),
+        "capabilities.baseline.videoProfiles[0].capabilities.VkVideoCapabilitiesKHR.maxDpbSlots"
+    );
+
+    let error = ProfilesFile::from_json(&VIDEO.replace("\"version\": 1,", "")).unwrap_err();
+    assert_eq!(error.path(), "profiles.VP_VPA_test_video");
+    assert_eq!(error.message(), "missing `version`");
+}
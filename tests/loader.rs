@@ -0,0 +1,125 @@
+mod common;
+
+use std::ffi::{c_void, CStr};
+
+use ash::vk;
+use common::{all_expected_profiles_exist, setup, setup_instance, strict_entry, supported_profile};
+use vp_ash::{capabilities::CapabilitiesFn, entry::EntryFn, vp};
+
+#[test]
+fn load_entry() {
+    let (_, entry, _) = setup();
+    let create_capabilities = entry.fp().create_capabilities;
+
+    let entry_fn = EntryFn::load(|name: &CStr| {
+        if name == c"vpCreateCapabilities" {
+            create_capabilities as *const c_void
+        } else {
+            std::ptr::null()
+        }
+    })
+    .unwrap();
+
+    assert_eq!(
+        entry_fn.create_capabilities as *const c_void,
+        create_capabilities as *const c_void
+    );
+}
+
+#[test]
+fn load_missing_entry_point() {
+    let error = EntryFn::load(|_| std::ptr::null()).err().unwrap();
+    assert_eq!(error.name, c"vpCreateCapabilities");
+
+    let error = CapabilitiesFn::load(|_| std::ptr::null()).err().unwrap();
+    assert_eq!(error.name, c"vpDestroyCapabilities");
+}
+
+#[test]
+fn capabilities_from_parts() {
+    let expected_profiles = [supported_profile()];
+
+    let (_, _, capabilities) = setup();
+
+    let capabilities = unsafe {
+        vp_ash::Capabilities::from_parts(capabilities.handle(), capabilities.fp().clone())
+    };
+
+    let profiles = unsafe { capabilities.get_profiles().unwrap() };
+
+    assert!(all_expected_profiles_exist(&expected_profiles, &profiles));
+}
+
+#[test]
+fn capabilities_from_entry() {
+    let expected_profiles = [supported_profile()];
+
+    let entry = ash::Entry::linked();
+    let mut vulkan_functions = vp::VulkanFunctions::default();
+    let create_info = vp::CapabilitiesCreateInfo::from_entry(&mut vulkan_functions, &entry)
+        .flags(vp::CapabilitiesCreateFlags::STATIC)
+        .api_version(vk::make_api_version(0, 1, 2, 0));
+
+    let vp_entry = vp_ash::Entry::linked();
+    let capabilities = unsafe { vp_entry.create_capabilities(&create_info, None).unwrap() };
+
+    let profiles = unsafe { capabilities.get_profiles().unwrap() };
+    assert!(all_expected_profiles_exist(&expected_profiles, &profiles));
+
+    unsafe { capabilities.destroy_capabilities(None) };
+}
+
+#[test]
+fn vulkan_functions_from_entry_are_global() {
+    let entry = strict_entry();
+    let vulkan_functions = vp::VulkanFunctions::from_entry(&entry);
+
+    assert!(vulkan_functions.get_instance_proc_addr.is_some());
+    assert!(vulkan_functions.enumerate_instance_version.is_some());
+    assert!(vulkan_functions
+        .enumerate_instance_extension_properties
+        .is_some());
+    assert!(vulkan_functions.create_instance.is_some());
+
+    assert!(vulkan_functions.get_device_proc_addr.is_none());
+    assert!(vulkan_functions.get_physical_device_features2.is_none());
+    assert!(vulkan_functions.create_device.is_none());
+
+    // Without the instance-level functions the library cannot create the capabilities.
+    let create_info = vp::CapabilitiesCreateInfo::default()
+        .vulkan_functions(&vulkan_functions)
+        .api_version(vk::make_api_version(0, 1, 2, 0));
+
+    let vp_entry = vp_ash::Entry::linked();
+    let result = unsafe { vp_entry.create_capabilities(&create_info, None) };
+    assert_eq!(result.err(), Some(vk::Result::ERROR_INITIALIZATION_FAILED));
+}
+
+#[test]
+fn capabilities_from_instance() {
+    let profile = supported_profile();
+
+    let (_, _, capabilities) = setup();
+    let (_, instance) = setup_instance(&capabilities, profile);
+
+    let entry = strict_entry();
+    let instance = unsafe { ash::Instance::load(entry.static_fn(), instance.handle()) };
+
+    let mut vulkan_functions = vp::VulkanFunctions::default();
+    let create_info = unsafe {
+        vp::CapabilitiesCreateInfo::from_instance(&mut vulkan_functions, &entry, &instance)
+    }
+    .api_version(vk::make_api_version(0, 1, 2, 0));
+
+    let vp_entry = vp_ash::Entry::linked();
+    let capabilities = unsafe { vp_entry.create_capabilities(&create_info, None).unwrap() };
+
+    let supported = unsafe {
+        capabilities
+            .get_physical_device_profile_support(&instance, vk::PhysicalDevice::null(), &profile)
+            .unwrap()
+    };
+    assert!(supported);
+
+    unsafe { capabilities.destroy_capabilities(None) };
+}
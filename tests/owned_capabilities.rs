@@ -0,0 +1,79 @@
+mod common;
+
+use ash::vk;
+use common::{all_expected_profiles_exist, supported_profile, vulkan_functions, SUPPORTED};
+use vp_ash::vp;
+
+fn create_info(vulkan_functions: &vp::VulkanFunctions) -> vp::CapabilitiesCreateInfo<'_> {
+    vp::CapabilitiesCreateInfo::default()
+        .flags(vp::CapabilitiesCreateFlags::STATIC)
+        .vulkan_functions(vulkan_functions)
+        .api_version(vk::make_api_version(0, 1, 2, 0))
+}
+
+#[test]
+fn owned_capabilities() {
+    let expected_profiles = [supported_profile()];
+
+    let vulkan_functions = vulkan_functions();
+    let entry = vp_ash::Entry::linked();
+
+    let capabilities = unsafe {
+        entry
+            .create_owned_capabilities(&create_info(&vulkan_functions), None)
+            .unwrap()
+    };
+
+    let profiles = unsafe { capabilities.get_profiles().unwrap() };
+    assert!(all_expected_profiles_exist(&expected_profiles, &profiles));
+}
+
+#[test]
+fn owned_capabilities_into_inner() {
+    let vulkan_functions = vulkan_functions();
+    let entry = vp_ash::Entry::linked();
+
+    let owned = unsafe {
+        entry
+            .create_owned_capabilities(&create_info(&vulkan_functions), None)
+            .unwrap()
+    };
+    let handle = owned.handle();
+
+    let capabilities = owned.into_inner();
+    assert_eq!(capabilities.handle(), handle);
+
+    unsafe { capabilities.destroy_capabilities(None) };
+}
+
+#[test]
+fn owned_capabilities_profile() {
+    let vulkan_functions = vulkan_functions();
+    let entry = vp_ash::Entry::linked();
+
+    let capabilities = unsafe {
+        entry
+            .create_owned_capabilities(&create_info(&vulkan_functions), None)
+            .unwrap()
+    };
+
+    let profile = capabilities.profile(SUPPORTED).unwrap();
+    assert_eq!(profile.name(), SUPPORTED);
+}
+
+#[test]
+fn owned_capabilities_borrowed() {
+    let vulkan_functions = vulkan_functions();
+    let entry = vp_ash::Entry::linked();
+
+    let owned = unsafe {
+        entry
+            .create_owned_capabilities(&create_info(&vulkan_functions), None)
+            .unwrap()
+    };
+
+    // The APIs that borrow a `Capabilities` accept the owned capabilities.
+    let capabilities: &vp_ash::Capabilities = &owned;
+    assert_eq!(capabilities.handle(), owned.handle());
+    assert!(capabilities.profile_graph().unwrap().contains(SUPPORTED));
+}
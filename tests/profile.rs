@@ -0,0 +1,91 @@
+mod common;
+
+use ash::{ext, vk};
+use common::{setup, FALLBACK, FALLBACK_FALLBACK, REQUIRES, SUPPORTED};
+use vp_ash::profiles;
+
+#[test]
+fn profiles() {
+    let (_, _, capabilities) = setup();
+
+    let profiles = capabilities.profiles().unwrap();
+    assert_eq!(profiles.len(), 10);
+    assert!(profiles.iter().any(|profile| profile.name() == SUPPORTED));
+}
+
+#[test]
+fn profile() {
+    let (_, _, capabilities) = setup();
+
+    let profile = capabilities.profile(SUPPORTED).unwrap();
+    assert_eq!(profile.name(), SUPPORTED);
+    assert_eq!(profile.spec_version(), 2);
+    assert_eq!(profile.api_version(), vk::make_api_version(0, 1, 2, 0));
+    assert!(!profile.has_multiple_variants().unwrap());
+}
+
+#[test]
+fn unknown_profile() {
+    let (_, _, capabilities) = setup();
+
+    assert_eq!(
+        capabilities.profile(c"VP_VPA_test_unknown"),
+        Err(vp_ash::Error::UnknownProfile)
+    );
+}
+
+#[test]
+fn profile_fallbacks() {
+    let (_, _, capabilities) = setup();
+
+    let profile = capabilities.profile(FALLBACK).unwrap();
+    let fallbacks = profile.fallbacks().unwrap();
+
+    assert_eq!(fallbacks.len(), 1);
+    assert_eq!(fallbacks[0].name(), FALLBACK_FALLBACK);
+}
+
+#[test]
+fn profile_required_profiles() {
+    let (_, _, capabilities) = setup();
+
+    let profile = capabilities.profile(REQUIRES).unwrap();
+    let required = profile.required_profiles().unwrap();
+
+    assert_eq!(required.len(), 1);
+    assert_eq!(required[0].name(), SUPPORTED);
+}
+
+#[test]
+fn profile_instance_extensions() {
+    let (_, _, capabilities) = setup();
+
+    let profile = capabilities.profile(SUPPORTED).unwrap();
+    let extensions = profile.instance_extensions(None).unwrap();
+
+    assert_eq!(extensions.len(), 1);
+    assert_eq!(
+        extensions[0].extension_name_as_c_str(),
+        Ok(ext::debug_utils::NAME)
+    );
+}
+
+#[test]
+fn generated_profiles() {
+    let (_, _, capabilities) = setup();
+
+    assert_eq!(profiles::ALL.len(), capabilities.profiles().unwrap().len());
+
+    for properties in profiles::ALL {
+        let profile = capabilities
+            .profile(properties.profile_name_as_c_str().unwrap())
+            .unwrap();
+        assert_eq!(profile.properties(), properties);
+    }
+
+    let profile = capabilities.profile(SUPPORTED).unwrap();
+    assert_eq!(
+        profile.api_version(),
+        profiles::VP_VPA_TEST_SUPPORTED_MIN_API_VERSION
+    );
+}
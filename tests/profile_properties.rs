@@ -237,7 +237,7 @@ fn profile_queue_family_properties_incomplete() {
         )
     };
 
-    assert_eq!(result, Err(vk::Result::INCOMPLETE));
+    assert_eq!(result, Err(vp_ash::Error::Vulkan(vk::Result::INCOMPLETE)));
     assert_eq!(
         properties.queue_family_properties.queue_count,
         expected.queue_count
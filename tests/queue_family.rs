@@ -0,0 +1,54 @@
+mod common;
+
+use ash::vk;
+use common::{setup, SUPPORTED, UNSUPPORTED_INSTANCE};
+
+#[test]
+fn queue_family_requirements() {
+    let (_, _, capabilities) = setup();
+
+    let profile = capabilities.profile(SUPPORTED).unwrap();
+    let requirements = profile.queue_family_requirements(None).unwrap();
+    assert_eq!(requirements.len(), 2);
+
+    assert_eq!(
+        requirements[0].queue_flags,
+        vk::QueueFlags::GRAPHICS | vk::QueueFlags::COMPUTE
+    );
+    assert_eq!(requirements[0].queue_count, 2);
+    assert!(requirements[0].global_priority().is_none());
+
+    assert_eq!(
+        requirements[1].queue_flags,
+        vk::QueueFlags::VIDEO_DECODE_KHR
+    );
+    assert_eq!(requirements[1].queue_count, 1);
+    assert_eq!(
+        requirements[1].video().unwrap().video_codec_operations,
+        vk::VideoCodecOperationFlagsKHR::DECODE_AV1
+    );
+    assert!(requirements[1].video().unwrap().p_next.is_null());
+}
+
+#[test]
+fn queue_family_requirements_without_queue_families() {
+    let (_, _, capabilities) = setup();
+
+    let profile = capabilities.profile(UNSUPPORTED_INSTANCE).unwrap();
+    assert!(profile.queue_family_requirements(None).unwrap().is_empty());
+}
+
+#[test]
+fn queue_family_is_satisfied_by() {
+    let (_, _, capabilities) = setup();
+
+    let profile = capabilities.profile(SUPPORTED).unwrap();
+    let requirement = &profile.queue_family_requirements(None).unwrap()[0];
+
+    let properties = vk::QueueFamilyProperties::default()
+        .queue_flags(vk::QueueFlags::GRAPHICS | vk::QueueFlags::COMPUTE | vk::QueueFlags::TRANSFER)
+        .queue_count(4);
+    assert!(requirement.is_satisfied_by(&properties));
+    assert!(!requirement.is_satisfied_by(&properties.queue_count(1)));
+    assert!(!requirement.is_satisfied_by(&properties.queue_flags(vk::QueueFlags::COMPUTE)));
+}
@@ -0,0 +1,145 @@
+mod common;
+
+use ash::vk;
+use common::{setup, setup_instance, supported_profile, UNSUPPORTED_DEVICE};
+use vp_ash::{
+    selector::{RankedDevice, Reason},
+    vp, DeviceSelector,
+};
+
+fn device(
+    handle: u64,
+    device_type: vk::PhysicalDeviceType,
+    vendor_id: u32,
+    device_local_memory: vk::DeviceSize,
+    reason: Reason,
+) -> RankedDevice {
+    RankedDevice {
+        physical_device: vk::Handle::from_raw(handle),
+        properties: vk::PhysicalDeviceProperties {
+            device_type,
+            vendor_id,
+            ..Default::default()
+        },
+        device_uuid: [handle as u8; vk::UUID_SIZE],
+        device_local_memory,
+        reason,
+    }
+}
+
+fn handles(devices: &[RankedDevice]) -> Vec<u64> {
+    devices
+        .iter()
+        .map(|device| vk::Handle::as_raw(device.physical_device))
+        .collect()
+}
+
+#[test]
+fn rank_by_device_type_and_memory() {
+    let (_, _, capabilities) = setup();
+
+    let devices = vec![
+        device(
+            1,
+            vk::PhysicalDeviceType::INTEGRATED_GPU,
+            0,
+            1 << 30,
+            Reason::SupportsProfiles,
+        ),
+        device(
+            2,
+            vk::PhysicalDeviceType::DISCRETE_GPU,
+            0,
+            1 << 30,
+            Reason::UnsupportedProfiles(vec![supported_profile()]),
+        ),
+        device(
+            3,
+            vk::PhysicalDeviceType::DISCRETE_GPU,
+            0,
+            1 << 30,
+            Reason::SupportsProfiles,
+        ),
+        device(
+            4,
+            vk::PhysicalDeviceType::DISCRETE_GPU,
+            0,
+            1 << 32,
+            Reason::SupportsProfiles,
+        ),
+    ];
+
+    let ranked = DeviceSelector::new(&capabilities).rank(devices.clone());
+    assert_eq!(handles(&ranked), [4, 3, 1, 2]);
+    assert!(!ranked[3].is_accepted());
+
+    let ranked = DeviceSelector::new(&capabilities)
+        .rank_by_memory(false)
+        .device_types(&[vk::PhysicalDeviceType::INTEGRATED_GPU])
+        .rank(devices);
+    assert_eq!(handles(&ranked), [1, 3, 4, 2]);
+}
+
+#[test]
+fn rank_by_vendor_and_uuid() {
+    let (_, _, capabilities) = setup();
+
+    let devices = vec![
+        device(
+            1,
+            vk::PhysicalDeviceType::DISCRETE_GPU,
+            0x1002,
+            0,
+            Reason::SupportsProfiles,
+        ),
+        device(
+            2,
+            vk::PhysicalDeviceType::DISCRETE_GPU,
+            0x10DE,
+            0,
+            Reason::SupportsProfiles,
+        ),
+        device(
+            3,
+            vk::PhysicalDeviceType::CPU,
+            0x10005,
+            0,
+            Reason::RequestedDevice,
+        ),
+    ];
+
+    let ranked = DeviceSelector::new(&capabilities)
+        .preferred_vendors(&[0x10DE])
+        .rank(devices);
+    assert_eq!(handles(&ranked), [3, 2, 1]);
+    assert_eq!(ranked[0].reason, Reason::RequestedDevice);
+}
+
+#[test]
+fn select() {
+    let profile = supported_profile();
+    let unsupported_profile = vp::ProfileProperties::default()
+        .profile_name(UNSUPPORTED_DEVICE)
+        .unwrap();
+
+    let (_, _, capabilities) = setup();
+    let (entry, instance) = setup_instance(&capabilities, profile);
+
+    let devices = unsafe {
+        DeviceSelector::new(&capabilities)
+            .profile(profile)
+            .select(&entry, &instance)
+            .unwrap()
+    };
+    assert!(devices.iter().all(|device| device.is_accepted()));
+
+    let devices = unsafe {
+        DeviceSelector::new(&capabilities)
+            .profiles(&[profile, unsupported_profile])
+            .select(&entry, &instance)
+            .unwrap()
+    };
+    assert!(devices
+        .iter()
+        .all(|device| { device.reason == Reason::UnsupportedProfiles(vec![unsupported_profile]) }));
+}
@@ -0,0 +1,114 @@
+use ash::vk;
+use vp_ash::structure::{self, Chain, Compare, MemberType, Structure, Value};
+
+#[test]
+fn registry() {
+    let registry = structure::registry();
+    for chain in [
+        Chain::Features,
+        Chain::Properties,
+        Chain::Format,
+        Chain::QueueFamily,
+        Chain::VideoProfile,
+        Chain::VideoCapabilities,
+        Chain::VideoFormat,
+    ] {
+        let first = registry.iter().find(|info| info.chain() == chain).unwrap();
+        assert_eq!(first.s_type(), chain.head());
+    }
+
+    for info in registry {
+        assert_eq!(structure::info(info.s_type()).unwrap().name(), info.name());
+        assert!(info.name().starts_with("Vk"));
+    }
+}
+
+#[test]
+fn structure_info() {
+    let info = structure::info(vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_2_FEATURES).unwrap();
+    assert_eq!(info.name(), "VkPhysicalDeviceVulkan12Features");
+    assert_eq!(info.chain(), Chain::Features);
+    assert_eq!(info.size(), size_of::<vk::PhysicalDeviceVulkan12Features>());
+    assert_eq!(
+        info.align(),
+        align_of::<vk::PhysicalDeviceVulkan12Features>()
+    );
+    assert_eq!(info.members()[0].name(), "sampler_mirror_clamp_to_edge");
+    assert_eq!(info.members()[0].ty(), &MemberType::Bool32);
+
+    assert!(structure::info(vk::StructureType::INSTANCE_CREATE_INFO).is_none());
+}
+
+#[test]
+fn member_compare() {
+    let info = structure::info(vk::StructureType::PHYSICAL_DEVICE_PROPERTIES_2).unwrap();
+    let MemberType::Struct(_, properties) = *info.members()[0].ty() else {
+        panic!("expected a structure");
+    };
+    let limits = properties
+        .iter()
+        .find(|member| member.name() == "limits")
+        .unwrap();
+    let MemberType::Struct(_, limits) = *limits.ty() else {
+        panic!("expected a structure");
+    };
+    let compare = |name| {
+        limits
+            .iter()
+            .find(|member| member.name() == name)
+            .unwrap()
+            .compare()
+    };
+
+    assert_eq!(compare("max_image_dimension2_d"), Compare::Max);
+    assert_eq!(compare("min_texel_offset"), Compare::Min);
+    assert_eq!(compare("point_size_range"), Compare::Range);
+    assert_eq!(compare("timestamp_compute_and_graphics"), Compare::Exact);
+    assert_eq!(Compare::Range.element(0), Compare::Min);
+    assert_eq!(Compare::Range.element(1), Compare::Max);
+}
+
+#[test]
+fn zeroed() {
+    let structure = Structure::new(vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_3_FEATURES).unwrap();
+    let features = structure
+        .get::<vk::PhysicalDeviceVulkan13Features>()
+        .unwrap();
+
+    assert_eq!(
+        features.s_type,
+        vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_3_FEATURES
+    );
+    assert!(features.p_next.is_null());
+    assert_eq!(features.synchronization2, vk::FALSE);
+    assert!(structure
+        .get::<vk::PhysicalDeviceVulkan12Features>()
+        .is_none());
+}
+
+#[test]
+fn members() {
+    let structure = Structure::new(vk::StructureType::PHYSICAL_DEVICE_PROPERTIES_2).unwrap();
+
+    let (member, properties) = structure.members().next().unwrap();
+    assert_eq!(member.name(), "properties");
+    let Value::Struct(properties) = properties else {
+        panic!("expected a structure");
+    };
+    assert_eq!(properties.name(), "VkPhysicalDeviceProperties");
+
+    let (_, device_name) = properties
+        .clone()
+        .find(|(member, _)| member.name() == "device_name")
+        .unwrap();
+    assert!(device_name == Value::String(c""));
+
+    let (_, uuid) = properties
+        .clone()
+        .find(|(member, _)| member.name() == "pipeline_cache_uuid")
+        .unwrap();
+    let Value::Array(uuid) = uuid else {
+        panic!("expected an array");
+    };
+    assert_eq!(uuid.count(), vk::UUID_SIZE);
+}
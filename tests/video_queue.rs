@@ -221,3 +221,37 @@ fn get_video_formats() {
         properties[0].image_usage_flags
     );
 }
+
+#[test]
+fn video_unknown_profile() {
+    let profile = vp::ProfileProperties::default()
+        .profile_name(c"VP_VPA_test_unknown")
+        .unwrap();
+
+    let (_, _, capabilities) = setup();
+    let vq_capabilities = vp::video_queue::Capabilities::linked(capabilities.handle());
+
+    let result = unsafe { vq_capabilities.get_profile_video_profiles(&profile, None) };
+    assert_eq!(result, Err(vp_ash::Error::UnknownProfile));
+}
+
+#[cfg(feature = "json")]
+#[test]
+fn video_json_capabilities() {
+    let library = vp_ash::json::Library::from_json(include_str!(
+        "vulkan_profiles/profiles/VP_VPA_test_supported.json"
+    ))
+    .unwrap();
+    let vulkan_functions = common::vulkan_functions();
+    let capabilities_create_info =
+        vp::CapabilitiesCreateInfo::default().vulkan_functions(&vulkan_functions);
+    let capabilities = unsafe {
+        library
+            .create_capabilities(&capabilities_create_info)
+            .unwrap()
+    };
+
+    let entry = vp_ash::Entry::linked();
+    let result = vp::video_queue::Capabilities::new(&entry, &capabilities);
+    assert_eq!(result.err(), Some(vp_ash::Error::UnsupportedBackend));
+}
@@ -0,0 +1,249 @@
+//! Generation of a Rust profiles module from profile JSON, without the Python generator.
+//!
+//! The generated module has the constants the build script generates for the linked library,
+//! like `VP_VPA_EXAMPLES_COMPUTE` and `VP_VPA_EXAMPLES_COMPUTE_MIN_API_VERSION`, and a
+//! `library()` function that returns the `vp_ash::json::Library` of the embedded JSON. The JSON is embedded
+//! as strings and checked when the module is generated, `library()` parses it on its first call
+//! and returns clones of the cached library afterwards, so the module requires `std`.
+//!
+//! In a build script, with `vp-ash` as a build dependency with the `json` and `std` features:
+//!
+//! ```no_run
+//! // build.rs
+//! vp_ash::json::generate::build_profiles("profiles");
+//! ```
+//!
+//! ```ignore
+//! // src/lib.rs
+//! pub mod profiles {
+//!     include!(concat!(env!("OUT_DIR"), "/profiles.rs"));
+//! }
+//!
+//! let library = profiles::library();
+//! ```
+//!
+//! The C++ library is not generated, the profiles are implemented by the JSON backend.
+
+use core::fmt::Write;
+
+use alloc::{format, string::String, vec::Vec};
+
+use ash::vk;
+
+use super::{parse, ParseError, ProfilesFile};
+
+/// Generates a Rust profiles module from profile JSON.
+#[derive(Clone, Default)]
+pub struct Generator {
+    sources: Vec<Source>,
+    files: Vec<ProfilesFile>,
+}
+
+impl Generator {
+    /// Creates a generator without profiles.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the profiles of `json` to the module.
+    ///
+    /// Returns an error like `vp_ash::json::Library::add_json`, if a profile name is not a valid
+    /// Rust identifier, and if two profile names are the same once uppercased.
+    pub fn add_json(&mut self, json: &str) -> Result<&mut Self, ParseError> {
+        self.add(json, Source::Embedded(json.into()))
+    }
+
+    /// Adds the profiles of `json`, read from `path`, to the module.
+    ///
+    /// The module includes `path` with `include_str!` instead of embedding `json`, so the crate
+    /// including the module is rebuilt when the file changes. `path` should be absolute.
+    pub fn add_included_json(&mut self, json: &str, path: &str) -> Result<&mut Self, ParseError> {
+        self.add(json, Source::Included(path.into()))
+    }
+
+    fn add(&mut self, json: &str, source: Source) -> Result<&mut Self, ParseError> {
+        let file = ProfilesFile::from_json(json)?;
+
+        for name in file.profiles.keys() {
+            let is_identifier = name.starts_with(|c: char| c.is_ascii_alphabetic())
+                && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+            if !is_identifier {
+                return Err(ParseError::new(
+                    &format!("profiles.{name}"),
+                    "the profile name is not a valid identifier",
+                ));
+            }
+
+            // The constants are named after the uppercased name, `VP_a` and `VP_A` would collide.
+            let existing = self.files.iter().flat_map(|file| file.profiles.keys());
+            let collision = existing
+                .chain(file.profiles.keys().take_while(|&other| other != name))
+                .find(|other| other.eq_ignore_ascii_case(name));
+            if let Some(other) = collision {
+                let message = match other == name {
+                    true => String::from("the profile is already in the module"),
+                    false => format!("the profile name collides with `{other}` once uppercased"),
+                };
+                return Err(ParseError::new(&format!("profiles.{name}"), message));
+            }
+        }
+
+        parse::parse_profiles(&file)?;
+        self.sources.push(source);
+        self.files.push(file);
+
+        Ok(self)
+    }
+
+    /// Returns the source of the module.
+    pub fn generate(&self) -> String {
+        let mut profiles: Vec<_> = self.files.iter().flat_map(|file| &file.profiles).collect();
+        profiles.sort_by_key(|&(name, _)| name);
+
+        let mut module = String::from(
+            "// Generated by `vp_ash::json::generate` from profile JSON, do not edit.\n\n",
+        );
+        let mut all = Vec::new();
+        for (name, profile) in profiles {
+            let prefix = name.to_ascii_uppercase();
+            let version = profile.api_version;
+            let (major, minor, patch) = (
+                vk::api_version_major(version),
+                vk::api_version_minor(version),
+                vk::api_version_patch(version),
+            );
+
+            writeln!(
+                module,
+                "/// The `{name}` profile.\n\
+                pub const {prefix}: ::vp_ash::vp::ProfileProperties = \
+                ::vp_ash::vp::ProfileProperties::new({prefix}_NAME, {prefix}_SPEC_VERSION);\n\
+                /// The name of the `{name}` profile.\n\
+                pub const {prefix}_NAME: &::core::ffi::CStr = c\"{name}\";\n\
+                /// The specification version of the `{name}` profile.\n\
+                pub const {prefix}_SPEC_VERSION: u32 = {};\n\
+                /// The minimum Vulkan API version of the `{name}` profile, \
+                {major}.{minor}.{patch}.\n\
+                pub const {prefix}_MIN_API_VERSION: u32 = {version:#x};\n",
+                profile.version,
+            )
+            .unwrap();
+            all.push(prefix);
+        }
+
+        writeln!(
+            module,
+            "/// Every profile in the module.\n\
+            pub const ALL: &[::vp_ash::vp::ProfileProperties] = &[{}];\n",
+            all.join(", ")
+        )
+        .unwrap();
+
+        module.push_str("/// The profile JSON of the module.\npub const JSON: &[&str] = &[\n");
+        for source in &self.sources {
+            match source {
+                Source::Embedded(json) => writeln!(module, "    {},", raw_string(json)),
+                Source::Included(path) => writeln!(module, "    include_str!({path:?}),"),
+            }
+            .unwrap();
+        }
+        module.push_str(
+            "];\n\n\
+            /// Returns a library with the profiles of the module.\n\
+            ///\n\
+            /// The JSON is parsed on the first call, later calls clone the cached library.\n\
+            pub fn library() -> ::vp_ash::json::Library {\n    \
+                static LIBRARY: ::std::sync::OnceLock<::vp_ash::json::Library> =\n        \
+                    ::std::sync::OnceLock::new();\n\n    \
+                LIBRARY\n        \
+                    .get_or_init(|| {\n            \
+                        let mut library = ::vp_ash::json::Library::new();\n            \
+                        for json in JSON {\n                \
+                            library\n                    \
+                                .add_json(json)\n                    \
+                                .expect(\"the profile JSON was checked when it was generated\");\n            \
+                        }\n\n            \
+                        library\n        \
+                    })\n        \
+                    .clone()\n\
+            }\n",
+        );
+
+        module
+    }
+}
+
+/// Where the module gets the JSON of a file from.
+#[derive(Clone)]
+enum Source {
+    Embedded(String),
+    Included(String),
+}
+
+/// Returns `string` as a raw string literal.
+fn raw_string(string: &str) -> String {
+    // Use one more `#` than the longest run following a quote.
+    let hashes = string
+        .split('"')
+        .skip(1)
+        .map(|part| part.len() - part.trim_start_matches('#').len())
+        .max()
+        .map_or(0, |len| len + 1);
+    let hashes = "#".repeat(hashes);
+
+    format!("r{hashes}\"{string}\"{hashes}")
+}
+
+/// Generates the profiles module of the profile JSON at `input` for a build script.
+///
+/// `input` is a JSON file or a directory of JSON files. The module is written to
+/// `OUT_DIR/profiles.rs` and cargo is told to rerun the build script when the JSON changes.
+///
+/// # Panics
+/// Panics with the location of the error if the JSON cannot be read or is invalid.
+#[cfg(feature = "std")]
+pub fn build_profiles(input: impl AsRef<std::path::Path>) {
+    use std::{fs, path::Path};
+
+    fn read_error(path: &Path, err: std::io::Error) -> ! {
+        panic!("failed to read `{}`: {err}", path.display())
+    }
+
+    let input = input.as_ref();
+
+    std::println!("cargo:rerun-if-changed={}", input.display());
+
+    let mut paths = Vec::new();
+    if input.is_dir() {
+        for entry in fs::read_dir(input).unwrap_or_else(|err| read_error(input, err)) {
+            let path = entry.unwrap_or_else(|err| read_error(input, err)).path();
+            if path
+                .extension()
+                .is_some_and(|extension| extension == "json")
+            {
+                paths.push(path);
+            }
+        }
+        // Keep the module stable across file systems.
+        paths.sort();
+    } else {
+        paths.push(input.to_path_buf());
+    }
+
+    let mut generator = Generator::new();
+    for path in &paths {
+        std::println!("cargo:rerun-if-changed={}", path.display());
+
+        let json = fs::read_to_string(path).unwrap_or_else(|err| read_error(path, err));
+        if let Err(err) = generator.add_json(&json) {
+            panic!("invalid profile JSON `{}`: {err}", path.display());
+        }
+    }
+
+    let out_dir = std::env::var_os("OUT_DIR").expect("`OUT_DIR` is set by cargo");
+    fs::write(
+        Path::new(&out_dir).join("profiles.rs"),
+        generator.generate(),
+    )
+    .expect("failed to write the generated profiles");
+}
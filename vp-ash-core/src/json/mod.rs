@@ -0,0 +1,51 @@
+//! Profile JSON, parsed into the data model of [`schema`] and checked against the structure
+//! registry.
+//!
+//! `vp-ash` re-exports this module as `vp_ash::json`, with the `Library` that implements the
+//! Vulkan Profiles API from the checked profiles.
+
+use core::fmt;
+
+use alloc::string::String;
+
+pub use generate::Generator;
+pub use schema::ProfilesFile;
+
+pub mod generate;
+#[doc(hidden)]
+pub mod parse;
+pub mod schema;
+
+/// Profile JSON could not be parsed or added to a `Library`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    path: String,
+    message: String,
+}
+
+impl ParseError {
+    /// Returns the location of the error in the JSON, like
+    /// `capabilities.baseline.features.VkPhysicalDeviceFeatures.shaderFloat64`.
+    ///
+    /// The path is empty for errors in the JSON syntax, their message contains the line and
+    /// column instead.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Returns the description of the error.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.path.is_empty() {
+            true => f.write_str(&self.message),
+            false => write!(f, "`{}`: {}", self.path, self.message),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
@@ -0,0 +1,646 @@
+//! Conversion of the [`ProfilesFile`] into the profile descriptions used by the backend.
+//!
+//! The descriptions follow the tables of the generated library: each profile lists its
+//! capabilities, each capability lists its variants, and each variant holds the requirements of
+//! one capabilities block of the JSON.
+
+use core::fmt;
+
+use alloc::{
+    collections::BTreeMap,
+    ffi::CString,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use ash::vk;
+
+use super::{
+    schema::{CapabilitiesBlock, Members, ProfileDefinition, ProfilesFile, Structures, Value},
+    ParseError,
+};
+use crate::structure::{self, Chain, Compare, Member, MemberType, StructureInfo, ValueNames};
+
+/// The size of the profile and block names with their nul, `VP_MAX_PROFILE_NAME_SIZE`.
+const MAX_NAME_SIZE: usize = 256;
+
+/// A profile of the JSON, like `VpProfileDesc` of the generated library.
+#[derive(Clone)]
+pub struct ProfileDesc {
+    pub name: CString,
+    pub spec_version: u32,
+    pub min_api_version: u32,
+    /// The names of the required profiles, resolved when queried.
+    pub required: Vec<String>,
+    /// The names of the fallback profiles, resolved when queried.
+    pub fallbacks: Vec<String>,
+    /// The variants of each required capability.
+    pub capabilities: Vec<Vec<VariantDesc>>,
+}
+
+/// A capabilities block of the JSON, like `VpVariantDesc` of the generated library.
+#[derive(Clone)]
+pub struct VariantDesc {
+    pub block_name: CString,
+    pub instance_extensions: Vec<vk::ExtensionProperties>,
+    pub device_extensions: Vec<vk::ExtensionProperties>,
+    pub features: Vec<Requirement>,
+    pub properties: Vec<Requirement>,
+    pub formats: Vec<FormatDesc>,
+    pub queue_families: Vec<Vec<Requirement>>,
+}
+
+#[derive(Clone)]
+pub struct FormatDesc {
+    pub format: vk::Format,
+    pub requirements: Vec<Requirement>,
+}
+
+/// The values a profile sets in one structure.
+#[derive(Clone)]
+pub struct Requirement {
+    pub info: &'static StructureInfo,
+    pub values: Vec<RequiredValue>,
+}
+
+impl Requirement {
+    /// Writes the values into every structure of the requirement in the chain at `p`.
+    pub unsafe fn fill(&self, mut p: *mut vk::BaseOutStructure<'_>) {
+        while !p.is_null() {
+            if (*p).s_type == self.info.s_type() {
+                for value in &self.values {
+                    value.write(p.cast());
+                }
+            }
+            p = (*p).p_next;
+        }
+    }
+
+    /// Returns whether every structure of the requirement in the chain at `p` satisfies the
+    /// values.
+    pub unsafe fn matches(&self, mut p: *const vk::BaseOutStructure<'_>) -> bool {
+        while !p.is_null() {
+            if (*p).s_type == self.info.s_type()
+                && !self.values.iter().all(|value| value.matches(p.cast()))
+            {
+                return false;
+            }
+            p = (*p).p_next;
+        }
+        true
+    }
+}
+
+/// A value set by the profile at an offset of a structure.
+#[derive(Clone, Copy)]
+pub struct RequiredValue {
+    pub offset: usize,
+    pub scalar: Scalar,
+    pub compare: Compare,
+}
+
+impl RequiredValue {
+    /// Writes the value into the structure at `p`, flags are added to the existing flags.
+    unsafe fn write(&self, p: *mut u8) {
+        let p = p.add(self.offset);
+        match self.scalar {
+            Scalar::True => p.cast::<vk::Bool32>().write_unaligned(vk::TRUE),
+            Scalar::U8(value) => p.write(value),
+            Scalar::U16(value) => p.cast::<u16>().write_unaligned(value),
+            Scalar::U32(value) => p.cast::<u32>().write_unaligned(value),
+            Scalar::I32(value) => p.cast::<i32>().write_unaligned(value),
+            Scalar::F32(value) => p.cast::<f32>().write_unaligned(value),
+            Scalar::U64(value) => p.cast::<u64>().write_unaligned(value),
+            Scalar::I64(value) => p.cast::<i64>().write_unaligned(value),
+            Scalar::Usize(value) => p.cast::<usize>().write_unaligned(value),
+            Scalar::Flags(value) => {
+                let p = p.cast::<u32>();
+                p.write_unaligned(p.read_unaligned() | value);
+            }
+            Scalar::Flags64(value) => {
+                let p = p.cast::<u64>();
+                p.write_unaligned(p.read_unaligned() | value);
+            }
+            Scalar::Enum(value) => p.cast::<i32>().write_unaligned(value),
+        }
+    }
+
+    /// Returns whether the structure at `p` satisfies the value.
+    unsafe fn matches(&self, p: *const u8) -> bool {
+        let p = p.add(self.offset);
+        match self.scalar {
+            Scalar::True => p.cast::<vk::Bool32>().read_unaligned() == vk::TRUE,
+            Scalar::U8(value) => self.compare.test(p.read(), value),
+            Scalar::U16(value) => self.compare.test(p.cast::<u16>().read_unaligned(), value),
+            Scalar::U32(value) => self.compare.test(p.cast::<u32>().read_unaligned(), value),
+            Scalar::I32(value) => self.compare.test(p.cast::<i32>().read_unaligned(), value),
+            Scalar::F32(value) => self.compare.test(p.cast::<f32>().read_unaligned(), value),
+            Scalar::U64(value) => self.compare.test(p.cast::<u64>().read_unaligned(), value),
+            Scalar::I64(value) => self.compare.test(p.cast::<i64>().read_unaligned(), value),
+            Scalar::Usize(value) => self.compare.test(p.cast::<usize>().read_unaligned(), value),
+            Scalar::Flags(value) => p.cast::<u32>().read_unaligned() & value == value,
+            Scalar::Flags64(value) => p.cast::<u64>().read_unaligned() & value == value,
+            Scalar::Enum(value) => p.cast::<i32>().read_unaligned() == value,
+        }
+    }
+}
+
+/// A scalar value set by the profile.
+#[derive(Clone, Copy)]
+pub enum Scalar {
+    /// A `VkBool32` that must be `VK_TRUE`, profiles never require a feature to be disabled.
+    True,
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    I32(i32),
+    F32(f32),
+    U64(u64),
+    I64(i64),
+    Usize(usize),
+    /// Flags that must all be set.
+    Flags(u32),
+    Flags64(u64),
+    Enum(i32),
+}
+
+impl VariantDesc {
+    /// Returns the structure types of the requirements in `chain`, the `*2` structures of the
+    /// embedded structures are listed for them.
+    pub fn structure_types(&self, chain: Chain) -> Vec<vk::StructureType> {
+        let mut s_types = Vec::new();
+        let mut push = |s_type| {
+            if !s_types.contains(&s_type) {
+                s_types.push(s_type);
+            }
+        };
+
+        match chain {
+            Chain::Features => self.features.iter().for_each(|r| push(r.info.s_type())),
+            Chain::Properties => self.properties.iter().for_each(|r| push(r.info.s_type())),
+            Chain::QueueFamily => self
+                .queue_families
+                .iter()
+                .flatten()
+                .for_each(|r| push(r.info.s_type())),
+            // The library always chains both format structures.
+            Chain::Format if !self.formats.is_empty() => {
+                push(vk::StructureType::FORMAT_PROPERTIES_2);
+                push(vk::StructureType::FORMAT_PROPERTIES_3);
+            }
+            _ => {}
+        }
+
+        s_types
+    }
+}
+
+/// Converts the profiles of a profile JSON document, the structures of the capabilities blocks
+/// are checked against the registry.
+pub fn parse_profiles(file: &ProfilesFile) -> Result<Vec<ProfileDesc>, ParseError> {
+    let blocks = file
+        .capabilities
+        .iter()
+        .map(|(name, block)| {
+            let variant = parse_variant(name, block, &format!("capabilities.{name}"))?;
+            Ok((name.as_str(), variant))
+        })
+        .collect::<Result<BTreeMap<_, _>, ParseError>>()?;
+
+    file.profiles
+        .iter()
+        .map(|(name, profile)| parse_profile(name, profile, &blocks, &format!("profiles.{name}")))
+        .collect()
+}
+
+fn parse_profile(
+    name: &str,
+    profile: &ProfileDefinition,
+    blocks: &BTreeMap<&str, VariantDesc>,
+    path: &str,
+) -> Result<ProfileDesc, ParseError> {
+    let name = CString::new(name).map_err(|err| ParseError::new(path, err))?;
+    if name.as_bytes_with_nul().len() > MAX_NAME_SIZE {
+        return Err(ParseError::new(path, "the profile name is too long"));
+    }
+
+    // The blocks of the capabilities were checked when the file was parsed.
+    let capabilities = profile
+        .capabilities
+        .iter()
+        .map(|variants| {
+            variants
+                .iter()
+                .filter_map(|block| blocks.get(block.as_str()).cloned())
+                .collect()
+        })
+        .collect();
+
+    Ok(ProfileDesc {
+        name,
+        spec_version: profile.version,
+        min_api_version: profile.api_version,
+        required: profile.profiles.clone(),
+        fallbacks: profile.fallback.clone(),
+        capabilities,
+    })
+}
+
+fn parse_variant(
+    name: &str,
+    block: &CapabilitiesBlock,
+    path: &str,
+) -> Result<VariantDesc, ParseError> {
+    let mut variant = VariantDesc {
+        block_name: CString::new(name).map_err(|err| ParseError::new(path, err))?,
+        instance_extensions: Vec::new(),
+        device_extensions: Vec::new(),
+        features: parse_requirements(
+            &block.features,
+            Chain::Features,
+            &format!("{path}.features"),
+        )?,
+        properties: parse_requirements(
+            &block.properties,
+            Chain::Properties,
+            &format!("{path}.properties"),
+        )?,
+        formats: Vec::new(),
+        queue_families: Vec::new(),
+    };
+    if variant.block_name.as_bytes_with_nul().len() > MAX_NAME_SIZE {
+        return Err(ParseError::new(path, "the block name is too long"));
+    }
+
+    for (name, &spec_version) in &block.extensions {
+        let path = format!("{path}.extensions.{name}");
+        let extension = parse_extension(name, spec_version, &path)?;
+        if INSTANCE_EXTENSIONS.contains(&name.as_str()) {
+            variant.instance_extensions.push(extension);
+        } else {
+            variant.device_extensions.push(extension);
+        }
+    }
+
+    for (name, requirements) in &block.formats {
+        let path = format!("{path}.formats.{name}");
+        let format = structure::enum_names("Format")
+            .and_then(|names| enum_value(names, name))
+            .map(vk::Format::from_raw)
+            .ok_or_else(|| ParseError::new(&path, format!("unknown format `{name}`")))?;
+
+        variant.formats.push(FormatDesc {
+            format,
+            requirements: parse_requirements(requirements, Chain::Format, &path)?,
+        });
+    }
+
+    for (index, family) in block.queue_families_properties.iter().enumerate() {
+        let path = format!("{path}.queueFamiliesProperties[{index}]");
+        variant
+            .queue_families
+            .push(parse_requirements(family, Chain::QueueFamily, &path)?);
+    }
+
+    if !block.video_profiles.is_empty() {
+        return Err(ParseError::new(
+            &format!("{path}.videoProfiles"),
+            "video profiles are not supported by the JSON backend",
+        ));
+    }
+
+    Ok(variant)
+}
+
+fn parse_extension(
+    name: &str,
+    spec_version: u32,
+    path: &str,
+) -> Result<vk::ExtensionProperties, ParseError> {
+    let c_name = CString::new(name).map_err(|err| ParseError::new(path, err))?;
+
+    Ok(vk::ExtensionProperties::default()
+        .extension_name(&c_name)
+        .map_err(|err| ParseError::new(path, err))?
+        .spec_version(spec_version))
+}
+
+/// Converts the structures of a capability like the `features` of a capabilities block.
+fn parse_requirements(
+    structs: &Structures,
+    chain: Chain,
+    path: &str,
+) -> Result<Vec<Requirement>, ParseError> {
+    structs
+        .iter()
+        .map(|(name, object)| {
+            let path = format!("{path}.{name}");
+            let (info, base, members) = find_structure(name, chain)
+                .ok_or_else(|| ParseError::new(&path, format!("unsupported structure `{name}`")))?;
+
+            let mut values = Vec::new();
+            parse_members(members, base, Compare::Exact, object, &path, &mut values)?;
+
+            Ok(Requirement { info, values })
+        })
+        .collect()
+}
+
+/// Returns the structure named `name` in `chain`, with the offset and the members of the
+/// structure the JSON sets, ignoring the `KHR` or `EXT` suffix of promoted structures.
+///
+/// The structures of Vulkan 1.0 are embedded in the head of their chain, like
+/// `VkPhysicalDeviceFeatures` in `VkPhysicalDeviceFeatures2`.
+fn find_structure(
+    name: &str,
+    chain: Chain,
+) -> Option<(&'static StructureInfo, usize, &'static [Member])> {
+    let infos = || {
+        structure::registry()
+            .iter()
+            .filter(move |info| info.chain() == chain)
+    };
+    let info = infos()
+        .find(|info| info.name() == name)
+        .or_else(|| infos().find(|info| strip_vendor(info.name()) == strip_vendor(name)));
+    if let Some(info) = info {
+        return Some((info, 0, info.members()));
+    }
+
+    let head = structure::info(chain.head())?;
+    head.members().iter().find_map(|member| match *member.ty() {
+        MemberType::Struct(embedded, members) if embedded == name => {
+            Some((head, member.offset(), members))
+        }
+        _ => None,
+    })
+}
+
+fn strip_vendor(name: &str) -> &str {
+    name.strip_suffix("KHR")
+        .or_else(|| name.strip_suffix("EXT"))
+        .unwrap_or(name)
+}
+
+/// Returns the member spelled `name` in the JSON, like `shaderFloat64` for `shader_float64`.
+fn find_member(members: &'static [Member], name: &str) -> Option<&'static Member> {
+    members
+        .iter()
+        .find(|member| normalize(member.name()).eq(normalize(name)))
+}
+
+fn normalize(name: &str) -> impl Iterator<Item = char> + '_ {
+    name.chars()
+        .filter(|&c| c != '_')
+        .map(|c| c.to_ascii_lowercase())
+}
+
+/// Returns the bit of a flag named like `VK_SHADER_STAGE_COMPUTE_BIT`.
+fn flag_bit(names: &ValueNames<u64>, name: &str) -> Option<u64> {
+    let name = name.strip_prefix(names.prefix)?.replace("_BIT", "");
+    lookup(names.known, &name)
+}
+
+/// Returns the value of an enum named like `VK_FORMAT_R8G8B8A8_UNORM`.
+fn enum_value(names: &ValueNames<i32>, name: &str) -> Option<i32> {
+    lookup(names.known, name.strip_prefix(names.prefix)?)
+}
+
+/// Finds `name` in `known`, ash prefixes names that start with a digit with `TYPE_` and only
+/// spells the promoted name of extension aliases.
+fn lookup<T: Copy>(known: &[(T, &'static str)], name: &str) -> Option<T> {
+    let find = |name: &str| {
+        known
+            .iter()
+            .find(|(_, known)| *known == name)
+            .map(|&(value, _)| value)
+    };
+
+    let name = if name.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("TYPE_{name}")
+    } else {
+        name.into()
+    };
+
+    find(&name).or_else(|| {
+        let promoted = name
+            .strip_suffix("_KHR")
+            .or_else(|| name.strip_suffix("_EXT"))?;
+        find(promoted)
+    })
+}
+
+fn parse_members(
+    members: &'static [Member],
+    base: usize,
+    parent_compare: Compare,
+    object: &Members,
+    path: &str,
+    values: &mut Vec<RequiredValue>,
+) -> Result<(), ParseError> {
+    for (name, value) in object {
+        let path = format!("{path}.{name}");
+        let member = find_member(members, name)
+            .ok_or_else(|| ParseError::new(&path, format!("unknown member `{name}`")))?;
+
+        // Nested limits, like `minImageTransferGranularity`, compare like their parent.
+        let compare = match member.compare() {
+            Compare::Exact => parent_compare,
+            compare => compare,
+        };
+
+        parse_member(
+            member.ty(),
+            base + member.offset(),
+            compare,
+            value,
+            &path,
+            values,
+        )?;
+    }
+
+    Ok(())
+}
+
+fn parse_member(
+    ty: &MemberType,
+    offset: usize,
+    compare: Compare,
+    value: &Value,
+    path: &str,
+    values: &mut Vec<RequiredValue>,
+) -> Result<(), ParseError> {
+    let mut push = |scalar| {
+        values.push(RequiredValue {
+            offset,
+            scalar,
+            compare,
+        })
+    };
+
+    match *ty {
+        MemberType::Bool32 => match value {
+            Value::Bool(true) => push(Scalar::True),
+            Value::Bool(false) => {}
+            _ => return Err(ParseError::new(path, "expected a boolean")),
+        },
+        MemberType::U8 => push(Scalar::U8(as_int(value, path)?)),
+        MemberType::U16 => push(Scalar::U16(as_int(value, path)?)),
+        MemberType::U32 => push(Scalar::U32(as_int(value, path)?)),
+        MemberType::I32 => push(Scalar::I32(as_int(value, path)?)),
+        MemberType::U64 => push(Scalar::U64(as_int(value, path)?)),
+        MemberType::I64 => push(Scalar::I64(as_int(value, path)?)),
+        MemberType::Usize => push(Scalar::Usize(as_int(value, path)?)),
+        MemberType::F32 => match value.as_f64() {
+            Some(float) => push(Scalar::F32(float as f32)),
+            None => return Err(ParseError::new(path, "expected a number")),
+        },
+        MemberType::Flags(name) | MemberType::Flags64(name) => {
+            let names = structure::flag_names(name)
+                .ok_or_else(|| ParseError::new(path, format!("unsupported flags `{name}`")))?;
+
+            let mut bits = 0;
+            for (index, flag) in as_array(value, path)?.iter().enumerate() {
+                let path = format!("{path}[{index}]");
+                let flag = as_str(flag, &path)?;
+                bits |= flag_bit(names, flag)
+                    .ok_or_else(|| ParseError::new(&path, format!("unknown flag `{flag}`")))?;
+            }
+
+            match *ty {
+                MemberType::Flags(_) => push(Scalar::Flags(bits as u32)),
+                _ => push(Scalar::Flags64(bits)),
+            }
+        }
+        MemberType::Enum(name) => {
+            let names = structure::enum_names(name)
+                .ok_or_else(|| ParseError::new(path, format!("unsupported enum `{name}`")))?;
+
+            let name = as_str(value, path)?;
+            let value = enum_value(names, name)
+                .ok_or_else(|| ParseError::new(path, format!("unknown value `{name}`")))?;
+            push(Scalar::Enum(value));
+        }
+        MemberType::Chars(len) => {
+            let string = as_str(value, path)?;
+            if string.len() >= len {
+                return Err(ParseError::new(
+                    path,
+                    format!("longer than {} bytes", len - 1),
+                ));
+            }
+
+            for (index, byte) in string.bytes().chain([0]).enumerate() {
+                values.push(RequiredValue {
+                    offset: offset + index,
+                    scalar: Scalar::U8(byte),
+                    compare: Compare::Exact,
+                });
+            }
+        }
+        MemberType::Array(element, len) => {
+            let elements = as_array(value, path)?;
+            if elements.len() > len {
+                return Err(ParseError::new(path, format!("more than {len} elements")));
+            }
+
+            for (index, value) in elements.iter().enumerate() {
+                parse_member(
+                    element,
+                    offset + index * element.size(),
+                    compare.element(index),
+                    value,
+                    &format!("{path}[{index}]"),
+                    values,
+                )?;
+            }
+        }
+        MemberType::Struct(_, members) => {
+            let object = value
+                .as_struct()
+                .ok_or_else(|| ParseError::new(path, "expected an object"))?;
+            parse_members(members, offset, compare, object, path, values)?;
+        }
+        MemberType::Pointer => return Err(ParseError::new(path, "unsupported member")),
+    }
+
+    Ok(())
+}
+
+fn as_array<'a>(value: &'a Value, path: &str) -> Result<&'a [Value], ParseError> {
+    value
+        .as_array()
+        .ok_or_else(|| ParseError::new(path, "expected an array"))
+}
+
+fn as_str<'a>(value: &'a Value, path: &str) -> Result<&'a str, ParseError> {
+    value
+        .as_str()
+        .ok_or_else(|| ParseError::new(path, "expected a string"))
+}
+
+/// Returns the integer `value` if it fits in `T`.
+fn as_int<T: TryFrom<i64> + TryFrom<u64>>(value: &Value, path: &str) -> Result<T, ParseError> {
+    let int = match *value {
+        Value::Unsigned(int) => T::try_from(int).ok(),
+        Value::Signed(int) => T::try_from(int).ok(),
+        _ => return Err(ParseError::new(path, "expected an integer")),
+    };
+
+    int.ok_or_else(|| ParseError::new(path, "the integer is out of range"))
+}
+
+impl ParseError {
+    #[doc(hidden)]
+    pub fn new(path: &str, message: impl fmt::Display) -> Self {
+        Self {
+            path: path.into(),
+            message: message.to_string(),
+        }
+    }
+}
+
+/// The instance extensions, any other extension in a profile is a device extension.
+const INSTANCE_EXTENSIONS: &[&str] = &[
+    "VK_KHR_surface",
+    "VK_KHR_display",
+    "VK_KHR_xlib_surface",
+    "VK_KHR_xcb_surface",
+    "VK_KHR_wayland_surface",
+    "VK_KHR_android_surface",
+    "VK_KHR_win32_surface",
+    "VK_EXT_debug_report",
+    "VK_GGP_stream_descriptor_surface",
+    "VK_NV_external_memory_capabilities",
+    "VK_KHR_get_physical_device_properties2",
+    "VK_EXT_validation_flags",
+    "VK_NN_vi_surface",
+    "VK_KHR_device_group_creation",
+    "VK_KHR_external_memory_capabilities",
+    "VK_KHR_external_semaphore_capabilities",
+    "VK_EXT_direct_mode_display",
+    "VK_EXT_acquire_xlib_display",
+    "VK_EXT_display_surface_counter",
+    "VK_EXT_swapchain_colorspace",
+    "VK_KHR_external_fence_capabilities",
+    "VK_KHR_get_surface_capabilities2",
+    "VK_KHR_get_display_properties2",
+    "VK_MVK_ios_surface",
+    "VK_MVK_macos_surface",
+    "VK_EXT_debug_utils",
+    "VK_FUCHSIA_imagepipe_surface",
+    "VK_EXT_metal_surface",
+    "VK_KHR_surface_protected_capabilities",
+    "VK_EXT_validation_features",
+    "VK_EXT_headless_surface",
+    "VK_EXT_surface_maintenance1",
+    "VK_EXT_acquire_drm_display",
+    "VK_EXT_directfb_surface",
+    "VK_QNX_screen_surface",
+    "VK_KHR_portability_enumeration",
+    "VK_GOOGLE_surfaceless_query",
+    "VK_LUNARG_direct_driver_loading",
+    "VK_EXT_layer_settings",
+    "VK_NV_display_stereo",
+];
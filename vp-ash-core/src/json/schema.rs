@@ -0,0 +1,503 @@
+//! The data model of profile JSON, following the Vulkan Profiles JSON schema.
+//!
+//! [`ProfilesFile::from_json`] checks the shape of the document: the keys, the types of the
+//! values and the capabilities blocks referenced by the profiles. The structures of the
+//! capabilities blocks are kept by name, they are checked against the Vulkan structures when the
+//! file is added to a `Library` or a [`Generator`](super::Generator).
+//!
+//! ```
+//! # use vp_ash::json::ProfilesFile;
+//! let file = ProfilesFile::from_json(r#"{
+//!     "capabilities": {
+//!         "baseline": {
+//!             "features": { "VkPhysicalDeviceFeatures": { "shaderFloat64": true } }
+//!         }
+//!     },
+//!     "profiles": {
+//!         "VP_EXAMPLE_baseline": {
+//!             "version": 1,
+//!             "api-version": "1.2.198",
+//!             "capabilities": ["baseline"]
+//!         }
+//!     }
+//! }"#)?;
+//!
+//! let profile = &file.profiles["VP_EXAMPLE_baseline"];
+//! assert_eq!(profile.capabilities, [["baseline"]]);
+//! # Ok::<(), vp_ash::json::ParseError>(())
+//! ```
+
+use core::str::FromStr;
+
+use alloc::{collections::BTreeMap, format, string::String, vec::Vec};
+
+use ash::vk;
+use serde_json::Map;
+
+use super::ParseError;
+
+/// The structures of a capability by name, like the `features` of a capabilities block.
+pub type Structures = BTreeMap<String, Members>;
+
+/// The members of a structure by name.
+pub type Members = BTreeMap<String, Value>;
+
+/// A profile JSON document.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProfilesFile {
+    /// The `$schema` the document conforms to.
+    pub schema: Option<String>,
+    /// The capabilities blocks by name.
+    pub capabilities: BTreeMap<String, CapabilitiesBlock>,
+    /// The profiles by name.
+    pub profiles: BTreeMap<String, ProfileDefinition>,
+}
+
+/// A profile of a [`ProfilesFile`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ProfileDefinition {
+    /// The `version` of the profile.
+    pub version: u32,
+    /// The `api-version` of the profile, like [`vk::make_api_version`].
+    pub api_version: u32,
+    pub label: Option<String>,
+    pub description: Option<String>,
+    pub status: Option<String>,
+    /// The required capabilities, each with the names of its variant blocks.
+    ///
+    /// A capability that names a single block has one variant.
+    pub capabilities: Vec<Vec<String>>,
+    /// The names of the required profiles.
+    pub profiles: Vec<String>,
+    /// The names of the fallback profiles, in order of preference.
+    pub fallback: Vec<String>,
+    /// The contributors by name.
+    pub contributors: BTreeMap<String, Contributor>,
+    pub history: Vec<Revision>,
+}
+
+/// A contributor to a profile.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Contributor {
+    pub company: Option<String>,
+    pub email: Option<String>,
+    pub github: Option<String>,
+    /// Whether the contributor is a contact for the profile.
+    pub contact: bool,
+}
+
+/// An entry of the history of a profile.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Revision {
+    pub revision: u32,
+    pub date: String,
+    pub author: String,
+    pub comment: String,
+}
+
+/// A capabilities block of a [`ProfilesFile`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CapabilitiesBlock {
+    /// The spec versions of the extensions by name.
+    pub extensions: BTreeMap<String, u32>,
+    pub features: Structures,
+    pub properties: Structures,
+    /// The format structures by format name, like `VK_FORMAT_R8G8B8A8_UNORM`.
+    pub formats: BTreeMap<String, Structures>,
+    /// The structures of each required queue family.
+    pub queue_families_properties: Vec<Structures>,
+    pub video_profiles: Vec<VideoProfile>,
+}
+
+/// A video profile of a capabilities block.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct VideoProfile {
+    /// The structures describing the video profile, like `VkVideoProfileInfoKHR`.
+    pub profile: Structures,
+    /// The structures of the video capabilities, like `VkVideoCapabilitiesKHR`.
+    pub capabilities: Structures,
+    /// The structures of each required video format.
+    pub formats: Vec<Structures>,
+}
+
+/// The value of a structure member.
+///
+/// Flags are arrays of flag names and enums are the names of their values.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Bool(bool),
+    Unsigned(u64),
+    /// A negative integer.
+    Signed(i64),
+    Float(f64),
+    String(String),
+    Array(Vec<Self>),
+    Struct(Members),
+}
+
+impl Value {
+    pub fn as_bool(&self) -> Option<bool> {
+        match *self {
+            Self::Bool(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the integer value if it is not negative.
+    pub fn as_u64(&self) -> Option<u64> {
+        match *self {
+            Self::Unsigned(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the integer value if it fits in an `i64`.
+    pub fn as_i64(&self) -> Option<i64> {
+        match *self {
+            Self::Unsigned(value) => value.try_into().ok(),
+            Self::Signed(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns any number as a float.
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            Self::Unsigned(value) => Some(value as f64),
+            Self::Signed(value) => Some(value as f64),
+            Self::Float(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&[Self]> {
+        match self {
+            Self::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    pub fn as_struct(&self) -> Option<&Members> {
+        match self {
+            Self::Struct(members) => Some(members),
+            _ => None,
+        }
+    }
+}
+
+impl ProfilesFile {
+    /// Parses a profile JSON document.
+    ///
+    /// The errors point to the malformed value, see [`ParseError::path`].
+    pub fn from_json(json: &str) -> Result<Self, ParseError> {
+        let root: serde_json::Value =
+            serde_json::from_str(json).map_err(|err| ParseError::new("", err))?;
+        let root = as_object(&root, "", &["$schema", "capabilities", "profiles"])?;
+
+        let schema = optional(root, "$schema", "", parse_string)?;
+        let capabilities = optional(root, "capabilities", "", |value, path| {
+            parse_map(value, path, parse_block)
+        })?
+        .unwrap_or_default();
+        let profiles = required(root, "profiles", "", |value, path| {
+            parse_map(value, path, |profile, path| {
+                parse_profile(profile, &capabilities, path)
+            })
+        })?;
+
+        Ok(Self {
+            schema,
+            capabilities,
+            profiles,
+        })
+    }
+}
+
+impl FromStr for ProfilesFile {
+    type Err = ParseError;
+
+    fn from_str(json: &str) -> Result<Self, Self::Err> {
+        Self::from_json(json)
+    }
+}
+
+fn parse_profile(
+    value: &serde_json::Value,
+    blocks: &BTreeMap<String, CapabilitiesBlock>,
+    path: &str,
+) -> Result<ProfileDefinition, ParseError> {
+    let profile = as_object(
+        value,
+        path,
+        &[
+            "version",
+            "api-version",
+            "label",
+            "description",
+            "status",
+            "capabilities",
+            "profiles",
+            "fallback",
+            "contributors",
+            "history",
+        ],
+    )?;
+
+    let parse_block_name = |value: &serde_json::Value, path: &str| {
+        let name = parse_string(value, path)?;
+        match blocks.contains_key(&name) {
+            true => Ok(name),
+            false => Err(ParseError::new(
+                path,
+                format!("unknown capabilities block `{name}`"),
+            )),
+        }
+    };
+    let parse_capability = |value: &serde_json::Value, path: &str| match value {
+        serde_json::Value::Array(_) => parse_array(value, path, parse_block_name),
+        _ => Ok(alloc::vec![parse_block_name(value, path)?]),
+    };
+
+    Ok(ProfileDefinition {
+        version: required(profile, "version", path, parse_u32)?,
+        api_version: required(profile, "api-version", path, parse_api_version)?,
+        label: optional(profile, "label", path, parse_string)?,
+        description: optional(profile, "description", path, parse_string)?,
+        status: optional(profile, "status", path, parse_string)?,
+        capabilities: optional(profile, "capabilities", path, |value, path| {
+            parse_array(value, path, parse_capability)
+        })?
+        .unwrap_or_default(),
+        profiles: optional(profile, "profiles", path, parse_names)?.unwrap_or_default(),
+        fallback: optional(profile, "fallback", path, parse_names)?.unwrap_or_default(),
+        contributors: optional(profile, "contributors", path, |value, path| {
+            parse_map(value, path, parse_contributor)
+        })?
+        .unwrap_or_default(),
+        history: optional(profile, "history", path, |value, path| {
+            parse_array(value, path, parse_revision)
+        })?
+        .unwrap_or_default(),
+    })
+}
+
+fn parse_contributor(value: &serde_json::Value, path: &str) -> Result<Contributor, ParseError> {
+    let contributor = as_object(value, path, &["company", "email", "github", "contact"])?;
+
+    Ok(Contributor {
+        company: optional(contributor, "company", path, parse_string)?,
+        email: optional(contributor, "email", path, parse_string)?,
+        github: optional(contributor, "github", path, parse_string)?,
+        contact: optional(contributor, "contact", path, parse_bool)?.unwrap_or_default(),
+    })
+}
+
+fn parse_revision(value: &serde_json::Value, path: &str) -> Result<Revision, ParseError> {
+    let revision = as_object(value, path, &["revision", "date", "author", "comment"])?;
+
+    Ok(Revision {
+        revision: required(revision, "revision", path, parse_u32)?,
+        date: required(revision, "date", path, parse_string)?,
+        author: required(revision, "author", path, parse_string)?,
+        comment: required(revision, "comment", path, parse_string)?,
+    })
+}
+
+fn parse_block(value: &serde_json::Value, path: &str) -> Result<CapabilitiesBlock, ParseError> {
+    let block = as_object(
+        value,
+        path,
+        &[
+            "extensions",
+            "features",
+            "properties",
+            "formats",
+            "queueFamiliesProperties",
+            "videoProfiles",
+        ],
+    )?;
+
+    let structures_array =
+        |value: &serde_json::Value, path: &str| parse_array(value, path, parse_structures);
+
+    Ok(CapabilitiesBlock {
+        extensions: optional(block, "extensions", path, |value, path| {
+            parse_map(value, path, parse_u32)
+        })?
+        .unwrap_or_default(),
+        features: optional(block, "features", path, parse_structures)?.unwrap_or_default(),
+        properties: optional(block, "properties", path, parse_structures)?.unwrap_or_default(),
+        formats: optional(block, "formats", path, |value, path| {
+            parse_map(value, path, parse_structures)
+        })?
+        .unwrap_or_default(),
+        queue_families_properties: optional(
+            block,
+            "queueFamiliesProperties",
+            path,
+            structures_array,
+        )?
+        .unwrap_or_default(),
+        video_profiles: optional(block, "videoProfiles", path, |value, path| {
+            parse_array(value, path, parse_video_profile)
+        })?
+        .unwrap_or_default(),
+    })
+}
+
+fn parse_video_profile(value: &serde_json::Value, path: &str) -> Result<VideoProfile, ParseError> {
+    let profile = as_object(value, path, &["profile", "capabilities", "formats"])?;
+
+    Ok(VideoProfile {
+        profile: required(profile, "profile", path, parse_structures)?,
+        capabilities: optional(profile, "capabilities", path, parse_structures)?
+            .unwrap_or_default(),
+        formats: optional(profile, "formats", path, |value, path| {
+            parse_array(value, path, parse_structures)
+        })?
+        .unwrap_or_default(),
+    })
+}
+
+fn parse_structures(value: &serde_json::Value, path: &str) -> Result<Structures, ParseError> {
+    parse_map(value, path, parse_members)
+}
+
+fn parse_members(value: &serde_json::Value, path: &str) -> Result<Members, ParseError> {
+    parse_map(value, path, parse_value)
+}
+
+fn parse_value(value: &serde_json::Value, path: &str) -> Result<Value, ParseError> {
+    Ok(match value {
+        serde_json::Value::Null => return Err(ParseError::new(path, "unexpected `null`")),
+        serde_json::Value::Bool(value) => Value::Bool(*value),
+        serde_json::Value::Number(number) => match (number.as_u64(), number.as_i64()) {
+            (Some(value), _) => Value::Unsigned(value),
+            (_, Some(value)) => Value::Signed(value),
+            _ => Value::Float(number.as_f64().unwrap_or_default()),
+        },
+        serde_json::Value::String(value) => Value::String(value.clone()),
+        serde_json::Value::Array(_) => Value::Array(parse_array(value, path, parse_value)?),
+        serde_json::Value::Object(_) => Value::Struct(parse_members(value, path)?),
+    })
+}
+
+fn parse_names(value: &serde_json::Value, path: &str) -> Result<Vec<String>, ParseError> {
+    parse_array(value, path, parse_string)
+}
+
+/// Parses an API version like `1.3.204`, the patch version is optional.
+fn parse_api_version(value: &serde_json::Value, path: &str) -> Result<u32, ParseError> {
+    let version = parse_string(value, path)?;
+    let invalid = || ParseError::new(path, format!("`{version}` is not a Vulkan API version"));
+
+    let mut parts = version.split('.').map(|part| part.parse::<u32>());
+    let major = parts.next().ok_or_else(invalid)?.map_err(|_| invalid())?;
+    let minor = parts.next().ok_or_else(invalid)?.map_err(|_| invalid())?;
+    let patch = parts.next().unwrap_or(Ok(0)).map_err(|_| invalid())?;
+    if parts.next().is_some() {
+        return Err(invalid());
+    }
+
+    Ok(vk::make_api_version(0, major, minor, patch))
+}
+
+fn parse_string(value: &serde_json::Value, path: &str) -> Result<String, ParseError> {
+    value
+        .as_str()
+        .map(String::from)
+        .ok_or_else(|| ParseError::new(path, "expected a string"))
+}
+
+fn parse_bool(value: &serde_json::Value, path: &str) -> Result<bool, ParseError> {
+    value
+        .as_bool()
+        .ok_or_else(|| ParseError::new(path, "expected a boolean"))
+}
+
+fn parse_u32(value: &serde_json::Value, path: &str) -> Result<u32, ParseError> {
+    value
+        .as_u64()
+        .and_then(|value| u32::try_from(value).ok())
+        .ok_or_else(|| ParseError::new(path, format_args!("expected a u32, found `{value}`")))
+}
+
+fn parse_array<T>(
+    value: &serde_json::Value,
+    path: &str,
+    mut parse: impl FnMut(&serde_json::Value, &str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    value
+        .as_array()
+        .ok_or_else(|| ParseError::new(path, "expected an array"))?
+        .iter()
+        .enumerate()
+        .map(|(index, value)| parse(value, &format!("{path}[{index}]")))
+        .collect()
+}
+
+fn parse_map<T>(
+    value: &serde_json::Value,
+    path: &str,
+    mut parse: impl FnMut(&serde_json::Value, &str) -> Result<T, ParseError>,
+) -> Result<BTreeMap<String, T>, ParseError> {
+    as_object(value, path, &[])?
+        .iter()
+        .map(|(key, value)| Ok((key.clone(), parse(value, &join(path, key))?)))
+        .collect()
+}
+
+/// Returns the object `value`, if `keys` is not empty the object may only contain `keys`.
+fn as_object<'a>(
+    value: &'a serde_json::Value,
+    path: &str,
+    keys: &[&str],
+) -> Result<&'a Map<String, serde_json::Value>, ParseError> {
+    let object = value
+        .as_object()
+        .ok_or_else(|| ParseError::new(path, "expected an object"))?;
+
+    if !keys.is_empty() {
+        if let Some(key) = object.keys().find(|key| !keys.contains(&key.as_str())) {
+            return Err(ParseError::new(&join(path, key), "unknown key"));
+        }
+    }
+
+    Ok(object)
+}
+
+fn optional<T>(
+    object: &Map<String, serde_json::Value>,
+    key: &str,
+    path: &str,
+    parse: impl FnOnce(&serde_json::Value, &str) -> Result<T, ParseError>,
+) -> Result<Option<T>, ParseError> {
+    object
+        .get(key)
+        .map(|value| parse(value, &join(path, key)))
+        .transpose()
+}
+
+fn required<T>(
+    object: &Map<String, serde_json::Value>,
+    key: &str,
+    path: &str,
+    parse: impl FnOnce(&serde_json::Value, &str) -> Result<T, ParseError>,
+) -> Result<T, ParseError> {
+    optional(object, key, path, parse)?
+        .ok_or_else(|| ParseError::new(path, format!("missing `{key}`")))
+}
+
+fn join(path: &str, key: &str) -> String {
+    match path.is_empty() {
+        true => key.into(),
+        false => format!("{path}.{key}"),
+    }
+}
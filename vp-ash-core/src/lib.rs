@@ -0,0 +1,29 @@
+//! # vp-ash-core
+//! The Vulkan structure registry and profile JSON of `vp-ash`.
+//!
+//! `vp-ash` re-exports these modules as `vp_ash::structure` and `vp_ash::json`, they are in their
+//! own crate so `vp-ash-macros` can check profile JSON without depending on `vp-ash`, which
+//! re-exports its macros. Depend on `vp-ash` instead of this crate.
+
+#![warn(
+    clippy::alloc_instead_of_core,
+    clippy::use_self,
+    clippy::std_instead_of_alloc,
+    clippy::std_instead_of_core,
+    deprecated_in_future,
+    rust_2018_idioms,
+    trivial_casts,
+    trivial_numeric_casts,
+    unused_qualifications,
+    clippy::unnecessary_self_imports
+)]
+#![allow(clippy::missing_safety_doc, clippy::upper_case_acronyms)]
+#![no_std]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "json")]
+pub mod json;
+pub mod structure;
@@ -0,0 +1,650 @@
+//! Owned Vulkan structures, allocated from the structure types reported by the library.
+//!
+//! The library reports the structure types of a profile's requirements, a [`Structure`] can be
+//! allocated for any of them in the registry of the structures `ash` knows, so the requirements
+//! can be queried without naming the structures ahead of time. The registry also lists the
+//! members of each structure, so a [`Structure`] can be printed or compared member by member.
+//!
+//! ```
+//! # use ash::vk;
+//! use vp_ash::structure::{self, Value};
+//!
+//! let info = structure::info(vk::StructureType::PHYSICAL_DEVICE_VULKAN_1_2_FEATURES).unwrap();
+//! assert_eq!(info.name(), "VkPhysicalDeviceVulkan12Features");
+//!
+//! let features = info.zeroed();
+//! for (member, value) in features.members() {
+//!     assert!(value == Value::Bool(false), "{}", member.name());
+//! }
+//! ```
+
+#[cfg(feature = "debug")]
+use core::fmt;
+use core::{
+    ffi::CStr,
+    mem::{align_of, size_of},
+};
+
+use alloc::{vec, vec::Vec};
+
+use ash::vk;
+
+/// An owned, zero-initialised Vulkan structure with its structure type set.
+///
+/// The `p_next` of a [`Structure`] is always null, so it can be cloned, compared and sent to
+/// other threads.
+#[derive(Clone)]
+pub struct Structure {
+    info: &'static StructureInfo,
+    /// The bytes of the structure, stored as `u64` for the alignment of the structures.
+    data: Vec<u64>,
+}
+
+impl Structure {
+    /// Allocates the structure of `s_type`.
+    ///
+    /// Returns `None` if `s_type` is not in the registry.
+    pub fn new(s_type: vk::StructureType) -> Option<Self> {
+        info(s_type).map(StructureInfo::zeroed)
+    }
+
+    /// Copies the members of `value`, without its `p_next`.
+    ///
+    /// The padding is not copied, it may be uninitialized and would make equal structures
+    /// compare unequal.
+    #[doc(hidden)]
+    pub fn from_struct<T: vk::TaggedStructure>(value: &T) -> Self {
+        let mut structure = info(T::STRUCTURE_TYPE)
+            .expect("the structure is in the registry")
+            .zeroed();
+
+        // SAFETY: The data is large enough for `T` and the members are within `T`.
+        unsafe {
+            copy_members(
+                <*const T>::cast::<u8>(value),
+                structure.data.as_mut_ptr().cast::<u8>(),
+                structure.info.members,
+            );
+        }
+
+        structure
+    }
+
+    /// Returns the registry entry of the structure.
+    pub fn info(&self) -> &'static StructureInfo {
+        self.info
+    }
+
+    /// Returns the structure type.
+    pub fn s_type(&self) -> vk::StructureType {
+        self.info.s_type
+    }
+
+    /// Returns the Vulkan name of the structure, like `VkPhysicalDeviceVulkan12Features`.
+    pub fn name(&self) -> &'static str {
+        self.info.name
+    }
+
+    /// Returns the structure as `T`, if it is a `T`.
+    pub fn get<T: vk::TaggedStructure>(&self) -> Option<&T> {
+        if T::STRUCTURE_TYPE != self.info.s_type || size_of::<T>() > self.info.size {
+            return None;
+        }
+
+        // SAFETY: The data is a `T` with a null `p_next`, and is aligned for `T`.
+        Some(unsafe { &*self.data.as_ptr().cast::<T>() })
+    }
+
+    /// Returns the bytes of the structure, its `p_next` is null.
+    pub fn as_bytes(&self) -> &[u8] {
+        // SAFETY: The data has at least `size` initialised bytes.
+        unsafe { core::slice::from_raw_parts(self.data.as_ptr().cast(), self.info.size) }
+    }
+
+    /// Returns the members of the structure, without `s_type` and `p_next`.
+    pub fn members(&self) -> Members<'_> {
+        Members {
+            name: self.info.name,
+            members: self.info.members,
+            data: self.as_bytes(),
+        }
+    }
+
+    /// Sets every member but the pointers to ones, so the members a query of the profile leaves
+    /// unset can be told apart from the members it sets to zero.
+    #[doc(hidden)]
+    pub fn mark(&mut self) {
+        // SAFETY: The data is large enough for the members.
+        unsafe { mark_members(self.data.as_mut_ptr().cast(), self.info.members) };
+    }
+
+    /// Returns a pointer to the structure, to be filled by Vulkan.
+    ///
+    /// # Safety
+    /// The writes through the pointer must leave `p_next` null, which the structure relies on to
+    /// be cloned, compared and sent to other threads, except while it is [`link`]ed.
+    #[doc(hidden)]
+    pub unsafe fn as_mut_ptr(&mut self) -> *mut vk::BaseOutStructure<'static> {
+        self.data.as_mut_ptr().cast()
+    }
+}
+
+impl PartialEq for Structure {
+    fn eq(&self, other: &Self) -> bool {
+        self.info.s_type == other.info.s_type && self.data == other.data
+    }
+}
+
+impl Eq for Structure {}
+
+#[cfg(feature = "debug")]
+impl fmt::Debug for Structure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.members(), f)
+    }
+}
+
+/// A structure in the registry.
+#[cfg_attr(feature = "debug", derive(Debug))]
+pub struct StructureInfo {
+    name: &'static str,
+    s_type: vk::StructureType,
+    chain: Chain,
+    size: usize,
+    align: usize,
+    members: &'static [Member],
+}
+
+impl StructureInfo {
+    const fn new<T: vk::TaggedStructure>(
+        name: &'static str,
+        chain: Chain,
+        members: &'static [Member],
+    ) -> Self {
+        assert!(align_of::<T>() <= align_of::<u64>());
+
+        Self {
+            name,
+            s_type: T::STRUCTURE_TYPE,
+            chain,
+            size: size_of::<T>(),
+            align: align_of::<T>(),
+            members,
+        }
+    }
+
+    /// Returns the Vulkan name of the structure, like `VkPhysicalDeviceVulkan12Features`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the structure type.
+    pub fn s_type(&self) -> vk::StructureType {
+        self.s_type
+    }
+
+    /// Returns the chain the structure is part of.
+    pub fn chain(&self) -> Chain {
+        self.chain
+    }
+
+    /// Returns the size of the structure in bytes.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the alignment of the structure in bytes.
+    pub fn align(&self) -> usize {
+        self.align
+    }
+
+    /// Returns the members of the structure, without `s_type` and `p_next`.
+    pub fn members(&self) -> &'static [Member] {
+        self.members
+    }
+
+    /// Allocates the structure, zeroed with its structure type set.
+    pub fn zeroed(&'static self) -> Structure {
+        let mut structure = Structure {
+            info: self,
+            data: vec![0; self.size.div_ceil(size_of::<u64>())],
+        };
+        // SAFETY: The data is zeroed and large enough for the structure.
+        unsafe { (*structure.as_mut_ptr()).s_type = self.s_type };
+
+        structure
+    }
+}
+
+/// Returns the structure of `s_type` in the registry.
+pub fn info(s_type: vk::StructureType) -> Option<&'static StructureInfo> {
+    registry::STRUCTURES
+        .iter()
+        .find(|info| info.s_type == s_type)
+}
+
+/// Returns the structures in the registry, the structures of each chain start with its head.
+///
+/// The registry has the structures of the feature, property, format, queue family and video
+/// chains in `ash`.
+pub fn registry() -> &'static [StructureInfo] {
+    registry::STRUCTURES
+}
+
+/// The query chain a structure is part of.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Chain {
+    Features,
+    Properties,
+    Format,
+    QueueFamily,
+    VideoProfile,
+    VideoCapabilities,
+    VideoFormat,
+}
+
+impl Chain {
+    /// Returns the structure type at the head of the chain.
+    pub fn head(self) -> vk::StructureType {
+        match self {
+            Self::Features => vk::StructureType::PHYSICAL_DEVICE_FEATURES_2,
+            Self::Properties => vk::StructureType::PHYSICAL_DEVICE_PROPERTIES_2,
+            Self::Format => vk::StructureType::FORMAT_PROPERTIES_2,
+            Self::QueueFamily => vk::StructureType::QUEUE_FAMILY_PROPERTIES_2,
+            Self::VideoProfile => vk::StructureType::VIDEO_PROFILE_INFO_KHR,
+            Self::VideoCapabilities => vk::StructureType::VIDEO_CAPABILITIES_KHR,
+            Self::VideoFormat => vk::StructureType::VIDEO_FORMAT_PROPERTIES_KHR,
+        }
+    }
+}
+
+/// A member of a structure in the registry.
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(PartialEq, Eq)]
+pub struct Member {
+    name: &'static str,
+    offset: usize,
+    ty: MemberType,
+    compare: Compare,
+}
+
+impl Member {
+    /// Returns the `ash` name of the member, like `shader_float64`.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the offset of the member in the structure.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the type of the member.
+    pub fn ty(&self) -> &MemberType {
+        &self.ty
+    }
+
+    /// Returns how the member is compared, from its `limittype` in `vk.xml`.
+    ///
+    /// Members that are not limits and the members of nested limits, like
+    /// `minImageTransferGranularity`, compare [`Compare::Exact`].
+    #[doc(hidden)]
+    pub fn compare(&self) -> Compare {
+        self.compare
+    }
+}
+
+/// The type of a [`Member`].
+#[cfg_attr(feature = "debug", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum MemberType {
+    Bool32,
+    U8,
+    U16,
+    U32,
+    I32,
+    U64,
+    I64,
+    F32,
+    Usize,
+    /// A 32-bit flags type, with its `ash` name.
+    Flags(&'static str),
+    /// A 64-bit flags type, with its `ash` name.
+    Flags64(&'static str),
+    /// An enum, with its `ash` name.
+    Enum(&'static str),
+    /// A null-terminated string in an array of the given length.
+    Chars(usize),
+    /// An array of the given length.
+    Array(&'static Self, usize),
+    /// A nested structure, with its Vulkan name.
+    Struct(&'static str, &'static [Member]),
+    Pointer,
+}
+
+impl MemberType {
+    /// Returns the size of an array element of this type.
+    #[doc(hidden)]
+    pub fn size(&self) -> usize {
+        match self {
+            Self::U8 => 1,
+            Self::U16 => 2,
+            Self::U64 | Self::I64 | Self::Flags64(_) => 8,
+            Self::Usize | Self::Pointer => size_of::<usize>(),
+            Self::Chars(len) => *len,
+            Self::Array(ty, len) => ty.size() * len,
+            // The registry has no arrays of structures.
+            Self::Struct(..) => 0,
+            _ => 4,
+        }
+    }
+}
+
+/// The named values of a flags or enum type of the members, as `ash` spells them.
+#[doc(hidden)]
+pub struct ValueNames<T: 'static> {
+    /// The `ash` name of the type, like `ShaderStageFlags`.
+    pub name: &'static str,
+    /// The prefix of the Vulkan names of the values, like `VK_SHADER_STAGE_`.
+    pub prefix: &'static str,
+    pub known: &'static [(T, &'static str)],
+}
+
+/// Returns the named bits of the flags type `name`, like `ShaderStageFlags`.
+#[cfg(feature = "json")]
+#[doc(hidden)]
+pub fn flag_names(name: &str) -> Option<&'static ValueNames<u64>> {
+    registry::FLAGS.iter().find(|names| names.name == name)
+}
+
+/// Returns the named values of the enum `name`, like `Format`.
+#[doc(hidden)]
+pub fn enum_names(name: &str) -> Option<&'static ValueNames<i32>> {
+    registry::ENUMS.iter().find(|names| names.name == name)
+}
+
+/// How a device value is compared against the value in the profile.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[doc(hidden)]
+pub enum Compare {
+    /// The device value must equal the profile value.
+    Exact,
+    /// The device value must be at least the profile value, like `maxImageDimension2D`.
+    Max,
+    /// The device value must be at most the profile value, like `minTexelOffset`.
+    Min,
+    /// The array holds a minimum and a maximum limit, like `pointSizeRange`, see
+    /// [`Compare::element`].
+    Range,
+}
+
+impl Compare {
+    /// Returns the comparison of the element at `index` of an array member.
+    pub fn element(self, index: usize) -> Self {
+        match (self, index) {
+            (Self::Range, 0) => Self::Min,
+            (Self::Range, _) => Self::Max,
+            (compare, _) => compare,
+        }
+    }
+
+    /// Returns whether the `actual` device value satisfies the `expected` profile value.
+    ///
+    /// [`Compare::Range`] compares its elements, a single value is compared exactly.
+    pub fn test<T: PartialOrd>(self, actual: T, expected: T) -> bool {
+        match self {
+            Self::Exact | Self::Range => actual == expected,
+            Self::Max => actual >= expected,
+            Self::Min => actual <= expected,
+        }
+    }
+}
+
+/// The value of a member.
+#[derive(Clone, PartialEq)]
+pub enum Value<'a> {
+    Bool(bool),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    I32(i32),
+    U64(u64),
+    I64(i64),
+    F32(f32),
+    Usize(usize),
+    /// The bits of a 32 or 64-bit flags type.
+    Flags(u64),
+    Enum(i32),
+    String(&'a CStr),
+    Array(Elements<'a>),
+    Struct(Members<'a>),
+    Pointer(usize),
+}
+
+impl<'a> Value<'a> {
+    /// Reads the value of type `ty` at the start of `data`.
+    fn read(ty: &'static MemberType, data: &'a [u8]) -> Self {
+        match *ty {
+            MemberType::Bool32 => Self::Bool(u32::from_ne_bytes(bytes(data)) != vk::FALSE),
+            MemberType::U8 => Self::U8(data[0]),
+            MemberType::U16 => Self::U16(u16::from_ne_bytes(bytes(data))),
+            MemberType::U32 => Self::U32(u32::from_ne_bytes(bytes(data))),
+            MemberType::I32 => Self::I32(i32::from_ne_bytes(bytes(data))),
+            MemberType::U64 => Self::U64(u64::from_ne_bytes(bytes(data))),
+            MemberType::I64 => Self::I64(i64::from_ne_bytes(bytes(data))),
+            MemberType::F32 => Self::F32(f32::from_ne_bytes(bytes(data))),
+            MemberType::Usize => Self::Usize(usize::from_ne_bytes(bytes(data))),
+            MemberType::Flags(_) => Self::Flags(u32::from_ne_bytes(bytes(data)).into()),
+            MemberType::Flags64(_) => Self::Flags(u64::from_ne_bytes(bytes(data))),
+            MemberType::Enum(_) => Self::Enum(i32::from_ne_bytes(bytes(data))),
+            MemberType::Chars(len) => {
+                Self::String(CStr::from_bytes_until_nul(&data[..len]).unwrap_or_default())
+            }
+            MemberType::Array(ty, len) => Self::Array(Elements {
+                ty,
+                data: &data[..ty.size() * len],
+            }),
+            MemberType::Struct(name, members) => Self::Struct(Members {
+                name,
+                members,
+                data,
+            }),
+            MemberType::Pointer => Self::Pointer(usize::from_ne_bytes(bytes(data))),
+        }
+    }
+}
+
+fn bytes<const N: usize>(data: &[u8]) -> [u8; N] {
+    let mut bytes = [0; N];
+    bytes.copy_from_slice(&data[..N]);
+    bytes
+}
+
+#[cfg(feature = "debug")]
+impl fmt::Debug for Value<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(value) => value.fmt(f),
+            Self::U8(value) => value.fmt(f),
+            Self::U16(value) => value.fmt(f),
+            Self::U32(value) => value.fmt(f),
+            Self::I32(value) => value.fmt(f),
+            Self::U64(value) => value.fmt(f),
+            Self::I64(value) => value.fmt(f),
+            Self::F32(value) => value.fmt(f),
+            Self::Usize(value) => value.fmt(f),
+            Self::Flags(value) => write!(f, "{value:#x}"),
+            Self::Enum(value) => value.fmt(f),
+            Self::String(value) => value.fmt(f),
+            Self::Array(elements) => f.debug_list().entries(elements.clone()).finish(),
+            Self::Struct(members) => members.fmt(f),
+            Self::Pointer(value) => write!(f, "{value:#x}"),
+        }
+    }
+}
+
+/// An iterator over the members of a structure and their values.
+#[derive(Clone, PartialEq)]
+pub struct Members<'a> {
+    name: &'static str,
+    members: &'static [Member],
+    /// The bytes of the structure.
+    data: &'a [u8],
+}
+
+impl Members<'_> {
+    /// Returns the Vulkan name of the structure.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<'a> Iterator for Members<'a> {
+    type Item = (&'static Member, Value<'a>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (member, members) = self.members.split_first()?;
+        self.members = members;
+
+        Some((member, Value::read(&member.ty, &self.data[member.offset..])))
+    }
+}
+
+#[cfg(feature = "debug")]
+impl fmt::Debug for Members<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct(self.name);
+        for (member, value) in self.clone() {
+            debug.field(member.name, &value);
+        }
+        debug.finish()
+    }
+}
+
+/// An iterator over the elements of an array member.
+#[derive(Clone, PartialEq)]
+pub struct Elements<'a> {
+    ty: &'static MemberType,
+    /// The bytes of the remaining elements.
+    data: &'a [u8],
+}
+
+impl<'a> Iterator for Elements<'a> {
+    type Item = Value<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let value = Value::read(self.ty, self.data);
+        self.data = &self.data[self.ty.size()..];
+
+        Some(value)
+    }
+}
+
+/// Copies the `members` from `src` to `dst`, recursing into nested structures.
+unsafe fn copy_members(src: *const u8, dst: *mut u8, members: &[Member]) {
+    for member in members {
+        let (src, dst) = (src.add(member.offset), dst.add(member.offset));
+        match member.ty {
+            MemberType::Struct(_, members) => copy_members(src, dst, members),
+            ref ty => core::ptr::copy_nonoverlapping(src, dst, ty.size()),
+        }
+    }
+}
+
+/// Sets the bytes of the `members` at `dst` to ones, recursing into nested structures.
+unsafe fn mark_members(dst: *mut u8, members: &[Member]) {
+    for member in members {
+        let dst = dst.add(member.offset);
+        match member.ty {
+            MemberType::Struct(_, members) => mark_members(dst, members),
+            MemberType::Pointer => {}
+            ref ty => core::ptr::write_bytes(dst, 0xFF, ty.size()),
+        }
+    }
+}
+
+/// Allocates the head of `chain` followed by the other structures of `s_types` in `chain`.
+///
+/// Structure types that are not in the registry are skipped.
+#[doc(hidden)]
+pub fn allocate_chain(chain: Chain, s_types: &[vk::StructureType]) -> Vec<Structure> {
+    let head = chain.head();
+    let mut structures = Vec::with_capacity(s_types.len() + 1);
+    structures.extend(Structure::new(head));
+    structures.extend(
+        s_types
+            .iter()
+            .filter(|&&s_type| s_type != head)
+            .filter_map(|&s_type| info(s_type))
+            .filter(|info| info.chain == chain)
+            .map(StructureInfo::zeroed),
+    );
+
+    structures
+}
+
+/// Allocates the structures of `s_types` in `chain` and fills them with `fill`, which is given the
+/// head of the linked chain.
+#[doc(hidden)]
+pub fn fill_chain<E>(
+    chain: Chain,
+    s_types: &[vk::StructureType],
+    fill: impl FnOnce(*mut vk::BaseOutStructure<'static>) -> Result<(), E>,
+) -> Result<Vec<Structure>, E> {
+    let mut structures = allocate_chain(chain, s_types);
+    // SAFETY: The structures are unlinked before they are returned.
+    let result = unsafe {
+        link(&mut structures);
+        fill(structures[0].as_mut_ptr())
+    };
+    unlink(&mut structures);
+
+    result.map(|()| structures)
+}
+
+/// Links `structures` into a `p_next` chain, in order.
+///
+/// # Safety
+/// The structures must be [`unlink`]ed before they are cloned, compared, read or sent to other
+/// threads, and the chain must not be used once one of them is moved or dropped.
+#[doc(hidden)]
+pub unsafe fn link(structures: &mut [Structure]) {
+    for index in 1..structures.len() {
+        let next = structures[index].as_mut_ptr();
+        // SAFETY: The structures start like `BaseOutStructure`.
+        (*structures[index - 1].as_mut_ptr()).p_next = next;
+    }
+}
+
+/// Clears the `p_next` of `structures`.
+#[doc(hidden)]
+pub fn unlink(structures: &mut [Structure]) {
+    for structure in structures {
+        // SAFETY: The structures start like `BaseOutStructure`, and `p_next` is cleared.
+        unsafe { (*structure.as_mut_ptr()).p_next = core::ptr::null_mut() };
+    }
+}
+
+/// Lists the members of `$ty` with their offsets, and the comparison of the limits.
+macro_rules! members {
+    (@compare) => {
+        Compare::Exact
+    };
+    (@compare $compare:ident) => {
+        Compare::$compare
+    };
+    ($ty:ty { $($field:ident: $member:expr $(=> $compare:ident)?),* $(,)? }) => {
+        &[$(Member {
+            name: stringify!($field),
+            offset: offset_of!($ty, $field),
+            ty: $member,
+            compare: members!(@compare $($compare)?),
+        }),*]
+    };
+}
+
+mod registry;
@@ -0,0 +1,4405 @@
+// Generated by `scripts/generate_structures.py` from ash 0.38.0+1.3.281 without vk.xml, the
+// comparisons of the limits are guessed from their names, do not edit.
+
+use core::mem::offset_of;
+
+use ash::vk;
+
+use super::{Chain, Compare, Member, MemberType, StructureInfo, ValueNames};
+
+/// The structures of each chain, the head first.
+pub(super) static STRUCTURES: &[StructureInfo] = &[
+    StructureInfo::new::<vk::PhysicalDeviceFeatures2<'static>>(
+        "VkPhysicalDeviceFeatures2",
+        Chain::Features,
+        PHYSICAL_DEVICE_FEATURES_2,
+    ),
+    StructureInfo::new::<vk::PhysicalDevice16BitStorageFeatures<'static>>(
+        "VkPhysicalDevice16BitStorageFeatures",
+        Chain::Features,
+        PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDevice4444FormatsFeaturesEXT<'static>>(
+        "VkPhysicalDevice4444FormatsFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_4444FORMATS_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDevice8BitStorageFeatures<'static>>(
+        "VkPhysicalDevice8BitStorageFeatures",
+        Chain::Features,
+        PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceAccelerationStructureFeaturesKHR<'static>>(
+        "VkPhysicalDeviceAccelerationStructureFeaturesKHR",
+        Chain::Features,
+        PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_FEATURES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceAddressBindingReportFeaturesEXT<'static>>(
+        "VkPhysicalDeviceAddressBindingReportFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_ADDRESS_BINDING_REPORT_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceAmigoProfilingFeaturesSEC<'static>>(
+        "VkPhysicalDeviceAmigoProfilingFeaturesSEC",
+        Chain::Features,
+        PHYSICAL_DEVICE_AMIGO_PROFILING_FEATURES_SEC,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceASTCDecodeFeaturesEXT<'static>>(
+        "VkPhysicalDeviceASTCDecodeFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceBlendOperationAdvancedFeaturesEXT<'static>>(
+        "VkPhysicalDeviceBlendOperationAdvancedFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceBorderColorSwizzleFeaturesEXT<'static>>(
+        "VkPhysicalDeviceBorderColorSwizzleFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_BORDER_COLOR_SWIZZLE_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceBufferDeviceAddressFeatures<'static>>(
+        "VkPhysicalDeviceBufferDeviceAddressFeatures",
+        Chain::Features,
+        PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceBufferDeviceAddressFeaturesEXT<'static>>(
+        "VkPhysicalDeviceBufferDeviceAddressFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceCoherentMemoryFeaturesAMD<'static>>(
+        "VkPhysicalDeviceCoherentMemoryFeaturesAMD",
+        Chain::Features,
+        PHYSICAL_DEVICE_COHERENT_MEMORY_FEATURES_AMD,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceColorWriteEnableFeaturesEXT<'static>>(
+        "VkPhysicalDeviceColorWriteEnableFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_COLOR_WRITE_ENABLE_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceConditionalRenderingFeaturesEXT<'static>>(
+        "VkPhysicalDeviceConditionalRenderingFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceCooperativeMatrixFeaturesKHR<'static>>(
+        "VkPhysicalDeviceCooperativeMatrixFeaturesKHR",
+        Chain::Features,
+        PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceCooperativeMatrixFeaturesNV<'static>>(
+        "VkPhysicalDeviceCooperativeMatrixFeaturesNV",
+        Chain::Features,
+        PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceCopyMemoryIndirectFeaturesNV<'static>>(
+        "VkPhysicalDeviceCopyMemoryIndirectFeaturesNV",
+        Chain::Features,
+        PHYSICAL_DEVICE_COPY_MEMORY_INDIRECT_FEATURES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceCornerSampledImageFeaturesNV<'static>>(
+        "VkPhysicalDeviceCornerSampledImageFeaturesNV",
+        Chain::Features,
+        PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceCoverageReductionModeFeaturesNV<'static>>(
+        "VkPhysicalDeviceCoverageReductionModeFeaturesNV",
+        Chain::Features,
+        PHYSICAL_DEVICE_COVERAGE_REDUCTION_MODE_FEATURES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceCubicClampFeaturesQCOM<'static>>(
+        "VkPhysicalDeviceCubicClampFeaturesQCOM",
+        Chain::Features,
+        PHYSICAL_DEVICE_CUBIC_CLAMP_FEATURES_QCOM,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceCubicWeightsFeaturesQCOM<'static>>(
+        "VkPhysicalDeviceCubicWeightsFeaturesQCOM",
+        Chain::Features,
+        PHYSICAL_DEVICE_CUBIC_WEIGHTS_FEATURES_QCOM,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceCudaKernelLaunchFeaturesNV<'static>>(
+        "VkPhysicalDeviceCudaKernelLaunchFeaturesNV",
+        Chain::Features,
+        PHYSICAL_DEVICE_CUDA_KERNEL_LAUNCH_FEATURES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceCustomBorderColorFeaturesEXT<'static>>(
+        "VkPhysicalDeviceCustomBorderColorFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceDepthBiasControlFeaturesEXT<'static>>(
+        "VkPhysicalDeviceDepthBiasControlFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_DEPTH_BIAS_CONTROL_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceDepthClampZeroOneFeaturesEXT<'static>>(
+        "VkPhysicalDeviceDepthClampZeroOneFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_DEPTH_CLAMP_ZERO_ONE_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceDepthClipControlFeaturesEXT<'static>>(
+        "VkPhysicalDeviceDepthClipControlFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_DEPTH_CLIP_CONTROL_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceDepthClipEnableFeaturesEXT<'static>>(
+        "VkPhysicalDeviceDepthClipEnableFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_DEPTH_CLIP_ENABLE_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceDescriptorBufferFeaturesEXT<'static>>(
+        "VkPhysicalDeviceDescriptorBufferFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceDescriptorIndexingFeatures<'static>>(
+        "VkPhysicalDeviceDescriptorIndexingFeatures",
+        Chain::Features,
+        PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceDeviceGeneratedCommandsFeaturesNV<'static>>(
+        "VkPhysicalDeviceDeviceGeneratedCommandsFeaturesNV",
+        Chain::Features,
+        PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_FEATURES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceDeviceMemoryReportFeaturesEXT<'static>>(
+        "VkPhysicalDeviceDeviceMemoryReportFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_DEVICE_MEMORY_REPORT_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceDiagnosticsConfigFeaturesNV<'static>>(
+        "VkPhysicalDeviceDiagnosticsConfigFeaturesNV",
+        Chain::Features,
+        PHYSICAL_DEVICE_DIAGNOSTICS_CONFIG_FEATURES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceDisplacementMicromapFeaturesNV<'static>>(
+        "VkPhysicalDeviceDisplacementMicromapFeaturesNV",
+        Chain::Features,
+        PHYSICAL_DEVICE_DISPLACEMENT_MICROMAP_FEATURES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceDynamicRenderingFeatures<'static>>(
+        "VkPhysicalDeviceDynamicRenderingFeatures",
+        Chain::Features,
+        PHYSICAL_DEVICE_DYNAMIC_RENDERING_FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceExclusiveScissorFeaturesNV<'static>>(
+        "VkPhysicalDeviceExclusiveScissorFeaturesNV",
+        Chain::Features,
+        PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceExtendedDynamicState2FeaturesEXT<'static>>(
+        "VkPhysicalDeviceExtendedDynamicState2FeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_2FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static>>(
+        "VkPhysicalDeviceExtendedDynamicState3FeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_3FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceExtendedDynamicStateFeaturesEXT<'static>>(
+        "VkPhysicalDeviceExtendedDynamicStateFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceExternalMemoryRDMAFeaturesNV<'static>>(
+        "VkPhysicalDeviceExternalMemoryRDMAFeaturesNV",
+        Chain::Features,
+        PHYSICAL_DEVICE_EXTERNAL_MEMORY_RDMA_FEATURES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceFaultFeaturesEXT<'static>>(
+        "VkPhysicalDeviceFaultFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_FAULT_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceFragmentDensityMap2FeaturesEXT<'static>>(
+        "VkPhysicalDeviceFragmentDensityMap2FeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceFragmentDensityMapFeaturesEXT<'static>>(
+        "VkPhysicalDeviceFragmentDensityMapFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceFragmentShadingRateFeaturesKHR<'static>>(
+        "VkPhysicalDeviceFragmentShadingRateFeaturesKHR",
+        Chain::Features,
+        PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_FEATURES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceFrameBoundaryFeaturesEXT<'static>>(
+        "VkPhysicalDeviceFrameBoundaryFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_FRAME_BOUNDARY_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceGlobalPriorityQueryFeaturesKHR<'static>>(
+        "VkPhysicalDeviceGlobalPriorityQueryFeaturesKHR",
+        Chain::Features,
+        PHYSICAL_DEVICE_GLOBAL_PRIORITY_QUERY_FEATURES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceHostImageCopyFeaturesEXT<'static>>(
+        "VkPhysicalDeviceHostImageCopyFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_HOST_IMAGE_COPY_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceHostQueryResetFeatures<'static>>(
+        "VkPhysicalDeviceHostQueryResetFeatures",
+        Chain::Features,
+        PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceImage2DViewOf3DFeaturesEXT<'static>>(
+        "VkPhysicalDeviceImage2DViewOf3DFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_IMAGE_2D_VIEW_OF_3D_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceImagelessFramebufferFeatures<'static>>(
+        "VkPhysicalDeviceImagelessFramebufferFeatures",
+        Chain::Features,
+        PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceImageProcessing2FeaturesQCOM<'static>>(
+        "VkPhysicalDeviceImageProcessing2FeaturesQCOM",
+        Chain::Features,
+        PHYSICAL_DEVICE_IMAGE_PROCESSING_2FEATURES_QCOM,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceImageProcessingFeaturesQCOM<'static>>(
+        "VkPhysicalDeviceImageProcessingFeaturesQCOM",
+        Chain::Features,
+        PHYSICAL_DEVICE_IMAGE_PROCESSING_FEATURES_QCOM,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceImageRobustnessFeatures<'static>>(
+        "VkPhysicalDeviceImageRobustnessFeatures",
+        Chain::Features,
+        PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceImageSlicedViewOf3DFeaturesEXT<'static>>(
+        "VkPhysicalDeviceImageSlicedViewOf3DFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_IMAGE_SLICED_VIEW_OF_3D_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceImageViewMinLodFeaturesEXT<'static>>(
+        "VkPhysicalDeviceImageViewMinLodFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_IMAGE_VIEW_MIN_LOD_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceIndexTypeUint8FeaturesKHR<'static>>(
+        "VkPhysicalDeviceIndexTypeUint8FeaturesKHR",
+        Chain::Features,
+        PHYSICAL_DEVICE_INDEX_TYPE_UINT_8FEATURES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceInlineUniformBlockFeatures<'static>>(
+        "VkPhysicalDeviceInlineUniformBlockFeatures",
+        Chain::Features,
+        PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceInvocationMaskFeaturesHUAWEI<'static>>(
+        "VkPhysicalDeviceInvocationMaskFeaturesHUAWEI",
+        Chain::Features,
+        PHYSICAL_DEVICE_INVOCATION_MASK_FEATURES_HUAWEI,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceLegacyDitheringFeaturesEXT<'static>>(
+        "VkPhysicalDeviceLegacyDitheringFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_LEGACY_DITHERING_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceLinearColorAttachmentFeaturesNV<'static>>(
+        "VkPhysicalDeviceLinearColorAttachmentFeaturesNV",
+        Chain::Features,
+        PHYSICAL_DEVICE_LINEAR_COLOR_ATTACHMENT_FEATURES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceLineRasterizationFeaturesKHR<'static>>(
+        "VkPhysicalDeviceLineRasterizationFeaturesKHR",
+        Chain::Features,
+        PHYSICAL_DEVICE_LINE_RASTERIZATION_FEATURES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceMaintenance4Features<'static>>(
+        "VkPhysicalDeviceMaintenance4Features",
+        Chain::Features,
+        PHYSICAL_DEVICE_MAINTENANCE_4FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceMaintenance5FeaturesKHR<'static>>(
+        "VkPhysicalDeviceMaintenance5FeaturesKHR",
+        Chain::Features,
+        PHYSICAL_DEVICE_MAINTENANCE_5FEATURES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceMaintenance6FeaturesKHR<'static>>(
+        "VkPhysicalDeviceMaintenance6FeaturesKHR",
+        Chain::Features,
+        PHYSICAL_DEVICE_MAINTENANCE_6FEATURES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceMapMemoryPlacedFeaturesEXT<'static>>(
+        "VkPhysicalDeviceMapMemoryPlacedFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_MAP_MEMORY_PLACED_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceMemoryDecompressionFeaturesNV<'static>>(
+        "VkPhysicalDeviceMemoryDecompressionFeaturesNV",
+        Chain::Features,
+        PHYSICAL_DEVICE_MEMORY_DECOMPRESSION_FEATURES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceMemoryPriorityFeaturesEXT<'static>>(
+        "VkPhysicalDeviceMemoryPriorityFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_MEMORY_PRIORITY_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceMeshShaderFeaturesEXT<'static>>(
+        "VkPhysicalDeviceMeshShaderFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_MESH_SHADER_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceMeshShaderFeaturesNV<'static>>(
+        "VkPhysicalDeviceMeshShaderFeaturesNV",
+        Chain::Features,
+        PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceMultiDrawFeaturesEXT<'static>>(
+        "VkPhysicalDeviceMultiDrawFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_MULTI_DRAW_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceMultiviewFeatures<'static>>(
+        "VkPhysicalDeviceMultiviewFeatures",
+        Chain::Features,
+        PHYSICAL_DEVICE_MULTIVIEW_FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceMutableDescriptorTypeFeaturesEXT<'static>>(
+        "VkPhysicalDeviceMutableDescriptorTypeFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_MUTABLE_DESCRIPTOR_TYPE_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceNestedCommandBufferFeaturesEXT<'static>>(
+        "VkPhysicalDeviceNestedCommandBufferFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_NESTED_COMMAND_BUFFER_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceNonSeamlessCubeMapFeaturesEXT<'static>>(
+        "VkPhysicalDeviceNonSeamlessCubeMapFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_NON_SEAMLESS_CUBE_MAP_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceOpacityMicromapFeaturesEXT<'static>>(
+        "VkPhysicalDeviceOpacityMicromapFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_OPACITY_MICROMAP_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceOpticalFlowFeaturesNV<'static>>(
+        "VkPhysicalDeviceOpticalFlowFeaturesNV",
+        Chain::Features,
+        PHYSICAL_DEVICE_OPTICAL_FLOW_FEATURES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDevicePerformanceQueryFeaturesKHR<'static>>(
+        "VkPhysicalDevicePerformanceQueryFeaturesKHR",
+        Chain::Features,
+        PHYSICAL_DEVICE_PERFORMANCE_QUERY_FEATURES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDevicePerStageDescriptorSetFeaturesNV<'static>>(
+        "VkPhysicalDevicePerStageDescriptorSetFeaturesNV",
+        Chain::Features,
+        PHYSICAL_DEVICE_PER_STAGE_DESCRIPTOR_SET_FEATURES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDevicePipelinePropertiesFeaturesEXT<'static>>(
+        "VkPhysicalDevicePipelinePropertiesFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_PIPELINE_PROPERTIES_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDevicePipelineRobustnessFeaturesEXT<'static>>(
+        "VkPhysicalDevicePipelineRobustnessFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_PIPELINE_ROBUSTNESS_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDevicePortabilitySubsetFeaturesKHR<'static>>(
+        "VkPhysicalDevicePortabilitySubsetFeaturesKHR",
+        Chain::Features,
+        PHYSICAL_DEVICE_PORTABILITY_SUBSET_FEATURES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDevicePresentBarrierFeaturesNV<'static>>(
+        "VkPhysicalDevicePresentBarrierFeaturesNV",
+        Chain::Features,
+        PHYSICAL_DEVICE_PRESENT_BARRIER_FEATURES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDevicePresentIdFeaturesKHR<'static>>(
+        "VkPhysicalDevicePresentIdFeaturesKHR",
+        Chain::Features,
+        PHYSICAL_DEVICE_PRESENT_ID_FEATURES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDevicePresentWaitFeaturesKHR<'static>>(
+        "VkPhysicalDevicePresentWaitFeaturesKHR",
+        Chain::Features,
+        PHYSICAL_DEVICE_PRESENT_WAIT_FEATURES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDevicePrivateDataFeatures<'static>>(
+        "VkPhysicalDevicePrivateDataFeatures",
+        Chain::Features,
+        PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceProtectedMemoryFeatures<'static>>(
+        "VkPhysicalDeviceProtectedMemoryFeatures",
+        Chain::Features,
+        PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceProvokingVertexFeaturesEXT<'static>>(
+        "VkPhysicalDeviceProvokingVertexFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_PROVOKING_VERTEX_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceRawAccessChainsFeaturesNV<'static>>(
+        "VkPhysicalDeviceRawAccessChainsFeaturesNV",
+        Chain::Features,
+        PHYSICAL_DEVICE_RAW_ACCESS_CHAINS_FEATURES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceRayQueryFeaturesKHR<'static>>(
+        "VkPhysicalDeviceRayQueryFeaturesKHR",
+        Chain::Features,
+        PHYSICAL_DEVICE_RAY_QUERY_FEATURES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceRayTracingMaintenance1FeaturesKHR<'static>>(
+        "VkPhysicalDeviceRayTracingMaintenance1FeaturesKHR",
+        Chain::Features,
+        PHYSICAL_DEVICE_RAY_TRACING_MAINTENANCE_1FEATURES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceRayTracingMotionBlurFeaturesNV<'static>>(
+        "VkPhysicalDeviceRayTracingMotionBlurFeaturesNV",
+        Chain::Features,
+        PHYSICAL_DEVICE_RAY_TRACING_MOTION_BLUR_FEATURES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceRayTracingPipelineFeaturesKHR<'static>>(
+        "VkPhysicalDeviceRayTracingPipelineFeaturesKHR",
+        Chain::Features,
+        PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_FEATURES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceRayTracingValidationFeaturesNV<'static>>(
+        "VkPhysicalDeviceRayTracingValidationFeaturesNV",
+        Chain::Features,
+        PHYSICAL_DEVICE_RAY_TRACING_VALIDATION_FEATURES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceRenderPassStripedFeaturesARM<'static>>(
+        "VkPhysicalDeviceRenderPassStripedFeaturesARM",
+        Chain::Features,
+        PHYSICAL_DEVICE_RENDER_PASS_STRIPED_FEATURES_ARM,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceRGBA10X6FormatsFeaturesEXT<'static>>(
+        "VkPhysicalDeviceRGBA10X6FormatsFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_RGBA10X6FORMATS_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceRobustness2FeaturesEXT<'static>>(
+        "VkPhysicalDeviceRobustness2FeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_ROBUSTNESS_2FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceSamplerYcbcrConversionFeatures<'static>>(
+        "VkPhysicalDeviceSamplerYcbcrConversionFeatures",
+        Chain::Features,
+        PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceScalarBlockLayoutFeatures<'static>>(
+        "VkPhysicalDeviceScalarBlockLayoutFeatures",
+        Chain::Features,
+        PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceSchedulingControlsFeaturesARM<'static>>(
+        "VkPhysicalDeviceSchedulingControlsFeaturesARM",
+        Chain::Features,
+        PHYSICAL_DEVICE_SCHEDULING_CONTROLS_FEATURES_ARM,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderAtomicFloat2FeaturesEXT<'static>>(
+        "VkPhysicalDeviceShaderAtomicFloat2FeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_2FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderAtomicFloatFeaturesEXT<'static>>(
+        "VkPhysicalDeviceShaderAtomicFloatFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderAtomicInt64Features<'static>>(
+        "VkPhysicalDeviceShaderAtomicInt64Features",
+        Chain::Features,
+        PHYSICAL_DEVICE_SHADER_ATOMIC_INT_64FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderClockFeaturesKHR<'static>>(
+        "VkPhysicalDeviceShaderClockFeaturesKHR",
+        Chain::Features,
+        PHYSICAL_DEVICE_SHADER_CLOCK_FEATURES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderCoreBuiltinsFeaturesARM<'static>>(
+        "VkPhysicalDeviceShaderCoreBuiltinsFeaturesARM",
+        Chain::Features,
+        PHYSICAL_DEVICE_SHADER_CORE_BUILTINS_FEATURES_ARM,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderDrawParametersFeatures<'static>>(
+        "VkPhysicalDeviceShaderDrawParametersFeatures",
+        Chain::Features,
+        PHYSICAL_DEVICE_SHADER_DRAW_PARAMETERS_FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderEnqueueFeaturesAMDX<'static>>(
+        "VkPhysicalDeviceShaderEnqueueFeaturesAMDX",
+        Chain::Features,
+        PHYSICAL_DEVICE_SHADER_ENQUEUE_FEATURES_AMDX,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderExpectAssumeFeaturesKHR<'static>>(
+        "VkPhysicalDeviceShaderExpectAssumeFeaturesKHR",
+        Chain::Features,
+        PHYSICAL_DEVICE_SHADER_EXPECT_ASSUME_FEATURES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderFloat16Int8Features<'static>>(
+        "VkPhysicalDeviceShaderFloat16Int8Features",
+        Chain::Features,
+        PHYSICAL_DEVICE_SHADER_FLOAT_16INT_8FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderFloatControls2FeaturesKHR<'static>>(
+        "VkPhysicalDeviceShaderFloatControls2FeaturesKHR",
+        Chain::Features,
+        PHYSICAL_DEVICE_SHADER_FLOAT_CONTROLS_2FEATURES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderImageAtomicInt64FeaturesEXT<'static>>(
+        "VkPhysicalDeviceShaderImageAtomicInt64FeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_SHADER_IMAGE_ATOMIC_INT_64FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderImageFootprintFeaturesNV<'static>>(
+        "VkPhysicalDeviceShaderImageFootprintFeaturesNV",
+        Chain::Features,
+        PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderIntegerDotProductFeatures<'static>>(
+        "VkPhysicalDeviceShaderIntegerDotProductFeatures",
+        Chain::Features,
+        PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderModuleIdentifierFeaturesEXT<'static>>(
+        "VkPhysicalDeviceShaderModuleIdentifierFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_SHADER_MODULE_IDENTIFIER_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderObjectFeaturesEXT<'static>>(
+        "VkPhysicalDeviceShaderObjectFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_SHADER_OBJECT_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderQuadControlFeaturesKHR<'static>>(
+        "VkPhysicalDeviceShaderQuadControlFeaturesKHR",
+        Chain::Features,
+        PHYSICAL_DEVICE_SHADER_QUAD_CONTROL_FEATURES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderSMBuiltinsFeaturesNV<'static>>(
+        "VkPhysicalDeviceShaderSMBuiltinsFeaturesNV",
+        Chain::Features,
+        PHYSICAL_DEVICE_SHADER_SM_BUILTINS_FEATURES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderSubgroupRotateFeaturesKHR<'static>>(
+        "VkPhysicalDeviceShaderSubgroupRotateFeaturesKHR",
+        Chain::Features,
+        PHYSICAL_DEVICE_SHADER_SUBGROUP_ROTATE_FEATURES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderTerminateInvocationFeatures<'static>>(
+        "VkPhysicalDeviceShaderTerminateInvocationFeatures",
+        Chain::Features,
+        PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderTileImageFeaturesEXT<'static>>(
+        "VkPhysicalDeviceShaderTileImageFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_SHADER_TILE_IMAGE_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShadingRateImageFeaturesNV<'static>>(
+        "VkPhysicalDeviceShadingRateImageFeaturesNV",
+        Chain::Features,
+        PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceSubgroupSizeControlFeatures<'static>>(
+        "VkPhysicalDeviceSubgroupSizeControlFeatures",
+        Chain::Features,
+        PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceSubpassMergeFeedbackFeaturesEXT<'static>>(
+        "VkPhysicalDeviceSubpassMergeFeedbackFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_SUBPASS_MERGE_FEEDBACK_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceSubpassShadingFeaturesHUAWEI<'static>>(
+        "VkPhysicalDeviceSubpassShadingFeaturesHUAWEI",
+        Chain::Features,
+        PHYSICAL_DEVICE_SUBPASS_SHADING_FEATURES_HUAWEI,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceSwapchainMaintenance1FeaturesEXT<'static>>(
+        "VkPhysicalDeviceSwapchainMaintenance1FeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_SWAPCHAIN_MAINTENANCE_1FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceSynchronization2Features<'static>>(
+        "VkPhysicalDeviceSynchronization2Features",
+        Chain::Features,
+        PHYSICAL_DEVICE_SYNCHRONIZATION_2FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceTexelBufferAlignmentFeaturesEXT<'static>>(
+        "VkPhysicalDeviceTexelBufferAlignmentFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceTextureCompressionASTCHDRFeatures<'static>>(
+        "VkPhysicalDeviceTextureCompressionASTCHDRFeatures",
+        Chain::Features,
+        PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTCHDR_FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceTilePropertiesFeaturesQCOM<'static>>(
+        "VkPhysicalDeviceTilePropertiesFeaturesQCOM",
+        Chain::Features,
+        PHYSICAL_DEVICE_TILE_PROPERTIES_FEATURES_QCOM,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceTimelineSemaphoreFeatures<'static>>(
+        "VkPhysicalDeviceTimelineSemaphoreFeatures",
+        Chain::Features,
+        PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceTransformFeedbackFeaturesEXT<'static>>(
+        "VkPhysicalDeviceTransformFeedbackFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceVariablePointersFeatures<'static>>(
+        "VkPhysicalDeviceVariablePointersFeatures",
+        Chain::Features,
+        PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceVertexAttributeDivisorFeaturesKHR<'static>>(
+        "VkPhysicalDeviceVertexAttributeDivisorFeaturesKHR",
+        Chain::Features,
+        PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceVideoMaintenance1FeaturesKHR<'static>>(
+        "VkPhysicalDeviceVideoMaintenance1FeaturesKHR",
+        Chain::Features,
+        PHYSICAL_DEVICE_VIDEO_MAINTENANCE_1FEATURES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceVulkan11Features<'static>>(
+        "VkPhysicalDeviceVulkan11Features",
+        Chain::Features,
+        PHYSICAL_DEVICE_VULKAN_11FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceVulkan12Features<'static>>(
+        "VkPhysicalDeviceVulkan12Features",
+        Chain::Features,
+        PHYSICAL_DEVICE_VULKAN_12FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceVulkan13Features<'static>>(
+        "VkPhysicalDeviceVulkan13Features",
+        Chain::Features,
+        PHYSICAL_DEVICE_VULKAN_13FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceVulkanMemoryModelFeatures<'static>>(
+        "VkPhysicalDeviceVulkanMemoryModelFeatures",
+        Chain::Features,
+        PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceYcbcr2Plane444FormatsFeaturesEXT<'static>>(
+        "VkPhysicalDeviceYcbcr2Plane444FormatsFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_YCBCR_2PLANE_444FORMATS_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceYcbcrDegammaFeaturesQCOM<'static>>(
+        "VkPhysicalDeviceYcbcrDegammaFeaturesQCOM",
+        Chain::Features,
+        PHYSICAL_DEVICE_YCBCR_DEGAMMA_FEATURES_QCOM,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceYcbcrImageArraysFeaturesEXT<'static>>(
+        "VkPhysicalDeviceYcbcrImageArraysFeaturesEXT",
+        Chain::Features,
+        PHYSICAL_DEVICE_YCBCR_IMAGE_ARRAYS_FEATURES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceProperties2<'static>>(
+        "VkPhysicalDeviceProperties2",
+        Chain::Properties,
+        PHYSICAL_DEVICE_PROPERTIES_2,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceCooperativeMatrixPropertiesKHR<'static>>(
+        "VkPhysicalDeviceCooperativeMatrixPropertiesKHR",
+        Chain::Properties,
+        PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceCooperativeMatrixPropertiesNV<'static>>(
+        "VkPhysicalDeviceCooperativeMatrixPropertiesNV",
+        Chain::Properties,
+        PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceCopyMemoryIndirectPropertiesNV<'static>>(
+        "VkPhysicalDeviceCopyMemoryIndirectPropertiesNV",
+        Chain::Properties,
+        PHYSICAL_DEVICE_COPY_MEMORY_INDIRECT_PROPERTIES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceCudaKernelLaunchPropertiesNV<'static>>(
+        "VkPhysicalDeviceCudaKernelLaunchPropertiesNV",
+        Chain::Properties,
+        PHYSICAL_DEVICE_CUDA_KERNEL_LAUNCH_PROPERTIES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceCustomBorderColorPropertiesEXT<'static>>(
+        "VkPhysicalDeviceCustomBorderColorPropertiesEXT",
+        Chain::Properties,
+        PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_PROPERTIES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceDepthStencilResolveProperties<'static>>(
+        "VkPhysicalDeviceDepthStencilResolveProperties",
+        Chain::Properties,
+        PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static>>(
+        "VkPhysicalDeviceDescriptorBufferPropertiesEXT",
+        Chain::Properties,
+        PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_PROPERTIES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceDescriptorIndexingProperties<'static>>(
+        "VkPhysicalDeviceDescriptorIndexingProperties",
+        Chain::Properties,
+        PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceDiscardRectanglePropertiesEXT<'static>>(
+        "VkPhysicalDeviceDiscardRectanglePropertiesEXT",
+        Chain::Properties,
+        PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceDriverProperties<'static>>(
+        "VkPhysicalDeviceDriverProperties",
+        Chain::Properties,
+        PHYSICAL_DEVICE_DRIVER_PROPERTIES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceDrmPropertiesEXT<'static>>(
+        "VkPhysicalDeviceDrmPropertiesEXT",
+        Chain::Properties,
+        PHYSICAL_DEVICE_DRM_PROPERTIES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceExternalMemoryHostPropertiesEXT<'static>>(
+        "VkPhysicalDeviceExternalMemoryHostPropertiesEXT",
+        Chain::Properties,
+        PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceFloatControlsProperties<'static>>(
+        "VkPhysicalDeviceFloatControlsProperties",
+        Chain::Properties,
+        PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceFragmentDensityMapPropertiesEXT<'static>>(
+        "VkPhysicalDeviceFragmentDensityMapPropertiesEXT",
+        Chain::Properties,
+        PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_PROPERTIES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceHostImageCopyPropertiesEXT<'static>>(
+        "VkPhysicalDeviceHostImageCopyPropertiesEXT",
+        Chain::Properties,
+        PHYSICAL_DEVICE_HOST_IMAGE_COPY_PROPERTIES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceIDProperties<'static>>(
+        "VkPhysicalDeviceIDProperties",
+        Chain::Properties,
+        PHYSICAL_DEVICE_ID_PROPERTIES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceImageProcessing2PropertiesQCOM<'static>>(
+        "VkPhysicalDeviceImageProcessing2PropertiesQCOM",
+        Chain::Properties,
+        PHYSICAL_DEVICE_IMAGE_PROCESSING_2PROPERTIES_QCOM,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceImageProcessingPropertiesQCOM<'static>>(
+        "VkPhysicalDeviceImageProcessingPropertiesQCOM",
+        Chain::Properties,
+        PHYSICAL_DEVICE_IMAGE_PROCESSING_PROPERTIES_QCOM,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceInlineUniformBlockProperties<'static>>(
+        "VkPhysicalDeviceInlineUniformBlockProperties",
+        Chain::Properties,
+        PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceLayeredDriverPropertiesMSFT<'static>>(
+        "VkPhysicalDeviceLayeredDriverPropertiesMSFT",
+        Chain::Properties,
+        PHYSICAL_DEVICE_LAYERED_DRIVER_PROPERTIES_MSFT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceLineRasterizationPropertiesKHR<'static>>(
+        "VkPhysicalDeviceLineRasterizationPropertiesKHR",
+        Chain::Properties,
+        PHYSICAL_DEVICE_LINE_RASTERIZATION_PROPERTIES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceMaintenance3Properties<'static>>(
+        "VkPhysicalDeviceMaintenance3Properties",
+        Chain::Properties,
+        PHYSICAL_DEVICE_MAINTENANCE_3PROPERTIES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceMaintenance4Properties<'static>>(
+        "VkPhysicalDeviceMaintenance4Properties",
+        Chain::Properties,
+        PHYSICAL_DEVICE_MAINTENANCE_4PROPERTIES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceMaintenance5PropertiesKHR<'static>>(
+        "VkPhysicalDeviceMaintenance5PropertiesKHR",
+        Chain::Properties,
+        PHYSICAL_DEVICE_MAINTENANCE_5PROPERTIES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceMaintenance6PropertiesKHR<'static>>(
+        "VkPhysicalDeviceMaintenance6PropertiesKHR",
+        Chain::Properties,
+        PHYSICAL_DEVICE_MAINTENANCE_6PROPERTIES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceMapMemoryPlacedPropertiesEXT<'static>>(
+        "VkPhysicalDeviceMapMemoryPlacedPropertiesEXT",
+        Chain::Properties,
+        PHYSICAL_DEVICE_MAP_MEMORY_PLACED_PROPERTIES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceMemoryDecompressionPropertiesNV<'static>>(
+        "VkPhysicalDeviceMemoryDecompressionPropertiesNV",
+        Chain::Properties,
+        PHYSICAL_DEVICE_MEMORY_DECOMPRESSION_PROPERTIES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceMeshShaderPropertiesEXT<'static>>(
+        "VkPhysicalDeviceMeshShaderPropertiesEXT",
+        Chain::Properties,
+        PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceMeshShaderPropertiesNV<'static>>(
+        "VkPhysicalDeviceMeshShaderPropertiesNV",
+        Chain::Properties,
+        PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceMultiDrawPropertiesEXT<'static>>(
+        "VkPhysicalDeviceMultiDrawPropertiesEXT",
+        Chain::Properties,
+        PHYSICAL_DEVICE_MULTI_DRAW_PROPERTIES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceMultiviewProperties<'static>>(
+        "VkPhysicalDeviceMultiviewProperties",
+        Chain::Properties,
+        PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceOpacityMicromapPropertiesEXT<'static>>(
+        "VkPhysicalDeviceOpacityMicromapPropertiesEXT",
+        Chain::Properties,
+        PHYSICAL_DEVICE_OPACITY_MICROMAP_PROPERTIES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceOpticalFlowPropertiesNV<'static>>(
+        "VkPhysicalDeviceOpticalFlowPropertiesNV",
+        Chain::Properties,
+        PHYSICAL_DEVICE_OPTICAL_FLOW_PROPERTIES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDevicePCIBusInfoPropertiesEXT<'static>>(
+        "VkPhysicalDevicePCIBusInfoPropertiesEXT",
+        Chain::Properties,
+        PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDevicePerformanceQueryPropertiesKHR<'static>>(
+        "VkPhysicalDevicePerformanceQueryPropertiesKHR",
+        Chain::Properties,
+        PHYSICAL_DEVICE_PERFORMANCE_QUERY_PROPERTIES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDevicePipelineRobustnessPropertiesEXT<'static>>(
+        "VkPhysicalDevicePipelineRobustnessPropertiesEXT",
+        Chain::Properties,
+        PHYSICAL_DEVICE_PIPELINE_ROBUSTNESS_PROPERTIES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDevicePointClippingProperties<'static>>(
+        "VkPhysicalDevicePointClippingProperties",
+        Chain::Properties,
+        PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES,
+    ),
+    StructureInfo::new::<vk::PhysicalDevicePortabilitySubsetPropertiesKHR<'static>>(
+        "VkPhysicalDevicePortabilitySubsetPropertiesKHR",
+        Chain::Properties,
+        PHYSICAL_DEVICE_PORTABILITY_SUBSET_PROPERTIES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceProtectedMemoryProperties<'static>>(
+        "VkPhysicalDeviceProtectedMemoryProperties",
+        Chain::Properties,
+        PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceProvokingVertexPropertiesEXT<'static>>(
+        "VkPhysicalDeviceProvokingVertexPropertiesEXT",
+        Chain::Properties,
+        PHYSICAL_DEVICE_PROVOKING_VERTEX_PROPERTIES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDevicePushDescriptorPropertiesKHR<'static>>(
+        "VkPhysicalDevicePushDescriptorPropertiesKHR",
+        Chain::Properties,
+        PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceRayTracingPipelinePropertiesKHR<'static>>(
+        "VkPhysicalDeviceRayTracingPipelinePropertiesKHR",
+        Chain::Properties,
+        PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_PROPERTIES_KHR,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceRayTracingPropertiesNV<'static>>(
+        "VkPhysicalDeviceRayTracingPropertiesNV",
+        Chain::Properties,
+        PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceRenderPassStripedPropertiesARM<'static>>(
+        "VkPhysicalDeviceRenderPassStripedPropertiesARM",
+        Chain::Properties,
+        PHYSICAL_DEVICE_RENDER_PASS_STRIPED_PROPERTIES_ARM,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceRobustness2PropertiesEXT<'static>>(
+        "VkPhysicalDeviceRobustness2PropertiesEXT",
+        Chain::Properties,
+        PHYSICAL_DEVICE_ROBUSTNESS_2PROPERTIES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceSampleLocationsPropertiesEXT<'static>>(
+        "VkPhysicalDeviceSampleLocationsPropertiesEXT",
+        Chain::Properties,
+        PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceSamplerFilterMinmaxProperties<'static>>(
+        "VkPhysicalDeviceSamplerFilterMinmaxProperties",
+        Chain::Properties,
+        PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceSchedulingControlsPropertiesARM<'static>>(
+        "VkPhysicalDeviceSchedulingControlsPropertiesARM",
+        Chain::Properties,
+        PHYSICAL_DEVICE_SCHEDULING_CONTROLS_PROPERTIES_ARM,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderCoreBuiltinsPropertiesARM<'static>>(
+        "VkPhysicalDeviceShaderCoreBuiltinsPropertiesARM",
+        Chain::Properties,
+        PHYSICAL_DEVICE_SHADER_CORE_BUILTINS_PROPERTIES_ARM,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderCoreProperties2AMD<'static>>(
+        "VkPhysicalDeviceShaderCoreProperties2AMD",
+        Chain::Properties,
+        PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_2AMD,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderCorePropertiesAMD<'static>>(
+        "VkPhysicalDeviceShaderCorePropertiesAMD",
+        Chain::Properties,
+        PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderCorePropertiesARM<'static>>(
+        "VkPhysicalDeviceShaderCorePropertiesARM",
+        Chain::Properties,
+        PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_ARM,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderEnqueuePropertiesAMDX<'static>>(
+        "VkPhysicalDeviceShaderEnqueuePropertiesAMDX",
+        Chain::Properties,
+        PHYSICAL_DEVICE_SHADER_ENQUEUE_PROPERTIES_AMDX,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderObjectPropertiesEXT<'static>>(
+        "VkPhysicalDeviceShaderObjectPropertiesEXT",
+        Chain::Properties,
+        PHYSICAL_DEVICE_SHADER_OBJECT_PROPERTIES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderSMBuiltinsPropertiesNV<'static>>(
+        "VkPhysicalDeviceShaderSMBuiltinsPropertiesNV",
+        Chain::Properties,
+        PHYSICAL_DEVICE_SHADER_SM_BUILTINS_PROPERTIES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShaderTileImagePropertiesEXT<'static>>(
+        "VkPhysicalDeviceShaderTileImagePropertiesEXT",
+        Chain::Properties,
+        PHYSICAL_DEVICE_SHADER_TILE_IMAGE_PROPERTIES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceShadingRateImagePropertiesNV<'static>>(
+        "VkPhysicalDeviceShadingRateImagePropertiesNV",
+        Chain::Properties,
+        PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceSubgroupProperties<'static>>(
+        "VkPhysicalDeviceSubgroupProperties",
+        Chain::Properties,
+        PHYSICAL_DEVICE_SUBGROUP_PROPERTIES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceSubgroupSizeControlProperties<'static>>(
+        "VkPhysicalDeviceSubgroupSizeControlProperties",
+        Chain::Properties,
+        PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceSubpassShadingPropertiesHUAWEI<'static>>(
+        "VkPhysicalDeviceSubpassShadingPropertiesHUAWEI",
+        Chain::Properties,
+        PHYSICAL_DEVICE_SUBPASS_SHADING_PROPERTIES_HUAWEI,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceTexelBufferAlignmentProperties<'static>>(
+        "VkPhysicalDeviceTexelBufferAlignmentProperties",
+        Chain::Properties,
+        PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_PROPERTIES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceTimelineSemaphoreProperties<'static>>(
+        "VkPhysicalDeviceTimelineSemaphoreProperties",
+        Chain::Properties,
+        PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceTransformFeedbackPropertiesEXT<'static>>(
+        "VkPhysicalDeviceTransformFeedbackPropertiesEXT",
+        Chain::Properties,
+        PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceVulkan11Properties<'static>>(
+        "VkPhysicalDeviceVulkan11Properties",
+        Chain::Properties,
+        PHYSICAL_DEVICE_VULKAN_11PROPERTIES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceVulkan12Properties<'static>>(
+        "VkPhysicalDeviceVulkan12Properties",
+        Chain::Properties,
+        PHYSICAL_DEVICE_VULKAN_12PROPERTIES,
+    ),
+    StructureInfo::new::<vk::PhysicalDeviceVulkan13Properties<'static>>(
+        "VkPhysicalDeviceVulkan13Properties",
+        Chain::Properties,
+        PHYSICAL_DEVICE_VULKAN_13PROPERTIES,
+    ),
+    StructureInfo::new::<vk::FormatProperties2<'static>>(
+        "VkFormatProperties2",
+        Chain::Format,
+        FORMAT_PROPERTIES_2,
+    ),
+    StructureInfo::new::<vk::DrmFormatModifierPropertiesList2EXT<'static>>(
+        "VkDrmFormatModifierPropertiesList2EXT",
+        Chain::Format,
+        DRM_FORMAT_MODIFIER_PROPERTIES_LIST_2EXT,
+    ),
+    StructureInfo::new::<vk::DrmFormatModifierPropertiesListEXT<'static>>(
+        "VkDrmFormatModifierPropertiesListEXT",
+        Chain::Format,
+        DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT,
+    ),
+    StructureInfo::new::<vk::FormatProperties3<'static>>(
+        "VkFormatProperties3",
+        Chain::Format,
+        FORMAT_PROPERTIES_3,
+    ),
+    StructureInfo::new::<vk::SubpassResolvePerformanceQueryEXT<'static>>(
+        "VkSubpassResolvePerformanceQueryEXT",
+        Chain::Format,
+        SUBPASS_RESOLVE_PERFORMANCE_QUERY_EXT,
+    ),
+    StructureInfo::new::<vk::QueueFamilyProperties2<'static>>(
+        "VkQueueFamilyProperties2",
+        Chain::QueueFamily,
+        QUEUE_FAMILY_PROPERTIES_2,
+    ),
+    StructureInfo::new::<vk::QueueFamilyCheckpointProperties2NV<'static>>(
+        "VkQueueFamilyCheckpointProperties2NV",
+        Chain::QueueFamily,
+        QUEUE_FAMILY_CHECKPOINT_PROPERTIES_2NV,
+    ),
+    StructureInfo::new::<vk::QueueFamilyCheckpointPropertiesNV<'static>>(
+        "VkQueueFamilyCheckpointPropertiesNV",
+        Chain::QueueFamily,
+        QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV,
+    ),
+    StructureInfo::new::<vk::QueueFamilyGlobalPriorityPropertiesKHR<'static>>(
+        "VkQueueFamilyGlobalPriorityPropertiesKHR",
+        Chain::QueueFamily,
+        QUEUE_FAMILY_GLOBAL_PRIORITY_PROPERTIES_KHR,
+    ),
+    StructureInfo::new::<vk::QueueFamilyQueryResultStatusPropertiesKHR<'static>>(
+        "VkQueueFamilyQueryResultStatusPropertiesKHR",
+        Chain::QueueFamily,
+        QUEUE_FAMILY_QUERY_RESULT_STATUS_PROPERTIES_KHR,
+    ),
+    StructureInfo::new::<vk::QueueFamilyVideoPropertiesKHR<'static>>(
+        "VkQueueFamilyVideoPropertiesKHR",
+        Chain::QueueFamily,
+        QUEUE_FAMILY_VIDEO_PROPERTIES_KHR,
+    ),
+    StructureInfo::new::<vk::VideoProfileInfoKHR<'static>>(
+        "VkVideoProfileInfoKHR",
+        Chain::VideoProfile,
+        VIDEO_PROFILE_INFO_KHR,
+    ),
+    StructureInfo::new::<vk::VideoDecodeAV1ProfileInfoKHR<'static>>(
+        "VkVideoDecodeAV1ProfileInfoKHR",
+        Chain::VideoProfile,
+        VIDEO_DECODE_AV1PROFILE_INFO_KHR,
+    ),
+    StructureInfo::new::<vk::VideoDecodeH264ProfileInfoKHR<'static>>(
+        "VkVideoDecodeH264ProfileInfoKHR",
+        Chain::VideoProfile,
+        VIDEO_DECODE_H264PROFILE_INFO_KHR,
+    ),
+    StructureInfo::new::<vk::VideoDecodeH265ProfileInfoKHR<'static>>(
+        "VkVideoDecodeH265ProfileInfoKHR",
+        Chain::VideoProfile,
+        VIDEO_DECODE_H265PROFILE_INFO_KHR,
+    ),
+    StructureInfo::new::<vk::VideoDecodeUsageInfoKHR<'static>>(
+        "VkVideoDecodeUsageInfoKHR",
+        Chain::VideoProfile,
+        VIDEO_DECODE_USAGE_INFO_KHR,
+    ),
+    StructureInfo::new::<vk::VideoEncodeH264ProfileInfoKHR<'static>>(
+        "VkVideoEncodeH264ProfileInfoKHR",
+        Chain::VideoProfile,
+        VIDEO_ENCODE_H264PROFILE_INFO_KHR,
+    ),
+    StructureInfo::new::<vk::VideoEncodeH265ProfileInfoKHR<'static>>(
+        "VkVideoEncodeH265ProfileInfoKHR",
+        Chain::VideoProfile,
+        VIDEO_ENCODE_H265PROFILE_INFO_KHR,
+    ),
+    StructureInfo::new::<vk::VideoEncodeUsageInfoKHR<'static>>(
+        "VkVideoEncodeUsageInfoKHR",
+        Chain::VideoProfile,
+        VIDEO_ENCODE_USAGE_INFO_KHR,
+    ),
+    StructureInfo::new::<vk::VideoCapabilitiesKHR<'static>>(
+        "VkVideoCapabilitiesKHR",
+        Chain::VideoCapabilities,
+        VIDEO_CAPABILITIES_KHR,
+    ),
+    StructureInfo::new::<vk::VideoDecodeAV1CapabilitiesKHR<'static>>(
+        "VkVideoDecodeAV1CapabilitiesKHR",
+        Chain::VideoCapabilities,
+        VIDEO_DECODE_AV1CAPABILITIES_KHR,
+    ),
+    StructureInfo::new::<vk::VideoDecodeCapabilitiesKHR<'static>>(
+        "VkVideoDecodeCapabilitiesKHR",
+        Chain::VideoCapabilities,
+        VIDEO_DECODE_CAPABILITIES_KHR,
+    ),
+    StructureInfo::new::<vk::VideoDecodeH264CapabilitiesKHR<'static>>(
+        "VkVideoDecodeH264CapabilitiesKHR",
+        Chain::VideoCapabilities,
+        VIDEO_DECODE_H264CAPABILITIES_KHR,
+    ),
+    StructureInfo::new::<vk::VideoDecodeH265CapabilitiesKHR<'static>>(
+        "VkVideoDecodeH265CapabilitiesKHR",
+        Chain::VideoCapabilities,
+        VIDEO_DECODE_H265CAPABILITIES_KHR,
+    ),
+    StructureInfo::new::<vk::VideoEncodeCapabilitiesKHR<'static>>(
+        "VkVideoEncodeCapabilitiesKHR",
+        Chain::VideoCapabilities,
+        VIDEO_ENCODE_CAPABILITIES_KHR,
+    ),
+    StructureInfo::new::<vk::VideoEncodeH264CapabilitiesKHR<'static>>(
+        "VkVideoEncodeH264CapabilitiesKHR",
+        Chain::VideoCapabilities,
+        VIDEO_ENCODE_H264CAPABILITIES_KHR,
+    ),
+    StructureInfo::new::<vk::VideoEncodeH265CapabilitiesKHR<'static>>(
+        "VkVideoEncodeH265CapabilitiesKHR",
+        Chain::VideoCapabilities,
+        VIDEO_ENCODE_H265CAPABILITIES_KHR,
+    ),
+    StructureInfo::new::<vk::VideoFormatPropertiesKHR<'static>>(
+        "VkVideoFormatPropertiesKHR",
+        Chain::VideoFormat,
+        VIDEO_FORMAT_PROPERTIES_KHR,
+    ),
+];
+
+const PHYSICAL_DEVICE_FEATURES: &[Member] = members!(vk::PhysicalDeviceFeatures {
+    robust_buffer_access: MemberType::Bool32,
+    full_draw_index_uint32: MemberType::Bool32,
+    image_cube_array: MemberType::Bool32,
+    independent_blend: MemberType::Bool32,
+    geometry_shader: MemberType::Bool32,
+    tessellation_shader: MemberType::Bool32,
+    sample_rate_shading: MemberType::Bool32,
+    dual_src_blend: MemberType::Bool32,
+    logic_op: MemberType::Bool32,
+    multi_draw_indirect: MemberType::Bool32,
+    draw_indirect_first_instance: MemberType::Bool32,
+    depth_clamp: MemberType::Bool32,
+    depth_bias_clamp: MemberType::Bool32,
+    fill_mode_non_solid: MemberType::Bool32,
+    depth_bounds: MemberType::Bool32,
+    wide_lines: MemberType::Bool32,
+    large_points: MemberType::Bool32,
+    alpha_to_one: MemberType::Bool32,
+    multi_viewport: MemberType::Bool32,
+    sampler_anisotropy: MemberType::Bool32,
+    texture_compression_etc2: MemberType::Bool32,
+    texture_compression_astc_ldr: MemberType::Bool32,
+    texture_compression_bc: MemberType::Bool32,
+    occlusion_query_precise: MemberType::Bool32,
+    pipeline_statistics_query: MemberType::Bool32,
+    vertex_pipeline_stores_and_atomics: MemberType::Bool32,
+    fragment_stores_and_atomics: MemberType::Bool32,
+    shader_tessellation_and_geometry_point_size: MemberType::Bool32,
+    shader_image_gather_extended: MemberType::Bool32,
+    shader_storage_image_extended_formats: MemberType::Bool32,
+    shader_storage_image_multisample: MemberType::Bool32,
+    shader_storage_image_read_without_format: MemberType::Bool32,
+    shader_storage_image_write_without_format: MemberType::Bool32,
+    shader_uniform_buffer_array_dynamic_indexing: MemberType::Bool32,
+    shader_sampled_image_array_dynamic_indexing: MemberType::Bool32,
+    shader_storage_buffer_array_dynamic_indexing: MemberType::Bool32,
+    shader_storage_image_array_dynamic_indexing: MemberType::Bool32,
+    shader_clip_distance: MemberType::Bool32,
+    shader_cull_distance: MemberType::Bool32,
+    shader_float64: MemberType::Bool32,
+    shader_int64: MemberType::Bool32,
+    shader_int16: MemberType::Bool32,
+    shader_resource_residency: MemberType::Bool32,
+    shader_resource_min_lod: MemberType::Bool32,
+    sparse_binding: MemberType::Bool32,
+    sparse_residency_buffer: MemberType::Bool32,
+    sparse_residency_image2_d: MemberType::Bool32,
+    sparse_residency_image3_d: MemberType::Bool32,
+    sparse_residency2_samples: MemberType::Bool32,
+    sparse_residency4_samples: MemberType::Bool32,
+    sparse_residency8_samples: MemberType::Bool32,
+    sparse_residency16_samples: MemberType::Bool32,
+    sparse_residency_aliased: MemberType::Bool32,
+    variable_multisample_rate: MemberType::Bool32,
+    inherited_queries: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_FEATURES_2: &[Member] = members!(vk::PhysicalDeviceFeatures2<'static> {
+    features: MemberType::Struct("VkPhysicalDeviceFeatures", PHYSICAL_DEVICE_FEATURES),
+});
+
+const PHYSICAL_DEVICE_16BIT_STORAGE_FEATURES: &[Member] = members!(vk::PhysicalDevice16BitStorageFeatures<'static> {
+    storage_buffer16_bit_access: MemberType::Bool32,
+    uniform_and_storage_buffer16_bit_access: MemberType::Bool32,
+    storage_push_constant16: MemberType::Bool32,
+    storage_input_output16: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_4444FORMATS_FEATURES_EXT: &[Member] = members!(vk::PhysicalDevice4444FormatsFeaturesEXT<'static> {
+    format_a4r4g4b4: MemberType::Bool32,
+    format_a4b4g4r4: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_8BIT_STORAGE_FEATURES: &[Member] = members!(vk::PhysicalDevice8BitStorageFeatures<'static> {
+    storage_buffer8_bit_access: MemberType::Bool32,
+    uniform_and_storage_buffer8_bit_access: MemberType::Bool32,
+    storage_push_constant8: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_ACCELERATION_STRUCTURE_FEATURES_KHR: &[Member] = members!(vk::PhysicalDeviceAccelerationStructureFeaturesKHR<'static> {
+    acceleration_structure: MemberType::Bool32,
+    acceleration_structure_capture_replay: MemberType::Bool32,
+    acceleration_structure_indirect_build: MemberType::Bool32,
+    acceleration_structure_host_commands: MemberType::Bool32,
+    descriptor_binding_acceleration_structure_update_after_bind: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_ADDRESS_BINDING_REPORT_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceAddressBindingReportFeaturesEXT<'static> {
+    report_address_binding: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_AMIGO_PROFILING_FEATURES_SEC: &[Member] = members!(vk::PhysicalDeviceAmigoProfilingFeaturesSEC<'static> {
+    amigo_profiling: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_ASTC_DECODE_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceASTCDecodeFeaturesEXT<'static> {
+    decode_mode_shared_exponent: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_BLEND_OPERATION_ADVANCED_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceBlendOperationAdvancedFeaturesEXT<'static> {
+    advanced_blend_coherent_operations: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_BORDER_COLOR_SWIZZLE_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceBorderColorSwizzleFeaturesEXT<'static> {
+    border_color_swizzle: MemberType::Bool32,
+    border_color_swizzle_from_image: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES: &[Member] = members!(vk::PhysicalDeviceBufferDeviceAddressFeatures<'static> {
+    buffer_device_address: MemberType::Bool32,
+    buffer_device_address_capture_replay: MemberType::Bool32,
+    buffer_device_address_multi_device: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_BUFFER_DEVICE_ADDRESS_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceBufferDeviceAddressFeaturesEXT<'static> {
+    buffer_device_address: MemberType::Bool32,
+    buffer_device_address_capture_replay: MemberType::Bool32,
+    buffer_device_address_multi_device: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_COHERENT_MEMORY_FEATURES_AMD: &[Member] = members!(vk::PhysicalDeviceCoherentMemoryFeaturesAMD<'static> {
+    device_coherent_memory: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_COLOR_WRITE_ENABLE_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceColorWriteEnableFeaturesEXT<'static> {
+    color_write_enable: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_CONDITIONAL_RENDERING_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceConditionalRenderingFeaturesEXT<'static> {
+    conditional_rendering: MemberType::Bool32,
+    inherited_conditional_rendering: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_KHR: &[Member] = members!(vk::PhysicalDeviceCooperativeMatrixFeaturesKHR<'static> {
+    cooperative_matrix: MemberType::Bool32,
+    cooperative_matrix_robust_buffer_access: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_COOPERATIVE_MATRIX_FEATURES_NV: &[Member] = members!(vk::PhysicalDeviceCooperativeMatrixFeaturesNV<'static> {
+    cooperative_matrix: MemberType::Bool32,
+    cooperative_matrix_robust_buffer_access: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_COPY_MEMORY_INDIRECT_FEATURES_NV: &[Member] = members!(vk::PhysicalDeviceCopyMemoryIndirectFeaturesNV<'static> {
+    indirect_copy: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_CORNER_SAMPLED_IMAGE_FEATURES_NV: &[Member] = members!(vk::PhysicalDeviceCornerSampledImageFeaturesNV<'static> {
+    corner_sampled_image: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_COVERAGE_REDUCTION_MODE_FEATURES_NV: &[Member] = members!(vk::PhysicalDeviceCoverageReductionModeFeaturesNV<'static> {
+    coverage_reduction_mode: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_CUBIC_CLAMP_FEATURES_QCOM: &[Member] = members!(vk::PhysicalDeviceCubicClampFeaturesQCOM<'static> {
+    cubic_range_clamp: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_CUBIC_WEIGHTS_FEATURES_QCOM: &[Member] = members!(vk::PhysicalDeviceCubicWeightsFeaturesQCOM<'static> {
+    selectable_cubic_weights: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_CUDA_KERNEL_LAUNCH_FEATURES_NV: &[Member] = members!(vk::PhysicalDeviceCudaKernelLaunchFeaturesNV<'static> {
+    cuda_kernel_launch_features: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceCustomBorderColorFeaturesEXT<'static> {
+    custom_border_colors: MemberType::Bool32,
+    custom_border_color_without_format: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_DEPTH_BIAS_CONTROL_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceDepthBiasControlFeaturesEXT<'static> {
+    depth_bias_control: MemberType::Bool32,
+    least_representable_value_force_unorm_representation: MemberType::Bool32,
+    float_representation: MemberType::Bool32,
+    depth_bias_exact: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_DEPTH_CLAMP_ZERO_ONE_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceDepthClampZeroOneFeaturesEXT<'static> {
+    depth_clamp_zero_one: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_DEPTH_CLIP_CONTROL_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceDepthClipControlFeaturesEXT<'static> {
+    depth_clip_control: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_DEPTH_CLIP_ENABLE_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceDepthClipEnableFeaturesEXT<'static> {
+    depth_clip_enable: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceDescriptorBufferFeaturesEXT<'static> {
+    descriptor_buffer: MemberType::Bool32,
+    descriptor_buffer_capture_replay: MemberType::Bool32,
+    descriptor_buffer_image_layout_ignored: MemberType::Bool32,
+    descriptor_buffer_push_descriptors: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_FEATURES: &[Member] = members!(vk::PhysicalDeviceDescriptorIndexingFeatures<'static> {
+    shader_input_attachment_array_dynamic_indexing: MemberType::Bool32,
+    shader_uniform_texel_buffer_array_dynamic_indexing: MemberType::Bool32,
+    shader_storage_texel_buffer_array_dynamic_indexing: MemberType::Bool32,
+    shader_uniform_buffer_array_non_uniform_indexing: MemberType::Bool32,
+    shader_sampled_image_array_non_uniform_indexing: MemberType::Bool32,
+    shader_storage_buffer_array_non_uniform_indexing: MemberType::Bool32,
+    shader_storage_image_array_non_uniform_indexing: MemberType::Bool32,
+    shader_input_attachment_array_non_uniform_indexing: MemberType::Bool32,
+    shader_uniform_texel_buffer_array_non_uniform_indexing: MemberType::Bool32,
+    shader_storage_texel_buffer_array_non_uniform_indexing: MemberType::Bool32,
+    descriptor_binding_uniform_buffer_update_after_bind: MemberType::Bool32,
+    descriptor_binding_sampled_image_update_after_bind: MemberType::Bool32,
+    descriptor_binding_storage_image_update_after_bind: MemberType::Bool32,
+    descriptor_binding_storage_buffer_update_after_bind: MemberType::Bool32,
+    descriptor_binding_uniform_texel_buffer_update_after_bind: MemberType::Bool32,
+    descriptor_binding_storage_texel_buffer_update_after_bind: MemberType::Bool32,
+    descriptor_binding_update_unused_while_pending: MemberType::Bool32,
+    descriptor_binding_partially_bound: MemberType::Bool32,
+    descriptor_binding_variable_descriptor_count: MemberType::Bool32,
+    runtime_descriptor_array: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_DEVICE_GENERATED_COMMANDS_FEATURES_NV: &[Member] = members!(vk::PhysicalDeviceDeviceGeneratedCommandsFeaturesNV<'static> {
+    device_generated_commands: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_DEVICE_MEMORY_REPORT_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceDeviceMemoryReportFeaturesEXT<'static> {
+    device_memory_report: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_DIAGNOSTICS_CONFIG_FEATURES_NV: &[Member] = members!(vk::PhysicalDeviceDiagnosticsConfigFeaturesNV<'static> {
+    diagnostics_config: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_DISPLACEMENT_MICROMAP_FEATURES_NV: &[Member] = members!(vk::PhysicalDeviceDisplacementMicromapFeaturesNV<'static> {
+    displacement_micromap: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_DYNAMIC_RENDERING_FEATURES: &[Member] = members!(vk::PhysicalDeviceDynamicRenderingFeatures<'static> {
+    dynamic_rendering: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_EXCLUSIVE_SCISSOR_FEATURES_NV: &[Member] = members!(vk::PhysicalDeviceExclusiveScissorFeaturesNV<'static> {
+    exclusive_scissor: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_2FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceExtendedDynamicState2FeaturesEXT<'static> {
+    extended_dynamic_state2: MemberType::Bool32,
+    extended_dynamic_state2_logic_op: MemberType::Bool32,
+    extended_dynamic_state2_patch_control_points: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_3FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceExtendedDynamicState3FeaturesEXT<'static> {
+    extended_dynamic_state3_tessellation_domain_origin: MemberType::Bool32,
+    extended_dynamic_state3_depth_clamp_enable: MemberType::Bool32,
+    extended_dynamic_state3_polygon_mode: MemberType::Bool32,
+    extended_dynamic_state3_rasterization_samples: MemberType::Bool32,
+    extended_dynamic_state3_sample_mask: MemberType::Bool32,
+    extended_dynamic_state3_alpha_to_coverage_enable: MemberType::Bool32,
+    extended_dynamic_state3_alpha_to_one_enable: MemberType::Bool32,
+    extended_dynamic_state3_logic_op_enable: MemberType::Bool32,
+    extended_dynamic_state3_color_blend_enable: MemberType::Bool32,
+    extended_dynamic_state3_color_blend_equation: MemberType::Bool32,
+    extended_dynamic_state3_color_write_mask: MemberType::Bool32,
+    extended_dynamic_state3_rasterization_stream: MemberType::Bool32,
+    extended_dynamic_state3_conservative_rasterization_mode: MemberType::Bool32,
+    extended_dynamic_state3_extra_primitive_overestimation_size: MemberType::Bool32,
+    extended_dynamic_state3_depth_clip_enable: MemberType::Bool32,
+    extended_dynamic_state3_sample_locations_enable: MemberType::Bool32,
+    extended_dynamic_state3_color_blend_advanced: MemberType::Bool32,
+    extended_dynamic_state3_provoking_vertex_mode: MemberType::Bool32,
+    extended_dynamic_state3_line_rasterization_mode: MemberType::Bool32,
+    extended_dynamic_state3_line_stipple_enable: MemberType::Bool32,
+    extended_dynamic_state3_depth_clip_negative_one_to_one: MemberType::Bool32,
+    extended_dynamic_state3_viewport_w_scaling_enable: MemberType::Bool32,
+    extended_dynamic_state3_viewport_swizzle: MemberType::Bool32,
+    extended_dynamic_state3_coverage_to_color_enable: MemberType::Bool32,
+    extended_dynamic_state3_coverage_to_color_location: MemberType::Bool32,
+    extended_dynamic_state3_coverage_modulation_mode: MemberType::Bool32,
+    extended_dynamic_state3_coverage_modulation_table_enable: MemberType::Bool32,
+    extended_dynamic_state3_coverage_modulation_table: MemberType::Bool32,
+    extended_dynamic_state3_coverage_reduction_mode: MemberType::Bool32,
+    extended_dynamic_state3_representative_fragment_test_enable: MemberType::Bool32,
+    extended_dynamic_state3_shading_rate_image_enable: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_EXTENDED_DYNAMIC_STATE_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceExtendedDynamicStateFeaturesEXT<'static> {
+    extended_dynamic_state: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_EXTERNAL_MEMORY_RDMA_FEATURES_NV: &[Member] = members!(vk::PhysicalDeviceExternalMemoryRDMAFeaturesNV<'static> {
+    external_memory_rdma: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_FAULT_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceFaultFeaturesEXT<'static> {
+    device_fault: MemberType::Bool32,
+    device_fault_vendor_binary: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_2FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceFragmentDensityMap2FeaturesEXT<'static> {
+    fragment_density_map_deferred: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceFragmentDensityMapFeaturesEXT<'static> {
+    fragment_density_map: MemberType::Bool32,
+    fragment_density_map_dynamic: MemberType::Bool32,
+    fragment_density_map_non_subsampled_images: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_FRAGMENT_SHADING_RATE_FEATURES_KHR: &[Member] = members!(vk::PhysicalDeviceFragmentShadingRateFeaturesKHR<'static> {
+    pipeline_fragment_shading_rate: MemberType::Bool32,
+    primitive_fragment_shading_rate: MemberType::Bool32,
+    attachment_fragment_shading_rate: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_FRAME_BOUNDARY_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceFrameBoundaryFeaturesEXT<'static> {
+    frame_boundary: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_GLOBAL_PRIORITY_QUERY_FEATURES_KHR: &[Member] = members!(vk::PhysicalDeviceGlobalPriorityQueryFeaturesKHR<'static> {
+    global_priority_query: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_HOST_IMAGE_COPY_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceHostImageCopyFeaturesEXT<'static> {
+    host_image_copy: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_HOST_QUERY_RESET_FEATURES: &[Member] = members!(vk::PhysicalDeviceHostQueryResetFeatures<'static> {
+    host_query_reset: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_IMAGE_2D_VIEW_OF_3D_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceImage2DViewOf3DFeaturesEXT<'static> {
+    image2_d_view_of3_d: MemberType::Bool32,
+    sampler2_d_view_of3_d: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_IMAGELESS_FRAMEBUFFER_FEATURES: &[Member] = members!(vk::PhysicalDeviceImagelessFramebufferFeatures<'static> {
+    imageless_framebuffer: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_IMAGE_PROCESSING_2FEATURES_QCOM: &[Member] = members!(vk::PhysicalDeviceImageProcessing2FeaturesQCOM<'static> {
+    texture_block_match2: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_IMAGE_PROCESSING_FEATURES_QCOM: &[Member] = members!(vk::PhysicalDeviceImageProcessingFeaturesQCOM<'static> {
+    texture_sample_weighted: MemberType::Bool32,
+    texture_box_filter: MemberType::Bool32,
+    texture_block_match: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_IMAGE_ROBUSTNESS_FEATURES: &[Member] = members!(vk::PhysicalDeviceImageRobustnessFeatures<'static> {
+    robust_image_access: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_IMAGE_SLICED_VIEW_OF_3D_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceImageSlicedViewOf3DFeaturesEXT<'static> {
+    image_sliced_view_of3_d: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_IMAGE_VIEW_MIN_LOD_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceImageViewMinLodFeaturesEXT<'static> {
+    min_lod: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_INDEX_TYPE_UINT_8FEATURES_KHR: &[Member] = members!(vk::PhysicalDeviceIndexTypeUint8FeaturesKHR<'static> {
+    index_type_uint8: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_FEATURES: &[Member] = members!(vk::PhysicalDeviceInlineUniformBlockFeatures<'static> {
+    inline_uniform_block: MemberType::Bool32,
+    descriptor_binding_inline_uniform_block_update_after_bind: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_INVOCATION_MASK_FEATURES_HUAWEI: &[Member] = members!(vk::PhysicalDeviceInvocationMaskFeaturesHUAWEI<'static> {
+    invocation_mask: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_LEGACY_DITHERING_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceLegacyDitheringFeaturesEXT<'static> {
+    legacy_dithering: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_LINEAR_COLOR_ATTACHMENT_FEATURES_NV: &[Member] = members!(vk::PhysicalDeviceLinearColorAttachmentFeaturesNV<'static> {
+    linear_color_attachment: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_LINE_RASTERIZATION_FEATURES_KHR: &[Member] = members!(vk::PhysicalDeviceLineRasterizationFeaturesKHR<'static> {
+    rectangular_lines: MemberType::Bool32,
+    bresenham_lines: MemberType::Bool32,
+    smooth_lines: MemberType::Bool32,
+    stippled_rectangular_lines: MemberType::Bool32,
+    stippled_bresenham_lines: MemberType::Bool32,
+    stippled_smooth_lines: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_MAINTENANCE_4FEATURES: &[Member] = members!(vk::PhysicalDeviceMaintenance4Features<'static> {
+    maintenance4: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_MAINTENANCE_5FEATURES_KHR: &[Member] = members!(vk::PhysicalDeviceMaintenance5FeaturesKHR<'static> {
+    maintenance5: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_MAINTENANCE_6FEATURES_KHR: &[Member] = members!(vk::PhysicalDeviceMaintenance6FeaturesKHR<'static> {
+    maintenance6: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_MAP_MEMORY_PLACED_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceMapMemoryPlacedFeaturesEXT<'static> {
+    memory_map_placed: MemberType::Bool32,
+    memory_map_range_placed: MemberType::Bool32,
+    memory_unmap_reserve: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_MEMORY_DECOMPRESSION_FEATURES_NV: &[Member] = members!(vk::PhysicalDeviceMemoryDecompressionFeaturesNV<'static> {
+    memory_decompression: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_MEMORY_PRIORITY_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceMemoryPriorityFeaturesEXT<'static> {
+    memory_priority: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_MESH_SHADER_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceMeshShaderFeaturesEXT<'static> {
+    task_shader: MemberType::Bool32,
+    mesh_shader: MemberType::Bool32,
+    multiview_mesh_shader: MemberType::Bool32,
+    primitive_fragment_shading_rate_mesh_shader: MemberType::Bool32,
+    mesh_shader_queries: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_MESH_SHADER_FEATURES_NV: &[Member] = members!(vk::PhysicalDeviceMeshShaderFeaturesNV<'static> {
+    task_shader: MemberType::Bool32,
+    mesh_shader: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_MULTI_DRAW_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceMultiDrawFeaturesEXT<'static> {
+    multi_draw: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_MULTIVIEW_FEATURES: &[Member] = members!(vk::PhysicalDeviceMultiviewFeatures<'static> {
+    multiview: MemberType::Bool32,
+    multiview_geometry_shader: MemberType::Bool32,
+    multiview_tessellation_shader: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_MUTABLE_DESCRIPTOR_TYPE_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceMutableDescriptorTypeFeaturesEXT<'static> {
+    mutable_descriptor_type: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_NESTED_COMMAND_BUFFER_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceNestedCommandBufferFeaturesEXT<'static> {
+    nested_command_buffer: MemberType::Bool32,
+    nested_command_buffer_rendering: MemberType::Bool32,
+    nested_command_buffer_simultaneous_use: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_NON_SEAMLESS_CUBE_MAP_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceNonSeamlessCubeMapFeaturesEXT<'static> {
+    non_seamless_cube_map: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_OPACITY_MICROMAP_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceOpacityMicromapFeaturesEXT<'static> {
+    micromap: MemberType::Bool32,
+    micromap_capture_replay: MemberType::Bool32,
+    micromap_host_commands: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_OPTICAL_FLOW_FEATURES_NV: &[Member] = members!(vk::PhysicalDeviceOpticalFlowFeaturesNV<'static> {
+    optical_flow: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_PERFORMANCE_QUERY_FEATURES_KHR: &[Member] = members!(vk::PhysicalDevicePerformanceQueryFeaturesKHR<'static> {
+    performance_counter_query_pools: MemberType::Bool32,
+    performance_counter_multiple_query_pools: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_PER_STAGE_DESCRIPTOR_SET_FEATURES_NV: &[Member] = members!(vk::PhysicalDevicePerStageDescriptorSetFeaturesNV<'static> {
+    per_stage_descriptor_set: MemberType::Bool32,
+    dynamic_pipeline_layout: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_PIPELINE_PROPERTIES_FEATURES_EXT: &[Member] = members!(vk::PhysicalDevicePipelinePropertiesFeaturesEXT<'static> {
+    pipeline_properties_identifier: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_PIPELINE_ROBUSTNESS_FEATURES_EXT: &[Member] = members!(vk::PhysicalDevicePipelineRobustnessFeaturesEXT<'static> {
+    pipeline_robustness: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_PORTABILITY_SUBSET_FEATURES_KHR: &[Member] = members!(vk::PhysicalDevicePortabilitySubsetFeaturesKHR<'static> {
+    constant_alpha_color_blend_factors: MemberType::Bool32,
+    events: MemberType::Bool32,
+    image_view_format_reinterpretation: MemberType::Bool32,
+    image_view_format_swizzle: MemberType::Bool32,
+    image_view2_d_on3_d_image: MemberType::Bool32,
+    multisample_array_image: MemberType::Bool32,
+    mutable_comparison_samplers: MemberType::Bool32,
+    point_polygons: MemberType::Bool32,
+    sampler_mip_lod_bias: MemberType::Bool32,
+    separate_stencil_mask_ref: MemberType::Bool32,
+    shader_sample_rate_interpolation_functions: MemberType::Bool32,
+    tessellation_isolines: MemberType::Bool32,
+    tessellation_point_mode: MemberType::Bool32,
+    triangle_fans: MemberType::Bool32,
+    vertex_attribute_access_beyond_stride: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_PRESENT_BARRIER_FEATURES_NV: &[Member] = members!(vk::PhysicalDevicePresentBarrierFeaturesNV<'static> {
+    present_barrier: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_PRESENT_ID_FEATURES_KHR: &[Member] = members!(vk::PhysicalDevicePresentIdFeaturesKHR<'static> {
+    present_id: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_PRESENT_WAIT_FEATURES_KHR: &[Member] = members!(vk::PhysicalDevicePresentWaitFeaturesKHR<'static> {
+    present_wait: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_PRIVATE_DATA_FEATURES: &[Member] = members!(vk::PhysicalDevicePrivateDataFeatures<'static> {
+    private_data: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_PROTECTED_MEMORY_FEATURES: &[Member] = members!(vk::PhysicalDeviceProtectedMemoryFeatures<'static> {
+    protected_memory: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_PROVOKING_VERTEX_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceProvokingVertexFeaturesEXT<'static> {
+    provoking_vertex_last: MemberType::Bool32,
+    transform_feedback_preserves_provoking_vertex: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_RAW_ACCESS_CHAINS_FEATURES_NV: &[Member] = members!(vk::PhysicalDeviceRawAccessChainsFeaturesNV<'static> {
+    shader_raw_access_chains: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_RAY_QUERY_FEATURES_KHR: &[Member] = members!(vk::PhysicalDeviceRayQueryFeaturesKHR<'static> {
+    ray_query: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_RAY_TRACING_MAINTENANCE_1FEATURES_KHR: &[Member] = members!(vk::PhysicalDeviceRayTracingMaintenance1FeaturesKHR<'static> {
+    ray_tracing_maintenance1: MemberType::Bool32,
+    ray_tracing_pipeline_trace_rays_indirect2: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_RAY_TRACING_MOTION_BLUR_FEATURES_NV: &[Member] = members!(vk::PhysicalDeviceRayTracingMotionBlurFeaturesNV<'static> {
+    ray_tracing_motion_blur: MemberType::Bool32,
+    ray_tracing_motion_blur_pipeline_trace_rays_indirect: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_FEATURES_KHR: &[Member] = members!(vk::PhysicalDeviceRayTracingPipelineFeaturesKHR<'static> {
+    ray_tracing_pipeline: MemberType::Bool32,
+    ray_tracing_pipeline_shader_group_handle_capture_replay: MemberType::Bool32,
+    ray_tracing_pipeline_shader_group_handle_capture_replay_mixed: MemberType::Bool32,
+    ray_tracing_pipeline_trace_rays_indirect: MemberType::Bool32,
+    ray_traversal_primitive_culling: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_RAY_TRACING_VALIDATION_FEATURES_NV: &[Member] = members!(vk::PhysicalDeviceRayTracingValidationFeaturesNV<'static> {
+    ray_tracing_validation: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_RENDER_PASS_STRIPED_FEATURES_ARM: &[Member] = members!(vk::PhysicalDeviceRenderPassStripedFeaturesARM<'static> {
+    render_pass_striped: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_RGBA10X6FORMATS_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceRGBA10X6FormatsFeaturesEXT<'static> {
+    format_rgba10x6_without_y_cb_cr_sampler: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_ROBUSTNESS_2FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceRobustness2FeaturesEXT<'static> {
+    robust_buffer_access2: MemberType::Bool32,
+    robust_image_access2: MemberType::Bool32,
+    null_descriptor: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SAMPLER_YCBCR_CONVERSION_FEATURES: &[Member] = members!(vk::PhysicalDeviceSamplerYcbcrConversionFeatures<'static> {
+    sampler_ycbcr_conversion: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SCALAR_BLOCK_LAYOUT_FEATURES: &[Member] = members!(vk::PhysicalDeviceScalarBlockLayoutFeatures<'static> {
+    scalar_block_layout: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SCHEDULING_CONTROLS_FEATURES_ARM: &[Member] = members!(vk::PhysicalDeviceSchedulingControlsFeaturesARM<'static> {
+    scheduling_controls: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_2FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceShaderAtomicFloat2FeaturesEXT<'static> {
+    shader_buffer_float16_atomics: MemberType::Bool32,
+    shader_buffer_float16_atomic_add: MemberType::Bool32,
+    shader_buffer_float16_atomic_min_max: MemberType::Bool32,
+    shader_buffer_float32_atomic_min_max: MemberType::Bool32,
+    shader_buffer_float64_atomic_min_max: MemberType::Bool32,
+    shader_shared_float16_atomics: MemberType::Bool32,
+    shader_shared_float16_atomic_add: MemberType::Bool32,
+    shader_shared_float16_atomic_min_max: MemberType::Bool32,
+    shader_shared_float32_atomic_min_max: MemberType::Bool32,
+    shader_shared_float64_atomic_min_max: MemberType::Bool32,
+    shader_image_float32_atomic_min_max: MemberType::Bool32,
+    sparse_image_float32_atomic_min_max: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SHADER_ATOMIC_FLOAT_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceShaderAtomicFloatFeaturesEXT<'static> {
+    shader_buffer_float32_atomics: MemberType::Bool32,
+    shader_buffer_float32_atomic_add: MemberType::Bool32,
+    shader_buffer_float64_atomics: MemberType::Bool32,
+    shader_buffer_float64_atomic_add: MemberType::Bool32,
+    shader_shared_float32_atomics: MemberType::Bool32,
+    shader_shared_float32_atomic_add: MemberType::Bool32,
+    shader_shared_float64_atomics: MemberType::Bool32,
+    shader_shared_float64_atomic_add: MemberType::Bool32,
+    shader_image_float32_atomics: MemberType::Bool32,
+    shader_image_float32_atomic_add: MemberType::Bool32,
+    sparse_image_float32_atomics: MemberType::Bool32,
+    sparse_image_float32_atomic_add: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SHADER_ATOMIC_INT_64FEATURES: &[Member] = members!(vk::PhysicalDeviceShaderAtomicInt64Features<'static> {
+    shader_buffer_int64_atomics: MemberType::Bool32,
+    shader_shared_int64_atomics: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SHADER_CLOCK_FEATURES_KHR: &[Member] = members!(vk::PhysicalDeviceShaderClockFeaturesKHR<'static> {
+    shader_subgroup_clock: MemberType::Bool32,
+    shader_device_clock: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SHADER_CORE_BUILTINS_FEATURES_ARM: &[Member] = members!(vk::PhysicalDeviceShaderCoreBuiltinsFeaturesARM<'static> {
+    shader_core_builtins: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SHADER_DRAW_PARAMETERS_FEATURES: &[Member] = members!(vk::PhysicalDeviceShaderDrawParametersFeatures<'static> {
+    shader_draw_parameters: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SHADER_ENQUEUE_FEATURES_AMDX: &[Member] = members!(vk::PhysicalDeviceShaderEnqueueFeaturesAMDX<'static> {
+    shader_enqueue: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SHADER_EXPECT_ASSUME_FEATURES_KHR: &[Member] = members!(vk::PhysicalDeviceShaderExpectAssumeFeaturesKHR<'static> {
+    shader_expect_assume: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SHADER_FLOAT_16INT_8FEATURES: &[Member] = members!(vk::PhysicalDeviceShaderFloat16Int8Features<'static> {
+    shader_float16: MemberType::Bool32,
+    shader_int8: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SHADER_FLOAT_CONTROLS_2FEATURES_KHR: &[Member] = members!(vk::PhysicalDeviceShaderFloatControls2FeaturesKHR<'static> {
+    shader_float_controls2: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SHADER_IMAGE_ATOMIC_INT_64FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceShaderImageAtomicInt64FeaturesEXT<'static> {
+    shader_image_int64_atomics: MemberType::Bool32,
+    sparse_image_int64_atomics: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SHADER_IMAGE_FOOTPRINT_FEATURES_NV: &[Member] = members!(vk::PhysicalDeviceShaderImageFootprintFeaturesNV<'static> {
+    image_footprint: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SHADER_INTEGER_DOT_PRODUCT_FEATURES: &[Member] = members!(vk::PhysicalDeviceShaderIntegerDotProductFeatures<'static> {
+    shader_integer_dot_product: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SHADER_MODULE_IDENTIFIER_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceShaderModuleIdentifierFeaturesEXT<'static> {
+    shader_module_identifier: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SHADER_OBJECT_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceShaderObjectFeaturesEXT<'static> {
+    shader_object: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SHADER_QUAD_CONTROL_FEATURES_KHR: &[Member] = members!(vk::PhysicalDeviceShaderQuadControlFeaturesKHR<'static> {
+    shader_quad_control: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SHADER_SM_BUILTINS_FEATURES_NV: &[Member] = members!(vk::PhysicalDeviceShaderSMBuiltinsFeaturesNV<'static> {
+    shader_sm_builtins: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SHADER_SUBGROUP_ROTATE_FEATURES_KHR: &[Member] = members!(vk::PhysicalDeviceShaderSubgroupRotateFeaturesKHR<'static> {
+    shader_subgroup_rotate: MemberType::Bool32,
+    shader_subgroup_rotate_clustered: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SHADER_TERMINATE_INVOCATION_FEATURES: &[Member] = members!(vk::PhysicalDeviceShaderTerminateInvocationFeatures<'static> {
+    shader_terminate_invocation: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SHADER_TILE_IMAGE_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceShaderTileImageFeaturesEXT<'static> {
+    shader_tile_image_color_read_access: MemberType::Bool32,
+    shader_tile_image_depth_read_access: MemberType::Bool32,
+    shader_tile_image_stencil_read_access: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SHADING_RATE_IMAGE_FEATURES_NV: &[Member] = members!(vk::PhysicalDeviceShadingRateImageFeaturesNV<'static> {
+    shading_rate_image: MemberType::Bool32,
+    shading_rate_coarse_sample_order: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_FEATURES: &[Member] = members!(vk::PhysicalDeviceSubgroupSizeControlFeatures<'static> {
+    subgroup_size_control: MemberType::Bool32,
+    compute_full_subgroups: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SUBPASS_MERGE_FEEDBACK_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceSubpassMergeFeedbackFeaturesEXT<'static> {
+    subpass_merge_feedback: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SUBPASS_SHADING_FEATURES_HUAWEI: &[Member] = members!(vk::PhysicalDeviceSubpassShadingFeaturesHUAWEI<'static> {
+    subpass_shading: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SWAPCHAIN_MAINTENANCE_1FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceSwapchainMaintenance1FeaturesEXT<'static> {
+    swapchain_maintenance1: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SYNCHRONIZATION_2FEATURES: &[Member] = members!(vk::PhysicalDeviceSynchronization2Features<'static> {
+    synchronization2: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceTexelBufferAlignmentFeaturesEXT<'static> {
+    texel_buffer_alignment: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_TEXTURE_COMPRESSION_ASTCHDR_FEATURES: &[Member] = members!(vk::PhysicalDeviceTextureCompressionASTCHDRFeatures<'static> {
+    texture_compression_astc_hdr: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_TILE_PROPERTIES_FEATURES_QCOM: &[Member] = members!(vk::PhysicalDeviceTilePropertiesFeaturesQCOM<'static> {
+    tile_properties: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_FEATURES: &[Member] = members!(vk::PhysicalDeviceTimelineSemaphoreFeatures<'static> {
+    timeline_semaphore: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceTransformFeedbackFeaturesEXT<'static> {
+    transform_feedback: MemberType::Bool32,
+    geometry_streams: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_VARIABLE_POINTERS_FEATURES: &[Member] = members!(vk::PhysicalDeviceVariablePointersFeatures<'static> {
+    variable_pointers_storage_buffer: MemberType::Bool32,
+    variable_pointers: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_VERTEX_ATTRIBUTE_DIVISOR_FEATURES_KHR: &[Member] = members!(vk::PhysicalDeviceVertexAttributeDivisorFeaturesKHR<'static> {
+    vertex_attribute_instance_rate_divisor: MemberType::Bool32,
+    vertex_attribute_instance_rate_zero_divisor: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_VIDEO_MAINTENANCE_1FEATURES_KHR: &[Member] = members!(vk::PhysicalDeviceVideoMaintenance1FeaturesKHR<'static> {
+    video_maintenance1: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_VULKAN_11FEATURES: &[Member] = members!(vk::PhysicalDeviceVulkan11Features<'static> {
+    storage_buffer16_bit_access: MemberType::Bool32,
+    uniform_and_storage_buffer16_bit_access: MemberType::Bool32,
+    storage_push_constant16: MemberType::Bool32,
+    storage_input_output16: MemberType::Bool32,
+    multiview: MemberType::Bool32,
+    multiview_geometry_shader: MemberType::Bool32,
+    multiview_tessellation_shader: MemberType::Bool32,
+    variable_pointers_storage_buffer: MemberType::Bool32,
+    variable_pointers: MemberType::Bool32,
+    protected_memory: MemberType::Bool32,
+    sampler_ycbcr_conversion: MemberType::Bool32,
+    shader_draw_parameters: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_VULKAN_12FEATURES: &[Member] = members!(vk::PhysicalDeviceVulkan12Features<'static> {
+    sampler_mirror_clamp_to_edge: MemberType::Bool32,
+    draw_indirect_count: MemberType::Bool32,
+    storage_buffer8_bit_access: MemberType::Bool32,
+    uniform_and_storage_buffer8_bit_access: MemberType::Bool32,
+    storage_push_constant8: MemberType::Bool32,
+    shader_buffer_int64_atomics: MemberType::Bool32,
+    shader_shared_int64_atomics: MemberType::Bool32,
+    shader_float16: MemberType::Bool32,
+    shader_int8: MemberType::Bool32,
+    descriptor_indexing: MemberType::Bool32,
+    shader_input_attachment_array_dynamic_indexing: MemberType::Bool32,
+    shader_uniform_texel_buffer_array_dynamic_indexing: MemberType::Bool32,
+    shader_storage_texel_buffer_array_dynamic_indexing: MemberType::Bool32,
+    shader_uniform_buffer_array_non_uniform_indexing: MemberType::Bool32,
+    shader_sampled_image_array_non_uniform_indexing: MemberType::Bool32,
+    shader_storage_buffer_array_non_uniform_indexing: MemberType::Bool32,
+    shader_storage_image_array_non_uniform_indexing: MemberType::Bool32,
+    shader_input_attachment_array_non_uniform_indexing: MemberType::Bool32,
+    shader_uniform_texel_buffer_array_non_uniform_indexing: MemberType::Bool32,
+    shader_storage_texel_buffer_array_non_uniform_indexing: MemberType::Bool32,
+    descriptor_binding_uniform_buffer_update_after_bind: MemberType::Bool32,
+    descriptor_binding_sampled_image_update_after_bind: MemberType::Bool32,
+    descriptor_binding_storage_image_update_after_bind: MemberType::Bool32,
+    descriptor_binding_storage_buffer_update_after_bind: MemberType::Bool32,
+    descriptor_binding_uniform_texel_buffer_update_after_bind: MemberType::Bool32,
+    descriptor_binding_storage_texel_buffer_update_after_bind: MemberType::Bool32,
+    descriptor_binding_update_unused_while_pending: MemberType::Bool32,
+    descriptor_binding_partially_bound: MemberType::Bool32,
+    descriptor_binding_variable_descriptor_count: MemberType::Bool32,
+    runtime_descriptor_array: MemberType::Bool32,
+    sampler_filter_minmax: MemberType::Bool32,
+    scalar_block_layout: MemberType::Bool32,
+    imageless_framebuffer: MemberType::Bool32,
+    uniform_buffer_standard_layout: MemberType::Bool32,
+    shader_subgroup_extended_types: MemberType::Bool32,
+    separate_depth_stencil_layouts: MemberType::Bool32,
+    host_query_reset: MemberType::Bool32,
+    timeline_semaphore: MemberType::Bool32,
+    buffer_device_address: MemberType::Bool32,
+    buffer_device_address_capture_replay: MemberType::Bool32,
+    buffer_device_address_multi_device: MemberType::Bool32,
+    vulkan_memory_model: MemberType::Bool32,
+    vulkan_memory_model_device_scope: MemberType::Bool32,
+    vulkan_memory_model_availability_visibility_chains: MemberType::Bool32,
+    shader_output_viewport_index: MemberType::Bool32,
+    shader_output_layer: MemberType::Bool32,
+    subgroup_broadcast_dynamic_id: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_VULKAN_13FEATURES: &[Member] = members!(vk::PhysicalDeviceVulkan13Features<'static> {
+    robust_image_access: MemberType::Bool32,
+    inline_uniform_block: MemberType::Bool32,
+    descriptor_binding_inline_uniform_block_update_after_bind: MemberType::Bool32,
+    pipeline_creation_cache_control: MemberType::Bool32,
+    private_data: MemberType::Bool32,
+    shader_demote_to_helper_invocation: MemberType::Bool32,
+    shader_terminate_invocation: MemberType::Bool32,
+    subgroup_size_control: MemberType::Bool32,
+    compute_full_subgroups: MemberType::Bool32,
+    synchronization2: MemberType::Bool32,
+    texture_compression_astc_hdr: MemberType::Bool32,
+    shader_zero_initialize_workgroup_memory: MemberType::Bool32,
+    dynamic_rendering: MemberType::Bool32,
+    shader_integer_dot_product: MemberType::Bool32,
+    maintenance4: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_VULKAN_MEMORY_MODEL_FEATURES: &[Member] = members!(vk::PhysicalDeviceVulkanMemoryModelFeatures<'static> {
+    vulkan_memory_model: MemberType::Bool32,
+    vulkan_memory_model_device_scope: MemberType::Bool32,
+    vulkan_memory_model_availability_visibility_chains: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_YCBCR_2PLANE_444FORMATS_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceYcbcr2Plane444FormatsFeaturesEXT<'static> {
+    ycbcr2plane444_formats: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_YCBCR_DEGAMMA_FEATURES_QCOM: &[Member] = members!(vk::PhysicalDeviceYcbcrDegammaFeaturesQCOM<'static> {
+    ycbcr_degamma: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_YCBCR_IMAGE_ARRAYS_FEATURES_EXT: &[Member] = members!(vk::PhysicalDeviceYcbcrImageArraysFeaturesEXT<'static> {
+    ycbcr_image_arrays: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_LIMITS: &[Member] = members!(vk::PhysicalDeviceLimits {
+    max_image_dimension1_d: MemberType::U32 => Max,
+    max_image_dimension2_d: MemberType::U32 => Max,
+    max_image_dimension3_d: MemberType::U32 => Max,
+    max_image_dimension_cube: MemberType::U32 => Max,
+    max_image_array_layers: MemberType::U32 => Max,
+    max_texel_buffer_elements: MemberType::U32 => Max,
+    max_uniform_buffer_range: MemberType::U32 => Max,
+    max_storage_buffer_range: MemberType::U32 => Max,
+    max_push_constants_size: MemberType::U32 => Max,
+    max_memory_allocation_count: MemberType::U32 => Max,
+    max_sampler_allocation_count: MemberType::U32 => Max,
+    buffer_image_granularity: MemberType::U64 => Min,
+    sparse_address_space_size: MemberType::U64,
+    max_bound_descriptor_sets: MemberType::U32 => Max,
+    max_per_stage_descriptor_samplers: MemberType::U32 => Max,
+    max_per_stage_descriptor_uniform_buffers: MemberType::U32 => Max,
+    max_per_stage_descriptor_storage_buffers: MemberType::U32 => Max,
+    max_per_stage_descriptor_sampled_images: MemberType::U32 => Max,
+    max_per_stage_descriptor_storage_images: MemberType::U32 => Max,
+    max_per_stage_descriptor_input_attachments: MemberType::U32 => Max,
+    max_per_stage_resources: MemberType::U32 => Max,
+    max_descriptor_set_samplers: MemberType::U32 => Max,
+    max_descriptor_set_uniform_buffers: MemberType::U32 => Max,
+    max_descriptor_set_uniform_buffers_dynamic: MemberType::U32 => Max,
+    max_descriptor_set_storage_buffers: MemberType::U32 => Max,
+    max_descriptor_set_storage_buffers_dynamic: MemberType::U32 => Max,
+    max_descriptor_set_sampled_images: MemberType::U32 => Max,
+    max_descriptor_set_storage_images: MemberType::U32 => Max,
+    max_descriptor_set_input_attachments: MemberType::U32 => Max,
+    max_vertex_input_attributes: MemberType::U32 => Max,
+    max_vertex_input_bindings: MemberType::U32 => Max,
+    max_vertex_input_attribute_offset: MemberType::U32 => Max,
+    max_vertex_input_binding_stride: MemberType::U32 => Max,
+    max_vertex_output_components: MemberType::U32 => Max,
+    max_tessellation_generation_level: MemberType::U32 => Max,
+    max_tessellation_patch_size: MemberType::U32 => Max,
+    max_tessellation_control_per_vertex_input_components: MemberType::U32 => Max,
+    max_tessellation_control_per_vertex_output_components: MemberType::U32 => Max,
+    max_tessellation_control_per_patch_output_components: MemberType::U32 => Max,
+    max_tessellation_control_total_output_components: MemberType::U32 => Max,
+    max_tessellation_evaluation_input_components: MemberType::U32 => Max,
+    max_tessellation_evaluation_output_components: MemberType::U32 => Max,
+    max_geometry_shader_invocations: MemberType::U32 => Max,
+    max_geometry_input_components: MemberType::U32 => Max,
+    max_geometry_output_components: MemberType::U32 => Max,
+    max_geometry_output_vertices: MemberType::U32 => Max,
+    max_geometry_total_output_components: MemberType::U32 => Max,
+    max_fragment_input_components: MemberType::U32 => Max,
+    max_fragment_output_attachments: MemberType::U32 => Max,
+    max_fragment_dual_src_attachments: MemberType::U32 => Max,
+    max_fragment_combined_output_resources: MemberType::U32 => Max,
+    max_compute_shared_memory_size: MemberType::U32 => Max,
+    max_compute_work_group_count: MemberType::Array(&MemberType::U32, 3) => Max,
+    max_compute_work_group_invocations: MemberType::U32 => Max,
+    max_compute_work_group_size: MemberType::Array(&MemberType::U32, 3) => Max,
+    sub_pixel_precision_bits: MemberType::U32 => Max,
+    sub_texel_precision_bits: MemberType::U32 => Max,
+    mipmap_precision_bits: MemberType::U32 => Max,
+    max_draw_indexed_index_value: MemberType::U32 => Max,
+    max_draw_indirect_count: MemberType::U32 => Max,
+    max_sampler_lod_bias: MemberType::F32 => Max,
+    max_sampler_anisotropy: MemberType::F32 => Max,
+    max_viewports: MemberType::U32 => Max,
+    max_viewport_dimensions: MemberType::Array(&MemberType::U32, 2) => Max,
+    viewport_bounds_range: MemberType::Array(&MemberType::F32, 2) => Range,
+    viewport_sub_pixel_bits: MemberType::U32 => Max,
+    min_memory_map_alignment: MemberType::Usize => Min,
+    min_texel_buffer_offset_alignment: MemberType::U64 => Min,
+    min_uniform_buffer_offset_alignment: MemberType::U64 => Min,
+    min_storage_buffer_offset_alignment: MemberType::U64 => Min,
+    min_texel_offset: MemberType::I32 => Min,
+    max_texel_offset: MemberType::U32 => Max,
+    min_texel_gather_offset: MemberType::I32 => Min,
+    max_texel_gather_offset: MemberType::U32 => Max,
+    min_interpolation_offset: MemberType::F32 => Min,
+    max_interpolation_offset: MemberType::F32 => Max,
+    sub_pixel_interpolation_offset_bits: MemberType::U32 => Max,
+    max_framebuffer_width: MemberType::U32 => Max,
+    max_framebuffer_height: MemberType::U32 => Max,
+    max_framebuffer_layers: MemberType::U32 => Max,
+    framebuffer_color_sample_counts: MemberType::Flags("SampleCountFlags"),
+    framebuffer_depth_sample_counts: MemberType::Flags("SampleCountFlags"),
+    framebuffer_stencil_sample_counts: MemberType::Flags("SampleCountFlags"),
+    framebuffer_no_attachments_sample_counts: MemberType::Flags("SampleCountFlags"),
+    max_color_attachments: MemberType::U32 => Max,
+    sampled_image_color_sample_counts: MemberType::Flags("SampleCountFlags"),
+    sampled_image_integer_sample_counts: MemberType::Flags("SampleCountFlags"),
+    sampled_image_depth_sample_counts: MemberType::Flags("SampleCountFlags"),
+    sampled_image_stencil_sample_counts: MemberType::Flags("SampleCountFlags"),
+    storage_image_sample_counts: MemberType::Flags("SampleCountFlags"),
+    max_sample_mask_words: MemberType::U32 => Max,
+    timestamp_compute_and_graphics: MemberType::Bool32,
+    timestamp_period: MemberType::F32,
+    max_clip_distances: MemberType::U32 => Max,
+    max_cull_distances: MemberType::U32 => Max,
+    max_combined_clip_and_cull_distances: MemberType::U32 => Max,
+    discrete_queue_priorities: MemberType::U32,
+    point_size_range: MemberType::Array(&MemberType::F32, 2) => Range,
+    line_width_range: MemberType::Array(&MemberType::F32, 2) => Range,
+    point_size_granularity: MemberType::F32 => Min,
+    line_width_granularity: MemberType::F32 => Min,
+    strict_lines: MemberType::Bool32,
+    standard_sample_locations: MemberType::Bool32,
+    optimal_buffer_copy_offset_alignment: MemberType::U64 => Min,
+    optimal_buffer_copy_row_pitch_alignment: MemberType::U64 => Min,
+    non_coherent_atom_size: MemberType::U64 => Min,
+});
+
+const PHYSICAL_DEVICE_SPARSE_PROPERTIES: &[Member] = members!(vk::PhysicalDeviceSparseProperties {
+    residency_standard2_d_block_shape: MemberType::Bool32,
+    residency_standard2_d_multisample_block_shape: MemberType::Bool32,
+    residency_standard3_d_block_shape: MemberType::Bool32,
+    residency_aligned_mip_size: MemberType::Bool32,
+    residency_non_resident_strict: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_PROPERTIES: &[Member] = members!(vk::PhysicalDeviceProperties {
+    api_version: MemberType::U32,
+    driver_version: MemberType::U32,
+    vendor_id: MemberType::U32,
+    device_id: MemberType::U32,
+    device_type: MemberType::Enum("PhysicalDeviceType"),
+    device_name: MemberType::Chars(vk::MAX_PHYSICAL_DEVICE_NAME_SIZE),
+    pipeline_cache_uuid: MemberType::Array(&MemberType::U8, vk::UUID_SIZE),
+    limits: MemberType::Struct("VkPhysicalDeviceLimits", PHYSICAL_DEVICE_LIMITS),
+    sparse_properties: MemberType::Struct(
+        "VkPhysicalDeviceSparseProperties",
+        PHYSICAL_DEVICE_SPARSE_PROPERTIES
+    ),
+});
+
+const PHYSICAL_DEVICE_PROPERTIES_2: &[Member] = members!(vk::PhysicalDeviceProperties2<'static> {
+    properties: MemberType::Struct("VkPhysicalDeviceProperties", PHYSICAL_DEVICE_PROPERTIES),
+});
+
+const PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_KHR: &[Member] = members!(vk::PhysicalDeviceCooperativeMatrixPropertiesKHR<'static> {
+    cooperative_matrix_supported_stages: MemberType::Flags("ShaderStageFlags"),
+});
+
+const PHYSICAL_DEVICE_COOPERATIVE_MATRIX_PROPERTIES_NV: &[Member] = members!(vk::PhysicalDeviceCooperativeMatrixPropertiesNV<'static> {
+    cooperative_matrix_supported_stages: MemberType::Flags("ShaderStageFlags"),
+});
+
+const PHYSICAL_DEVICE_COPY_MEMORY_INDIRECT_PROPERTIES_NV: &[Member] = members!(vk::PhysicalDeviceCopyMemoryIndirectPropertiesNV<'static> {
+    supported_queues: MemberType::Flags("QueueFlags"),
+});
+
+const PHYSICAL_DEVICE_CUDA_KERNEL_LAUNCH_PROPERTIES_NV: &[Member] = members!(vk::PhysicalDeviceCudaKernelLaunchPropertiesNV<'static> {
+    compute_capability_minor: MemberType::U32,
+    compute_capability_major: MemberType::U32,
+});
+
+const PHYSICAL_DEVICE_CUSTOM_BORDER_COLOR_PROPERTIES_EXT: &[Member] = members!(vk::PhysicalDeviceCustomBorderColorPropertiesEXT<'static> {
+    max_custom_border_color_samplers: MemberType::U32 => Max,
+});
+
+const PHYSICAL_DEVICE_DEPTH_STENCIL_RESOLVE_PROPERTIES: &[Member] = members!(vk::PhysicalDeviceDepthStencilResolveProperties<'static> {
+    supported_depth_resolve_modes: MemberType::Flags("ResolveModeFlags"),
+    supported_stencil_resolve_modes: MemberType::Flags("ResolveModeFlags"),
+    independent_resolve_none: MemberType::Bool32,
+    independent_resolve: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_DESCRIPTOR_BUFFER_PROPERTIES_EXT: &[Member] = members!(vk::PhysicalDeviceDescriptorBufferPropertiesEXT<'static> {
+    combined_image_sampler_descriptor_single_array: MemberType::Bool32,
+    bufferless_push_descriptors: MemberType::Bool32,
+    allow_sampler_image_view_post_submit_creation: MemberType::Bool32,
+    descriptor_buffer_offset_alignment: MemberType::U64 => Min,
+    max_descriptor_buffer_bindings: MemberType::U32 => Max,
+    max_resource_descriptor_buffer_bindings: MemberType::U32 => Max,
+    max_sampler_descriptor_buffer_bindings: MemberType::U32 => Max,
+    max_embedded_immutable_sampler_bindings: MemberType::U32 => Max,
+    max_embedded_immutable_samplers: MemberType::U32 => Max,
+    buffer_capture_replay_descriptor_data_size: MemberType::Usize,
+    image_capture_replay_descriptor_data_size: MemberType::Usize,
+    image_view_capture_replay_descriptor_data_size: MemberType::Usize,
+    sampler_capture_replay_descriptor_data_size: MemberType::Usize,
+    acceleration_structure_capture_replay_descriptor_data_size: MemberType::Usize,
+    sampler_descriptor_size: MemberType::Usize,
+    combined_image_sampler_descriptor_size: MemberType::Usize,
+    sampled_image_descriptor_size: MemberType::Usize,
+    storage_image_descriptor_size: MemberType::Usize,
+    uniform_texel_buffer_descriptor_size: MemberType::Usize,
+    robust_uniform_texel_buffer_descriptor_size: MemberType::Usize,
+    storage_texel_buffer_descriptor_size: MemberType::Usize,
+    robust_storage_texel_buffer_descriptor_size: MemberType::Usize,
+    uniform_buffer_descriptor_size: MemberType::Usize,
+    robust_uniform_buffer_descriptor_size: MemberType::Usize,
+    storage_buffer_descriptor_size: MemberType::Usize,
+    robust_storage_buffer_descriptor_size: MemberType::Usize,
+    input_attachment_descriptor_size: MemberType::Usize,
+    acceleration_structure_descriptor_size: MemberType::Usize,
+    max_sampler_descriptor_buffer_range: MemberType::U64 => Max,
+    max_resource_descriptor_buffer_range: MemberType::U64 => Max,
+    sampler_descriptor_buffer_address_space_size: MemberType::U64,
+    resource_descriptor_buffer_address_space_size: MemberType::U64,
+    descriptor_buffer_address_space_size: MemberType::U64,
+});
+
+const PHYSICAL_DEVICE_DESCRIPTOR_INDEXING_PROPERTIES: &[Member] = members!(vk::PhysicalDeviceDescriptorIndexingProperties<'static> {
+    max_update_after_bind_descriptors_in_all_pools: MemberType::U32 => Max,
+    shader_uniform_buffer_array_non_uniform_indexing_native: MemberType::Bool32,
+    shader_sampled_image_array_non_uniform_indexing_native: MemberType::Bool32,
+    shader_storage_buffer_array_non_uniform_indexing_native: MemberType::Bool32,
+    shader_storage_image_array_non_uniform_indexing_native: MemberType::Bool32,
+    shader_input_attachment_array_non_uniform_indexing_native: MemberType::Bool32,
+    robust_buffer_access_update_after_bind: MemberType::Bool32,
+    quad_divergent_implicit_lod: MemberType::Bool32,
+    max_per_stage_descriptor_update_after_bind_samplers: MemberType::U32 => Max,
+    max_per_stage_descriptor_update_after_bind_uniform_buffers: MemberType::U32 => Max,
+    max_per_stage_descriptor_update_after_bind_storage_buffers: MemberType::U32 => Max,
+    max_per_stage_descriptor_update_after_bind_sampled_images: MemberType::U32 => Max,
+    max_per_stage_descriptor_update_after_bind_storage_images: MemberType::U32 => Max,
+    max_per_stage_descriptor_update_after_bind_input_attachments: MemberType::U32 => Max,
+    max_per_stage_update_after_bind_resources: MemberType::U32 => Max,
+    max_descriptor_set_update_after_bind_samplers: MemberType::U32 => Max,
+    max_descriptor_set_update_after_bind_uniform_buffers: MemberType::U32 => Max,
+    max_descriptor_set_update_after_bind_uniform_buffers_dynamic: MemberType::U32 => Max,
+    max_descriptor_set_update_after_bind_storage_buffers: MemberType::U32 => Max,
+    max_descriptor_set_update_after_bind_storage_buffers_dynamic: MemberType::U32 => Max,
+    max_descriptor_set_update_after_bind_sampled_images: MemberType::U32 => Max,
+    max_descriptor_set_update_after_bind_storage_images: MemberType::U32 => Max,
+    max_descriptor_set_update_after_bind_input_attachments: MemberType::U32 => Max,
+});
+
+const PHYSICAL_DEVICE_DISCARD_RECTANGLE_PROPERTIES_EXT: &[Member] = members!(vk::PhysicalDeviceDiscardRectanglePropertiesEXT<'static> {
+    max_discard_rectangles: MemberType::U32 => Max,
+});
+
+const CONFORMANCE_VERSION: &[Member] = members!(vk::ConformanceVersion {
+    major: MemberType::U8,
+    minor: MemberType::U8,
+    subminor: MemberType::U8,
+    patch: MemberType::U8,
+});
+
+const PHYSICAL_DEVICE_DRIVER_PROPERTIES: &[Member] = members!(vk::PhysicalDeviceDriverProperties<'static> {
+    driver_id: MemberType::Enum("DriverId"),
+    driver_name: MemberType::Chars(vk::MAX_DRIVER_NAME_SIZE),
+    driver_info: MemberType::Chars(vk::MAX_DRIVER_INFO_SIZE),
+    conformance_version: MemberType::Struct("VkConformanceVersion", CONFORMANCE_VERSION),
+});
+
+const PHYSICAL_DEVICE_DRM_PROPERTIES_EXT: &[Member] = members!(vk::PhysicalDeviceDrmPropertiesEXT<'static> {
+    has_primary: MemberType::Bool32,
+    has_render: MemberType::Bool32,
+    primary_major: MemberType::I64,
+    primary_minor: MemberType::I64,
+    render_major: MemberType::I64,
+    render_minor: MemberType::I64,
+});
+
+const PHYSICAL_DEVICE_EXTERNAL_MEMORY_HOST_PROPERTIES_EXT: &[Member] = members!(vk::PhysicalDeviceExternalMemoryHostPropertiesEXT<'static> {
+    min_imported_host_pointer_alignment: MemberType::U64 => Min,
+});
+
+const PHYSICAL_DEVICE_FLOAT_CONTROLS_PROPERTIES: &[Member] = members!(vk::PhysicalDeviceFloatControlsProperties<'static> {
+    denorm_behavior_independence: MemberType::Enum("ShaderFloatControlsIndependence"),
+    rounding_mode_independence: MemberType::Enum("ShaderFloatControlsIndependence"),
+    shader_signed_zero_inf_nan_preserve_float16: MemberType::Bool32,
+    shader_signed_zero_inf_nan_preserve_float32: MemberType::Bool32,
+    shader_signed_zero_inf_nan_preserve_float64: MemberType::Bool32,
+    shader_denorm_preserve_float16: MemberType::Bool32,
+    shader_denorm_preserve_float32: MemberType::Bool32,
+    shader_denorm_preserve_float64: MemberType::Bool32,
+    shader_denorm_flush_to_zero_float16: MemberType::Bool32,
+    shader_denorm_flush_to_zero_float32: MemberType::Bool32,
+    shader_denorm_flush_to_zero_float64: MemberType::Bool32,
+    shader_rounding_mode_rte_float16: MemberType::Bool32,
+    shader_rounding_mode_rte_float32: MemberType::Bool32,
+    shader_rounding_mode_rte_float64: MemberType::Bool32,
+    shader_rounding_mode_rtz_float16: MemberType::Bool32,
+    shader_rounding_mode_rtz_float32: MemberType::Bool32,
+    shader_rounding_mode_rtz_float64: MemberType::Bool32,
+});
+
+const EXTENT_2D: &[Member] = members!(vk::Extent2D {
+    width: MemberType::U32,
+    height: MemberType::U32,
+});
+
+const PHYSICAL_DEVICE_FRAGMENT_DENSITY_MAP_PROPERTIES_EXT: &[Member] = members!(vk::PhysicalDeviceFragmentDensityMapPropertiesEXT<'static> {
+    min_fragment_density_texel_size: MemberType::Struct("VkExtent2D", EXTENT_2D) => Min,
+    max_fragment_density_texel_size: MemberType::Struct("VkExtent2D", EXTENT_2D) => Max,
+    fragment_density_invocations: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_HOST_IMAGE_COPY_PROPERTIES_EXT: &[Member] = members!(vk::PhysicalDeviceHostImageCopyPropertiesEXT<'static> {
+    copy_src_layout_count: MemberType::U32 => Max,
+    p_copy_src_layouts: MemberType::Pointer,
+    copy_dst_layout_count: MemberType::U32 => Max,
+    p_copy_dst_layouts: MemberType::Pointer,
+    optimal_tiling_layout_uuid: MemberType::Array(&MemberType::U8, vk::UUID_SIZE),
+    identical_memory_type_requirements: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_ID_PROPERTIES: &[Member] = members!(vk::PhysicalDeviceIDProperties<'static> {
+    device_uuid: MemberType::Array(&MemberType::U8, vk::UUID_SIZE),
+    driver_uuid: MemberType::Array(&MemberType::U8, vk::UUID_SIZE),
+    device_luid: MemberType::Array(&MemberType::U8, vk::LUID_SIZE),
+    device_node_mask: MemberType::U32,
+    device_luid_valid: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_IMAGE_PROCESSING_2PROPERTIES_QCOM: &[Member] = members!(vk::PhysicalDeviceImageProcessing2PropertiesQCOM<'static> {
+    max_block_match_window: MemberType::Struct("VkExtent2D", EXTENT_2D) => Max,
+});
+
+const PHYSICAL_DEVICE_IMAGE_PROCESSING_PROPERTIES_QCOM: &[Member] = members!(vk::PhysicalDeviceImageProcessingPropertiesQCOM<'static> {
+    max_weight_filter_phases: MemberType::U32 => Max,
+    max_weight_filter_dimension: MemberType::Struct("VkExtent2D", EXTENT_2D) => Max,
+    max_block_match_region: MemberType::Struct("VkExtent2D", EXTENT_2D) => Max,
+    max_box_filter_block_size: MemberType::Struct("VkExtent2D", EXTENT_2D) => Max,
+});
+
+const PHYSICAL_DEVICE_INLINE_UNIFORM_BLOCK_PROPERTIES: &[Member] = members!(vk::PhysicalDeviceInlineUniformBlockProperties<'static> {
+    max_inline_uniform_block_size: MemberType::U32 => Max,
+    max_per_stage_descriptor_inline_uniform_blocks: MemberType::U32 => Max,
+    max_per_stage_descriptor_update_after_bind_inline_uniform_blocks: MemberType::U32 => Max,
+    max_descriptor_set_inline_uniform_blocks: MemberType::U32 => Max,
+    max_descriptor_set_update_after_bind_inline_uniform_blocks: MemberType::U32 => Max,
+});
+
+const PHYSICAL_DEVICE_LAYERED_DRIVER_PROPERTIES_MSFT: &[Member] = members!(vk::PhysicalDeviceLayeredDriverPropertiesMSFT<'static> {
+    underlying_api: MemberType::Enum("LayeredDriverUnderlyingApiMSFT"),
+});
+
+const PHYSICAL_DEVICE_LINE_RASTERIZATION_PROPERTIES_KHR: &[Member] = members!(vk::PhysicalDeviceLineRasterizationPropertiesKHR<'static> {
+    line_sub_pixel_precision_bits: MemberType::U32 => Max,
+});
+
+const PHYSICAL_DEVICE_MAINTENANCE_3PROPERTIES: &[Member] = members!(vk::PhysicalDeviceMaintenance3Properties<'static> {
+    max_per_set_descriptors: MemberType::U32 => Max,
+    max_memory_allocation_size: MemberType::U64 => Max,
+});
+
+const PHYSICAL_DEVICE_MAINTENANCE_4PROPERTIES: &[Member] = members!(vk::PhysicalDeviceMaintenance4Properties<'static> {
+    max_buffer_size: MemberType::U64 => Max,
+});
+
+const PHYSICAL_DEVICE_MAINTENANCE_5PROPERTIES_KHR: &[Member] = members!(vk::PhysicalDeviceMaintenance5PropertiesKHR<'static> {
+    early_fragment_multisample_coverage_after_sample_counting: MemberType::Bool32,
+    early_fragment_sample_mask_test_before_sample_counting: MemberType::Bool32,
+    depth_stencil_swizzle_one_support: MemberType::Bool32,
+    polygon_mode_point_size: MemberType::Bool32,
+    non_strict_single_pixel_wide_lines_use_parallelogram: MemberType::Bool32,
+    non_strict_wide_lines_use_parallelogram: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_MAINTENANCE_6PROPERTIES_KHR: &[Member] = members!(vk::PhysicalDeviceMaintenance6PropertiesKHR<'static> {
+    block_texel_view_compatible_multiple_layers: MemberType::Bool32,
+    max_combined_image_sampler_descriptor_count: MemberType::U32 => Max,
+    fragment_shading_rate_clamp_combiner_inputs: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_MAP_MEMORY_PLACED_PROPERTIES_EXT: &[Member] = members!(vk::PhysicalDeviceMapMemoryPlacedPropertiesEXT<'static> {
+    min_placed_memory_map_alignment: MemberType::U64 => Min,
+});
+
+const PHYSICAL_DEVICE_MEMORY_DECOMPRESSION_PROPERTIES_NV: &[Member] = members!(vk::PhysicalDeviceMemoryDecompressionPropertiesNV<'static> {
+    decompression_methods: MemberType::Flags64("MemoryDecompressionMethodFlagsNV"),
+    max_decompression_indirect_count: MemberType::U64 => Max,
+});
+
+const PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_EXT: &[Member] = members!(vk::PhysicalDeviceMeshShaderPropertiesEXT<'static> {
+    max_task_work_group_total_count: MemberType::U32 => Max,
+    max_task_work_group_count: MemberType::Array(&MemberType::U32, 3) => Max,
+    max_task_work_group_invocations: MemberType::U32 => Max,
+    max_task_work_group_size: MemberType::Array(&MemberType::U32, 3) => Max,
+    max_task_payload_size: MemberType::U32 => Max,
+    max_task_shared_memory_size: MemberType::U32 => Max,
+    max_task_payload_and_shared_memory_size: MemberType::U32 => Max,
+    max_mesh_work_group_total_count: MemberType::U32 => Max,
+    max_mesh_work_group_count: MemberType::Array(&MemberType::U32, 3) => Max,
+    max_mesh_work_group_invocations: MemberType::U32 => Max,
+    max_mesh_work_group_size: MemberType::Array(&MemberType::U32, 3) => Max,
+    max_mesh_shared_memory_size: MemberType::U32 => Max,
+    max_mesh_payload_and_shared_memory_size: MemberType::U32 => Max,
+    max_mesh_output_memory_size: MemberType::U32 => Max,
+    max_mesh_payload_and_output_memory_size: MemberType::U32 => Max,
+    max_mesh_output_components: MemberType::U32 => Max,
+    max_mesh_output_vertices: MemberType::U32 => Max,
+    max_mesh_output_primitives: MemberType::U32 => Max,
+    max_mesh_output_layers: MemberType::U32 => Max,
+    max_mesh_multiview_view_count: MemberType::U32 => Max,
+    mesh_output_per_vertex_granularity: MemberType::U32 => Min,
+    mesh_output_per_primitive_granularity: MemberType::U32 => Min,
+    max_preferred_task_work_group_invocations: MemberType::U32 => Max,
+    max_preferred_mesh_work_group_invocations: MemberType::U32 => Max,
+    prefers_local_invocation_vertex_output: MemberType::Bool32,
+    prefers_local_invocation_primitive_output: MemberType::Bool32,
+    prefers_compact_vertex_output: MemberType::Bool32,
+    prefers_compact_primitive_output: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_MESH_SHADER_PROPERTIES_NV: &[Member] = members!(vk::PhysicalDeviceMeshShaderPropertiesNV<'static> {
+    max_draw_mesh_tasks_count: MemberType::U32 => Max,
+    max_task_work_group_invocations: MemberType::U32 => Max,
+    max_task_work_group_size: MemberType::Array(&MemberType::U32, 3) => Max,
+    max_task_total_memory_size: MemberType::U32 => Max,
+    max_task_output_count: MemberType::U32 => Max,
+    max_mesh_work_group_invocations: MemberType::U32 => Max,
+    max_mesh_work_group_size: MemberType::Array(&MemberType::U32, 3) => Max,
+    max_mesh_total_memory_size: MemberType::U32 => Max,
+    max_mesh_output_vertices: MemberType::U32 => Max,
+    max_mesh_output_primitives: MemberType::U32 => Max,
+    max_mesh_multiview_view_count: MemberType::U32 => Max,
+    mesh_output_per_vertex_granularity: MemberType::U32 => Min,
+    mesh_output_per_primitive_granularity: MemberType::U32 => Min,
+});
+
+const PHYSICAL_DEVICE_MULTI_DRAW_PROPERTIES_EXT: &[Member] = members!(vk::PhysicalDeviceMultiDrawPropertiesEXT<'static> {
+    max_multi_draw_count: MemberType::U32 => Max,
+});
+
+const PHYSICAL_DEVICE_MULTIVIEW_PROPERTIES: &[Member] = members!(vk::PhysicalDeviceMultiviewProperties<'static> {
+    max_multiview_view_count: MemberType::U32 => Max,
+    max_multiview_instance_index: MemberType::U32 => Max,
+});
+
+const PHYSICAL_DEVICE_OPACITY_MICROMAP_PROPERTIES_EXT: &[Member] = members!(vk::PhysicalDeviceOpacityMicromapPropertiesEXT<'static> {
+    max_opacity2_state_subdivision_level: MemberType::U32 => Max,
+    max_opacity4_state_subdivision_level: MemberType::U32 => Max,
+});
+
+const PHYSICAL_DEVICE_OPTICAL_FLOW_PROPERTIES_NV: &[Member] = members!(vk::PhysicalDeviceOpticalFlowPropertiesNV<'static> {
+    supported_output_grid_sizes: MemberType::Flags("OpticalFlowGridSizeFlagsNV"),
+    supported_hint_grid_sizes: MemberType::Flags("OpticalFlowGridSizeFlagsNV"),
+    hint_supported: MemberType::Bool32,
+    cost_supported: MemberType::Bool32,
+    bidirectional_flow_supported: MemberType::Bool32,
+    global_flow_supported: MemberType::Bool32,
+    min_width: MemberType::U32 => Min,
+    min_height: MemberType::U32 => Min,
+    max_width: MemberType::U32 => Max,
+    max_height: MemberType::U32 => Max,
+    max_num_regions_of_interest: MemberType::U32 => Max,
+});
+
+const PHYSICAL_DEVICE_PCI_BUS_INFO_PROPERTIES_EXT: &[Member] = members!(vk::PhysicalDevicePCIBusInfoPropertiesEXT<'static> {
+    pci_domain: MemberType::U32,
+    pci_bus: MemberType::U32,
+    pci_device: MemberType::U32,
+    pci_function: MemberType::U32,
+});
+
+const PHYSICAL_DEVICE_PERFORMANCE_QUERY_PROPERTIES_KHR: &[Member] = members!(vk::PhysicalDevicePerformanceQueryPropertiesKHR<'static> {
+    allow_command_buffer_query_copies: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_PIPELINE_ROBUSTNESS_PROPERTIES_EXT: &[Member] = members!(vk::PhysicalDevicePipelineRobustnessPropertiesEXT<'static> {
+    default_robustness_storage_buffers: MemberType::Enum("PipelineRobustnessBufferBehaviorEXT"),
+    default_robustness_uniform_buffers: MemberType::Enum("PipelineRobustnessBufferBehaviorEXT"),
+    default_robustness_vertex_inputs: MemberType::Enum("PipelineRobustnessBufferBehaviorEXT"),
+    default_robustness_images: MemberType::Enum("PipelineRobustnessImageBehaviorEXT"),
+});
+
+const PHYSICAL_DEVICE_POINT_CLIPPING_PROPERTIES: &[Member] = members!(vk::PhysicalDevicePointClippingProperties<'static> {
+    point_clipping_behavior: MemberType::Enum("PointClippingBehavior"),
+});
+
+const PHYSICAL_DEVICE_PORTABILITY_SUBSET_PROPERTIES_KHR: &[Member] = members!(vk::PhysicalDevicePortabilitySubsetPropertiesKHR<'static> {
+    min_vertex_input_binding_stride_alignment: MemberType::U32 => Min,
+});
+
+const PHYSICAL_DEVICE_PROTECTED_MEMORY_PROPERTIES: &[Member] = members!(vk::PhysicalDeviceProtectedMemoryProperties<'static> {
+    protected_no_fault: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_PROVOKING_VERTEX_PROPERTIES_EXT: &[Member] = members!(vk::PhysicalDeviceProvokingVertexPropertiesEXT<'static> {
+    provoking_vertex_mode_per_pipeline: MemberType::Bool32,
+    transform_feedback_preserves_triangle_fan_provoking_vertex: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_PUSH_DESCRIPTOR_PROPERTIES_KHR: &[Member] = members!(vk::PhysicalDevicePushDescriptorPropertiesKHR<'static> {
+    max_push_descriptors: MemberType::U32 => Max,
+});
+
+const PHYSICAL_DEVICE_RAY_TRACING_PIPELINE_PROPERTIES_KHR: &[Member] = members!(vk::PhysicalDeviceRayTracingPipelinePropertiesKHR<'static> {
+    shader_group_handle_size: MemberType::U32,
+    max_ray_recursion_depth: MemberType::U32 => Max,
+    max_shader_group_stride: MemberType::U32 => Max,
+    shader_group_base_alignment: MemberType::U32 => Min,
+    shader_group_handle_capture_replay_size: MemberType::U32,
+    max_ray_dispatch_invocation_count: MemberType::U32 => Max,
+    shader_group_handle_alignment: MemberType::U32 => Min,
+    max_ray_hit_attribute_size: MemberType::U32 => Max,
+});
+
+const PHYSICAL_DEVICE_RAY_TRACING_PROPERTIES_NV: &[Member] = members!(vk::PhysicalDeviceRayTracingPropertiesNV<'static> {
+    shader_group_handle_size: MemberType::U32,
+    max_recursion_depth: MemberType::U32 => Max,
+    max_shader_group_stride: MemberType::U32 => Max,
+    shader_group_base_alignment: MemberType::U32 => Min,
+    max_geometry_count: MemberType::U64 => Max,
+    max_instance_count: MemberType::U64 => Max,
+    max_triangle_count: MemberType::U64 => Max,
+    max_descriptor_set_acceleration_structures: MemberType::U32 => Max,
+});
+
+const PHYSICAL_DEVICE_RENDER_PASS_STRIPED_PROPERTIES_ARM: &[Member] = members!(vk::PhysicalDeviceRenderPassStripedPropertiesARM<'static> {
+    render_pass_stripe_granularity: MemberType::Struct("VkExtent2D", EXTENT_2D) => Min,
+    max_render_pass_stripes: MemberType::U32 => Max,
+});
+
+const PHYSICAL_DEVICE_ROBUSTNESS_2PROPERTIES_EXT: &[Member] = members!(vk::PhysicalDeviceRobustness2PropertiesEXT<'static> {
+    robust_storage_buffer_access_size_alignment: MemberType::U64 => Min,
+    robust_uniform_buffer_access_size_alignment: MemberType::U64 => Min,
+});
+
+const PHYSICAL_DEVICE_SAMPLE_LOCATIONS_PROPERTIES_EXT: &[Member] = members!(vk::PhysicalDeviceSampleLocationsPropertiesEXT<'static> {
+    sample_location_sample_counts: MemberType::Flags("SampleCountFlags"),
+    max_sample_location_grid_size: MemberType::Struct("VkExtent2D", EXTENT_2D) => Max,
+    sample_location_coordinate_range: MemberType::Array(&MemberType::F32, 2) => Range,
+    sample_location_sub_pixel_bits: MemberType::U32 => Max,
+    variable_sample_locations: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SAMPLER_FILTER_MINMAX_PROPERTIES: &[Member] = members!(vk::PhysicalDeviceSamplerFilterMinmaxProperties<'static> {
+    filter_minmax_single_component_formats: MemberType::Bool32,
+    filter_minmax_image_component_mapping: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SCHEDULING_CONTROLS_PROPERTIES_ARM: &[Member] = members!(vk::PhysicalDeviceSchedulingControlsPropertiesARM<'static> {
+    scheduling_controls_flags: MemberType::Flags64("PhysicalDeviceSchedulingControlsFlagsARM"),
+});
+
+const PHYSICAL_DEVICE_SHADER_CORE_BUILTINS_PROPERTIES_ARM: &[Member] = members!(vk::PhysicalDeviceShaderCoreBuiltinsPropertiesARM<'static> {
+    shader_core_mask: MemberType::U64,
+    shader_core_count: MemberType::U32 => Max,
+    shader_warps_per_core: MemberType::U32,
+});
+
+const PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_2AMD: &[Member] = members!(vk::PhysicalDeviceShaderCoreProperties2AMD<'static> {
+    shader_core_features: MemberType::Flags("ShaderCorePropertiesFlagsAMD"),
+    active_compute_unit_count: MemberType::U32 => Max,
+});
+
+const PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_AMD: &[Member] = members!(vk::PhysicalDeviceShaderCorePropertiesAMD<'static> {
+    shader_engine_count: MemberType::U32 => Max,
+    shader_arrays_per_engine_count: MemberType::U32 => Max,
+    compute_units_per_shader_array: MemberType::U32,
+    simd_per_compute_unit: MemberType::U32,
+    wavefronts_per_simd: MemberType::U32,
+    wavefront_size: MemberType::U32,
+    sgprs_per_simd: MemberType::U32,
+    min_sgpr_allocation: MemberType::U32 => Min,
+    max_sgpr_allocation: MemberType::U32 => Max,
+    sgpr_allocation_granularity: MemberType::U32 => Min,
+    vgprs_per_simd: MemberType::U32,
+    min_vgpr_allocation: MemberType::U32 => Min,
+    max_vgpr_allocation: MemberType::U32 => Max,
+    vgpr_allocation_granularity: MemberType::U32 => Min,
+});
+
+const PHYSICAL_DEVICE_SHADER_CORE_PROPERTIES_ARM: &[Member] = members!(vk::PhysicalDeviceShaderCorePropertiesARM<'static> {
+    pixel_rate: MemberType::U32,
+    texel_rate: MemberType::U32,
+    fma_rate: MemberType::U32,
+});
+
+const PHYSICAL_DEVICE_SHADER_ENQUEUE_PROPERTIES_AMDX: &[Member] = members!(vk::PhysicalDeviceShaderEnqueuePropertiesAMDX<'static> {
+    max_execution_graph_depth: MemberType::U32 => Max,
+    max_execution_graph_shader_output_nodes: MemberType::U32 => Max,
+    max_execution_graph_shader_payload_size: MemberType::U32 => Max,
+    max_execution_graph_shader_payload_count: MemberType::U32 => Max,
+    execution_graph_dispatch_address_alignment: MemberType::U32 => Min,
+});
+
+const PHYSICAL_DEVICE_SHADER_OBJECT_PROPERTIES_EXT: &[Member] = members!(vk::PhysicalDeviceShaderObjectPropertiesEXT<'static> {
+    shader_binary_uuid: MemberType::Array(&MemberType::U8, vk::UUID_SIZE),
+    shader_binary_version: MemberType::U32,
+});
+
+const PHYSICAL_DEVICE_SHADER_SM_BUILTINS_PROPERTIES_NV: &[Member] = members!(vk::PhysicalDeviceShaderSMBuiltinsPropertiesNV<'static> {
+    shader_sm_count: MemberType::U32 => Max,
+    shader_warps_per_sm: MemberType::U32,
+});
+
+const PHYSICAL_DEVICE_SHADER_TILE_IMAGE_PROPERTIES_EXT: &[Member] = members!(vk::PhysicalDeviceShaderTileImagePropertiesEXT<'static> {
+    shader_tile_image_coherent_read_accelerated: MemberType::Bool32,
+    shader_tile_image_read_sample_from_pixel_rate_invocation: MemberType::Bool32,
+    shader_tile_image_read_from_helper_invocation: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SHADING_RATE_IMAGE_PROPERTIES_NV: &[Member] = members!(vk::PhysicalDeviceShadingRateImagePropertiesNV<'static> {
+    shading_rate_texel_size: MemberType::Struct("VkExtent2D", EXTENT_2D),
+    shading_rate_palette_size: MemberType::U32,
+    shading_rate_max_coarse_samples: MemberType::U32,
+});
+
+const PHYSICAL_DEVICE_SUBGROUP_PROPERTIES: &[Member] = members!(vk::PhysicalDeviceSubgroupProperties<'static> {
+    subgroup_size: MemberType::U32,
+    supported_stages: MemberType::Flags("ShaderStageFlags"),
+    supported_operations: MemberType::Flags("SubgroupFeatureFlags"),
+    quad_operations_in_all_stages: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_SUBGROUP_SIZE_CONTROL_PROPERTIES: &[Member] = members!(vk::PhysicalDeviceSubgroupSizeControlProperties<'static> {
+    min_subgroup_size: MemberType::U32 => Min,
+    max_subgroup_size: MemberType::U32 => Max,
+    max_compute_workgroup_subgroups: MemberType::U32 => Max,
+    required_subgroup_size_stages: MemberType::Flags("ShaderStageFlags"),
+});
+
+const PHYSICAL_DEVICE_SUBPASS_SHADING_PROPERTIES_HUAWEI: &[Member] = members!(vk::PhysicalDeviceSubpassShadingPropertiesHUAWEI<'static> {
+    max_subpass_shading_workgroup_size_aspect_ratio: MemberType::U32 => Max,
+});
+
+const PHYSICAL_DEVICE_TEXEL_BUFFER_ALIGNMENT_PROPERTIES: &[Member] = members!(vk::PhysicalDeviceTexelBufferAlignmentProperties<'static> {
+    storage_texel_buffer_offset_alignment_bytes: MemberType::U64,
+    storage_texel_buffer_offset_single_texel_alignment: MemberType::Bool32,
+    uniform_texel_buffer_offset_alignment_bytes: MemberType::U64,
+    uniform_texel_buffer_offset_single_texel_alignment: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_TIMELINE_SEMAPHORE_PROPERTIES: &[Member] = members!(vk::PhysicalDeviceTimelineSemaphoreProperties<'static> {
+    max_timeline_semaphore_value_difference: MemberType::U64 => Max,
+});
+
+const PHYSICAL_DEVICE_TRANSFORM_FEEDBACK_PROPERTIES_EXT: &[Member] = members!(vk::PhysicalDeviceTransformFeedbackPropertiesEXT<'static> {
+    max_transform_feedback_streams: MemberType::U32 => Max,
+    max_transform_feedback_buffers: MemberType::U32 => Max,
+    max_transform_feedback_buffer_size: MemberType::U64 => Max,
+    max_transform_feedback_stream_data_size: MemberType::U32 => Max,
+    max_transform_feedback_buffer_data_size: MemberType::U32 => Max,
+    max_transform_feedback_buffer_data_stride: MemberType::U32 => Max,
+    transform_feedback_queries: MemberType::Bool32,
+    transform_feedback_streams_lines_triangles: MemberType::Bool32,
+    transform_feedback_rasterization_stream_select: MemberType::Bool32,
+    transform_feedback_draw: MemberType::Bool32,
+});
+
+const PHYSICAL_DEVICE_VULKAN_11PROPERTIES: &[Member] = members!(vk::PhysicalDeviceVulkan11Properties<'static> {
+    device_uuid: MemberType::Array(&MemberType::U8, vk::UUID_SIZE),
+    driver_uuid: MemberType::Array(&MemberType::U8, vk::UUID_SIZE),
+    device_luid: MemberType::Array(&MemberType::U8, vk::LUID_SIZE),
+    device_node_mask: MemberType::U32,
+    device_luid_valid: MemberType::Bool32,
+    subgroup_size: MemberType::U32,
+    subgroup_supported_stages: MemberType::Flags("ShaderStageFlags"),
+    subgroup_supported_operations: MemberType::Flags("SubgroupFeatureFlags"),
+    subgroup_quad_operations_in_all_stages: MemberType::Bool32,
+    point_clipping_behavior: MemberType::Enum("PointClippingBehavior"),
+    max_multiview_view_count: MemberType::U32 => Max,
+    max_multiview_instance_index: MemberType::U32 => Max,
+    protected_no_fault: MemberType::Bool32,
+    max_per_set_descriptors: MemberType::U32 => Max,
+    max_memory_allocation_size: MemberType::U64 => Max,
+});
+
+const PHYSICAL_DEVICE_VULKAN_12PROPERTIES: &[Member] = members!(vk::PhysicalDeviceVulkan12Properties<'static> {
+    driver_id: MemberType::Enum("DriverId"),
+    driver_name: MemberType::Chars(vk::MAX_DRIVER_NAME_SIZE),
+    driver_info: MemberType::Chars(vk::MAX_DRIVER_INFO_SIZE),
+    conformance_version: MemberType::Struct("VkConformanceVersion", CONFORMANCE_VERSION),
+    denorm_behavior_independence: MemberType::Enum("ShaderFloatControlsIndependence"),
+    rounding_mode_independence: MemberType::Enum("ShaderFloatControlsIndependence"),
+    shader_signed_zero_inf_nan_preserve_float16: MemberType::Bool32,
+    shader_signed_zero_inf_nan_preserve_float32: MemberType::Bool32,
+    shader_signed_zero_inf_nan_preserve_float64: MemberType::Bool32,
+    shader_denorm_preserve_float16: MemberType::Bool32,
+    shader_denorm_preserve_float32: MemberType::Bool32,
+    shader_denorm_preserve_float64: MemberType::Bool32,
+    shader_denorm_flush_to_zero_float16: MemberType::Bool32,
+    shader_denorm_flush_to_zero_float32: MemberType::Bool32,
+    shader_denorm_flush_to_zero_float64: MemberType::Bool32,
+    shader_rounding_mode_rte_float16: MemberType::Bool32,
+    shader_rounding_mode_rte_float32: MemberType::Bool32,
+    shader_rounding_mode_rte_float64: MemberType::Bool32,
+    shader_rounding_mode_rtz_float16: MemberType::Bool32,
+    shader_rounding_mode_rtz_float32: MemberType::Bool32,
+    shader_rounding_mode_rtz_float64: MemberType::Bool32,
+    max_update_after_bind_descriptors_in_all_pools: MemberType::U32 => Max,
+    shader_uniform_buffer_array_non_uniform_indexing_native: MemberType::Bool32,
+    shader_sampled_image_array_non_uniform_indexing_native: MemberType::Bool32,
+    shader_storage_buffer_array_non_uniform_indexing_native: MemberType::Bool32,
+    shader_storage_image_array_non_uniform_indexing_native: MemberType::Bool32,
+    shader_input_attachment_array_non_uniform_indexing_native: MemberType::Bool32,
+    robust_buffer_access_update_after_bind: MemberType::Bool32,
+    quad_divergent_implicit_lod: MemberType::Bool32,
+    max_per_stage_descriptor_update_after_bind_samplers: MemberType::U32 => Max,
+    max_per_stage_descriptor_update_after_bind_uniform_buffers: MemberType::U32 => Max,
+    max_per_stage_descriptor_update_after_bind_storage_buffers: MemberType::U32 => Max,
+    max_per_stage_descriptor_update_after_bind_sampled_images: MemberType::U32 => Max,
+    max_per_stage_descriptor_update_after_bind_storage_images: MemberType::U32 => Max,
+    max_per_stage_descriptor_update_after_bind_input_attachments: MemberType::U32 => Max,
+    max_per_stage_update_after_bind_resources: MemberType::U32 => Max,
+    max_descriptor_set_update_after_bind_samplers: MemberType::U32 => Max,
+    max_descriptor_set_update_after_bind_uniform_buffers: MemberType::U32 => Max,
+    max_descriptor_set_update_after_bind_uniform_buffers_dynamic: MemberType::U32 => Max,
+    max_descriptor_set_update_after_bind_storage_buffers: MemberType::U32 => Max,
+    max_descriptor_set_update_after_bind_storage_buffers_dynamic: MemberType::U32 => Max,
+    max_descriptor_set_update_after_bind_sampled_images: MemberType::U32 => Max,
+    max_descriptor_set_update_after_bind_storage_images: MemberType::U32 => Max,
+    max_descriptor_set_update_after_bind_input_attachments: MemberType::U32 => Max,
+    supported_depth_resolve_modes: MemberType::Flags("ResolveModeFlags"),
+    supported_stencil_resolve_modes: MemberType::Flags("ResolveModeFlags"),
+    independent_resolve_none: MemberType::Bool32,
+    independent_resolve: MemberType::Bool32,
+    filter_minmax_single_component_formats: MemberType::Bool32,
+    filter_minmax_image_component_mapping: MemberType::Bool32,
+    max_timeline_semaphore_value_difference: MemberType::U64 => Max,
+    framebuffer_integer_color_sample_counts: MemberType::Flags("SampleCountFlags"),
+});
+
+const PHYSICAL_DEVICE_VULKAN_13PROPERTIES: &[Member] = members!(vk::PhysicalDeviceVulkan13Properties<'static> {
+    min_subgroup_size: MemberType::U32 => Min,
+    max_subgroup_size: MemberType::U32 => Max,
+    max_compute_workgroup_subgroups: MemberType::U32 => Max,
+    required_subgroup_size_stages: MemberType::Flags("ShaderStageFlags"),
+    max_inline_uniform_block_size: MemberType::U32 => Max,
+    max_per_stage_descriptor_inline_uniform_blocks: MemberType::U32 => Max,
+    max_per_stage_descriptor_update_after_bind_inline_uniform_blocks: MemberType::U32 => Max,
+    max_descriptor_set_inline_uniform_blocks: MemberType::U32 => Max,
+    max_descriptor_set_update_after_bind_inline_uniform_blocks: MemberType::U32 => Max,
+    max_inline_uniform_total_size: MemberType::U32 => Max,
+    integer_dot_product8_bit_unsigned_accelerated: MemberType::Bool32,
+    integer_dot_product8_bit_signed_accelerated: MemberType::Bool32,
+    integer_dot_product8_bit_mixed_signedness_accelerated: MemberType::Bool32,
+    integer_dot_product4x8_bit_packed_unsigned_accelerated: MemberType::Bool32,
+    integer_dot_product4x8_bit_packed_signed_accelerated: MemberType::Bool32,
+    integer_dot_product4x8_bit_packed_mixed_signedness_accelerated: MemberType::Bool32,
+    integer_dot_product16_bit_unsigned_accelerated: MemberType::Bool32,
+    integer_dot_product16_bit_signed_accelerated: MemberType::Bool32,
+    integer_dot_product16_bit_mixed_signedness_accelerated: MemberType::Bool32,
+    integer_dot_product32_bit_unsigned_accelerated: MemberType::Bool32,
+    integer_dot_product32_bit_signed_accelerated: MemberType::Bool32,
+    integer_dot_product32_bit_mixed_signedness_accelerated: MemberType::Bool32,
+    integer_dot_product64_bit_unsigned_accelerated: MemberType::Bool32,
+    integer_dot_product64_bit_signed_accelerated: MemberType::Bool32,
+    integer_dot_product64_bit_mixed_signedness_accelerated: MemberType::Bool32,
+    integer_dot_product_accumulating_saturating8_bit_unsigned_accelerated: MemberType::Bool32,
+    integer_dot_product_accumulating_saturating8_bit_signed_accelerated: MemberType::Bool32,
+    integer_dot_product_accumulating_saturating8_bit_mixed_signedness_accelerated: MemberType::Bool32,
+    integer_dot_product_accumulating_saturating4x8_bit_packed_unsigned_accelerated: MemberType::Bool32,
+    integer_dot_product_accumulating_saturating4x8_bit_packed_signed_accelerated: MemberType::Bool32,
+    integer_dot_product_accumulating_saturating16_bit_unsigned_accelerated: MemberType::Bool32,
+    integer_dot_product_accumulating_saturating16_bit_signed_accelerated: MemberType::Bool32,
+    integer_dot_product_accumulating_saturating16_bit_mixed_signedness_accelerated: MemberType::Bool32,
+    integer_dot_product_accumulating_saturating32_bit_unsigned_accelerated: MemberType::Bool32,
+    integer_dot_product_accumulating_saturating32_bit_signed_accelerated: MemberType::Bool32,
+    integer_dot_product_accumulating_saturating32_bit_mixed_signedness_accelerated: MemberType::Bool32,
+    integer_dot_product_accumulating_saturating64_bit_unsigned_accelerated: MemberType::Bool32,
+    integer_dot_product_accumulating_saturating64_bit_signed_accelerated: MemberType::Bool32,
+    integer_dot_product_accumulating_saturating64_bit_mixed_signedness_accelerated: MemberType::Bool32,
+    storage_texel_buffer_offset_alignment_bytes: MemberType::U64,
+    storage_texel_buffer_offset_single_texel_alignment: MemberType::Bool32,
+    uniform_texel_buffer_offset_alignment_bytes: MemberType::U64,
+    uniform_texel_buffer_offset_single_texel_alignment: MemberType::Bool32,
+    max_buffer_size: MemberType::U64 => Max,
+});
+
+const FORMAT_PROPERTIES: &[Member] = members!(vk::FormatProperties {
+    linear_tiling_features: MemberType::Flags("FormatFeatureFlags"),
+    optimal_tiling_features: MemberType::Flags("FormatFeatureFlags"),
+    buffer_features: MemberType::Flags("FormatFeatureFlags"),
+});
+
+const FORMAT_PROPERTIES_2: &[Member] = members!(vk::FormatProperties2<'static> {
+    format_properties: MemberType::Struct("VkFormatProperties", FORMAT_PROPERTIES),
+});
+
+const DRM_FORMAT_MODIFIER_PROPERTIES_LIST_2EXT: &[Member] = members!(vk::DrmFormatModifierPropertiesList2EXT<'static> {
+    drm_format_modifier_count: MemberType::U32 => Max,
+    p_drm_format_modifier_properties: MemberType::Pointer,
+});
+
+const DRM_FORMAT_MODIFIER_PROPERTIES_LIST_EXT: &[Member] = members!(vk::DrmFormatModifierPropertiesListEXT<'static> {
+    drm_format_modifier_count: MemberType::U32 => Max,
+    p_drm_format_modifier_properties: MemberType::Pointer,
+});
+
+const FORMAT_PROPERTIES_3: &[Member] = members!(vk::FormatProperties3<'static> {
+    linear_tiling_features: MemberType::Flags64("FormatFeatureFlags2"),
+    optimal_tiling_features: MemberType::Flags64("FormatFeatureFlags2"),
+    buffer_features: MemberType::Flags64("FormatFeatureFlags2"),
+});
+
+const SUBPASS_RESOLVE_PERFORMANCE_QUERY_EXT: &[Member] = members!(vk::SubpassResolvePerformanceQueryEXT<'static> {
+    optimal: MemberType::Bool32,
+});
+
+const EXTENT_3D: &[Member] = members!(vk::Extent3D {
+    width: MemberType::U32,
+    height: MemberType::U32,
+    depth: MemberType::U32,
+});
+
+const QUEUE_FAMILY_PROPERTIES: &[Member] = members!(vk::QueueFamilyProperties {
+    queue_flags: MemberType::Flags("QueueFlags"),
+    queue_count: MemberType::U32 => Max,
+    timestamp_valid_bits: MemberType::U32 => Max,
+    min_image_transfer_granularity: MemberType::Struct("VkExtent3D", EXTENT_3D) => Min,
+});
+
+const QUEUE_FAMILY_PROPERTIES_2: &[Member] = members!(vk::QueueFamilyProperties2<'static> {
+    queue_family_properties: MemberType::Struct("VkQueueFamilyProperties", QUEUE_FAMILY_PROPERTIES),
+});
+
+const QUEUE_FAMILY_CHECKPOINT_PROPERTIES_2NV: &[Member] = members!(vk::QueueFamilyCheckpointProperties2NV<'static> {
+    checkpoint_execution_stage_mask: MemberType::Flags64("PipelineStageFlags2"),
+});
+
+const QUEUE_FAMILY_CHECKPOINT_PROPERTIES_NV: &[Member] = members!(vk::QueueFamilyCheckpointPropertiesNV<'static> {
+    checkpoint_execution_stage_mask: MemberType::Flags("PipelineStageFlags"),
+});
+
+const QUEUE_FAMILY_GLOBAL_PRIORITY_PROPERTIES_KHR: &[Member] = members!(vk::QueueFamilyGlobalPriorityPropertiesKHR<'static> {
+    priority_count: MemberType::U32 => Max,
+    priorities: MemberType::Array(&MemberType::Enum("QueueGlobalPriorityKHR"), vk::MAX_GLOBAL_PRIORITY_SIZE_KHR),
+});
+
+const QUEUE_FAMILY_QUERY_RESULT_STATUS_PROPERTIES_KHR: &[Member] = members!(vk::QueueFamilyQueryResultStatusPropertiesKHR<'static> {
+    query_result_status_support: MemberType::Bool32,
+});
+
+const QUEUE_FAMILY_VIDEO_PROPERTIES_KHR: &[Member] = members!(vk::QueueFamilyVideoPropertiesKHR<'static> {
+    video_codec_operations: MemberType::Flags("VideoCodecOperationFlagsKHR"),
+});
+
+const VIDEO_PROFILE_INFO_KHR: &[Member] = members!(vk::VideoProfileInfoKHR<'static> {
+    video_codec_operation: MemberType::Flags("VideoCodecOperationFlagsKHR"),
+    chroma_subsampling: MemberType::Flags("VideoChromaSubsamplingFlagsKHR"),
+    luma_bit_depth: MemberType::Flags("VideoComponentBitDepthFlagsKHR"),
+    chroma_bit_depth: MemberType::Flags("VideoComponentBitDepthFlagsKHR"),
+});
+
+const VIDEO_DECODE_AV1PROFILE_INFO_KHR: &[Member] = members!(vk::VideoDecodeAV1ProfileInfoKHR<'static> {
+    std_profile: MemberType::U32,
+    film_grain_support: MemberType::Bool32,
+});
+
+const VIDEO_DECODE_H264PROFILE_INFO_KHR: &[Member] = members!(vk::VideoDecodeH264ProfileInfoKHR<'static> {
+    std_profile_idc: MemberType::U32,
+    picture_layout: MemberType::Flags("VideoDecodeH264PictureLayoutFlagsKHR"),
+});
+
+const VIDEO_DECODE_H265PROFILE_INFO_KHR: &[Member] = members!(vk::VideoDecodeH265ProfileInfoKHR<'static> {
+    std_profile_idc: MemberType::U32,
+});
+
+const VIDEO_DECODE_USAGE_INFO_KHR: &[Member] = members!(vk::VideoDecodeUsageInfoKHR<'static> {
+    video_usage_hints: MemberType::Flags("VideoDecodeUsageFlagsKHR"),
+});
+
+const VIDEO_ENCODE_H264PROFILE_INFO_KHR: &[Member] = members!(vk::VideoEncodeH264ProfileInfoKHR<'static> {
+    std_profile_idc: MemberType::U32,
+});
+
+const VIDEO_ENCODE_H265PROFILE_INFO_KHR: &[Member] = members!(vk::VideoEncodeH265ProfileInfoKHR<'static> {
+    std_profile_idc: MemberType::U32,
+});
+
+const VIDEO_ENCODE_USAGE_INFO_KHR: &[Member] = members!(vk::VideoEncodeUsageInfoKHR<'static> {
+    video_usage_hints: MemberType::Flags("VideoEncodeUsageFlagsKHR"),
+    video_content_hints: MemberType::Flags("VideoEncodeContentFlagsKHR"),
+    tuning_mode: MemberType::Enum("VideoEncodeTuningModeKHR"),
+});
+
+const EXTENSION_PROPERTIES: &[Member] = members!(vk::ExtensionProperties {
+    extension_name: MemberType::Chars(vk::MAX_EXTENSION_NAME_SIZE),
+    spec_version: MemberType::U32,
+});
+
+const VIDEO_CAPABILITIES_KHR: &[Member] = members!(vk::VideoCapabilitiesKHR<'static> {
+    flags: MemberType::Flags("VideoCapabilityFlagsKHR"),
+    min_bitstream_buffer_offset_alignment: MemberType::U64 => Min,
+    min_bitstream_buffer_size_alignment: MemberType::U64 => Min,
+    picture_access_granularity: MemberType::Struct("VkExtent2D", EXTENT_2D) => Min,
+    min_coded_extent: MemberType::Struct("VkExtent2D", EXTENT_2D) => Min,
+    max_coded_extent: MemberType::Struct("VkExtent2D", EXTENT_2D) => Max,
+    max_dpb_slots: MemberType::U32 => Max,
+    max_active_reference_pictures: MemberType::U32 => Max,
+    std_header_version: MemberType::Struct("VkExtensionProperties", EXTENSION_PROPERTIES),
+});
+
+const VIDEO_DECODE_AV1CAPABILITIES_KHR: &[Member] = members!(vk::VideoDecodeAV1CapabilitiesKHR<'static> {
+    max_level: MemberType::U32 => Max,
+});
+
+const VIDEO_DECODE_CAPABILITIES_KHR: &[Member] = members!(vk::VideoDecodeCapabilitiesKHR<'static> {
+    flags: MemberType::Flags("VideoDecodeCapabilityFlagsKHR"),
+});
+
+const OFFSET_2D: &[Member] = members!(vk::Offset2D {
+    x: MemberType::I32,
+    y: MemberType::I32,
+});
+
+const VIDEO_DECODE_H264CAPABILITIES_KHR: &[Member] = members!(vk::VideoDecodeH264CapabilitiesKHR<'static> {
+    max_level_idc: MemberType::U32 => Max,
+    field_offset_granularity: MemberType::Struct("VkOffset2D", OFFSET_2D) => Min,
+});
+
+const VIDEO_DECODE_H265CAPABILITIES_KHR: &[Member] = members!(vk::VideoDecodeH265CapabilitiesKHR<'static> {
+    max_level_idc: MemberType::U32 => Max,
+});
+
+const VIDEO_ENCODE_CAPABILITIES_KHR: &[Member] = members!(vk::VideoEncodeCapabilitiesKHR<'static> {
+    flags: MemberType::Flags("VideoEncodeCapabilityFlagsKHR"),
+    rate_control_modes: MemberType::Flags("VideoEncodeRateControlModeFlagsKHR"),
+    max_rate_control_layers: MemberType::U32 => Max,
+    max_bitrate: MemberType::U64 => Max,
+    max_quality_levels: MemberType::U32 => Max,
+    encode_input_picture_granularity: MemberType::Struct("VkExtent2D", EXTENT_2D) => Min,
+    supported_encode_feedback_flags: MemberType::Flags("VideoEncodeFeedbackFlagsKHR"),
+});
+
+const VIDEO_ENCODE_H264CAPABILITIES_KHR: &[Member] = members!(vk::VideoEncodeH264CapabilitiesKHR<'static> {
+    flags: MemberType::Flags("VideoEncodeH264CapabilityFlagsKHR"),
+    max_level_idc: MemberType::U32 => Max,
+    max_slice_count: MemberType::U32 => Max,
+    max_p_picture_l0_reference_count: MemberType::U32 => Max,
+    max_b_picture_l0_reference_count: MemberType::U32 => Max,
+    max_l1_reference_count: MemberType::U32 => Max,
+    max_temporal_layer_count: MemberType::U32 => Max,
+    expect_dyadic_temporal_layer_pattern: MemberType::Bool32,
+    min_qp: MemberType::I32 => Min,
+    max_qp: MemberType::I32 => Max,
+    prefers_gop_remaining_frames: MemberType::Bool32,
+    requires_gop_remaining_frames: MemberType::Bool32,
+    std_syntax_flags: MemberType::Flags("VideoEncodeH264StdFlagsKHR"),
+});
+
+const VIDEO_ENCODE_H265CAPABILITIES_KHR: &[Member] = members!(vk::VideoEncodeH265CapabilitiesKHR<'static> {
+    flags: MemberType::Flags("VideoEncodeH265CapabilityFlagsKHR"),
+    max_level_idc: MemberType::U32 => Max,
+    max_slice_segment_count: MemberType::U32 => Max,
+    max_tiles: MemberType::Struct("VkExtent2D", EXTENT_2D) => Max,
+    ctb_sizes: MemberType::Flags("VideoEncodeH265CtbSizeFlagsKHR"),
+    transform_block_sizes: MemberType::Flags("VideoEncodeH265TransformBlockSizeFlagsKHR"),
+    max_p_picture_l0_reference_count: MemberType::U32 => Max,
+    max_b_picture_l0_reference_count: MemberType::U32 => Max,
+    max_l1_reference_count: MemberType::U32 => Max,
+    max_sub_layer_count: MemberType::U32 => Max,
+    expect_dyadic_temporal_sub_layer_pattern: MemberType::Bool32,
+    min_qp: MemberType::I32 => Min,
+    max_qp: MemberType::I32 => Max,
+    prefers_gop_remaining_frames: MemberType::Bool32,
+    requires_gop_remaining_frames: MemberType::Bool32,
+    std_syntax_flags: MemberType::Flags("VideoEncodeH265StdFlagsKHR"),
+});
+
+const COMPONENT_MAPPING: &[Member] = members!(vk::ComponentMapping {
+    r: MemberType::Enum("ComponentSwizzle"),
+    g: MemberType::Enum("ComponentSwizzle"),
+    b: MemberType::Enum("ComponentSwizzle"),
+    a: MemberType::Enum("ComponentSwizzle"),
+});
+
+const VIDEO_FORMAT_PROPERTIES_KHR: &[Member] = members!(vk::VideoFormatPropertiesKHR<'static> {
+    format: MemberType::Enum("Format"),
+    component_mapping: MemberType::Struct("VkComponentMapping", COMPONENT_MAPPING),
+    image_create_flags: MemberType::Flags("ImageCreateFlags"),
+    image_type: MemberType::Enum("ImageType"),
+    image_tiling: MemberType::Enum("ImageTiling"),
+    image_usage_flags: MemberType::Flags("ImageUsageFlags"),
+});
+
+/// The named values of the flags of the members.
+#[cfg(feature = "json")]
+pub(super) static FLAGS: &[ValueNames<u64>] = &[
+    ValueNames {
+        name: "FormatFeatureFlags",
+        prefix: "VK_FORMAT_FEATURE_",
+        known: &[
+            (vk::FormatFeatureFlags::SAMPLED_IMAGE.as_raw() as u64, "SAMPLED_IMAGE"),
+            (vk::FormatFeatureFlags::STORAGE_IMAGE.as_raw() as u64, "STORAGE_IMAGE"),
+            (vk::FormatFeatureFlags::STORAGE_IMAGE_ATOMIC.as_raw() as u64, "STORAGE_IMAGE_ATOMIC"),
+            (vk::FormatFeatureFlags::UNIFORM_TEXEL_BUFFER.as_raw() as u64, "UNIFORM_TEXEL_BUFFER"),
+            (vk::FormatFeatureFlags::STORAGE_TEXEL_BUFFER.as_raw() as u64, "STORAGE_TEXEL_BUFFER"),
+            (vk::FormatFeatureFlags::STORAGE_TEXEL_BUFFER_ATOMIC.as_raw() as u64, "STORAGE_TEXEL_BUFFER_ATOMIC"),
+            (vk::FormatFeatureFlags::VERTEX_BUFFER.as_raw() as u64, "VERTEX_BUFFER"),
+            (vk::FormatFeatureFlags::COLOR_ATTACHMENT.as_raw() as u64, "COLOR_ATTACHMENT"),
+            (vk::FormatFeatureFlags::COLOR_ATTACHMENT_BLEND.as_raw() as u64, "COLOR_ATTACHMENT_BLEND"),
+            (vk::FormatFeatureFlags::DEPTH_STENCIL_ATTACHMENT.as_raw() as u64, "DEPTH_STENCIL_ATTACHMENT"),
+            (vk::FormatFeatureFlags::BLIT_SRC.as_raw() as u64, "BLIT_SRC"),
+            (vk::FormatFeatureFlags::BLIT_DST.as_raw() as u64, "BLIT_DST"),
+            (vk::FormatFeatureFlags::SAMPLED_IMAGE_FILTER_LINEAR.as_raw() as u64, "SAMPLED_IMAGE_FILTER_LINEAR"),
+            (vk::FormatFeatureFlags::VIDEO_DECODE_OUTPUT_KHR.as_raw() as u64, "VIDEO_DECODE_OUTPUT_KHR"),
+            (vk::FormatFeatureFlags::VIDEO_DECODE_DPB_KHR.as_raw() as u64, "VIDEO_DECODE_DPB_KHR"),
+            (vk::FormatFeatureFlags::ACCELERATION_STRUCTURE_VERTEX_BUFFER_KHR.as_raw() as u64, "ACCELERATION_STRUCTURE_VERTEX_BUFFER_KHR"),
+            (vk::FormatFeatureFlags::SAMPLED_IMAGE_FILTER_CUBIC_EXT.as_raw() as u64, "SAMPLED_IMAGE_FILTER_CUBIC_EXT"),
+            (vk::FormatFeatureFlags::FRAGMENT_DENSITY_MAP_EXT.as_raw() as u64, "FRAGMENT_DENSITY_MAP_EXT"),
+            (vk::FormatFeatureFlags::FRAGMENT_SHADING_RATE_ATTACHMENT_KHR.as_raw() as u64, "FRAGMENT_SHADING_RATE_ATTACHMENT_KHR"),
+            (vk::FormatFeatureFlags::VIDEO_ENCODE_INPUT_KHR.as_raw() as u64, "VIDEO_ENCODE_INPUT_KHR"),
+            (vk::FormatFeatureFlags::VIDEO_ENCODE_DPB_KHR.as_raw() as u64, "VIDEO_ENCODE_DPB_KHR"),
+            (vk::FormatFeatureFlags::TRANSFER_SRC.as_raw() as u64, "TRANSFER_SRC"),
+            (vk::FormatFeatureFlags::TRANSFER_DST.as_raw() as u64, "TRANSFER_DST"),
+            (vk::FormatFeatureFlags::MIDPOINT_CHROMA_SAMPLES.as_raw() as u64, "MIDPOINT_CHROMA_SAMPLES"),
+            (vk::FormatFeatureFlags::SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER.as_raw() as u64, "SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER"),
+            (vk::FormatFeatureFlags::SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER.as_raw() as u64, "SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER"),
+            (vk::FormatFeatureFlags::SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT.as_raw() as u64, "SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT"),
+            (vk::FormatFeatureFlags::SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE.as_raw() as u64, "SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE"),
+            (vk::FormatFeatureFlags::DISJOINT.as_raw() as u64, "DISJOINT"),
+            (vk::FormatFeatureFlags::COSITED_CHROMA_SAMPLES.as_raw() as u64, "COSITED_CHROMA_SAMPLES"),
+            (vk::FormatFeatureFlags::SAMPLED_IMAGE_FILTER_MINMAX.as_raw() as u64, "SAMPLED_IMAGE_FILTER_MINMAX"),
+        ],
+    },
+    ValueNames {
+        name: "FormatFeatureFlags2",
+        prefix: "VK_FORMAT_FEATURE_2_",
+        known: &[
+            (vk::FormatFeatureFlags2::SAMPLED_IMAGE.as_raw(), "SAMPLED_IMAGE"),
+            (vk::FormatFeatureFlags2::STORAGE_IMAGE.as_raw(), "STORAGE_IMAGE"),
+            (vk::FormatFeatureFlags2::STORAGE_IMAGE_ATOMIC.as_raw(), "STORAGE_IMAGE_ATOMIC"),
+            (vk::FormatFeatureFlags2::UNIFORM_TEXEL_BUFFER.as_raw(), "UNIFORM_TEXEL_BUFFER"),
+            (vk::FormatFeatureFlags2::STORAGE_TEXEL_BUFFER.as_raw(), "STORAGE_TEXEL_BUFFER"),
+            (vk::FormatFeatureFlags2::STORAGE_TEXEL_BUFFER_ATOMIC.as_raw(), "STORAGE_TEXEL_BUFFER_ATOMIC"),
+            (vk::FormatFeatureFlags2::VERTEX_BUFFER.as_raw(), "VERTEX_BUFFER"),
+            (vk::FormatFeatureFlags2::COLOR_ATTACHMENT.as_raw(), "COLOR_ATTACHMENT"),
+            (vk::FormatFeatureFlags2::COLOR_ATTACHMENT_BLEND.as_raw(), "COLOR_ATTACHMENT_BLEND"),
+            (vk::FormatFeatureFlags2::DEPTH_STENCIL_ATTACHMENT.as_raw(), "DEPTH_STENCIL_ATTACHMENT"),
+            (vk::FormatFeatureFlags2::BLIT_SRC.as_raw(), "BLIT_SRC"),
+            (vk::FormatFeatureFlags2::BLIT_DST.as_raw(), "BLIT_DST"),
+            (vk::FormatFeatureFlags2::SAMPLED_IMAGE_FILTER_LINEAR.as_raw(), "SAMPLED_IMAGE_FILTER_LINEAR"),
+            (vk::FormatFeatureFlags2::SAMPLED_IMAGE_FILTER_CUBIC.as_raw(), "SAMPLED_IMAGE_FILTER_CUBIC"),
+            (vk::FormatFeatureFlags2::TRANSFER_SRC.as_raw(), "TRANSFER_SRC"),
+            (vk::FormatFeatureFlags2::TRANSFER_DST.as_raw(), "TRANSFER_DST"),
+            (vk::FormatFeatureFlags2::SAMPLED_IMAGE_FILTER_MINMAX.as_raw(), "SAMPLED_IMAGE_FILTER_MINMAX"),
+            (vk::FormatFeatureFlags2::MIDPOINT_CHROMA_SAMPLES.as_raw(), "MIDPOINT_CHROMA_SAMPLES"),
+            (vk::FormatFeatureFlags2::SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER.as_raw(), "SAMPLED_IMAGE_YCBCR_CONVERSION_LINEAR_FILTER"),
+            (vk::FormatFeatureFlags2::SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER.as_raw(), "SAMPLED_IMAGE_YCBCR_CONVERSION_SEPARATE_RECONSTRUCTION_FILTER"),
+            (vk::FormatFeatureFlags2::SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT.as_raw(), "SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT"),
+            (vk::FormatFeatureFlags2::SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE.as_raw(), "SAMPLED_IMAGE_YCBCR_CONVERSION_CHROMA_RECONSTRUCTION_EXPLICIT_FORCEABLE"),
+            (vk::FormatFeatureFlags2::DISJOINT.as_raw(), "DISJOINT"),
+            (vk::FormatFeatureFlags2::COSITED_CHROMA_SAMPLES.as_raw(), "COSITED_CHROMA_SAMPLES"),
+            (vk::FormatFeatureFlags2::STORAGE_READ_WITHOUT_FORMAT.as_raw(), "STORAGE_READ_WITHOUT_FORMAT"),
+            (vk::FormatFeatureFlags2::STORAGE_WRITE_WITHOUT_FORMAT.as_raw(), "STORAGE_WRITE_WITHOUT_FORMAT"),
+            (vk::FormatFeatureFlags2::SAMPLED_IMAGE_DEPTH_COMPARISON.as_raw(), "SAMPLED_IMAGE_DEPTH_COMPARISON"),
+            (vk::FormatFeatureFlags2::VIDEO_DECODE_OUTPUT_KHR.as_raw(), "VIDEO_DECODE_OUTPUT_KHR"),
+            (vk::FormatFeatureFlags2::VIDEO_DECODE_DPB_KHR.as_raw(), "VIDEO_DECODE_DPB_KHR"),
+            (vk::FormatFeatureFlags2::ACCELERATION_STRUCTURE_VERTEX_BUFFER_KHR.as_raw(), "ACCELERATION_STRUCTURE_VERTEX_BUFFER_KHR"),
+            (vk::FormatFeatureFlags2::FRAGMENT_DENSITY_MAP_EXT.as_raw(), "FRAGMENT_DENSITY_MAP_EXT"),
+            (vk::FormatFeatureFlags2::FRAGMENT_SHADING_RATE_ATTACHMENT_KHR.as_raw(), "FRAGMENT_SHADING_RATE_ATTACHMENT_KHR"),
+            (vk::FormatFeatureFlags2::HOST_IMAGE_TRANSFER_EXT.as_raw(), "HOST_IMAGE_TRANSFER_EXT"),
+            (vk::FormatFeatureFlags2::VIDEO_ENCODE_INPUT_KHR.as_raw(), "VIDEO_ENCODE_INPUT_KHR"),
+            (vk::FormatFeatureFlags2::VIDEO_ENCODE_DPB_KHR.as_raw(), "VIDEO_ENCODE_DPB_KHR"),
+            (vk::FormatFeatureFlags2::LINEAR_COLOR_ATTACHMENT_NV.as_raw(), "LINEAR_COLOR_ATTACHMENT_NV"),
+            (vk::FormatFeatureFlags2::WEIGHT_IMAGE_QCOM.as_raw(), "WEIGHT_IMAGE_QCOM"),
+            (vk::FormatFeatureFlags2::WEIGHT_SAMPLED_IMAGE_QCOM.as_raw(), "WEIGHT_SAMPLED_IMAGE_QCOM"),
+            (vk::FormatFeatureFlags2::BLOCK_MATCHING_QCOM.as_raw(), "BLOCK_MATCHING_QCOM"),
+            (vk::FormatFeatureFlags2::BOX_FILTER_SAMPLED_QCOM.as_raw(), "BOX_FILTER_SAMPLED_QCOM"),
+            (vk::FormatFeatureFlags2::OPTICAL_FLOW_IMAGE_NV.as_raw(), "OPTICAL_FLOW_IMAGE_NV"),
+            (vk::FormatFeatureFlags2::OPTICAL_FLOW_VECTOR_NV.as_raw(), "OPTICAL_FLOW_VECTOR_NV"),
+            (vk::FormatFeatureFlags2::OPTICAL_FLOW_COST_NV.as_raw(), "OPTICAL_FLOW_COST_NV"),
+        ],
+    },
+    ValueNames {
+        name: "ImageCreateFlags",
+        prefix: "VK_IMAGE_CREATE_",
+        known: &[
+            (vk::ImageCreateFlags::SPARSE_BINDING.as_raw() as u64, "SPARSE_BINDING"),
+            (vk::ImageCreateFlags::SPARSE_RESIDENCY.as_raw() as u64, "SPARSE_RESIDENCY"),
+            (vk::ImageCreateFlags::SPARSE_ALIASED.as_raw() as u64, "SPARSE_ALIASED"),
+            (vk::ImageCreateFlags::MUTABLE_FORMAT.as_raw() as u64, "MUTABLE_FORMAT"),
+            (vk::ImageCreateFlags::CUBE_COMPATIBLE.as_raw() as u64, "CUBE_COMPATIBLE"),
+            (vk::ImageCreateFlags::CORNER_SAMPLED_NV.as_raw() as u64, "CORNER_SAMPLED_NV"),
+            (vk::ImageCreateFlags::SAMPLE_LOCATIONS_COMPATIBLE_DEPTH_EXT.as_raw() as u64, "SAMPLE_LOCATIONS_COMPATIBLE_DEPTH_EXT"),
+            (vk::ImageCreateFlags::SUBSAMPLED_EXT.as_raw() as u64, "SUBSAMPLED_EXT"),
+            (vk::ImageCreateFlags::DESCRIPTOR_BUFFER_CAPTURE_REPLAY_EXT.as_raw() as u64, "DESCRIPTOR_BUFFER_CAPTURE_REPLAY_EXT"),
+            (vk::ImageCreateFlags::MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_EXT.as_raw() as u64, "MULTISAMPLED_RENDER_TO_SINGLE_SAMPLED_EXT"),
+            (vk::ImageCreateFlags::TYPE_2D_VIEW_COMPATIBLE_EXT.as_raw() as u64, "TYPE_2D_VIEW_COMPATIBLE_EXT"),
+            (vk::ImageCreateFlags::FRAGMENT_DENSITY_MAP_OFFSET_QCOM.as_raw() as u64, "FRAGMENT_DENSITY_MAP_OFFSET_QCOM"),
+            (vk::ImageCreateFlags::VIDEO_PROFILE_INDEPENDENT_KHR.as_raw() as u64, "VIDEO_PROFILE_INDEPENDENT_KHR"),
+            (vk::ImageCreateFlags::ALIAS.as_raw() as u64, "ALIAS"),
+            (vk::ImageCreateFlags::SPLIT_INSTANCE_BIND_REGIONS.as_raw() as u64, "SPLIT_INSTANCE_BIND_REGIONS"),
+            (vk::ImageCreateFlags::TYPE_2D_ARRAY_COMPATIBLE.as_raw() as u64, "TYPE_2D_ARRAY_COMPATIBLE"),
+            (vk::ImageCreateFlags::BLOCK_TEXEL_VIEW_COMPATIBLE.as_raw() as u64, "BLOCK_TEXEL_VIEW_COMPATIBLE"),
+            (vk::ImageCreateFlags::EXTENDED_USAGE.as_raw() as u64, "EXTENDED_USAGE"),
+            (vk::ImageCreateFlags::PROTECTED.as_raw() as u64, "PROTECTED"),
+            (vk::ImageCreateFlags::DISJOINT.as_raw() as u64, "DISJOINT"),
+        ],
+    },
+    ValueNames {
+        name: "ImageUsageFlags",
+        prefix: "VK_IMAGE_USAGE_",
+        known: &[
+            (vk::ImageUsageFlags::TRANSFER_SRC.as_raw() as u64, "TRANSFER_SRC"),
+            (vk::ImageUsageFlags::TRANSFER_DST.as_raw() as u64, "TRANSFER_DST"),
+            (vk::ImageUsageFlags::SAMPLED.as_raw() as u64, "SAMPLED"),
+            (vk::ImageUsageFlags::STORAGE.as_raw() as u64, "STORAGE"),
+            (vk::ImageUsageFlags::COLOR_ATTACHMENT.as_raw() as u64, "COLOR_ATTACHMENT"),
+            (vk::ImageUsageFlags::DEPTH_STENCIL_ATTACHMENT.as_raw() as u64, "DEPTH_STENCIL_ATTACHMENT"),
+            (vk::ImageUsageFlags::TRANSIENT_ATTACHMENT.as_raw() as u64, "TRANSIENT_ATTACHMENT"),
+            (vk::ImageUsageFlags::INPUT_ATTACHMENT.as_raw() as u64, "INPUT_ATTACHMENT"),
+            (vk::ImageUsageFlags::VIDEO_DECODE_DST_KHR.as_raw() as u64, "VIDEO_DECODE_DST_KHR"),
+            (vk::ImageUsageFlags::VIDEO_DECODE_SRC_KHR.as_raw() as u64, "VIDEO_DECODE_SRC_KHR"),
+            (vk::ImageUsageFlags::VIDEO_DECODE_DPB_KHR.as_raw() as u64, "VIDEO_DECODE_DPB_KHR"),
+            (vk::ImageUsageFlags::FRAGMENT_DENSITY_MAP_EXT.as_raw() as u64, "FRAGMENT_DENSITY_MAP_EXT"),
+            (vk::ImageUsageFlags::FRAGMENT_SHADING_RATE_ATTACHMENT_KHR.as_raw() as u64, "FRAGMENT_SHADING_RATE_ATTACHMENT_KHR"),
+            (vk::ImageUsageFlags::HOST_TRANSFER_EXT.as_raw() as u64, "HOST_TRANSFER_EXT"),
+            (vk::ImageUsageFlags::VIDEO_ENCODE_DST_KHR.as_raw() as u64, "VIDEO_ENCODE_DST_KHR"),
+            (vk::ImageUsageFlags::VIDEO_ENCODE_SRC_KHR.as_raw() as u64, "VIDEO_ENCODE_SRC_KHR"),
+            (vk::ImageUsageFlags::VIDEO_ENCODE_DPB_KHR.as_raw() as u64, "VIDEO_ENCODE_DPB_KHR"),
+            (vk::ImageUsageFlags::ATTACHMENT_FEEDBACK_LOOP_EXT.as_raw() as u64, "ATTACHMENT_FEEDBACK_LOOP_EXT"),
+            (vk::ImageUsageFlags::INVOCATION_MASK_HUAWEI.as_raw() as u64, "INVOCATION_MASK_HUAWEI"),
+            (vk::ImageUsageFlags::SAMPLE_WEIGHT_QCOM.as_raw() as u64, "SAMPLE_WEIGHT_QCOM"),
+            (vk::ImageUsageFlags::SAMPLE_BLOCK_MATCH_QCOM.as_raw() as u64, "SAMPLE_BLOCK_MATCH_QCOM"),
+        ],
+    },
+    ValueNames {
+        name: "MemoryDecompressionMethodFlagsNV",
+        prefix: "VK_MEMORY_DECOMPRESSION_METHOD_",
+        known: &[
+            (vk::MemoryDecompressionMethodFlagsNV::GDEFLATE_1_0.as_raw(), "GDEFLATE_1_0"),
+        ],
+    },
+    ValueNames {
+        name: "OpticalFlowGridSizeFlagsNV",
+        prefix: "VK_OPTICAL_FLOW_GRID_SIZE_",
+        known: &[
+            (vk::OpticalFlowGridSizeFlagsNV::UNKNOWN.as_raw() as u64, "UNKNOWN"),
+            (vk::OpticalFlowGridSizeFlagsNV::TYPE_1X1.as_raw() as u64, "TYPE_1X1"),
+            (vk::OpticalFlowGridSizeFlagsNV::TYPE_2X2.as_raw() as u64, "TYPE_2X2"),
+            (vk::OpticalFlowGridSizeFlagsNV::TYPE_4X4.as_raw() as u64, "TYPE_4X4"),
+            (vk::OpticalFlowGridSizeFlagsNV::TYPE_8X8.as_raw() as u64, "TYPE_8X8"),
+        ],
+    },
+    ValueNames {
+        name: "PhysicalDeviceSchedulingControlsFlagsARM",
+        prefix: "VK_PHYSICAL_DEVICE_SCHEDULING_CONTROLS_",
+        known: &[
+            (vk::PhysicalDeviceSchedulingControlsFlagsARM::SHADER_CORE_COUNT.as_raw(), "SHADER_CORE_COUNT"),
+        ],
+    },
+    ValueNames {
+        name: "PipelineStageFlags",
+        prefix: "VK_PIPELINE_STAGE_",
+        known: &[
+            (vk::PipelineStageFlags::TOP_OF_PIPE.as_raw() as u64, "TOP_OF_PIPE"),
+            (vk::PipelineStageFlags::DRAW_INDIRECT.as_raw() as u64, "DRAW_INDIRECT"),
+            (vk::PipelineStageFlags::VERTEX_INPUT.as_raw() as u64, "VERTEX_INPUT"),
+            (vk::PipelineStageFlags::VERTEX_SHADER.as_raw() as u64, "VERTEX_SHADER"),
+            (vk::PipelineStageFlags::TESSELLATION_CONTROL_SHADER.as_raw() as u64, "TESSELLATION_CONTROL_SHADER"),
+            (vk::PipelineStageFlags::TESSELLATION_EVALUATION_SHADER.as_raw() as u64, "TESSELLATION_EVALUATION_SHADER"),
+            (vk::PipelineStageFlags::GEOMETRY_SHADER.as_raw() as u64, "GEOMETRY_SHADER"),
+            (vk::PipelineStageFlags::FRAGMENT_SHADER.as_raw() as u64, "FRAGMENT_SHADER"),
+            (vk::PipelineStageFlags::EARLY_FRAGMENT_TESTS.as_raw() as u64, "EARLY_FRAGMENT_TESTS"),
+            (vk::PipelineStageFlags::LATE_FRAGMENT_TESTS.as_raw() as u64, "LATE_FRAGMENT_TESTS"),
+            (vk::PipelineStageFlags::COLOR_ATTACHMENT_OUTPUT.as_raw() as u64, "COLOR_ATTACHMENT_OUTPUT"),
+            (vk::PipelineStageFlags::COMPUTE_SHADER.as_raw() as u64, "COMPUTE_SHADER"),
+            (vk::PipelineStageFlags::TRANSFER.as_raw() as u64, "TRANSFER"),
+            (vk::PipelineStageFlags::BOTTOM_OF_PIPE.as_raw() as u64, "BOTTOM_OF_PIPE"),
+            (vk::PipelineStageFlags::HOST.as_raw() as u64, "HOST"),
+            (vk::PipelineStageFlags::ALL_GRAPHICS.as_raw() as u64, "ALL_GRAPHICS"),
+            (vk::PipelineStageFlags::ALL_COMMANDS.as_raw() as u64, "ALL_COMMANDS"),
+            (vk::PipelineStageFlags::TRANSFORM_FEEDBACK_EXT.as_raw() as u64, "TRANSFORM_FEEDBACK_EXT"),
+            (vk::PipelineStageFlags::CONDITIONAL_RENDERING_EXT.as_raw() as u64, "CONDITIONAL_RENDERING_EXT"),
+            (vk::PipelineStageFlags::ACCELERATION_STRUCTURE_BUILD_KHR.as_raw() as u64, "ACCELERATION_STRUCTURE_BUILD_KHR"),
+            (vk::PipelineStageFlags::RAY_TRACING_SHADER_KHR.as_raw() as u64, "RAY_TRACING_SHADER_KHR"),
+            (vk::PipelineStageFlags::FRAGMENT_DENSITY_PROCESS_EXT.as_raw() as u64, "FRAGMENT_DENSITY_PROCESS_EXT"),
+            (vk::PipelineStageFlags::FRAGMENT_SHADING_RATE_ATTACHMENT_KHR.as_raw() as u64, "FRAGMENT_SHADING_RATE_ATTACHMENT_KHR"),
+            (vk::PipelineStageFlags::COMMAND_PREPROCESS_NV.as_raw() as u64, "COMMAND_PREPROCESS_NV"),
+            (vk::PipelineStageFlags::TASK_SHADER_EXT.as_raw() as u64, "TASK_SHADER_EXT"),
+            (vk::PipelineStageFlags::MESH_SHADER_EXT.as_raw() as u64, "MESH_SHADER_EXT"),
+            (vk::PipelineStageFlags::NONE.as_raw() as u64, "NONE"),
+        ],
+    },
+    ValueNames {
+        name: "PipelineStageFlags2",
+        prefix: "VK_PIPELINE_STAGE_2_",
+        known: &[
+            (vk::PipelineStageFlags2::NONE.as_raw(), "NONE"),
+            (vk::PipelineStageFlags2::TOP_OF_PIPE.as_raw(), "TOP_OF_PIPE"),
+            (vk::PipelineStageFlags2::DRAW_INDIRECT.as_raw(), "DRAW_INDIRECT"),
+            (vk::PipelineStageFlags2::VERTEX_INPUT.as_raw(), "VERTEX_INPUT"),
+            (vk::PipelineStageFlags2::VERTEX_SHADER.as_raw(), "VERTEX_SHADER"),
+            (vk::PipelineStageFlags2::TESSELLATION_CONTROL_SHADER.as_raw(), "TESSELLATION_CONTROL_SHADER"),
+            (vk::PipelineStageFlags2::TESSELLATION_EVALUATION_SHADER.as_raw(), "TESSELLATION_EVALUATION_SHADER"),
+            (vk::PipelineStageFlags2::GEOMETRY_SHADER.as_raw(), "GEOMETRY_SHADER"),
+            (vk::PipelineStageFlags2::FRAGMENT_SHADER.as_raw(), "FRAGMENT_SHADER"),
+            (vk::PipelineStageFlags2::EARLY_FRAGMENT_TESTS.as_raw(), "EARLY_FRAGMENT_TESTS"),
+            (vk::PipelineStageFlags2::LATE_FRAGMENT_TESTS.as_raw(), "LATE_FRAGMENT_TESTS"),
+            (vk::PipelineStageFlags2::COLOR_ATTACHMENT_OUTPUT.as_raw(), "COLOR_ATTACHMENT_OUTPUT"),
+            (vk::PipelineStageFlags2::COMPUTE_SHADER.as_raw(), "COMPUTE_SHADER"),
+            (vk::PipelineStageFlags2::ALL_TRANSFER.as_raw(), "ALL_TRANSFER"),
+            (vk::PipelineStageFlags2::BOTTOM_OF_PIPE.as_raw(), "BOTTOM_OF_PIPE"),
+            (vk::PipelineStageFlags2::HOST.as_raw(), "HOST"),
+            (vk::PipelineStageFlags2::ALL_GRAPHICS.as_raw(), "ALL_GRAPHICS"),
+            (vk::PipelineStageFlags2::ALL_COMMANDS.as_raw(), "ALL_COMMANDS"),
+            (vk::PipelineStageFlags2::COPY.as_raw(), "COPY"),
+            (vk::PipelineStageFlags2::RESOLVE.as_raw(), "RESOLVE"),
+            (vk::PipelineStageFlags2::BLIT.as_raw(), "BLIT"),
+            (vk::PipelineStageFlags2::CLEAR.as_raw(), "CLEAR"),
+            (vk::PipelineStageFlags2::INDEX_INPUT.as_raw(), "INDEX_INPUT"),
+            (vk::PipelineStageFlags2::VERTEX_ATTRIBUTE_INPUT.as_raw(), "VERTEX_ATTRIBUTE_INPUT"),
+            (vk::PipelineStageFlags2::PRE_RASTERIZATION_SHADERS.as_raw(), "PRE_RASTERIZATION_SHADERS"),
+            (vk::PipelineStageFlags2::VIDEO_DECODE_KHR.as_raw(), "VIDEO_DECODE_KHR"),
+            (vk::PipelineStageFlags2::VIDEO_ENCODE_KHR.as_raw(), "VIDEO_ENCODE_KHR"),
+            (vk::PipelineStageFlags2::TRANSFORM_FEEDBACK_EXT.as_raw(), "TRANSFORM_FEEDBACK_EXT"),
+            (vk::PipelineStageFlags2::CONDITIONAL_RENDERING_EXT.as_raw(), "CONDITIONAL_RENDERING_EXT"),
+            (vk::PipelineStageFlags2::COMMAND_PREPROCESS_NV.as_raw(), "COMMAND_PREPROCESS_NV"),
+            (vk::PipelineStageFlags2::FRAGMENT_SHADING_RATE_ATTACHMENT_KHR.as_raw(), "FRAGMENT_SHADING_RATE_ATTACHMENT_KHR"),
+            (vk::PipelineStageFlags2::ACCELERATION_STRUCTURE_BUILD_KHR.as_raw(), "ACCELERATION_STRUCTURE_BUILD_KHR"),
+            (vk::PipelineStageFlags2::RAY_TRACING_SHADER_KHR.as_raw(), "RAY_TRACING_SHADER_KHR"),
+            (vk::PipelineStageFlags2::FRAGMENT_DENSITY_PROCESS_EXT.as_raw(), "FRAGMENT_DENSITY_PROCESS_EXT"),
+            (vk::PipelineStageFlags2::TASK_SHADER_EXT.as_raw(), "TASK_SHADER_EXT"),
+            (vk::PipelineStageFlags2::MESH_SHADER_EXT.as_raw(), "MESH_SHADER_EXT"),
+            (vk::PipelineStageFlags2::SUBPASS_SHADER_HUAWEI.as_raw(), "SUBPASS_SHADER_HUAWEI"),
+            (vk::PipelineStageFlags2::INVOCATION_MASK_HUAWEI.as_raw(), "INVOCATION_MASK_HUAWEI"),
+            (vk::PipelineStageFlags2::ACCELERATION_STRUCTURE_COPY_KHR.as_raw(), "ACCELERATION_STRUCTURE_COPY_KHR"),
+            (vk::PipelineStageFlags2::MICROMAP_BUILD_EXT.as_raw(), "MICROMAP_BUILD_EXT"),
+            (vk::PipelineStageFlags2::CLUSTER_CULLING_SHADER_HUAWEI.as_raw(), "CLUSTER_CULLING_SHADER_HUAWEI"),
+            (vk::PipelineStageFlags2::OPTICAL_FLOW_NV.as_raw(), "OPTICAL_FLOW_NV"),
+        ],
+    },
+    ValueNames {
+        name: "QueueFlags",
+        prefix: "VK_QUEUE_",
+        known: &[
+            (vk::QueueFlags::GRAPHICS.as_raw() as u64, "GRAPHICS"),
+            (vk::QueueFlags::COMPUTE.as_raw() as u64, "COMPUTE"),
+            (vk::QueueFlags::TRANSFER.as_raw() as u64, "TRANSFER"),
+            (vk::QueueFlags::SPARSE_BINDING.as_raw() as u64, "SPARSE_BINDING"),
+            (vk::QueueFlags::VIDEO_DECODE_KHR.as_raw() as u64, "VIDEO_DECODE_KHR"),
+            (vk::QueueFlags::VIDEO_ENCODE_KHR.as_raw() as u64, "VIDEO_ENCODE_KHR"),
+            (vk::QueueFlags::OPTICAL_FLOW_NV.as_raw() as u64, "OPTICAL_FLOW_NV"),
+            (vk::QueueFlags::PROTECTED.as_raw() as u64, "PROTECTED"),
+        ],
+    },
+    ValueNames {
+        name: "ResolveModeFlags",
+        prefix: "VK_RESOLVE_MODE_",
+        known: &[
+            (vk::ResolveModeFlags::NONE.as_raw() as u64, "NONE"),
+            (vk::ResolveModeFlags::SAMPLE_ZERO.as_raw() as u64, "SAMPLE_ZERO"),
+            (vk::ResolveModeFlags::AVERAGE.as_raw() as u64, "AVERAGE"),
+            (vk::ResolveModeFlags::MIN.as_raw() as u64, "MIN"),
+            (vk::ResolveModeFlags::MAX.as_raw() as u64, "MAX"),
+            (vk::ResolveModeFlags::EXTERNAL_FORMAT_DOWNSAMPLE_ANDROID.as_raw() as u64, "EXTERNAL_FORMAT_DOWNSAMPLE_ANDROID"),
+        ],
+    },
+    ValueNames {
+        name: "SampleCountFlags",
+        prefix: "VK_SAMPLE_COUNT_",
+        known: &[
+            (vk::SampleCountFlags::TYPE_1.as_raw() as u64, "TYPE_1"),
+            (vk::SampleCountFlags::TYPE_2.as_raw() as u64, "TYPE_2"),
+            (vk::SampleCountFlags::TYPE_4.as_raw() as u64, "TYPE_4"),
+            (vk::SampleCountFlags::TYPE_8.as_raw() as u64, "TYPE_8"),
+            (vk::SampleCountFlags::TYPE_16.as_raw() as u64, "TYPE_16"),
+            (vk::SampleCountFlags::TYPE_32.as_raw() as u64, "TYPE_32"),
+            (vk::SampleCountFlags::TYPE_64.as_raw() as u64, "TYPE_64"),
+        ],
+    },
+    ValueNames {
+        name: "ShaderCorePropertiesFlagsAMD",
+        prefix: "VK_SHADER_CORE_PROPERTIES_",
+        known: &[
+        ],
+    },
+    ValueNames {
+        name: "ShaderStageFlags",
+        prefix: "VK_SHADER_STAGE_",
+        known: &[
+            (vk::ShaderStageFlags::VERTEX.as_raw() as u64, "VERTEX"),
+            (vk::ShaderStageFlags::TESSELLATION_CONTROL.as_raw() as u64, "TESSELLATION_CONTROL"),
+            (vk::ShaderStageFlags::TESSELLATION_EVALUATION.as_raw() as u64, "TESSELLATION_EVALUATION"),
+            (vk::ShaderStageFlags::GEOMETRY.as_raw() as u64, "GEOMETRY"),
+            (vk::ShaderStageFlags::FRAGMENT.as_raw() as u64, "FRAGMENT"),
+            (vk::ShaderStageFlags::COMPUTE.as_raw() as u64, "COMPUTE"),
+            (vk::ShaderStageFlags::ALL_GRAPHICS.as_raw() as u64, "ALL_GRAPHICS"),
+            (vk::ShaderStageFlags::ALL.as_raw() as u64, "ALL"),
+            (vk::ShaderStageFlags::RAYGEN_KHR.as_raw() as u64, "RAYGEN_KHR"),
+            (vk::ShaderStageFlags::ANY_HIT_KHR.as_raw() as u64, "ANY_HIT_KHR"),
+            (vk::ShaderStageFlags::CLOSEST_HIT_KHR.as_raw() as u64, "CLOSEST_HIT_KHR"),
+            (vk::ShaderStageFlags::MISS_KHR.as_raw() as u64, "MISS_KHR"),
+            (vk::ShaderStageFlags::INTERSECTION_KHR.as_raw() as u64, "INTERSECTION_KHR"),
+            (vk::ShaderStageFlags::CALLABLE_KHR.as_raw() as u64, "CALLABLE_KHR"),
+            (vk::ShaderStageFlags::TASK_EXT.as_raw() as u64, "TASK_EXT"),
+            (vk::ShaderStageFlags::MESH_EXT.as_raw() as u64, "MESH_EXT"),
+            (vk::ShaderStageFlags::SUBPASS_SHADING_HUAWEI.as_raw() as u64, "SUBPASS_SHADING_HUAWEI"),
+            (vk::ShaderStageFlags::CLUSTER_CULLING_HUAWEI.as_raw() as u64, "CLUSTER_CULLING_HUAWEI"),
+        ],
+    },
+    ValueNames {
+        name: "SubgroupFeatureFlags",
+        prefix: "VK_SUBGROUP_FEATURE_",
+        known: &[
+            (vk::SubgroupFeatureFlags::BASIC.as_raw() as u64, "BASIC"),
+            (vk::SubgroupFeatureFlags::VOTE.as_raw() as u64, "VOTE"),
+            (vk::SubgroupFeatureFlags::ARITHMETIC.as_raw() as u64, "ARITHMETIC"),
+            (vk::SubgroupFeatureFlags::BALLOT.as_raw() as u64, "BALLOT"),
+            (vk::SubgroupFeatureFlags::SHUFFLE.as_raw() as u64, "SHUFFLE"),
+            (vk::SubgroupFeatureFlags::SHUFFLE_RELATIVE.as_raw() as u64, "SHUFFLE_RELATIVE"),
+            (vk::SubgroupFeatureFlags::CLUSTERED.as_raw() as u64, "CLUSTERED"),
+            (vk::SubgroupFeatureFlags::QUAD.as_raw() as u64, "QUAD"),
+            (vk::SubgroupFeatureFlags::PARTITIONED_NV.as_raw() as u64, "PARTITIONED_NV"),
+            (vk::SubgroupFeatureFlags::ROTATE_KHR.as_raw() as u64, "ROTATE_KHR"),
+            (vk::SubgroupFeatureFlags::ROTATE_CLUSTERED_KHR.as_raw() as u64, "ROTATE_CLUSTERED_KHR"),
+        ],
+    },
+    ValueNames {
+        name: "VideoCapabilityFlagsKHR",
+        prefix: "VK_VIDEO_CAPABILITY_",
+        known: &[
+            (vk::VideoCapabilityFlagsKHR::PROTECTED_CONTENT.as_raw() as u64, "PROTECTED_CONTENT"),
+            (vk::VideoCapabilityFlagsKHR::SEPARATE_REFERENCE_IMAGES.as_raw() as u64, "SEPARATE_REFERENCE_IMAGES"),
+        ],
+    },
+    ValueNames {
+        name: "VideoChromaSubsamplingFlagsKHR",
+        prefix: "VK_VIDEO_CHROMA_SUBSAMPLING_",
+        known: &[
+            (vk::VideoChromaSubsamplingFlagsKHR::INVALID.as_raw() as u64, "INVALID"),
+            (vk::VideoChromaSubsamplingFlagsKHR::MONOCHROME.as_raw() as u64, "MONOCHROME"),
+            (vk::VideoChromaSubsamplingFlagsKHR::TYPE_420.as_raw() as u64, "TYPE_420"),
+            (vk::VideoChromaSubsamplingFlagsKHR::TYPE_422.as_raw() as u64, "TYPE_422"),
+            (vk::VideoChromaSubsamplingFlagsKHR::TYPE_444.as_raw() as u64, "TYPE_444"),
+        ],
+    },
+    ValueNames {
+        name: "VideoCodecOperationFlagsKHR",
+        prefix: "VK_VIDEO_CODEC_OPERATION_",
+        known: &[
+            (vk::VideoCodecOperationFlagsKHR::NONE.as_raw() as u64, "NONE"),
+            (vk::VideoCodecOperationFlagsKHR::ENCODE_H264.as_raw() as u64, "ENCODE_H264"),
+            (vk::VideoCodecOperationFlagsKHR::ENCODE_H265.as_raw() as u64, "ENCODE_H265"),
+            (vk::VideoCodecOperationFlagsKHR::DECODE_H264.as_raw() as u64, "DECODE_H264"),
+            (vk::VideoCodecOperationFlagsKHR::DECODE_H265.as_raw() as u64, "DECODE_H265"),
+            (vk::VideoCodecOperationFlagsKHR::DECODE_AV1.as_raw() as u64, "DECODE_AV1"),
+        ],
+    },
+    ValueNames {
+        name: "VideoComponentBitDepthFlagsKHR",
+        prefix: "VK_VIDEO_COMPONENT_BIT_DEPTH_",
+        known: &[
+            (vk::VideoComponentBitDepthFlagsKHR::INVALID.as_raw() as u64, "INVALID"),
+            (vk::VideoComponentBitDepthFlagsKHR::TYPE_8.as_raw() as u64, "TYPE_8"),
+            (vk::VideoComponentBitDepthFlagsKHR::TYPE_10.as_raw() as u64, "TYPE_10"),
+            (vk::VideoComponentBitDepthFlagsKHR::TYPE_12.as_raw() as u64, "TYPE_12"),
+        ],
+    },
+    ValueNames {
+        name: "VideoDecodeCapabilityFlagsKHR",
+        prefix: "VK_VIDEO_DECODE_CAPABILITY_",
+        known: &[
+            (vk::VideoDecodeCapabilityFlagsKHR::DPB_AND_OUTPUT_COINCIDE.as_raw() as u64, "DPB_AND_OUTPUT_COINCIDE"),
+            (vk::VideoDecodeCapabilityFlagsKHR::DPB_AND_OUTPUT_DISTINCT.as_raw() as u64, "DPB_AND_OUTPUT_DISTINCT"),
+        ],
+    },
+    ValueNames {
+        name: "VideoDecodeH264PictureLayoutFlagsKHR",
+        prefix: "VK_VIDEO_DECODE_H264_PICTURE_LAYOUT_",
+        known: &[
+            (vk::VideoDecodeH264PictureLayoutFlagsKHR::PROGRESSIVE.as_raw() as u64, "PROGRESSIVE"),
+            (vk::VideoDecodeH264PictureLayoutFlagsKHR::INTERLACED_INTERLEAVED_LINES.as_raw() as u64, "INTERLACED_INTERLEAVED_LINES"),
+            (vk::VideoDecodeH264PictureLayoutFlagsKHR::INTERLACED_SEPARATE_PLANES.as_raw() as u64, "INTERLACED_SEPARATE_PLANES"),
+        ],
+    },
+    ValueNames {
+        name: "VideoDecodeUsageFlagsKHR",
+        prefix: "VK_VIDEO_DECODE_USAGE_",
+        known: &[
+            (vk::VideoDecodeUsageFlagsKHR::DEFAULT.as_raw() as u64, "DEFAULT"),
+            (vk::VideoDecodeUsageFlagsKHR::TRANSCODING.as_raw() as u64, "TRANSCODING"),
+            (vk::VideoDecodeUsageFlagsKHR::OFFLINE.as_raw() as u64, "OFFLINE"),
+            (vk::VideoDecodeUsageFlagsKHR::STREAMING.as_raw() as u64, "STREAMING"),
+        ],
+    },
+    ValueNames {
+        name: "VideoEncodeCapabilityFlagsKHR",
+        prefix: "VK_VIDEO_ENCODE_CAPABILITY_",
+        known: &[
+            (vk::VideoEncodeCapabilityFlagsKHR::PRECEDING_EXTERNALLY_ENCODED_BYTES.as_raw() as u64, "PRECEDING_EXTERNALLY_ENCODED_BYTES"),
+            (vk::VideoEncodeCapabilityFlagsKHR::INSUFFICIENTSTREAM_BUFFER_RANGE_DETECTION.as_raw() as u64, "INSUFFICIENTSTREAM_BUFFER_RANGE_DETECTION"),
+        ],
+    },
+    ValueNames {
+        name: "VideoEncodeContentFlagsKHR",
+        prefix: "VK_VIDEO_ENCODE_CONTENT_",
+        known: &[
+            (vk::VideoEncodeContentFlagsKHR::DEFAULT.as_raw() as u64, "DEFAULT"),
+            (vk::VideoEncodeContentFlagsKHR::CAMERA.as_raw() as u64, "CAMERA"),
+            (vk::VideoEncodeContentFlagsKHR::DESKTOP.as_raw() as u64, "DESKTOP"),
+            (vk::VideoEncodeContentFlagsKHR::RENDERED.as_raw() as u64, "RENDERED"),
+        ],
+    },
+    ValueNames {
+        name: "VideoEncodeFeedbackFlagsKHR",
+        prefix: "VK_VIDEO_ENCODE_FEEDBACK_",
+        known: &[
+            (vk::VideoEncodeFeedbackFlagsKHR::BITSTREAM_BUFFER_OFFSET.as_raw() as u64, "BITSTREAM_BUFFER_OFFSET"),
+            (vk::VideoEncodeFeedbackFlagsKHR::BITSTREAM_BYTES_WRITTEN.as_raw() as u64, "BITSTREAM_BYTES_WRITTEN"),
+            (vk::VideoEncodeFeedbackFlagsKHR::BITSTREAM_HAS_OVERRIDES.as_raw() as u64, "BITSTREAM_HAS_OVERRIDES"),
+        ],
+    },
+    ValueNames {
+        name: "VideoEncodeH264CapabilityFlagsKHR",
+        prefix: "VK_VIDEO_ENCODE_H264_CAPABILITY_",
+        known: &[
+            (vk::VideoEncodeH264CapabilityFlagsKHR::HRD_COMPLIANCE.as_raw() as u64, "HRD_COMPLIANCE"),
+            (vk::VideoEncodeH264CapabilityFlagsKHR::PREDICTION_WEIGHT_TABLE_GENERATED.as_raw() as u64, "PREDICTION_WEIGHT_TABLE_GENERATED"),
+            (vk::VideoEncodeH264CapabilityFlagsKHR::ROW_UNALIGNED_SLICE.as_raw() as u64, "ROW_UNALIGNED_SLICE"),
+            (vk::VideoEncodeH264CapabilityFlagsKHR::DIFFERENT_SLICE_TYPE.as_raw() as u64, "DIFFERENT_SLICE_TYPE"),
+            (vk::VideoEncodeH264CapabilityFlagsKHR::B_FRAME_IN_L0_LIST.as_raw() as u64, "B_FRAME_IN_L0_LIST"),
+            (vk::VideoEncodeH264CapabilityFlagsKHR::B_FRAME_IN_L1_LIST.as_raw() as u64, "B_FRAME_IN_L1_LIST"),
+            (vk::VideoEncodeH264CapabilityFlagsKHR::PER_PICTURE_TYPE_MIN_MAX_QP.as_raw() as u64, "PER_PICTURE_TYPE_MIN_MAX_QP"),
+            (vk::VideoEncodeH264CapabilityFlagsKHR::PER_SLICE_CONSTANT_QP.as_raw() as u64, "PER_SLICE_CONSTANT_QP"),
+            (vk::VideoEncodeH264CapabilityFlagsKHR::GENERATE_PREFIX_NALU.as_raw() as u64, "GENERATE_PREFIX_NALU"),
+        ],
+    },
+    ValueNames {
+        name: "VideoEncodeH264StdFlagsKHR",
+        prefix: "VK_VIDEO_ENCODE_H264_STD_",
+        known: &[
+            (vk::VideoEncodeH264StdFlagsKHR::SEPARATE_COLOR_PLANE_FLAG_SET.as_raw() as u64, "SEPARATE_COLOR_PLANE_FLAG_SET"),
+            (vk::VideoEncodeH264StdFlagsKHR::QPPRIME_Y_ZERO_TRANSFORM_BYPASS_FLAG_SET.as_raw() as u64, "QPPRIME_Y_ZERO_TRANSFORM_BYPASS_FLAG_SET"),
+            (vk::VideoEncodeH264StdFlagsKHR::SCALING_MATRIX_PRESENT_FLAG_SET.as_raw() as u64, "SCALING_MATRIX_PRESENT_FLAG_SET"),
+            (vk::VideoEncodeH264StdFlagsKHR::CHROMA_QP_INDEX_OFFSET.as_raw() as u64, "CHROMA_QP_INDEX_OFFSET"),
+            (vk::VideoEncodeH264StdFlagsKHR::SECOND_CHROMA_QP_INDEX_OFFSET.as_raw() as u64, "SECOND_CHROMA_QP_INDEX_OFFSET"),
+            (vk::VideoEncodeH264StdFlagsKHR::PIC_INIT_QP_MINUS26.as_raw() as u64, "PIC_INIT_QP_MINUS26"),
+            (vk::VideoEncodeH264StdFlagsKHR::WEIGHTED_PRED_FLAG_SET.as_raw() as u64, "WEIGHTED_PRED_FLAG_SET"),
+            (vk::VideoEncodeH264StdFlagsKHR::WEIGHTED_BIPRED_IDC_EXPLICIT.as_raw() as u64, "WEIGHTED_BIPRED_IDC_EXPLICIT"),
+            (vk::VideoEncodeH264StdFlagsKHR::WEIGHTED_BIPRED_IDC_IMPLICIT.as_raw() as u64, "WEIGHTED_BIPRED_IDC_IMPLICIT"),
+            (vk::VideoEncodeH264StdFlagsKHR::TRANSFORM_8X8_MODE_FLAG_SET.as_raw() as u64, "TRANSFORM_8X8_MODE_FLAG_SET"),
+            (vk::VideoEncodeH264StdFlagsKHR::DIRECT_SPATIAL_MV_PRED_FLAG_UNSET.as_raw() as u64, "DIRECT_SPATIAL_MV_PRED_FLAG_UNSET"),
+            (vk::VideoEncodeH264StdFlagsKHR::ENTROPY_CODING_MODE_FLAG_UNSET.as_raw() as u64, "ENTROPY_CODING_MODE_FLAG_UNSET"),
+            (vk::VideoEncodeH264StdFlagsKHR::ENTROPY_CODING_MODE_FLAG_SET.as_raw() as u64, "ENTROPY_CODING_MODE_FLAG_SET"),
+            (vk::VideoEncodeH264StdFlagsKHR::DIRECT_8X8_INFERENCE_FLAG_UNSET.as_raw() as u64, "DIRECT_8X8_INFERENCE_FLAG_UNSET"),
+            (vk::VideoEncodeH264StdFlagsKHR::CONSTRAINED_INTRA_PRED_FLAG_SET.as_raw() as u64, "CONSTRAINED_INTRA_PRED_FLAG_SET"),
+            (vk::VideoEncodeH264StdFlagsKHR::DEBLOCKING_FILTER_DISABLED.as_raw() as u64, "DEBLOCKING_FILTER_DISABLED"),
+            (vk::VideoEncodeH264StdFlagsKHR::DEBLOCKING_FILTER_ENABLED.as_raw() as u64, "DEBLOCKING_FILTER_ENABLED"),
+            (vk::VideoEncodeH264StdFlagsKHR::DEBLOCKING_FILTER_PARTIAL.as_raw() as u64, "DEBLOCKING_FILTER_PARTIAL"),
+            (vk::VideoEncodeH264StdFlagsKHR::SLICE_QP_DELTA.as_raw() as u64, "SLICE_QP_DELTA"),
+            (vk::VideoEncodeH264StdFlagsKHR::DIFFERENT_SLICE_QP_DELTA.as_raw() as u64, "DIFFERENT_SLICE_QP_DELTA"),
+        ],
+    },
+    ValueNames {
+        name: "VideoEncodeH265CapabilityFlagsKHR",
+        prefix: "VK_VIDEO_ENCODE_H265_CAPABILITY_",
+        known: &[
+            (vk::VideoEncodeH265CapabilityFlagsKHR::HRD_COMPLIANCE.as_raw() as u64, "HRD_COMPLIANCE"),
+            (vk::VideoEncodeH265CapabilityFlagsKHR::PREDICTION_WEIGHT_TABLE_GENERATED.as_raw() as u64, "PREDICTION_WEIGHT_TABLE_GENERATED"),
+            (vk::VideoEncodeH265CapabilityFlagsKHR::ROW_UNALIGNED_SLICE_SEGMENT.as_raw() as u64, "ROW_UNALIGNED_SLICE_SEGMENT"),
+            (vk::VideoEncodeH265CapabilityFlagsKHR::DIFFERENT_SLICE_SEGMENT_TYPE.as_raw() as u64, "DIFFERENT_SLICE_SEGMENT_TYPE"),
+            (vk::VideoEncodeH265CapabilityFlagsKHR::B_FRAME_IN_L0_LIST.as_raw() as u64, "B_FRAME_IN_L0_LIST"),
+            (vk::VideoEncodeH265CapabilityFlagsKHR::B_FRAME_IN_L1_LIST.as_raw() as u64, "B_FRAME_IN_L1_LIST"),
+            (vk::VideoEncodeH265CapabilityFlagsKHR::PER_PICTURE_TYPE_MIN_MAX_QP.as_raw() as u64, "PER_PICTURE_TYPE_MIN_MAX_QP"),
+            (vk::VideoEncodeH265CapabilityFlagsKHR::PER_SLICE_SEGMENT_CONSTANT_QP.as_raw() as u64, "PER_SLICE_SEGMENT_CONSTANT_QP"),
+            (vk::VideoEncodeH265CapabilityFlagsKHR::MULTIPLE_TILES_PER_SLICE_SEGMENT.as_raw() as u64, "MULTIPLE_TILES_PER_SLICE_SEGMENT"),
+            (vk::VideoEncodeH265CapabilityFlagsKHR::MULTIPLE_SLICE_SEGMENTS_PER_TILE.as_raw() as u64, "MULTIPLE_SLICE_SEGMENTS_PER_TILE"),
+        ],
+    },
+    ValueNames {
+        name: "VideoEncodeH265CtbSizeFlagsKHR",
+        prefix: "VK_VIDEO_ENCODE_H265_CTB_SIZE_",
+        known: &[
+            (vk::VideoEncodeH265CtbSizeFlagsKHR::TYPE_16.as_raw() as u64, "TYPE_16"),
+            (vk::VideoEncodeH265CtbSizeFlagsKHR::TYPE_32.as_raw() as u64, "TYPE_32"),
+            (vk::VideoEncodeH265CtbSizeFlagsKHR::TYPE_64.as_raw() as u64, "TYPE_64"),
+        ],
+    },
+    ValueNames {
+        name: "VideoEncodeH265StdFlagsKHR",
+        prefix: "VK_VIDEO_ENCODE_H265_STD_",
+        known: &[
+            (vk::VideoEncodeH265StdFlagsKHR::SEPARATE_COLOR_PLANE_FLAG_SET.as_raw() as u64, "SEPARATE_COLOR_PLANE_FLAG_SET"),
+            (vk::VideoEncodeH265StdFlagsKHR::SAMPLE_ADAPTIVE_OFFSET_ENABLED_FLAG_SET.as_raw() as u64, "SAMPLE_ADAPTIVE_OFFSET_ENABLED_FLAG_SET"),
+            (vk::VideoEncodeH265StdFlagsKHR::SCALING_LIST_DATA_PRESENT_FLAG_SET.as_raw() as u64, "SCALING_LIST_DATA_PRESENT_FLAG_SET"),
+            (vk::VideoEncodeH265StdFlagsKHR::PCM_ENABLED_FLAG_SET.as_raw() as u64, "PCM_ENABLED_FLAG_SET"),
+            (vk::VideoEncodeH265StdFlagsKHR::SPS_TEMPORAL_MVP_ENABLED_FLAG_SET.as_raw() as u64, "SPS_TEMPORAL_MVP_ENABLED_FLAG_SET"),
+            (vk::VideoEncodeH265StdFlagsKHR::INIT_QP_MINUS26.as_raw() as u64, "INIT_QP_MINUS26"),
+            (vk::VideoEncodeH265StdFlagsKHR::WEIGHTED_PRED_FLAG_SET.as_raw() as u64, "WEIGHTED_PRED_FLAG_SET"),
+            (vk::VideoEncodeH265StdFlagsKHR::WEIGHTED_BIPRED_FLAG_SET.as_raw() as u64, "WEIGHTED_BIPRED_FLAG_SET"),
+            (vk::VideoEncodeH265StdFlagsKHR::LOG2_PARALLEL_MERGE_LEVEL_MINUS2.as_raw() as u64, "LOG2_PARALLEL_MERGE_LEVEL_MINUS2"),
+            (vk::VideoEncodeH265StdFlagsKHR::SIGN_DATA_HIDING_ENABLED_FLAG_SET.as_raw() as u64, "SIGN_DATA_HIDING_ENABLED_FLAG_SET"),
+            (vk::VideoEncodeH265StdFlagsKHR::TRANSFORM_SKIP_ENABLED_FLAG_SET.as_raw() as u64, "TRANSFORM_SKIP_ENABLED_FLAG_SET"),
+            (vk::VideoEncodeH265StdFlagsKHR::TRANSFORM_SKIP_ENABLED_FLAG_UNSET.as_raw() as u64, "TRANSFORM_SKIP_ENABLED_FLAG_UNSET"),
+            (vk::VideoEncodeH265StdFlagsKHR::PPS_SLICE_CHROMA_QP_OFFSETS_PRESENT_FLAG_SET.as_raw() as u64, "PPS_SLICE_CHROMA_QP_OFFSETS_PRESENT_FLAG_SET"),
+            (vk::VideoEncodeH265StdFlagsKHR::TRANSQUANT_BYPASS_ENABLED_FLAG_SET.as_raw() as u64, "TRANSQUANT_BYPASS_ENABLED_FLAG_SET"),
+            (vk::VideoEncodeH265StdFlagsKHR::CONSTRAINED_INTRA_PRED_FLAG_SET.as_raw() as u64, "CONSTRAINED_INTRA_PRED_FLAG_SET"),
+            (vk::VideoEncodeH265StdFlagsKHR::ENTROPY_CODING_SYNC_ENABLED_FLAG_SET.as_raw() as u64, "ENTROPY_CODING_SYNC_ENABLED_FLAG_SET"),
+            (vk::VideoEncodeH265StdFlagsKHR::DEBLOCKING_FILTER_OVERRIDE_ENABLED_FLAG_SET.as_raw() as u64, "DEBLOCKING_FILTER_OVERRIDE_ENABLED_FLAG_SET"),
+            (vk::VideoEncodeH265StdFlagsKHR::DEPENDENT_SLICE_SEGMENTS_ENABLED_FLAG_SET.as_raw() as u64, "DEPENDENT_SLICE_SEGMENTS_ENABLED_FLAG_SET"),
+            (vk::VideoEncodeH265StdFlagsKHR::DEPENDENT_SLICE_SEGMENT_FLAG_SET.as_raw() as u64, "DEPENDENT_SLICE_SEGMENT_FLAG_SET"),
+            (vk::VideoEncodeH265StdFlagsKHR::SLICE_QP_DELTA.as_raw() as u64, "SLICE_QP_DELTA"),
+            (vk::VideoEncodeH265StdFlagsKHR::DIFFERENT_SLICE_QP_DELTA.as_raw() as u64, "DIFFERENT_SLICE_QP_DELTA"),
+        ],
+    },
+    ValueNames {
+        name: "VideoEncodeH265TransformBlockSizeFlagsKHR",
+        prefix: "VK_VIDEO_ENCODE_H265_TRANSFORM_BLOCK_SIZE_",
+        known: &[
+            (vk::VideoEncodeH265TransformBlockSizeFlagsKHR::TYPE_4.as_raw() as u64, "TYPE_4"),
+            (vk::VideoEncodeH265TransformBlockSizeFlagsKHR::TYPE_8.as_raw() as u64, "TYPE_8"),
+            (vk::VideoEncodeH265TransformBlockSizeFlagsKHR::TYPE_16.as_raw() as u64, "TYPE_16"),
+            (vk::VideoEncodeH265TransformBlockSizeFlagsKHR::TYPE_32.as_raw() as u64, "TYPE_32"),
+        ],
+    },
+    ValueNames {
+        name: "VideoEncodeRateControlModeFlagsKHR",
+        prefix: "VK_VIDEO_ENCODE_RATE_CONTROL_MODE_",
+        known: &[
+            (vk::VideoEncodeRateControlModeFlagsKHR::DEFAULT.as_raw() as u64, "DEFAULT"),
+            (vk::VideoEncodeRateControlModeFlagsKHR::DISABLED.as_raw() as u64, "DISABLED"),
+            (vk::VideoEncodeRateControlModeFlagsKHR::CBR.as_raw() as u64, "CBR"),
+            (vk::VideoEncodeRateControlModeFlagsKHR::VBR.as_raw() as u64, "VBR"),
+        ],
+    },
+    ValueNames {
+        name: "VideoEncodeUsageFlagsKHR",
+        prefix: "VK_VIDEO_ENCODE_USAGE_",
+        known: &[
+            (vk::VideoEncodeUsageFlagsKHR::DEFAULT.as_raw() as u64, "DEFAULT"),
+            (vk::VideoEncodeUsageFlagsKHR::TRANSCODING.as_raw() as u64, "TRANSCODING"),
+            (vk::VideoEncodeUsageFlagsKHR::STREAMING.as_raw() as u64, "STREAMING"),
+            (vk::VideoEncodeUsageFlagsKHR::RECORDING.as_raw() as u64, "RECORDING"),
+            (vk::VideoEncodeUsageFlagsKHR::CONFERENCING.as_raw() as u64, "CONFERENCING"),
+        ],
+    },
+];
+
+/// The named values of the enums of the members.
+pub(super) static ENUMS: &[ValueNames<i32>] = &[
+    ValueNames {
+        name: "ComponentSwizzle",
+        prefix: "VK_COMPONENT_SWIZZLE_",
+        known: &[
+            (vk::ComponentSwizzle::IDENTITY.as_raw(), "IDENTITY"),
+            (vk::ComponentSwizzle::ZERO.as_raw(), "ZERO"),
+            (vk::ComponentSwizzle::ONE.as_raw(), "ONE"),
+            (vk::ComponentSwizzle::R.as_raw(), "R"),
+            (vk::ComponentSwizzle::G.as_raw(), "G"),
+            (vk::ComponentSwizzle::B.as_raw(), "B"),
+            (vk::ComponentSwizzle::A.as_raw(), "A"),
+        ],
+    },
+    ValueNames {
+        name: "DriverId",
+        prefix: "VK_DRIVER_ID_",
+        known: &[
+            (vk::DriverId::AMD_PROPRIETARY.as_raw(), "AMD_PROPRIETARY"),
+            (vk::DriverId::AMD_OPEN_SOURCE.as_raw(), "AMD_OPEN_SOURCE"),
+            (vk::DriverId::MESA_RADV.as_raw(), "MESA_RADV"),
+            (
+                vk::DriverId::NVIDIA_PROPRIETARY.as_raw(),
+                "NVIDIA_PROPRIETARY",
+            ),
+            (
+                vk::DriverId::INTEL_PROPRIETARY_WINDOWS.as_raw(),
+                "INTEL_PROPRIETARY_WINDOWS",
+            ),
+            (
+                vk::DriverId::INTEL_OPEN_SOURCE_MESA.as_raw(),
+                "INTEL_OPEN_SOURCE_MESA",
+            ),
+            (
+                vk::DriverId::IMAGINATION_PROPRIETARY.as_raw(),
+                "IMAGINATION_PROPRIETARY",
+            ),
+            (
+                vk::DriverId::QUALCOMM_PROPRIETARY.as_raw(),
+                "QUALCOMM_PROPRIETARY",
+            ),
+            (vk::DriverId::ARM_PROPRIETARY.as_raw(), "ARM_PROPRIETARY"),
+            (
+                vk::DriverId::GOOGLE_SWIFTSHADER.as_raw(),
+                "GOOGLE_SWIFTSHADER",
+            ),
+            (vk::DriverId::GGP_PROPRIETARY.as_raw(), "GGP_PROPRIETARY"),
+            (
+                vk::DriverId::BROADCOM_PROPRIETARY.as_raw(),
+                "BROADCOM_PROPRIETARY",
+            ),
+            (vk::DriverId::MESA_LLVMPIPE.as_raw(), "MESA_LLVMPIPE"),
+            (vk::DriverId::MOLTENVK.as_raw(), "MOLTENVK"),
+            (
+                vk::DriverId::COREAVI_PROPRIETARY.as_raw(),
+                "COREAVI_PROPRIETARY",
+            ),
+            (
+                vk::DriverId::JUICE_PROPRIETARY.as_raw(),
+                "JUICE_PROPRIETARY",
+            ),
+            (
+                vk::DriverId::VERISILICON_PROPRIETARY.as_raw(),
+                "VERISILICON_PROPRIETARY",
+            ),
+            (vk::DriverId::MESA_TURNIP.as_raw(), "MESA_TURNIP"),
+            (vk::DriverId::MESA_V3DV.as_raw(), "MESA_V3DV"),
+            (vk::DriverId::MESA_PANVK.as_raw(), "MESA_PANVK"),
+            (
+                vk::DriverId::SAMSUNG_PROPRIETARY.as_raw(),
+                "SAMSUNG_PROPRIETARY",
+            ),
+            (vk::DriverId::MESA_VENUS.as_raw(), "MESA_VENUS"),
+            (vk::DriverId::MESA_DOZEN.as_raw(), "MESA_DOZEN"),
+            (vk::DriverId::MESA_NVK.as_raw(), "MESA_NVK"),
+            (
+                vk::DriverId::IMAGINATION_OPEN_SOURCE_MESA.as_raw(),
+                "IMAGINATION_OPEN_SOURCE_MESA",
+            ),
+            (vk::DriverId::MESA_AGXV.as_raw(), "MESA_AGXV"),
+        ],
+    },
+    ValueNames {
+        name: "Format",
+        prefix: "VK_FORMAT_",
+        known: &[
+            (vk::Format::UNDEFINED.as_raw(), "UNDEFINED"),
+            (vk::Format::R4G4_UNORM_PACK8.as_raw(), "R4G4_UNORM_PACK8"),
+            (
+                vk::Format::R4G4B4A4_UNORM_PACK16.as_raw(),
+                "R4G4B4A4_UNORM_PACK16",
+            ),
+            (
+                vk::Format::B4G4R4A4_UNORM_PACK16.as_raw(),
+                "B4G4R4A4_UNORM_PACK16",
+            ),
+            (
+                vk::Format::R5G6B5_UNORM_PACK16.as_raw(),
+                "R5G6B5_UNORM_PACK16",
+            ),
+            (
+                vk::Format::B5G6R5_UNORM_PACK16.as_raw(),
+                "B5G6R5_UNORM_PACK16",
+            ),
+            (
+                vk::Format::R5G5B5A1_UNORM_PACK16.as_raw(),
+                "R5G5B5A1_UNORM_PACK16",
+            ),
+            (
+                vk::Format::B5G5R5A1_UNORM_PACK16.as_raw(),
+                "B5G5R5A1_UNORM_PACK16",
+            ),
+            (
+                vk::Format::A1R5G5B5_UNORM_PACK16.as_raw(),
+                "A1R5G5B5_UNORM_PACK16",
+            ),
+            (vk::Format::R8_UNORM.as_raw(), "R8_UNORM"),
+            (vk::Format::R8_SNORM.as_raw(), "R8_SNORM"),
+            (vk::Format::R8_USCALED.as_raw(), "R8_USCALED"),
+            (vk::Format::R8_SSCALED.as_raw(), "R8_SSCALED"),
+            (vk::Format::R8_UINT.as_raw(), "R8_UINT"),
+            (vk::Format::R8_SINT.as_raw(), "R8_SINT"),
+            (vk::Format::R8_SRGB.as_raw(), "R8_SRGB"),
+            (vk::Format::R8G8_UNORM.as_raw(), "R8G8_UNORM"),
+            (vk::Format::R8G8_SNORM.as_raw(), "R8G8_SNORM"),
+            (vk::Format::R8G8_USCALED.as_raw(), "R8G8_USCALED"),
+            (vk::Format::R8G8_SSCALED.as_raw(), "R8G8_SSCALED"),
+            (vk::Format::R8G8_UINT.as_raw(), "R8G8_UINT"),
+            (vk::Format::R8G8_SINT.as_raw(), "R8G8_SINT"),
+            (vk::Format::R8G8_SRGB.as_raw(), "R8G8_SRGB"),
+            (vk::Format::R8G8B8_UNORM.as_raw(), "R8G8B8_UNORM"),
+            (vk::Format::R8G8B8_SNORM.as_raw(), "R8G8B8_SNORM"),
+            (vk::Format::R8G8B8_USCALED.as_raw(), "R8G8B8_USCALED"),
+            (vk::Format::R8G8B8_SSCALED.as_raw(), "R8G8B8_SSCALED"),
+            (vk::Format::R8G8B8_UINT.as_raw(), "R8G8B8_UINT"),
+            (vk::Format::R8G8B8_SINT.as_raw(), "R8G8B8_SINT"),
+            (vk::Format::R8G8B8_SRGB.as_raw(), "R8G8B8_SRGB"),
+            (vk::Format::B8G8R8_UNORM.as_raw(), "B8G8R8_UNORM"),
+            (vk::Format::B8G8R8_SNORM.as_raw(), "B8G8R8_SNORM"),
+            (vk::Format::B8G8R8_USCALED.as_raw(), "B8G8R8_USCALED"),
+            (vk::Format::B8G8R8_SSCALED.as_raw(), "B8G8R8_SSCALED"),
+            (vk::Format::B8G8R8_UINT.as_raw(), "B8G8R8_UINT"),
+            (vk::Format::B8G8R8_SINT.as_raw(), "B8G8R8_SINT"),
+            (vk::Format::B8G8R8_SRGB.as_raw(), "B8G8R8_SRGB"),
+            (vk::Format::R8G8B8A8_UNORM.as_raw(), "R8G8B8A8_UNORM"),
+            (vk::Format::R8G8B8A8_SNORM.as_raw(), "R8G8B8A8_SNORM"),
+            (vk::Format::R8G8B8A8_USCALED.as_raw(), "R8G8B8A8_USCALED"),
+            (vk::Format::R8G8B8A8_SSCALED.as_raw(), "R8G8B8A8_SSCALED"),
+            (vk::Format::R8G8B8A8_UINT.as_raw(), "R8G8B8A8_UINT"),
+            (vk::Format::R8G8B8A8_SINT.as_raw(), "R8G8B8A8_SINT"),
+            (vk::Format::R8G8B8A8_SRGB.as_raw(), "R8G8B8A8_SRGB"),
+            (vk::Format::B8G8R8A8_UNORM.as_raw(), "B8G8R8A8_UNORM"),
+            (vk::Format::B8G8R8A8_SNORM.as_raw(), "B8G8R8A8_SNORM"),
+            (vk::Format::B8G8R8A8_USCALED.as_raw(), "B8G8R8A8_USCALED"),
+            (vk::Format::B8G8R8A8_SSCALED.as_raw(), "B8G8R8A8_SSCALED"),
+            (vk::Format::B8G8R8A8_UINT.as_raw(), "B8G8R8A8_UINT"),
+            (vk::Format::B8G8R8A8_SINT.as_raw(), "B8G8R8A8_SINT"),
+            (vk::Format::B8G8R8A8_SRGB.as_raw(), "B8G8R8A8_SRGB"),
+            (
+                vk::Format::A8B8G8R8_UNORM_PACK32.as_raw(),
+                "A8B8G8R8_UNORM_PACK32",
+            ),
+            (
+                vk::Format::A8B8G8R8_SNORM_PACK32.as_raw(),
+                "A8B8G8R8_SNORM_PACK32",
+            ),
+            (
+                vk::Format::A8B8G8R8_USCALED_PACK32.as_raw(),
+                "A8B8G8R8_USCALED_PACK32",
+            ),
+            (
+                vk::Format::A8B8G8R8_SSCALED_PACK32.as_raw(),
+                "A8B8G8R8_SSCALED_PACK32",
+            ),
+            (
+                vk::Format::A8B8G8R8_UINT_PACK32.as_raw(),
+                "A8B8G8R8_UINT_PACK32",
+            ),
+            (
+                vk::Format::A8B8G8R8_SINT_PACK32.as_raw(),
+                "A8B8G8R8_SINT_PACK32",
+            ),
+            (
+                vk::Format::A8B8G8R8_SRGB_PACK32.as_raw(),
+                "A8B8G8R8_SRGB_PACK32",
+            ),
+            (
+                vk::Format::A2R10G10B10_UNORM_PACK32.as_raw(),
+                "A2R10G10B10_UNORM_PACK32",
+            ),
+            (
+                vk::Format::A2R10G10B10_SNORM_PACK32.as_raw(),
+                "A2R10G10B10_SNORM_PACK32",
+            ),
+            (
+                vk::Format::A2R10G10B10_USCALED_PACK32.as_raw(),
+                "A2R10G10B10_USCALED_PACK32",
+            ),
+            (
+                vk::Format::A2R10G10B10_SSCALED_PACK32.as_raw(),
+                "A2R10G10B10_SSCALED_PACK32",
+            ),
+            (
+                vk::Format::A2R10G10B10_UINT_PACK32.as_raw(),
+                "A2R10G10B10_UINT_PACK32",
+            ),
+            (
+                vk::Format::A2R10G10B10_SINT_PACK32.as_raw(),
+                "A2R10G10B10_SINT_PACK32",
+            ),
+            (
+                vk::Format::A2B10G10R10_UNORM_PACK32.as_raw(),
+                "A2B10G10R10_UNORM_PACK32",
+            ),
+            (
+                vk::Format::A2B10G10R10_SNORM_PACK32.as_raw(),
+                "A2B10G10R10_SNORM_PACK32",
+            ),
+            (
+                vk::Format::A2B10G10R10_USCALED_PACK32.as_raw(),
+                "A2B10G10R10_USCALED_PACK32",
+            ),
+            (
+                vk::Format::A2B10G10R10_SSCALED_PACK32.as_raw(),
+                "A2B10G10R10_SSCALED_PACK32",
+            ),
+            (
+                vk::Format::A2B10G10R10_UINT_PACK32.as_raw(),
+                "A2B10G10R10_UINT_PACK32",
+            ),
+            (
+                vk::Format::A2B10G10R10_SINT_PACK32.as_raw(),
+                "A2B10G10R10_SINT_PACK32",
+            ),
+            (vk::Format::R16_UNORM.as_raw(), "R16_UNORM"),
+            (vk::Format::R16_SNORM.as_raw(), "R16_SNORM"),
+            (vk::Format::R16_USCALED.as_raw(), "R16_USCALED"),
+            (vk::Format::R16_SSCALED.as_raw(), "R16_SSCALED"),
+            (vk::Format::R16_UINT.as_raw(), "R16_UINT"),
+            (vk::Format::R16_SINT.as_raw(), "R16_SINT"),
+            (vk::Format::R16_SFLOAT.as_raw(), "R16_SFLOAT"),
+            (vk::Format::R16G16_UNORM.as_raw(), "R16G16_UNORM"),
+            (vk::Format::R16G16_SNORM.as_raw(), "R16G16_SNORM"),
+            (vk::Format::R16G16_USCALED.as_raw(), "R16G16_USCALED"),
+            (vk::Format::R16G16_SSCALED.as_raw(), "R16G16_SSCALED"),
+            (vk::Format::R16G16_UINT.as_raw(), "R16G16_UINT"),
+            (vk::Format::R16G16_SINT.as_raw(), "R16G16_SINT"),
+            (vk::Format::R16G16_SFLOAT.as_raw(), "R16G16_SFLOAT"),
+            (vk::Format::R16G16B16_UNORM.as_raw(), "R16G16B16_UNORM"),
+            (vk::Format::R16G16B16_SNORM.as_raw(), "R16G16B16_SNORM"),
+            (vk::Format::R16G16B16_USCALED.as_raw(), "R16G16B16_USCALED"),
+            (vk::Format::R16G16B16_SSCALED.as_raw(), "R16G16B16_SSCALED"),
+            (vk::Format::R16G16B16_UINT.as_raw(), "R16G16B16_UINT"),
+            (vk::Format::R16G16B16_SINT.as_raw(), "R16G16B16_SINT"),
+            (vk::Format::R16G16B16_SFLOAT.as_raw(), "R16G16B16_SFLOAT"),
+            (
+                vk::Format::R16G16B16A16_UNORM.as_raw(),
+                "R16G16B16A16_UNORM",
+            ),
+            (
+                vk::Format::R16G16B16A16_SNORM.as_raw(),
+                "R16G16B16A16_SNORM",
+            ),
+            (
+                vk::Format::R16G16B16A16_USCALED.as_raw(),
+                "R16G16B16A16_USCALED",
+            ),
+            (
+                vk::Format::R16G16B16A16_SSCALED.as_raw(),
+                "R16G16B16A16_SSCALED",
+            ),
+            (vk::Format::R16G16B16A16_UINT.as_raw(), "R16G16B16A16_UINT"),
+            (vk::Format::R16G16B16A16_SINT.as_raw(), "R16G16B16A16_SINT"),
+            (
+                vk::Format::R16G16B16A16_SFLOAT.as_raw(),
+                "R16G16B16A16_SFLOAT",
+            ),
+            (vk::Format::R32_UINT.as_raw(), "R32_UINT"),
+            (vk::Format::R32_SINT.as_raw(), "R32_SINT"),
+            (vk::Format::R32_SFLOAT.as_raw(), "R32_SFLOAT"),
+            (vk::Format::R32G32_UINT.as_raw(), "R32G32_UINT"),
+            (vk::Format::R32G32_SINT.as_raw(), "R32G32_SINT"),
+            (vk::Format::R32G32_SFLOAT.as_raw(), "R32G32_SFLOAT"),
+            (vk::Format::R32G32B32_UINT.as_raw(), "R32G32B32_UINT"),
+            (vk::Format::R32G32B32_SINT.as_raw(), "R32G32B32_SINT"),
+            (vk::Format::R32G32B32_SFLOAT.as_raw(), "R32G32B32_SFLOAT"),
+            (vk::Format::R32G32B32A32_UINT.as_raw(), "R32G32B32A32_UINT"),
+            (vk::Format::R32G32B32A32_SINT.as_raw(), "R32G32B32A32_SINT"),
+            (
+                vk::Format::R32G32B32A32_SFLOAT.as_raw(),
+                "R32G32B32A32_SFLOAT",
+            ),
+            (vk::Format::R64_UINT.as_raw(), "R64_UINT"),
+            (vk::Format::R64_SINT.as_raw(), "R64_SINT"),
+            (vk::Format::R64_SFLOAT.as_raw(), "R64_SFLOAT"),
+            (vk::Format::R64G64_UINT.as_raw(), "R64G64_UINT"),
+            (vk::Format::R64G64_SINT.as_raw(), "R64G64_SINT"),
+            (vk::Format::R64G64_SFLOAT.as_raw(), "R64G64_SFLOAT"),
+            (vk::Format::R64G64B64_UINT.as_raw(), "R64G64B64_UINT"),
+            (vk::Format::R64G64B64_SINT.as_raw(), "R64G64B64_SINT"),
+            (vk::Format::R64G64B64_SFLOAT.as_raw(), "R64G64B64_SFLOAT"),
+            (vk::Format::R64G64B64A64_UINT.as_raw(), "R64G64B64A64_UINT"),
+            (vk::Format::R64G64B64A64_SINT.as_raw(), "R64G64B64A64_SINT"),
+            (
+                vk::Format::R64G64B64A64_SFLOAT.as_raw(),
+                "R64G64B64A64_SFLOAT",
+            ),
+            (
+                vk::Format::B10G11R11_UFLOAT_PACK32.as_raw(),
+                "B10G11R11_UFLOAT_PACK32",
+            ),
+            (
+                vk::Format::E5B9G9R9_UFLOAT_PACK32.as_raw(),
+                "E5B9G9R9_UFLOAT_PACK32",
+            ),
+            (vk::Format::D16_UNORM.as_raw(), "D16_UNORM"),
+            (
+                vk::Format::X8_D24_UNORM_PACK32.as_raw(),
+                "X8_D24_UNORM_PACK32",
+            ),
+            (vk::Format::D32_SFLOAT.as_raw(), "D32_SFLOAT"),
+            (vk::Format::S8_UINT.as_raw(), "S8_UINT"),
+            (vk::Format::D16_UNORM_S8_UINT.as_raw(), "D16_UNORM_S8_UINT"),
+            (vk::Format::D24_UNORM_S8_UINT.as_raw(), "D24_UNORM_S8_UINT"),
+            (
+                vk::Format::D32_SFLOAT_S8_UINT.as_raw(),
+                "D32_SFLOAT_S8_UINT",
+            ),
+            (
+                vk::Format::BC1_RGB_UNORM_BLOCK.as_raw(),
+                "BC1_RGB_UNORM_BLOCK",
+            ),
+            (
+                vk::Format::BC1_RGB_SRGB_BLOCK.as_raw(),
+                "BC1_RGB_SRGB_BLOCK",
+            ),
+            (
+                vk::Format::BC1_RGBA_UNORM_BLOCK.as_raw(),
+                "BC1_RGBA_UNORM_BLOCK",
+            ),
+            (
+                vk::Format::BC1_RGBA_SRGB_BLOCK.as_raw(),
+                "BC1_RGBA_SRGB_BLOCK",
+            ),
+            (vk::Format::BC2_UNORM_BLOCK.as_raw(), "BC2_UNORM_BLOCK"),
+            (vk::Format::BC2_SRGB_BLOCK.as_raw(), "BC2_SRGB_BLOCK"),
+            (vk::Format::BC3_UNORM_BLOCK.as_raw(), "BC3_UNORM_BLOCK"),
+            (vk::Format::BC3_SRGB_BLOCK.as_raw(), "BC3_SRGB_BLOCK"),
+            (vk::Format::BC4_UNORM_BLOCK.as_raw(), "BC4_UNORM_BLOCK"),
+            (vk::Format::BC4_SNORM_BLOCK.as_raw(), "BC4_SNORM_BLOCK"),
+            (vk::Format::BC5_UNORM_BLOCK.as_raw(), "BC5_UNORM_BLOCK"),
+            (vk::Format::BC5_SNORM_BLOCK.as_raw(), "BC5_SNORM_BLOCK"),
+            (vk::Format::BC6H_UFLOAT_BLOCK.as_raw(), "BC6H_UFLOAT_BLOCK"),
+            (vk::Format::BC6H_SFLOAT_BLOCK.as_raw(), "BC6H_SFLOAT_BLOCK"),
+            (vk::Format::BC7_UNORM_BLOCK.as_raw(), "BC7_UNORM_BLOCK"),
+            (vk::Format::BC7_SRGB_BLOCK.as_raw(), "BC7_SRGB_BLOCK"),
+            (
+                vk::Format::ETC2_R8G8B8_UNORM_BLOCK.as_raw(),
+                "ETC2_R8G8B8_UNORM_BLOCK",
+            ),
+            (
+                vk::Format::ETC2_R8G8B8_SRGB_BLOCK.as_raw(),
+                "ETC2_R8G8B8_SRGB_BLOCK",
+            ),
+            (
+                vk::Format::ETC2_R8G8B8A1_UNORM_BLOCK.as_raw(),
+                "ETC2_R8G8B8A1_UNORM_BLOCK",
+            ),
+            (
+                vk::Format::ETC2_R8G8B8A1_SRGB_BLOCK.as_raw(),
+                "ETC2_R8G8B8A1_SRGB_BLOCK",
+            ),
+            (
+                vk::Format::ETC2_R8G8B8A8_UNORM_BLOCK.as_raw(),
+                "ETC2_R8G8B8A8_UNORM_BLOCK",
+            ),
+            (
+                vk::Format::ETC2_R8G8B8A8_SRGB_BLOCK.as_raw(),
+                "ETC2_R8G8B8A8_SRGB_BLOCK",
+            ),
+            (
+                vk::Format::EAC_R11_UNORM_BLOCK.as_raw(),
+                "EAC_R11_UNORM_BLOCK",
+            ),
+            (
+                vk::Format::EAC_R11_SNORM_BLOCK.as_raw(),
+                "EAC_R11_SNORM_BLOCK",
+            ),
+            (
+                vk::Format::EAC_R11G11_UNORM_BLOCK.as_raw(),
+                "EAC_R11G11_UNORM_BLOCK",
+            ),
+            (
+                vk::Format::EAC_R11G11_SNORM_BLOCK.as_raw(),
+                "EAC_R11G11_SNORM_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_4X4_UNORM_BLOCK.as_raw(),
+                "ASTC_4X4_UNORM_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_4X4_SRGB_BLOCK.as_raw(),
+                "ASTC_4X4_SRGB_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_5X4_UNORM_BLOCK.as_raw(),
+                "ASTC_5X4_UNORM_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_5X4_SRGB_BLOCK.as_raw(),
+                "ASTC_5X4_SRGB_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_5X5_UNORM_BLOCK.as_raw(),
+                "ASTC_5X5_UNORM_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_5X5_SRGB_BLOCK.as_raw(),
+                "ASTC_5X5_SRGB_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_6X5_UNORM_BLOCK.as_raw(),
+                "ASTC_6X5_UNORM_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_6X5_SRGB_BLOCK.as_raw(),
+                "ASTC_6X5_SRGB_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_6X6_UNORM_BLOCK.as_raw(),
+                "ASTC_6X6_UNORM_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_6X6_SRGB_BLOCK.as_raw(),
+                "ASTC_6X6_SRGB_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_8X5_UNORM_BLOCK.as_raw(),
+                "ASTC_8X5_UNORM_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_8X5_SRGB_BLOCK.as_raw(),
+                "ASTC_8X5_SRGB_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_8X6_UNORM_BLOCK.as_raw(),
+                "ASTC_8X6_UNORM_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_8X6_SRGB_BLOCK.as_raw(),
+                "ASTC_8X6_SRGB_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_8X8_UNORM_BLOCK.as_raw(),
+                "ASTC_8X8_UNORM_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_8X8_SRGB_BLOCK.as_raw(),
+                "ASTC_8X8_SRGB_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_10X5_UNORM_BLOCK.as_raw(),
+                "ASTC_10X5_UNORM_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_10X5_SRGB_BLOCK.as_raw(),
+                "ASTC_10X5_SRGB_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_10X6_UNORM_BLOCK.as_raw(),
+                "ASTC_10X6_UNORM_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_10X6_SRGB_BLOCK.as_raw(),
+                "ASTC_10X6_SRGB_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_10X8_UNORM_BLOCK.as_raw(),
+                "ASTC_10X8_UNORM_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_10X8_SRGB_BLOCK.as_raw(),
+                "ASTC_10X8_SRGB_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_10X10_UNORM_BLOCK.as_raw(),
+                "ASTC_10X10_UNORM_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_10X10_SRGB_BLOCK.as_raw(),
+                "ASTC_10X10_SRGB_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_12X10_UNORM_BLOCK.as_raw(),
+                "ASTC_12X10_UNORM_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_12X10_SRGB_BLOCK.as_raw(),
+                "ASTC_12X10_SRGB_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_12X12_UNORM_BLOCK.as_raw(),
+                "ASTC_12X12_UNORM_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_12X12_SRGB_BLOCK.as_raw(),
+                "ASTC_12X12_SRGB_BLOCK",
+            ),
+            (
+                vk::Format::PVRTC1_2BPP_UNORM_BLOCK_IMG.as_raw(),
+                "PVRTC1_2BPP_UNORM_BLOCK_IMG",
+            ),
+            (
+                vk::Format::PVRTC1_4BPP_UNORM_BLOCK_IMG.as_raw(),
+                "PVRTC1_4BPP_UNORM_BLOCK_IMG",
+            ),
+            (
+                vk::Format::PVRTC2_2BPP_UNORM_BLOCK_IMG.as_raw(),
+                "PVRTC2_2BPP_UNORM_BLOCK_IMG",
+            ),
+            (
+                vk::Format::PVRTC2_4BPP_UNORM_BLOCK_IMG.as_raw(),
+                "PVRTC2_4BPP_UNORM_BLOCK_IMG",
+            ),
+            (
+                vk::Format::PVRTC1_2BPP_SRGB_BLOCK_IMG.as_raw(),
+                "PVRTC1_2BPP_SRGB_BLOCK_IMG",
+            ),
+            (
+                vk::Format::PVRTC1_4BPP_SRGB_BLOCK_IMG.as_raw(),
+                "PVRTC1_4BPP_SRGB_BLOCK_IMG",
+            ),
+            (
+                vk::Format::PVRTC2_2BPP_SRGB_BLOCK_IMG.as_raw(),
+                "PVRTC2_2BPP_SRGB_BLOCK_IMG",
+            ),
+            (
+                vk::Format::PVRTC2_4BPP_SRGB_BLOCK_IMG.as_raw(),
+                "PVRTC2_4BPP_SRGB_BLOCK_IMG",
+            ),
+            (vk::Format::R16G16_S10_5_NV.as_raw(), "R16G16_S10_5_NV"),
+            (
+                vk::Format::A1B5G5R5_UNORM_PACK16_KHR.as_raw(),
+                "A1B5G5R5_UNORM_PACK16_KHR",
+            ),
+            (vk::Format::A8_UNORM_KHR.as_raw(), "A8_UNORM_KHR"),
+            (
+                vk::Format::G8B8G8R8_422_UNORM.as_raw(),
+                "G8B8G8R8_422_UNORM",
+            ),
+            (
+                vk::Format::B8G8R8G8_422_UNORM.as_raw(),
+                "B8G8R8G8_422_UNORM",
+            ),
+            (
+                vk::Format::G8_B8_R8_3PLANE_420_UNORM.as_raw(),
+                "G8_B8_R8_3PLANE_420_UNORM",
+            ),
+            (
+                vk::Format::G8_B8R8_2PLANE_420_UNORM.as_raw(),
+                "G8_B8R8_2PLANE_420_UNORM",
+            ),
+            (
+                vk::Format::G8_B8_R8_3PLANE_422_UNORM.as_raw(),
+                "G8_B8_R8_3PLANE_422_UNORM",
+            ),
+            (
+                vk::Format::G8_B8R8_2PLANE_422_UNORM.as_raw(),
+                "G8_B8R8_2PLANE_422_UNORM",
+            ),
+            (
+                vk::Format::G8_B8_R8_3PLANE_444_UNORM.as_raw(),
+                "G8_B8_R8_3PLANE_444_UNORM",
+            ),
+            (
+                vk::Format::R10X6_UNORM_PACK16.as_raw(),
+                "R10X6_UNORM_PACK16",
+            ),
+            (
+                vk::Format::R10X6G10X6_UNORM_2PACK16.as_raw(),
+                "R10X6G10X6_UNORM_2PACK16",
+            ),
+            (
+                vk::Format::R10X6G10X6B10X6A10X6_UNORM_4PACK16.as_raw(),
+                "R10X6G10X6B10X6A10X6_UNORM_4PACK16",
+            ),
+            (
+                vk::Format::R12X4_UNORM_PACK16.as_raw(),
+                "R12X4_UNORM_PACK16",
+            ),
+            (
+                vk::Format::R12X4G12X4_UNORM_2PACK16.as_raw(),
+                "R12X4G12X4_UNORM_2PACK16",
+            ),
+            (
+                vk::Format::R12X4G12X4B12X4A12X4_UNORM_4PACK16.as_raw(),
+                "R12X4G12X4B12X4A12X4_UNORM_4PACK16",
+            ),
+            (
+                vk::Format::G16B16G16R16_422_UNORM.as_raw(),
+                "G16B16G16R16_422_UNORM",
+            ),
+            (
+                vk::Format::B16G16R16G16_422_UNORM.as_raw(),
+                "B16G16R16G16_422_UNORM",
+            ),
+            (
+                vk::Format::G16_B16_R16_3PLANE_420_UNORM.as_raw(),
+                "G16_B16_R16_3PLANE_420_UNORM",
+            ),
+            (
+                vk::Format::G16_B16R16_2PLANE_420_UNORM.as_raw(),
+                "G16_B16R16_2PLANE_420_UNORM",
+            ),
+            (
+                vk::Format::G16_B16_R16_3PLANE_422_UNORM.as_raw(),
+                "G16_B16_R16_3PLANE_422_UNORM",
+            ),
+            (
+                vk::Format::G16_B16R16_2PLANE_422_UNORM.as_raw(),
+                "G16_B16R16_2PLANE_422_UNORM",
+            ),
+            (
+                vk::Format::G16_B16_R16_3PLANE_444_UNORM.as_raw(),
+                "G16_B16_R16_3PLANE_444_UNORM",
+            ),
+            (
+                vk::Format::G8_B8R8_2PLANE_444_UNORM.as_raw(),
+                "G8_B8R8_2PLANE_444_UNORM",
+            ),
+            (
+                vk::Format::G16_B16R16_2PLANE_444_UNORM.as_raw(),
+                "G16_B16R16_2PLANE_444_UNORM",
+            ),
+            (
+                vk::Format::A4R4G4B4_UNORM_PACK16.as_raw(),
+                "A4R4G4B4_UNORM_PACK16",
+            ),
+            (
+                vk::Format::A4B4G4R4_UNORM_PACK16.as_raw(),
+                "A4B4G4R4_UNORM_PACK16",
+            ),
+            (
+                vk::Format::ASTC_4X4_SFLOAT_BLOCK.as_raw(),
+                "ASTC_4X4_SFLOAT_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_5X4_SFLOAT_BLOCK.as_raw(),
+                "ASTC_5X4_SFLOAT_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_5X5_SFLOAT_BLOCK.as_raw(),
+                "ASTC_5X5_SFLOAT_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_6X5_SFLOAT_BLOCK.as_raw(),
+                "ASTC_6X5_SFLOAT_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_6X6_SFLOAT_BLOCK.as_raw(),
+                "ASTC_6X6_SFLOAT_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_8X5_SFLOAT_BLOCK.as_raw(),
+                "ASTC_8X5_SFLOAT_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_8X6_SFLOAT_BLOCK.as_raw(),
+                "ASTC_8X6_SFLOAT_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_8X8_SFLOAT_BLOCK.as_raw(),
+                "ASTC_8X8_SFLOAT_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_10X5_SFLOAT_BLOCK.as_raw(),
+                "ASTC_10X5_SFLOAT_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_10X6_SFLOAT_BLOCK.as_raw(),
+                "ASTC_10X6_SFLOAT_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_10X8_SFLOAT_BLOCK.as_raw(),
+                "ASTC_10X8_SFLOAT_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_10X10_SFLOAT_BLOCK.as_raw(),
+                "ASTC_10X10_SFLOAT_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_12X10_SFLOAT_BLOCK.as_raw(),
+                "ASTC_12X10_SFLOAT_BLOCK",
+            ),
+            (
+                vk::Format::ASTC_12X12_SFLOAT_BLOCK.as_raw(),
+                "ASTC_12X12_SFLOAT_BLOCK",
+            ),
+        ],
+    },
+    ValueNames {
+        name: "ImageTiling",
+        prefix: "VK_IMAGE_TILING_",
+        known: &[
+            (vk::ImageTiling::OPTIMAL.as_raw(), "OPTIMAL"),
+            (vk::ImageTiling::LINEAR.as_raw(), "LINEAR"),
+            (
+                vk::ImageTiling::DRM_FORMAT_MODIFIER_EXT.as_raw(),
+                "DRM_FORMAT_MODIFIER_EXT",
+            ),
+        ],
+    },
+    ValueNames {
+        name: "ImageType",
+        prefix: "VK_IMAGE_TYPE_",
+        known: &[
+            (vk::ImageType::TYPE_1D.as_raw(), "TYPE_1D"),
+            (vk::ImageType::TYPE_2D.as_raw(), "TYPE_2D"),
+            (vk::ImageType::TYPE_3D.as_raw(), "TYPE_3D"),
+        ],
+    },
+    ValueNames {
+        name: "LayeredDriverUnderlyingApiMSFT",
+        prefix: "VK_LAYERED_DRIVER_UNDERLYING_API_",
+        known: &[
+            (vk::LayeredDriverUnderlyingApiMSFT::NONE.as_raw(), "NONE"),
+            (vk::LayeredDriverUnderlyingApiMSFT::D3D12.as_raw(), "D3D12"),
+        ],
+    },
+    ValueNames {
+        name: "PhysicalDeviceType",
+        prefix: "VK_PHYSICAL_DEVICE_TYPE_",
+        known: &[
+            (vk::PhysicalDeviceType::OTHER.as_raw(), "OTHER"),
+            (
+                vk::PhysicalDeviceType::INTEGRATED_GPU.as_raw(),
+                "INTEGRATED_GPU",
+            ),
+            (
+                vk::PhysicalDeviceType::DISCRETE_GPU.as_raw(),
+                "DISCRETE_GPU",
+            ),
+            (vk::PhysicalDeviceType::VIRTUAL_GPU.as_raw(), "VIRTUAL_GPU"),
+            (vk::PhysicalDeviceType::CPU.as_raw(), "CPU"),
+        ],
+    },
+    ValueNames {
+        name: "PipelineRobustnessBufferBehaviorEXT",
+        prefix: "VK_PIPELINE_ROBUSTNESS_BUFFER_BEHAVIOR_",
+        known: &[
+            (
+                vk::PipelineRobustnessBufferBehaviorEXT::DEVICE_DEFAULT.as_raw(),
+                "DEVICE_DEFAULT",
+            ),
+            (
+                vk::PipelineRobustnessBufferBehaviorEXT::DISABLED.as_raw(),
+                "DISABLED",
+            ),
+            (
+                vk::PipelineRobustnessBufferBehaviorEXT::ROBUST_BUFFER_ACCESS.as_raw(),
+                "ROBUST_BUFFER_ACCESS",
+            ),
+            (
+                vk::PipelineRobustnessBufferBehaviorEXT::ROBUST_BUFFER_ACCESS_2.as_raw(),
+                "ROBUST_BUFFER_ACCESS_2",
+            ),
+        ],
+    },
+    ValueNames {
+        name: "PipelineRobustnessImageBehaviorEXT",
+        prefix: "VK_PIPELINE_ROBUSTNESS_IMAGE_BEHAVIOR_",
+        known: &[
+            (
+                vk::PipelineRobustnessImageBehaviorEXT::DEVICE_DEFAULT.as_raw(),
+                "DEVICE_DEFAULT",
+            ),
+            (
+                vk::PipelineRobustnessImageBehaviorEXT::DISABLED.as_raw(),
+                "DISABLED",
+            ),
+            (
+                vk::PipelineRobustnessImageBehaviorEXT::ROBUST_IMAGE_ACCESS.as_raw(),
+                "ROBUST_IMAGE_ACCESS",
+            ),
+            (
+                vk::PipelineRobustnessImageBehaviorEXT::ROBUST_IMAGE_ACCESS_2.as_raw(),
+                "ROBUST_IMAGE_ACCESS_2",
+            ),
+        ],
+    },
+    ValueNames {
+        name: "PointClippingBehavior",
+        prefix: "VK_POINT_CLIPPING_BEHAVIOR_",
+        known: &[
+            (
+                vk::PointClippingBehavior::ALL_CLIP_PLANES.as_raw(),
+                "ALL_CLIP_PLANES",
+            ),
+            (
+                vk::PointClippingBehavior::USER_CLIP_PLANES_ONLY.as_raw(),
+                "USER_CLIP_PLANES_ONLY",
+            ),
+        ],
+    },
+    ValueNames {
+        name: "QueueGlobalPriorityKHR",
+        prefix: "VK_QUEUE_GLOBAL_PRIORITY_",
+        known: &[
+            (vk::QueueGlobalPriorityKHR::LOW.as_raw(), "LOW"),
+            (vk::QueueGlobalPriorityKHR::MEDIUM.as_raw(), "MEDIUM"),
+            (vk::QueueGlobalPriorityKHR::HIGH.as_raw(), "HIGH"),
+            (vk::QueueGlobalPriorityKHR::REALTIME.as_raw(), "REALTIME"),
+        ],
+    },
+    ValueNames {
+        name: "ShaderFloatControlsIndependence",
+        prefix: "VK_SHADER_FLOAT_CONTROLS_INDEPENDENCE_",
+        known: &[
+            (
+                vk::ShaderFloatControlsIndependence::TYPE_32_ONLY.as_raw(),
+                "TYPE_32_ONLY",
+            ),
+            (vk::ShaderFloatControlsIndependence::ALL.as_raw(), "ALL"),
+            (vk::ShaderFloatControlsIndependence::NONE.as_raw(), "NONE"),
+        ],
+    },
+    ValueNames {
+        name: "VideoEncodeTuningModeKHR",
+        prefix: "VK_VIDEO_ENCODE_TUNING_MODE_",
+        known: &[
+            (vk::VideoEncodeTuningModeKHR::DEFAULT.as_raw(), "DEFAULT"),
+            (
+                vk::VideoEncodeTuningModeKHR::HIGH_QUALITY.as_raw(),
+                "HIGH_QUALITY",
+            ),
+            (
+                vk::VideoEncodeTuningModeKHR::LOW_LATENCY.as_raw(),
+                "LOW_LATENCY",
+            ),
+            (
+                vk::VideoEncodeTuningModeKHR::ULTRA_LOW_LATENCY.as_raw(),
+                "ULTRA_LOW_LATENCY",
+            ),
+            (vk::VideoEncodeTuningModeKHR::LOSSLESS.as_raw(), "LOSSLESS"),
+        ],
+    },
+];
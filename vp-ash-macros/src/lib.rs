@@ -0,0 +1,124 @@
+//! # vp-ash-macros
+//! Compile-time profile embedding for `vp-ash`.
+//!
+//! `vp-ash` re-exports the macros with the `json` feature, use them through `vp-ash`.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, LitStr};
+use vp_ash_core::json::Generator;
+
+/// Embeds profile JSON and expands to the profile constants and `library()` of
+/// `vp_ash::json::generate`.
+///
+/// The path is relative to the directory of the crate's `Cargo.toml`, and its file name may
+/// contain `*` wildcards to include several files. The JSON is checked like
+/// `vp_ash::json::Library::add_json` when the crate is compiled, so an unknown structure, member
+/// or value is a compile error with its location in the JSON.
+///
+/// ```ignore
+/// mod profiles {
+///     vp_ash::include_profiles!("profiles/*.json");
+/// }
+///
+/// let library = profiles::library();
+/// let profile = profiles::VP_VPA_EXAMPLES_COMPUTE;
+/// ```
+#[proc_macro]
+pub fn include_profiles(input: TokenStream) -> TokenStream {
+    let pattern = parse_macro_input!(input as LitStr);
+
+    match include(&pattern.value()) {
+        Ok(module) => module
+            .parse()
+            .expect("the generated module is valid Rust tokens"),
+        Err(message) => syn::Error::new(pattern.span(), message)
+            .to_compile_error()
+            .into(),
+    }
+}
+
+/// Returns the generated module of the JSON matching `pattern`.
+fn include(pattern: &str) -> Result<String, String> {
+    let manifest_dir = std::env::var_os("CARGO_MANIFEST_DIR")
+        .ok_or_else(|| String::from("`CARGO_MANIFEST_DIR` is not set"))?;
+
+    let paths = find_files(&Path::new(&manifest_dir).join(pattern))?;
+    if paths.is_empty() {
+        return Err(format!("no files match `{pattern}`"));
+    }
+
+    let mut generator = Generator::new();
+    for path in &paths {
+        let json = fs::read_to_string(path)
+            .map_err(|err| format!("failed to read `{}`: {err}", path.display()))?;
+        let included = path
+            .to_str()
+            .ok_or_else(|| format!("`{}` is not valid UTF-8", path.display()))?;
+
+        generator
+            .add_included_json(&json, included)
+            .map_err(|err| format!("invalid profile JSON `{}`: {err}", path.display()))?;
+    }
+
+    Ok(generator.generate())
+}
+
+/// Returns the files matching `pattern`, sorted so the module is stable across file systems.
+fn find_files(pattern: &Path) -> Result<Vec<PathBuf>, String> {
+    let Some(file_pattern) = pattern.file_name().and_then(|name| name.to_str()) else {
+        return Err(format!("`{}` is not a file pattern", pattern.display()));
+    };
+    if !file_pattern.contains('*') {
+        return Ok(vec![pattern.to_path_buf()]);
+    }
+
+    let dir = pattern.parent().unwrap_or(Path::new("."));
+    if dir.to_string_lossy().contains('*') {
+        return Err(String::from("only the file name may contain `*`"));
+    }
+
+    let entries =
+        fs::read_dir(dir).map_err(|err| format!("failed to read `{}`: {err}", dir.display()))?;
+    let mut paths = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|err| format!("failed to read `{}`: {err}", dir.display()))?
+            .path();
+        let is_match = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| wildcard_match(file_pattern, name));
+
+        if is_match && path.is_file() {
+            paths.push(path);
+        }
+    }
+    paths.sort();
+
+    Ok(paths)
+}
+
+/// Returns whether `name` matches `pattern`, where `*` matches any sequence of characters.
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let first = parts.next().unwrap_or_default();
+    let Some(mut rest) = name.strip_prefix(first) else {
+        return false;
+    };
+
+    let mut parts: Vec<&str> = parts.collect();
+    let last = parts.pop().unwrap_or_default();
+    for part in parts {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+
+    rest.len() >= last.len() && rest.ends_with(last)
+}